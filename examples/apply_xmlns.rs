@@ -81,8 +81,8 @@ fn main() {
     let doc3 = parse_html().one(html_no_ns);
 
     let strict_options = NsOptions {
-        namespaces: HashMap::new(),
         strict: true,
+        ..NsOptions::default()
     };
 
     match doc3.apply_xmlns_opts(&strict_options) {
@@ -122,6 +122,7 @@ fn main() {
     let options_with_svg = NsOptions {
         namespaces,
         strict: true, // Strict mode - will error on undefined 'c' prefix
+        ..NsOptions::default()
     };
 
     match doc4.apply_xmlns_opts(&options_with_svg) {
@@ -0,0 +1,45 @@
+/*
+ * C declarations for brik, built with the `ffi` feature.
+ *
+ * Handles returned by brik are owned by the caller and must be freed with
+ * the matching free function. Each handle keeps its whole tree alive, so
+ * handles may be freed in any order. Strings passed in must be NUL-terminated
+ * UTF-8; functions return NULL or false when they are not.
+ */
+
+#ifndef BRIK_H
+#define BRIK_H
+
+#include <stdbool.h>
+#include <stddef.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+typedef struct BrikNode BrikNode;
+typedef struct BrikNodeList BrikNodeList;
+
+BrikNode *brik_parse_html(const char *html);
+BrikNode *brik_parse_fragment(const char *html);
+void brik_node_free(BrikNode *node);
+
+BrikNodeList *brik_select(const BrikNode *node, const char *selectors);
+BrikNode *brik_select_first(const BrikNode *node, const char *selectors);
+size_t brik_node_list_len(const BrikNodeList *list);
+BrikNode *brik_node_list_get(const BrikNodeList *list, size_t index);
+void brik_node_list_free(BrikNodeList *list);
+
+char *brik_node_get_attribute(const BrikNode *node, const char *name);
+bool brik_node_set_attribute(const BrikNode *node, const char *name, const char *value);
+bool brik_node_remove_attribute(const BrikNode *node, const char *name);
+
+char *brik_node_serialize(const BrikNode *node);
+char *brik_node_text_contents(const BrikNode *node);
+void brik_string_free(char *value);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* BRIK_H */
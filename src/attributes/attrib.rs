@@ -1,3 +1,4 @@
+use html5ever::tendril::StrTendril;
 use html5ever::Prefix;
 
 /// The non-identifying parts of an attribute
@@ -6,5 +7,12 @@ pub struct Attribute {
     /// The namespace prefix, if any
     pub prefix: Option<Prefix>,
     /// The attribute value
-    pub value: String,
+    pub value: StrTendril,
+    /// The attribute name as written in the source, before HTML case folding
+    ///
+    /// This is only recorded when parsing with
+    /// [`ParseOpts::preserve_attribute_names`](crate::ParseOpts::preserve_attribute_names),
+    /// and written instead of the folded name when serializing with
+    /// [`SerializeOptions::source_attribute_names`](crate::SerializeOptions::source_attribute_names).
+    pub source_name: Option<String>,
 }
@@ -1,3 +1,4 @@
+use html5ever::tendril::StrTendril;
 use html5ever::LocalName;
 #[cfg(feature = "namespaces")]
 use html5ever::{Namespace, Prefix};
@@ -26,7 +27,7 @@ impl Attributes {
     }
 
     /// Like IndexMap::get_mut
-    pub fn get_mut<A: Into<LocalName>>(&mut self, local_name: A) -> Option<&mut String> {
+    pub fn get_mut<A: Into<LocalName>>(&mut self, local_name: A) -> Option<&mut StrTendril> {
         self.map
             .get_mut(&ExpandedName::new(ns!(), local_name))
             .map(|attr| &mut attr.value)
@@ -41,16 +42,17 @@ impl Attributes {
     }
 
     /// Like IndexMap::insert
-    pub fn insert<A: Into<LocalName>>(
+    pub fn insert<A: Into<LocalName>, V: Into<StrTendril>>(
         &mut self,
         local_name: A,
-        value: String,
+        value: V,
     ) -> Option<Attribute> {
         self.map.insert(
             ExpandedName::new(ns!(), local_name),
             Attribute {
                 prefix: None,
-                value,
+                value: value.into(),
+                source_name: None,
             },
         )
     }
@@ -60,6 +62,187 @@ impl Attributes {
         self.map.swap_remove(&ExpandedName::new(ns!(), local_name))
     }
 
+    /// Inserts many attributes in the null namespace, overwriting existing values.
+    ///
+    /// Existing attributes keep their position; new ones are appended in
+    /// iteration order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::parse_html;
+    /// use brik::traits::*;
+    ///
+    /// let doc = parse_html().one("<img src='a.png'>");
+    /// let img = doc.select_first("img").unwrap();
+    /// img.attributes
+    ///     .borrow_mut()
+    ///     .extend_from([("src", "b.png"), ("loading", "lazy")]);
+    /// assert_eq!(img.attributes.borrow().get("src"), Some("b.png"));
+    /// assert_eq!(img.attributes.borrow().get("loading"), Some("lazy"));
+    /// ```
+    pub fn extend_from<I, A, V>(&mut self, attributes: I)
+    where
+        I: IntoIterator<Item = (A, V)>,
+        A: Into<LocalName>,
+        V: Into<StrTendril>,
+    {
+        for (local_name, value) in attributes {
+            self.insert(local_name, value);
+        }
+    }
+
+    /// Inserts many attributes in the null namespace, keeping existing values.
+    ///
+    /// Only attributes that are not already present are added, which suits
+    /// applying defaults.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::parse_html;
+    /// use brik::traits::*;
+    ///
+    /// let doc = parse_html().one("<img src='a.png' loading='eager'>");
+    /// let img = doc.select_first("img").unwrap();
+    /// img.attributes
+    ///     .borrow_mut()
+    ///     .extend_missing_from([("loading", "lazy"), ("decoding", "async")]);
+    /// assert_eq!(img.attributes.borrow().get("loading"), Some("eager"));
+    /// assert_eq!(img.attributes.borrow().get("decoding"), Some("async"));
+    /// ```
+    pub fn extend_missing_from<I, A, V>(&mut self, attributes: I)
+    where
+        I: IntoIterator<Item = (A, V)>,
+        A: Into<LocalName>,
+        V: Into<StrTendril>,
+    {
+        for (local_name, value) in attributes {
+            if let Entry::Vacant(entry) = self.entry(local_name) {
+                entry.insert(Attribute {
+                    prefix: None,
+                    value: value.into(),
+                    source_name: None,
+                });
+            }
+        }
+    }
+
+    /// Like IndexMap::retain
+    ///
+    /// Keeps only the attributes for which the predicate returns true,
+    /// preserving the order of the remaining attributes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::parse_html;
+    /// use brik::traits::*;
+    ///
+    /// let doc = parse_html().one("<div id='a' onclick='x()' onload='y()'></div>");
+    /// let div = doc.select_first("div").unwrap();
+    /// div.attributes
+    ///     .borrow_mut()
+    ///     .retain(|name, _| !name.local.starts_with("on"));
+    /// assert_eq!(div.attributes.borrow().map.len(), 1);
+    /// ```
+    pub fn retain<F>(&mut self, predicate: F)
+    where
+        F: FnMut(&ExpandedName, &mut Attribute) -> bool,
+    {
+        self.map.retain(predicate);
+    }
+
+    /// Returns true if a boolean attribute is present.
+    ///
+    /// HTML boolean attributes such as `disabled` are true when present,
+    /// whatever their value, including `disabled="false"`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::parse_html;
+    /// use brik::traits::*;
+    ///
+    /// let doc = parse_html().one(r#"<input disabled="false">"#);
+    /// let input = doc.select_first("input").unwrap();
+    /// let attrs = input.attributes.borrow();
+    /// assert!(attrs.get_bool("disabled"));
+    /// assert!(!attrs.get_bool("required"));
+    /// ```
+    #[inline]
+    pub fn get_bool<A: Into<LocalName>>(&self, local_name: A) -> bool {
+        self.contains(local_name)
+    }
+
+    /// Returns an attribute value parsed as a non-negative integer.
+    ///
+    /// Follows the HTML rules for parsing non-negative integers: leading ASCII
+    /// whitespace and a `+` sign are allowed, and parsing stops at the first
+    /// non-digit, so `" 3px"` yields 3. Returns `None` if the attribute is
+    /// absent, has no leading digits, or overflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::parse_html;
+    /// use brik::traits::*;
+    ///
+    /// let doc = parse_html().one(r#"<table><tr><td colspan=" 2 " rowspan="x">A</td></tr></table>"#);
+    /// let td = doc.select_first("td").unwrap();
+    /// let attrs = td.attributes.borrow();
+    /// assert_eq!(attrs.get_u64("colspan"), Some(2));
+    /// assert_eq!(attrs.get_u64("rowspan"), None);
+    /// ```
+    pub fn get_u64<A: Into<LocalName>>(&self, local_name: A) -> Option<u64> {
+        let value = self.get_trimmed(local_name)?;
+        let value = value.strip_prefix('+').unwrap_or(value);
+        let end = value
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(value.len());
+        value[..end].parse().ok()
+    }
+
+    /// Returns an attribute value with leading and trailing ASCII whitespace removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::parse_html;
+    /// use brik::traits::*;
+    ///
+    /// let doc = parse_html().one("<a href='\n /about '>About</a>");
+    /// let a = doc.select_first("a").unwrap();
+    /// assert_eq!(a.attributes.borrow().get_trimmed("href"), Some("/about"));
+    /// ```
+    pub fn get_trimmed<A: Into<LocalName>>(&self, local_name: A) -> Option<&str> {
+        self.get(local_name)
+            .map(|value| value.trim_matches(|c: char| c.is_ascii_whitespace()))
+    }
+
+    /// Returns an iterator over the whitespace-separated tokens of an attribute.
+    ///
+    /// This suits token list attributes such as `class` and `rel`. The iterator
+    /// is empty if the attribute is absent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::parse_html;
+    /// use brik::traits::*;
+    ///
+    /// let doc = parse_html().one("<a rel=' noopener\tnoreferrer '>Link</a>");
+    /// let a = doc.select_first("a").unwrap();
+    /// let attrs = a.attributes.borrow();
+    /// let tokens: Vec<&str> = attrs.get_tokens("rel").collect();
+    /// assert_eq!(tokens, vec!["noopener", "noreferrer"]);
+    /// ```
+    pub fn get_tokens<A: Into<LocalName>>(&self, local_name: A) -> impl Iterator<Item = &str> {
+        self.get(local_name)
+            .unwrap_or_default()
+            .split_ascii_whitespace()
+    }
+
     /// Returns the value of an attribute in a specific namespace.
     ///
     /// Similar to DOM's `getAttributeNS()`.
@@ -158,20 +341,25 @@ impl Attributes {
     /// }
     /// ```
     #[cfg(feature = "namespaces")]
-    pub fn insert_ns<N, L>(
+    pub fn insert_ns<N, L, V>(
         &mut self,
         namespace: N,
         local_name: L,
         prefix: Option<Prefix>,
-        value: String,
+        value: V,
     ) -> Option<Attribute>
     where
         N: Into<Namespace>,
         L: Into<LocalName>,
+        V: Into<StrTendril>,
     {
         self.map.insert(
             ExpandedName::new(namespace, local_name),
-            Attribute { prefix, value },
+            Attribute {
+                prefix,
+                value: value.into(),
+                source_name: None,
+            },
         )
     }
 
@@ -243,7 +431,7 @@ impl Attributes {
         let ns = namespace.into();
         self.map.iter().filter_map(move |(name, attr)| {
             if name.ns == ns {
-                Some((&name.local, attr.value.as_str()))
+                Some((&name.local, &*attr.value))
             } else {
                 None
             }
@@ -305,7 +493,7 @@ impl Attributes {
             .map
             .iter()
             .filter_map(|(name, attr)| {
-                if name.ns == xmlns_ns && attr.value == namespace_uri {
+                if name.ns == xmlns_ns && &*attr.value == namespace_uri {
                     Some(name.local.clone())
                 } else {
                     None
@@ -326,6 +514,106 @@ mod tests {
     use crate::parser::parse_html;
     use crate::traits::*;
 
+    /// Tests bulk insertion with and without overwriting.
+    ///
+    /// Verifies that extend_from() overwrites in place, that
+    /// extend_missing_from() keeps existing values, and that new attributes
+    /// are appended in order.
+    #[test]
+    fn extend_from_and_missing() {
+        let doc = parse_html().one(r#"<p a="1" b="2">Text</p>"#);
+        let p = doc.select_first("p").unwrap();
+        let mut attrs = p.attributes.borrow_mut();
+
+        attrs.extend_from(vec![("b", "3".to_string()), ("c", "4".to_string())]);
+        attrs.extend_missing_from([("a", "5"), ("d", "6")]);
+
+        let pairs: Vec<(&str, &str)> = attrs
+            .map
+            .iter()
+            .map(|(name, attr)| (&*name.local, &*attr.value))
+            .collect();
+        assert_eq!(pairs, vec![("a", "1"), ("b", "3"), ("c", "4"), ("d", "6")]);
+    }
+
+    /// Tests retain() with a predicate that also edits values.
+    ///
+    /// Verifies that removed attributes are dropped, kept ones keep their
+    /// order, and mutations made by the predicate persist.
+    #[test]
+    fn retain_attributes() {
+        let doc = parse_html().one(r#"<p a="1" data-x="2" b="3">Text</p>"#);
+        let p = doc.select_first("p").unwrap();
+        let mut attrs = p.attributes.borrow_mut();
+
+        attrs.retain(|name, attr| {
+            attr.value.push_char('!');
+            !name.local.starts_with("data-")
+        });
+
+        assert_eq!(attrs.map.len(), 2);
+        assert_eq!(attrs.get("a"), Some("1!"));
+        assert_eq!(attrs.get("b"), Some("3!"));
+        assert_eq!(attrs.map.get_index(1).unwrap().0.local.as_ref(), "b");
+    }
+
+    /// Tests the typed value accessors.
+    ///
+    /// Verifies presence semantics, integer parsing, trimming, and token
+    /// splitting on a single element.
+    #[test]
+    fn typed_accessors() {
+        let doc = parse_html().one(
+            r#"<table><tr><td hidden colspan="+3abc" width="" class="  a  b
+c " title=" x ">A</td></tr></table>"#,
+        );
+        let td = doc.select_first("td").unwrap();
+        let attrs = td.attributes.borrow();
+
+        assert!(attrs.get_bool("hidden"));
+        assert_eq!(attrs.get_u64("colspan"), Some(3));
+        assert_eq!(attrs.get_u64("width"), None);
+        assert_eq!(attrs.get_trimmed("title"), Some("x"));
+        assert_eq!(
+            attrs.get_tokens("class").collect::<Vec<_>>(),
+            vec!["a", "b", "c"]
+        );
+    }
+
+    /// Tests the typed value accessors on absent attributes.
+    ///
+    /// Edge case: every accessor must report absence rather than a default
+    /// value, except get_tokens() which yields nothing.
+    #[test]
+    fn typed_accessors_absent() {
+        let doc = parse_html().one("<p>Text</p>");
+        let p = doc.select_first("p").unwrap();
+        let attrs = p.attributes.borrow();
+
+        assert!(!attrs.get_bool("hidden"));
+        assert_eq!(attrs.get_u64("colspan"), None);
+        assert_eq!(attrs.get_trimmed("title"), None);
+        assert_eq!(attrs.get_tokens("class").count(), 0);
+        assert_eq!(
+            Attributes {
+                map: Default::default()
+            }
+            .get_u64("x"),
+            None
+        );
+    }
+
+    /// Tests integer overflow in get_u64().
+    ///
+    /// Edge case: a value too large for u64 is rejected rather than wrapped.
+    #[test]
+    fn get_u64_overflow() {
+        let doc = parse_html()
+            .one(r#"<table><tr><td colspan="99999999999999999999999">A</td></tr></table>"#);
+        let td = doc.select_first("td").unwrap();
+        assert_eq!(td.attributes.borrow().get_u64("colspan"), None);
+    }
+
     /// Tests that `get_ns()` retrieves attributes from the null namespace.
     ///
     /// Regular HTML attributes (class, id, etc.) are in the null namespace.
@@ -422,7 +710,7 @@ mod tests {
 
         let old = attrs.insert_ns(ns!(), "test", None, "second".to_string());
 
-        assert_eq!(old.as_ref().map(|a| a.value.as_str()), Some("first"));
+        assert_eq!(old.as_ref().map(|a| &*a.value), Some("first"));
         assert_eq!(attrs.get_ns(ns!(), "test"), Some("second"));
     }
 
@@ -440,7 +728,7 @@ mod tests {
         assert!(attrs.has_ns(ns!(), "class"));
 
         let removed = attrs.remove_ns(ns!(), "class");
-        assert_eq!(removed.as_ref().map(|a| a.value.as_str()), Some("test"));
+        assert_eq!(removed.as_ref().map(|a| &*a.value), Some("test"));
 
         assert!(!attrs.has_ns(ns!(), "class"));
         assert_eq!(attrs.get_ns(ns!(), "class"), None);
@@ -678,7 +966,7 @@ mod tests {
         let mut attrs = div.attributes.borrow_mut();
 
         if let Some(value) = attrs.get_mut("class") {
-            *value = "new".to_string();
+            *value = "new".into();
         }
 
         assert_eq!(attrs.get("class"), Some("new"));
@@ -709,7 +997,8 @@ mod tests {
 
         attrs.entry("class").or_insert(Attribute {
             prefix: None,
-            value: "test".to_string(),
+            value: "test".into(),
+            source_name: None,
         });
 
         assert_eq!(attrs.get("class"), Some("test"));
@@ -727,7 +1016,8 @@ mod tests {
 
         attrs.entry("class").or_insert(Attribute {
             prefix: None,
-            value: "new".to_string(),
+            value: "new".into(),
+            source_name: None,
         });
 
         // Should keep existing value
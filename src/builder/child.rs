@@ -0,0 +1,11 @@
+use super::Element;
+use crate::tree::NodeRef;
+
+/// A child of an [`Element`] under construction.
+#[derive(Debug, Clone)]
+pub(super) enum Child {
+    /// An element still to be built, so it can inherit its parent's namespace.
+    Element(Element),
+    /// An existing node, appended as is.
+    Node(NodeRef),
+}
@@ -0,0 +1,232 @@
+use html5ever::{LocalName, Namespace, QualName};
+
+use super::child::Child;
+use super::ElementChild;
+use crate::attributes::{Attribute, ExpandedName};
+use crate::tree::NodeRef;
+
+/// A fluent builder for element nodes.
+///
+/// Elements default to the namespace the HTML parser would give them: `svg`
+/// and `math` elements start the SVG and MathML namespaces, other elements
+/// inherit the namespace of their parent (switching back to HTML inside SVG
+/// `foreignObject`), and top-level elements are HTML.
+///
+/// # Examples
+///
+/// ```
+/// use brik::builder::Element;
+///
+/// let div = Element::new("div")
+///     .attr("class", "x")
+///     .child(Element::new("span").text("hi"))
+///     .build();
+/// assert_eq!(div.to_string(), r#"<div class="x"><span>hi</span></div>"#);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Element {
+    /// The local name of the element.
+    local: LocalName,
+    /// The namespace, or `None` to use the default for its position.
+    ns: Option<Namespace>,
+    /// Attributes in the null namespace, in insertion order.
+    attributes: Vec<(LocalName, String)>,
+    /// Children, in insertion order.
+    children: Vec<Child>,
+}
+
+/// Methods for Element.
+///
+/// Provides the fluent setters and conversion into a node tree.
+impl Element {
+    /// Starts building an element with the given local name.
+    pub fn new<L: Into<LocalName>>(local_name: L) -> Self {
+        Element {
+            local: local_name.into(),
+            ns: None,
+            attributes: Vec::new(),
+            children: Vec::new(),
+        }
+    }
+
+    /// Sets the namespace explicitly instead of using the default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::builder::Element;
+    /// use brik::Namespace;
+    ///
+    /// let node = Element::new("item").ns(Namespace::from("urn:example")).build();
+    /// assert_eq!(node.as_element().unwrap().name.ns.as_ref(), "urn:example");
+    /// ```
+    pub fn ns<N: Into<Namespace>>(mut self, namespace: N) -> Self {
+        self.ns = Some(namespace.into());
+        self
+    }
+
+    /// Sets an attribute, replacing any previous value of the same name.
+    pub fn attr<L: Into<LocalName>, V: Into<String>>(mut self, name: L, value: V) -> Self {
+        let name = name.into();
+        let value = value.into();
+        match self.attributes.iter_mut().find(|(n, _)| *n == name) {
+            Some(existing) => existing.1 = value,
+            None => self.attributes.push((name, value)),
+        }
+        self
+    }
+
+    /// Appends a child element or node.
+    ///
+    /// Existing nodes are detached from their current position when built.
+    pub fn child<C: Into<ElementChild>>(mut self, child: C) -> Self {
+        self.children.push(child.into().0);
+        self
+    }
+
+    /// Appends several children.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::builder::Element;
+    ///
+    /// let list = Element::new("ul")
+    ///     .children(["a", "b"].iter().map(|item| Element::new("li").text(*item)))
+    ///     .build();
+    /// assert_eq!(list.to_string(), "<ul><li>a</li><li>b</li></ul>");
+    /// ```
+    pub fn children<I>(mut self, children: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<ElementChild>,
+    {
+        self.children
+            .extend(children.into_iter().map(|child| child.into().0));
+        self
+    }
+
+    /// Appends a text child.
+    pub fn text<T: Into<String>>(self, text: T) -> Self {
+        let text: String = text.into();
+        self.child(NodeRef::new_text(text))
+    }
+
+    /// Builds the element and its descendants into a new node tree.
+    pub fn build(self) -> NodeRef {
+        self.build_in(&ns!(html), false)
+    }
+
+    /// Builds the element with the namespace context of its parent.
+    fn build_in(self, parent_ns: &Namespace, parent_is_foreign_object: bool) -> NodeRef {
+        let ns = match self.ns {
+            Some(ns) => ns,
+            None if self.local == local_name!("svg") => ns!(svg),
+            None if self.local == local_name!("math") => ns!(mathml),
+            None if parent_is_foreign_object => ns!(html),
+            None => parent_ns.clone(),
+        };
+        let is_foreign_object = ns == ns!(svg) && self.local == local_name!("foreignObject");
+
+        let attributes = self.attributes.into_iter().map(|(name, value)| {
+            (
+                ExpandedName::new(ns!(), name),
+                Attribute {
+                    prefix: None,
+                    value: value.into(),
+                    source_name: None,
+                },
+            )
+        });
+        let node = NodeRef::new_element(QualName::new(None, ns.clone(), self.local), attributes);
+
+        // Children of a template element belong to its contents fragment.
+        let container = node
+            .as_element()
+            .and_then(|element| element.template_contents.clone())
+            .unwrap_or_else(|| node.clone());
+        for child in self.children {
+            container.append(match child {
+                Child::Element(element) => element.build_in(&ns, is_foreign_object),
+                Child::Node(node) => node,
+            });
+        }
+        node
+    }
+}
+
+/// Implements From<Element> for NodeRef.
+///
+/// Builds the element, so builders can be passed where nodes are expected.
+impl From<Element> for NodeRef {
+    fn from(element: Element) -> NodeRef {
+        element.build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::traits::*;
+
+    /// Tests namespace defaults for foreign content.
+    ///
+    /// Verifies that svg starts the SVG namespace, its descendants inherit it,
+    /// and foreignObject switches children back to HTML.
+    #[test]
+    fn namespace_defaults() {
+        let svg = Element::new("svg")
+            .child(Element::new("rect"))
+            .child(Element::new("foreignObject").child(Element::new("p")))
+            .build();
+
+        let ns_of = |name: &str| {
+            svg.inclusive_descendants()
+                .elements()
+                .find(|e| &*e.name.local == name)
+                .unwrap()
+                .name
+                .ns
+                .clone()
+        };
+        assert_eq!(ns_of("svg"), ns!(svg));
+        assert_eq!(ns_of("rect"), ns!(svg));
+        assert_eq!(ns_of("foreignObject"), ns!(svg));
+        assert_eq!(ns_of("p"), ns!(html));
+    }
+
+    /// Tests attribute replacement and existing node children.
+    ///
+    /// Verifies that setting an attribute twice keeps one value in its first
+    /// position, and that existing nodes are moved into the new tree.
+    #[test]
+    fn attributes_and_nodes() {
+        let existing = Element::new("b").text("bold").build();
+        let p = Element::new("p")
+            .attr("id", "a")
+            .attr("class", "c")
+            .attr("id", "b")
+            .child(existing.clone())
+            .build();
+        assert_eq!(p.to_string(), r#"<p id="b" class="c"><b>bold</b></p>"#);
+        assert_eq!(existing.parent().unwrap(), p);
+    }
+
+    /// Tests building a template element.
+    ///
+    /// Edge case: template children must go into the template contents
+    /// fragment, as the parser puts them.
+    #[test]
+    fn template_contents() {
+        let template = Element::new("template").child(Element::new("li")).build();
+        assert!(template.first_child().is_none());
+        let contents = template
+            .as_element()
+            .unwrap()
+            .template_contents
+            .clone()
+            .unwrap();
+        assert_eq!(contents.children().count(), 1);
+        assert_eq!(template.to_string(), "<template><li></li></template>");
+    }
+}
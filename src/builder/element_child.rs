@@ -0,0 +1,44 @@
+use super::child::Child;
+use super::Element;
+use crate::tree::NodeRef;
+
+/// A value accepted by [`Element::child`]: an [`Element`], an existing [`NodeRef`],
+/// or a string, which becomes a text node.
+#[derive(Debug, Clone)]
+pub struct ElementChild(pub(super) Child);
+
+/// Implements From<Element> for ElementChild.
+///
+/// Element children are built with their parent's namespace context.
+impl From<Element> for ElementChild {
+    fn from(element: Element) -> ElementChild {
+        ElementChild(Child::Element(element))
+    }
+}
+
+/// Implements From<NodeRef> for ElementChild.
+///
+/// Existing nodes are appended as they are.
+impl From<NodeRef> for ElementChild {
+    fn from(node: NodeRef) -> ElementChild {
+        ElementChild(Child::Node(node))
+    }
+}
+
+/// Implements From<String> for ElementChild.
+///
+/// Strings become text nodes.
+impl From<String> for ElementChild {
+    fn from(text: String) -> ElementChild {
+        ElementChild(Child::Node(NodeRef::new_text(text)))
+    }
+}
+
+/// Implements From<&str> for ElementChild.
+///
+/// Strings become text nodes.
+impl From<&str> for ElementChild {
+    fn from(text: &str) -> ElementChild {
+        ElementChild(Child::Node(NodeRef::new_text(text)))
+    }
+}
@@ -0,0 +1,14 @@
+//! Fluent construction of node trees.
+//!
+//! [`Element`] builds elements with attributes and children without spelling
+//! out qualified names, and picks namespaces the way the HTML parser does.
+
+/// A child of an element under construction.
+mod child;
+/// Element builder.
+mod element;
+/// Values accepted as element builder children.
+mod element_child;
+
+pub use element::Element;
+pub use element_child::ElementChild;
@@ -0,0 +1,48 @@
+/// Asserts that two HTML strings parse to equivalent documents.
+///
+/// Both sides are parsed as documents and compared with
+/// [`first_difference()`](crate::compare::first_difference). By default,
+/// insignificant whitespace and attribute order are ignored; pass
+/// [`CompareOptions`](crate::compare::CompareOptions) as a third argument to
+/// choose otherwise.
+///
+/// On mismatch, this panics with the first difference and a line diff of the
+/// two trees as rendered by [`NodeRef::debug_tree()`](crate::NodeRef::debug_tree).
+///
+/// **Note:** This macro requires the `testing` feature to be enabled.
+///
+/// # Examples
+///
+/// ```
+/// use brik::assert_html_eq;
+/// use brik::compare::CompareOptions;
+///
+/// assert_html_eq!("<p b=2 a=1>\n  Hi\n</p>", "<p a=1 b=2>Hi</p>");
+///
+/// let options = CompareOptions {
+///     ignore_comments: true,
+///     ..CompareOptions::default()
+/// };
+/// assert_html_eq!("<p>Hi<!-- note --></p>", "<p>Hi</p>", options);
+/// ```
+#[macro_export]
+macro_rules! assert_html_eq {
+    ($actual:expr, $expected:expr $(,)?) => {
+        $crate::compare::assert_html_eq_with(
+            ::std::convert::AsRef::<str>::as_ref(&$actual),
+            ::std::convert::AsRef::<str>::as_ref(&$expected),
+            &$crate::compare::CompareOptions {
+                ignore_whitespace: true,
+                ignore_attribute_order: true,
+                ..::std::default::Default::default()
+            },
+        )
+    };
+    ($actual:expr, $expected:expr, $options:expr $(,)?) => {
+        $crate::compare::assert_html_eq_with(
+            ::std::convert::AsRef::<str>::as_ref(&$actual),
+            ::std::convert::AsRef::<str>::as_ref(&$expected),
+            &$options,
+        )
+    };
+}
@@ -0,0 +1,100 @@
+use html5ever::tendril::TendrilSink;
+
+use super::{first_difference, CompareOptions};
+use crate::parse_html;
+
+/// Asserts that two HTML strings parse to equivalent documents under the given options.
+///
+/// This is the function behind the [`assert_html_eq!`](crate::assert_html_eq)
+/// macro, which is usually more convenient.
+///
+/// **Note:** This function requires the `testing` feature to be enabled.
+///
+/// # Panics
+///
+/// Panics if the documents differ, with the first difference and a line diff
+/// of the two trees.
+#[track_caller]
+pub fn assert_html_eq_with(actual: &str, expected: &str, options: &CompareOptions) {
+    let actual = parse_html().one(actual);
+    let expected = parse_html().one(expected);
+    if let Some(difference) = first_difference(&expected, &actual, options) {
+        panic!(
+            "HTML trees differ at {}\n\n--- expected\n+++ actual\n{}",
+            difference,
+            line_diff(&expected.debug_tree(), &actual.debug_tree())
+        );
+    }
+}
+
+/// Renders a line diff, prefixing lines with `-` when only in `expected`,
+/// `+` when only in `actual`, and a space when in both.
+fn line_diff(expected: &str, actual: &str) -> String {
+    let expected: Vec<&str> = expected.lines().collect();
+    let actual: Vec<&str> = actual.lines().collect();
+
+    // Longest common subsequence lengths of each pair of suffixes.
+    let mut lengths = vec![vec![0usize; actual.len() + 1]; expected.len() + 1];
+    for i in (0..expected.len()).rev() {
+        for j in (0..actual.len()).rev() {
+            lengths[i][j] = if expected[i] == actual[j] {
+                lengths[i + 1][j + 1] + 1
+            } else {
+                lengths[i + 1][j].max(lengths[i][j + 1])
+            };
+        }
+    }
+
+    let mut diff = String::new();
+    let (mut i, mut j) = (0, 0);
+    while i < expected.len() || j < actual.len() {
+        if i < expected.len() && j < actual.len() && expected[i] == actual[j] {
+            diff.push_str(&format!("  {}\n", expected[i]));
+            i += 1;
+            j += 1;
+        } else if j < actual.len()
+            && (i == expected.len() || lengths[i][j + 1] >= lengths[i + 1][j])
+        {
+            diff.push_str(&format!("+ {}\n", actual[j]));
+            j += 1;
+        } else {
+            diff.push_str(&format!("- {}\n", expected[i]));
+            i += 1;
+        }
+    }
+    diff
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Tests the assert_html_eq! macro with equivalent documents.
+    ///
+    /// Verifies that whitespace and attribute order are ignored by default,
+    /// and that String and &str arguments are accepted.
+    #[test]
+    fn equivalent() {
+        crate::assert_html_eq!(
+            String::from("<div b=2 a=1>\n  <p>x</p>\n</div>"),
+            "<div a=1 b=2><p>x</p></div>"
+        );
+    }
+
+    /// Tests the assert_html_eq! macro with different documents.
+    ///
+    /// Verifies that the panic message names the first difference.
+    #[test]
+    #[should_panic(expected = "HTML trees differ at /html[1]/body[1]/p[1]/text()[1]")]
+    fn different() {
+        crate::assert_html_eq!("<p>actual</p>", "<p>expected</p>");
+    }
+
+    /// Tests the line diff in failure messages.
+    ///
+    /// Verifies that shared lines are kept and changed lines are marked.
+    #[test]
+    fn diff() {
+        assert_eq!(line_diff("a\nb\nc\n", "a\nx\nc\n"), "  a\n+ x\n- b\n  c\n");
+    }
+}
@@ -0,0 +1,22 @@
+/// Options for [`first_difference()`](super::first_difference).
+///
+/// The defaults compare trees strictly: every text node, comment and
+/// attribute must match, with attributes in the same order.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CompareOptions {
+    /// Whether to ignore insignificant whitespace.
+    ///
+    /// Whitespace-only text is skipped, and other text is compared with
+    /// leading and trailing whitespace trimmed and inner runs collapsed to a
+    /// single space.
+    pub ignore_whitespace: bool,
+    /// Whether to skip comment nodes.
+    ///
+    /// Text on either side of a skipped comment is compared as one run.
+    pub ignore_comments: bool,
+    /// Whether attributes may appear in any order.
+    pub ignore_attribute_order: bool,
+    /// Local names of attributes to leave out of the comparison, such as
+    /// auto-generated ids.
+    pub ignore_attributes: Vec<String>,
+}
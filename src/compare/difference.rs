@@ -0,0 +1,24 @@
+use std::fmt;
+
+/// The first mismatch found when comparing two trees.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Difference {
+    /// The path to the mismatched node in the expected tree.
+    ///
+    /// Each step is an element name, `text()`, `comment()` or `node()` with a
+    /// 1-based index among siblings of that kind, as in XPath, for example
+    /// `/html[1]/body[1]/p[2]/text()[1]`. Template contents appear as a
+    /// `#contents` step.
+    pub path: String,
+    /// A description of the mismatch.
+    pub message: String,
+}
+
+/// Implements Display for Difference.
+///
+/// Formats the difference as `path: message`.
+impl fmt::Display for Difference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.path, self.message)
+    }
+}
@@ -0,0 +1,416 @@
+use std::collections::HashMap;
+
+use super::{CompareOptions, Difference};
+use crate::attributes::{Attribute, ExpandedName};
+use crate::tree::{NodeData, NodeRef};
+
+/// Compares two trees and returns the first difference, if any.
+///
+/// Nodes are compared by kind, name, namespace, attributes and text, then
+/// children are compared in order. Adjacent text nodes are compared as a
+/// single run of text.
+///
+/// # Examples
+///
+/// ```
+/// use brik::compare::{first_difference, CompareOptions};
+/// use brik::parse_html;
+/// use brik::traits::*;
+///
+/// let expected = parse_html().one("<p id=a class=x>Hello <b>world</b></p>");
+/// let actual = parse_html().one("<p class=x id=b>Hello\n  <b>world</b></p>");
+///
+/// let options = CompareOptions {
+///     ignore_whitespace: true,
+///     ignore_attribute_order: true,
+///     ignore_attributes: vec!["id".to_string()],
+///     ..CompareOptions::default()
+/// };
+/// assert_eq!(first_difference(&expected, &actual, &options), None);
+///
+/// let difference = first_difference(&expected, &actual, &CompareOptions::default()).unwrap();
+/// assert_eq!(difference.path, "/html[1]/body[1]/p[1]");
+/// ```
+pub fn first_difference(
+    expected: &NodeRef,
+    actual: &NodeRef,
+    options: &CompareOptions,
+) -> Option<Difference> {
+    let path = match expected.as_element() {
+        Some(element) => format!("/{}", element.name.local),
+        None => String::new(),
+    };
+    compare_nodes(expected, actual, options, &path)
+}
+
+/// A child to compare: a node, or a merged run of adjacent text.
+enum Item {
+    /// A non-text node.
+    Node(NodeRef),
+    /// The combined text of adjacent text nodes.
+    Text(String),
+}
+
+/// Compares two nodes and their descendants.
+fn compare_nodes(
+    expected: &NodeRef,
+    actual: &NodeRef,
+    options: &CompareOptions,
+    path: &str,
+) -> Option<Difference> {
+    let difference = |message: String| {
+        Some(Difference {
+            path: if path.is_empty() {
+                "/".to_string()
+            } else {
+                path.to_string()
+            },
+            message,
+        })
+    };
+    match (expected.data(), actual.data()) {
+        (NodeData::Document(_), NodeData::Document(_)) => {}
+        (NodeData::DocumentFragment, NodeData::DocumentFragment) => {}
+        (NodeData::Doctype(a), NodeData::Doctype(b)) => {
+            if a.name != b.name || a.public_id != b.public_id || a.system_id != b.system_id {
+                return difference(format!(
+                    "expected {}, found {}",
+                    describe(expected),
+                    describe(actual)
+                ));
+            }
+        }
+        (NodeData::Element(a), NodeData::Element(b)) => {
+            if a.name.ns != b.name.ns || a.name.local != b.name.local {
+                return difference(format!(
+                    "expected {}, found {}",
+                    describe(expected),
+                    describe(actual)
+                ));
+            }
+            let attributes = a.attributes.borrow();
+            let other_attributes = b.attributes.borrow();
+            if let Some(message) = compare_attributes(
+                filter_attributes(attributes.map.iter(), options),
+                filter_attributes(other_attributes.map.iter(), options),
+                options,
+            ) {
+                return difference(message);
+            }
+            match (&a.template_contents, &b.template_contents) {
+                (Some(contents), Some(other_contents)) => {
+                    let contents_path = format!("{}/#contents", path);
+                    if let Some(found) =
+                        compare_nodes(contents, other_contents, options, &contents_path)
+                    {
+                        return Some(found);
+                    }
+                }
+                (None, None) => {}
+                _ => return difference("template contents differ".to_string()),
+            }
+        }
+        (NodeData::Comment(a), NodeData::Comment(b)) => {
+            if *a.borrow() != *b.borrow() {
+                return difference(format!(
+                    "expected {}, found {}",
+                    describe(expected),
+                    describe(actual)
+                ));
+            }
+        }
+        (NodeData::ProcessingInstruction(a), NodeData::ProcessingInstruction(b)) => {
+            if *a.borrow() != *b.borrow() {
+                return difference(format!(
+                    "expected {}, found {}",
+                    describe(expected),
+                    describe(actual)
+                ));
+            }
+        }
+        _ => {
+            return difference(format!(
+                "expected {}, found {}",
+                describe(expected),
+                describe(actual)
+            ))
+        }
+    }
+    compare_children(expected, actual, options, path)
+}
+
+/// Compares the children of two nodes in order.
+fn compare_children(
+    expected: &NodeRef,
+    actual: &NodeRef,
+    options: &CompareOptions,
+    path: &str,
+) -> Option<Difference> {
+    let expected_items = items(expected, options);
+    let actual_items = items(actual, options);
+    let mut counts: HashMap<String, usize> = HashMap::new();
+    for index in 0..expected_items.len().max(actual_items.len()) {
+        let step_item = expected_items
+            .get(index)
+            .or_else(|| actual_items.get(index));
+        let step = step_item.map(step_name).unwrap_or_default();
+        let count = counts.entry(step.clone()).or_default();
+        *count += 1;
+        let child_path = format!("{}/{}[{}]", path, step, count);
+        let difference = |message: String| {
+            Some(Difference {
+                path: child_path.clone(),
+                message,
+            })
+        };
+        match (expected_items.get(index), actual_items.get(index)) {
+            (Some(Item::Text(a)), Some(Item::Text(b))) => {
+                if a != b {
+                    return difference(format!("expected text {:?}, found text {:?}", a, b));
+                }
+            }
+            (Some(Item::Node(a)), Some(Item::Node(b))) => {
+                if let Some(found) = compare_nodes(a, b, options, &child_path) {
+                    return Some(found);
+                }
+            }
+            (Some(a), Some(b)) => {
+                return difference(format!(
+                    "expected {}, found {}",
+                    describe_item(a),
+                    describe_item(b)
+                ))
+            }
+            (Some(a), None) => return difference(format!("missing {}", describe_item(a))),
+            (None, Some(b)) => return difference(format!("unexpected {}", describe_item(b))),
+            (None, None) => {}
+        }
+    }
+    None
+}
+
+/// Collects the children of a node to compare, applying the options.
+fn items(node: &NodeRef, options: &CompareOptions) -> Vec<Item> {
+    let mut items = Vec::new();
+    for child in node.children() {
+        if let Some(text) = child.as_text() {
+            if let Some(Item::Text(previous)) = items.last_mut() {
+                previous.push_str(&text.borrow());
+            } else {
+                items.push(Item::Text(text.borrow().to_string()));
+            }
+        } else if !(options.ignore_comments && child.as_comment().is_some()) {
+            items.push(Item::Node(child));
+        }
+    }
+    if options.ignore_whitespace {
+        items.retain_mut(|item| match item {
+            Item::Text(text) => {
+                *text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+                !text.is_empty()
+            }
+            Item::Node(_) => true,
+        });
+    }
+    items
+}
+
+/// Returns the attributes to compare as `(name, value)` pairs.
+fn filter_attributes<'a>(
+    attributes: impl Iterator<Item = (&'a ExpandedName, &'a Attribute)>,
+    options: &CompareOptions,
+) -> Vec<(&'a ExpandedName, &'a str)> {
+    attributes
+        .filter(|(name, _)| {
+            !options
+                .ignore_attributes
+                .iter()
+                .any(|ignored| *ignored == *name.local)
+        })
+        .map(|(name, attribute)| (name, &*attribute.value))
+        .collect()
+}
+
+/// Compares two attribute lists and describes the first mismatch.
+fn compare_attributes(
+    expected: Vec<(&ExpandedName, &str)>,
+    actual: Vec<(&ExpandedName, &str)>,
+    options: &CompareOptions,
+) -> Option<String> {
+    for (name, value) in &expected {
+        match actual.iter().find(|(other, _)| other == name) {
+            None => return Some(format!("missing attribute {}", name.local)),
+            Some((_, other)) if other != value => {
+                return Some(format!(
+                    "attribute {}: expected {:?}, found {:?}",
+                    name.local, value, other
+                ))
+            }
+            Some(_) => {}
+        }
+    }
+    if let Some((name, _)) = actual
+        .iter()
+        .find(|(name, _)| !expected.iter().any(|(other, _)| other == name))
+    {
+        return Some(format!("unexpected attribute {}", name.local));
+    }
+    if !options.ignore_attribute_order {
+        let names = |attributes: &[(&ExpandedName, &str)]| {
+            attributes
+                .iter()
+                .map(|(name, _)| name.local.to_string())
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+        if names(&expected) != names(&actual) {
+            return Some(format!(
+                "expected attribute order [{}], found [{}]",
+                names(&expected),
+                names(&actual)
+            ));
+        }
+    }
+    None
+}
+
+/// Returns the path step name for a child item.
+fn step_name(item: &Item) -> String {
+    match item {
+        Item::Text(_) => "text()".to_string(),
+        Item::Node(node) => match node.data() {
+            NodeData::Element(element) => element.name.local.to_string(),
+            NodeData::Comment(_) => "comment()".to_string(),
+            _ => "node()".to_string(),
+        },
+    }
+}
+
+/// Describes a child item for difference messages.
+fn describe_item(item: &Item) -> String {
+    match item {
+        Item::Text(text) => format!("text {:?}", text),
+        Item::Node(node) => describe(node),
+    }
+}
+
+/// Describes a node for difference messages.
+fn describe(node: &NodeRef) -> String {
+    match node.data() {
+        NodeData::Document(_) => "document".to_string(),
+        NodeData::DocumentFragment => "document fragment".to_string(),
+        NodeData::Doctype(doctype) => format!("<!DOCTYPE {}>", doctype.name),
+        NodeData::Element(element) => {
+            if element.name.ns == ns!(html) {
+                format!("<{}>", element.name.local)
+            } else {
+                format!(
+                    "<{}> in namespace {:?}",
+                    element.name.local, &*element.name.ns
+                )
+            }
+        }
+        NodeData::Text(text) => format!("text {:?}", &**text.borrow()),
+        NodeData::Comment(text) => format!("comment {:?}", &**text.borrow()),
+        NodeData::ProcessingInstruction(contents) => {
+            format!("processing instruction {:?}", contents.borrow().0)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::html5ever::tendril::TendrilSink;
+    use crate::parse_html;
+
+    /// Parses a document for comparison tests.
+    fn parse(html: &str) -> NodeRef {
+        parse_html().one(html)
+    }
+
+    /// Tests each ignore option.
+    ///
+    /// Verifies that a difference is reported under the default strict
+    /// options and disappears once the matching option is enabled.
+    #[test]
+    fn ignore_options() {
+        let cases = [
+            (
+                "<p>a b</p>",
+                "<p>\n a\n  b </p>\n",
+                CompareOptions {
+                    ignore_whitespace: true,
+                    ..CompareOptions::default()
+                },
+            ),
+            (
+                "<p>ab</p>",
+                "<p>a<!--x-->b</p>",
+                CompareOptions {
+                    ignore_comments: true,
+                    ..CompareOptions::default()
+                },
+            ),
+            (
+                "<p a=1 b=2></p>",
+                "<p b=2 a=1></p>",
+                CompareOptions {
+                    ignore_attribute_order: true,
+                    ..CompareOptions::default()
+                },
+            ),
+            (
+                "<p id=x></p>",
+                "<p id=y></p>",
+                CompareOptions {
+                    ignore_attributes: vec!["id".to_string()],
+                    ..CompareOptions::default()
+                },
+            ),
+        ];
+        for (expected, actual, options) in &cases {
+            let (expected, actual) = (parse(expected), parse(actual));
+            assert!(first_difference(&expected, &actual, &CompareOptions::default()).is_some());
+            assert_eq!(first_difference(&expected, &actual, options), None);
+        }
+    }
+
+    /// Tests the paths and messages of reported differences.
+    ///
+    /// Verifies that steps count siblings of the same kind and that
+    /// mismatches of names, text, attributes and child counts are described.
+    #[test]
+    fn reports() {
+        let options = CompareOptions::default();
+        let report = |expected: &str, actual: &str| {
+            first_difference(&parse(expected), &parse(actual), &options)
+                .unwrap()
+                .to_string()
+        };
+
+        assert_eq!(
+            report("<p>1</p><p>2</p>", "<p>1</p><div>2</div>"),
+            "/html[1]/body[1]/p[2]: expected <p>, found <div>"
+        );
+        assert_eq!(
+            report("<p>1</p><p>2</p>", "<p>1</p><p>3</p>"),
+            "/html[1]/body[1]/p[2]/text()[1]: expected text \"2\", found text \"3\""
+        );
+        assert_eq!(
+            report("<p a=1></p>", "<p a=2></p>"),
+            "/html[1]/body[1]/p[1]: attribute a: expected \"1\", found \"2\""
+        );
+        assert_eq!(
+            report("<p></p>", "<p></p><br>"),
+            "/html[1]/body[1]/br[1]: unexpected <br>"
+        );
+        assert_eq!(
+            report(
+                "<template><i></i></template>",
+                "<template><b></b></template>"
+            ),
+            "/html[1]/head[1]/template[1]/#contents/i[1]: expected <i>, found <b>"
+        );
+    }
+}
@@ -0,0 +1,24 @@
+//! Configurable comparison of node trees.
+//!
+//! [`first_difference()`] compares two trees while optionally ignoring
+//! insignificant whitespace, comments, attribute order and chosen attributes,
+//! and reports the first mismatch with the path to the node where it occurs.
+
+/// The `assert_html_eq!` macro.
+#[cfg(feature = "testing")]
+mod assert_html_eq;
+/// HTML equivalence assertion.
+#[cfg(feature = "testing")]
+mod assert_html_eq_with;
+/// Comparison options.
+mod compare_options;
+/// Difference report.
+mod difference;
+/// Tree comparison.
+mod first_difference;
+
+#[cfg(feature = "testing")]
+pub use assert_html_eq_with::assert_html_eq_with;
+pub use compare_options::CompareOptions;
+pub use difference::Difference;
+pub use first_difference::first_difference;
@@ -0,0 +1,26 @@
+use html5ever::LocalName;
+
+/// A component whose expansion contains itself, which would never end.
+///
+/// Returned by [`ComponentRegistry::expand()`](super::ComponentRegistry::expand).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ComponentCycleError {
+    /// The names of the components being expanded, outermost first, ending
+    /// with the repeated name.
+    pub chain: Vec<LocalName>,
+}
+
+/// Implements Display for ComponentCycleError.
+///
+/// Provides the chain of expansions, like `x-a -> x-b -> x-a`.
+impl std::fmt::Display for ComponentCycleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let chain: Vec<&str> = self.chain.iter().map(|name| &**name).collect();
+        write!(f, "component expansion cycle: {}", chain.join(" -> "))
+    }
+}
+
+/// Implements Error for ComponentCycleError.
+///
+/// Allows ComponentCycleError to be used with Rust's standard error handling mechanisms.
+impl std::error::Error for ComponentCycleError {}
@@ -0,0 +1,263 @@
+use super::ComponentCycleError;
+use crate::node_data_ref::NodeDataRef;
+use crate::tree::{ElementData, NodeData, NodeRef};
+use html5ever::LocalName;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+/// A callback building the replacement of a component element.
+type Expansion<'a> = Box<dyn Fn(&NodeDataRef<ElementData>) -> NodeRef + 'a>;
+
+/// Maps custom element names to callbacks that expand them.
+///
+/// Each callback receives a component element, with its attributes and
+/// children, and returns its replacement: a node, or a document fragment
+/// whose children replace the element. Callbacks may move the element's
+/// children into the replacement, like slotted content.
+///
+/// # Examples
+///
+/// ```
+/// use brik::component::ComponentRegistry;
+/// use brik::parse_html;
+/// use brik::traits::*;
+/// use brik::NodeRef;
+///
+/// let mut registry = ComponentRegistry::new();
+/// registry.register("x-card", |card| {
+///     let title = card.attributes.borrow().get("title").unwrap_or("").to_string();
+///     let html = format!("<div class=card><h2>{}</h2><div class=body></div></div>", title);
+///     let replacement = parse_html().one(html).select_first("div").unwrap();
+///     let body = replacement.as_node().last_child().unwrap();
+///     for child in card.as_node().children() {
+///         body.append(child);
+///     }
+///     replacement.as_node().clone()
+/// });
+///
+/// let doc = parse_html().one("<x-card title=Hi><p>Text</p></x-card>");
+/// assert_eq!(registry.expand(&doc), Ok(1));
+/// assert_eq!(
+///     doc.select_first("body").unwrap().as_node().to_string(),
+///     r#"<body><div class="card"><h2>Hi</h2><div class="body"><p>Text</p></div></div></body>"#
+/// );
+/// ```
+#[derive(Default)]
+pub struct ComponentRegistry<'a> {
+    /// Expansion callbacks by element name.
+    components: HashMap<LocalName, Expansion<'a>>,
+}
+
+/// A component being expanded, in a chain of enclosing expansions.
+struct Expanding {
+    /// The component's name.
+    name: LocalName,
+    /// The enclosing expansion, if any.
+    parent: Option<Rc<Expanding>>,
+}
+
+/// Methods for ComponentRegistry.
+///
+/// Provides registration and expansion of components.
+impl<'a> ComponentRegistry<'a> {
+    /// Creates an empty registry.
+    pub fn new() -> ComponentRegistry<'a> {
+        ComponentRegistry::default()
+    }
+
+    /// Registers `expansion` for elements named `name`, replacing any
+    /// previous registration.
+    pub fn register<N, F>(&mut self, name: N, expansion: F) -> &mut Self
+    where
+        N: Into<LocalName>,
+        F: Fn(&NodeDataRef<ElementData>) -> NodeRef + 'a,
+    {
+        self.components.insert(name.into(), Box::new(expansion));
+        self
+    }
+
+    /// Expands the components in this subtree in a single pass, returning
+    /// the number of elements expanded.
+    ///
+    /// Replacements are searched for components in turn, as are template
+    /// contents. Children the callback moved into the replacement are
+    /// treated as part of the enclosing markup, so a component may contain
+    /// another of the same kind.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ComponentCycleError`] if a component's replacement
+    /// contains the same component, directly or through other components.
+    /// The tree is left partly expanded.
+    pub fn expand(&self, root: &NodeRef) -> Result<usize, ComponentCycleError> {
+        let mut expanded = 0;
+        // Where an expansion moved a component's children, keyed by node.
+        let mut moved: HashMap<NodeRef, Option<Rc<Expanding>>> = HashMap::new();
+        let mut pending: Vec<(NodeRef, Option<Rc<Expanding>>)> =
+            root.children().map(|child| (child, None)).collect();
+        pending.reverse();
+        while let Some((node, mut chain)) = pending.pop() {
+            if let Some(outer) = moved.remove(&node) {
+                chain = outer;
+            }
+            let element = match node.clone().into_element_ref() {
+                Some(element) => element,
+                None => continue,
+            };
+            let expansion = match self.components.get(&element.name.local) {
+                Some(expansion) if element.name.ns == ns!(html) => expansion,
+                _ => {
+                    let children: Vec<NodeRef> = node
+                        .children()
+                        .chain(
+                            element
+                                .template_contents
+                                .iter()
+                                .flat_map(|contents| contents.children()),
+                        )
+                        .collect();
+                    pending.extend(
+                        children
+                            .into_iter()
+                            .rev()
+                            .map(|child| (child, chain.clone())),
+                    );
+                    continue;
+                }
+            };
+
+            let mut names = vec![element.name.local.clone()];
+            let mut outer = chain.as_deref();
+            while let Some(current) = outer {
+                names.push(current.name.clone());
+                if current.name == element.name.local {
+                    names.reverse();
+                    return Err(ComponentCycleError { chain: names });
+                }
+                outer = current.parent.as_deref();
+            }
+
+            for child in node.children() {
+                moved.insert(child, chain.clone());
+            }
+            let replacement = expansion(&element);
+            expanded += 1;
+            let nodes: Vec<NodeRef> = match replacement.data() {
+                NodeData::DocumentFragment => replacement.children().collect(),
+                _ => vec![replacement],
+            };
+            for replacement in &nodes {
+                node.insert_before(replacement.clone());
+            }
+            node.detach();
+            let inner = Some(Rc::new(Expanding {
+                name: element.name.local.clone(),
+                parent: chain,
+            }));
+            pending.extend(nodes.into_iter().rev().map(|node| (node, inner.clone())));
+        }
+        Ok(expanded)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::html5ever::tendril::TendrilSink;
+    use crate::parse_html;
+
+    /// Returns a callback that wraps a component's children in `tag`, after
+    /// `prefix` markup.
+    fn wrap(
+        tag: &'static str,
+        prefix: &'static str,
+    ) -> impl Fn(&NodeDataRef<ElementData>) -> NodeRef {
+        move |element| {
+            let wrapper = NodeRef::new_element(
+                html5ever::QualName::new(None, ns!(html), LocalName::from(tag)),
+                None,
+            );
+            let fragment = parse_html().one(prefix);
+            let body = fragment.select_first("body").unwrap();
+            for child in body.as_node().children() {
+                wrapper.append(child);
+            }
+            for child in element.as_node().children() {
+                wrapper.append(child);
+            }
+            wrapper
+        }
+    }
+
+    /// Tests nested and composed components.
+    ///
+    /// Verifies that replacements are expanded in turn, and that a
+    /// component nested in the children of the same component is not a
+    /// cycle.
+    #[test]
+    fn expand_nested_components() {
+        let mut registry = ComponentRegistry::new();
+        registry
+            .register("x-box", wrap("section", "<x-label></x-label>"))
+            .register("x-label", wrap("b", "label"));
+        let doc = parse_html().one("<x-box><x-box>inner</x-box></x-box>");
+
+        assert_eq!(registry.expand(&doc), Ok(4));
+        assert_eq!(
+            doc.select_first("body").unwrap().as_node().to_string(),
+            "<body><section><b>label</b><section><b>label</b>inner</section></section></body>"
+        );
+    }
+
+    /// Tests a component that expands to itself.
+    ///
+    /// Edge case: a cycle through another component is reported with the
+    /// whole chain instead of expanding forever.
+    #[test]
+    fn expand_cycle() {
+        let mut registry = ComponentRegistry::new();
+        registry
+            .register("x-a", wrap("div", "<x-b></x-b>"))
+            .register("x-b", wrap("div", "<x-a></x-a>"));
+        let doc = parse_html().one("<x-a></x-a>");
+
+        let error = registry.expand(&doc).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "component expansion cycle: x-a -> x-b -> x-a"
+        );
+    }
+
+    /// Tests a component whose expansion drops its children.
+    ///
+    /// Edge case: the dropped children are forgotten, so nodes created by
+    /// later expansions are not given their place in the expansion chain
+    /// and reported as a false cycle.
+    #[test]
+    fn expand_dropping_children() {
+        let element = |name: &str| {
+            NodeRef::new_element(
+                html5ever::QualName::new(None, ns!(html), LocalName::from(name)),
+                None,
+            )
+        };
+        let mut registry = ComponentRegistry::new();
+        registry
+            .register("x-outer", |_| {
+                let inner = element("x-drop");
+                for _ in 0..8 {
+                    inner.append(element("i"));
+                }
+                inner
+            })
+            .register("x-drop", |_| element("span"))
+            .register("x-b", |_| element("x-outer"));
+        let doc = parse_html().one("<x-outer></x-outer><x-b></x-b>");
+
+        assert_eq!(registry.expand(&doc), Ok(5));
+        assert_eq!(
+            doc.select_first("body").unwrap().as_node().to_string(),
+            "<body><span></span><span></span></body>"
+        );
+    }
+}
@@ -0,0 +1,13 @@
+//! Server-side expansion of custom elements.
+//!
+//! A [`ComponentRegistry`] maps custom element names, such as `x-card`, to
+//! callbacks that build their replacement markup, so a design system's
+//! components can be rendered into plain HTML in a single pass.
+
+/// Cycle found while expanding components.
+mod component_cycle_error;
+/// Registry of component expansions.
+mod component_registry;
+
+pub use component_cycle_error::ComponentCycleError;
+pub use component_registry::ComponentRegistry;
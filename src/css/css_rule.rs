@@ -0,0 +1,236 @@
+//! Minimal CSS rule and declaration parsing for inlining.
+
+use cssparser::{
+    AtRuleParser, CowRcStr, DeclarationParser, ParseError, Parser, ParserInput, ParserState,
+    QualifiedRuleParser, RuleBodyItemParser, RuleBodyParser, StyleSheetParser,
+};
+
+/// A single CSS property declaration.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct Declaration {
+    /// The property name, lowercased unless it is a custom property.
+    pub name: String,
+    /// The value, without any `!important` flag.
+    pub value: String,
+    /// Whether the declaration is marked `!important`.
+    pub important: bool,
+}
+
+/// A top-level stylesheet rule.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) enum CssRule {
+    /// A style rule.
+    Style {
+        /// The selector list source.
+        selectors: String,
+        /// The declarations in the rule body.
+        declarations: Vec<Declaration>,
+        /// The source of the whole rule.
+        source: String,
+    },
+    /// An at-rule, kept as source.
+    Other(String),
+}
+
+/// Parses the top-level rules of a stylesheet, dropping invalid ones.
+pub(super) fn parse_stylesheet(css: &str) -> Vec<CssRule> {
+    let mut input = ParserInput::new(css);
+    let mut parser = Parser::new(&mut input);
+    StyleSheetParser::new(&mut parser, &mut RuleParser)
+        .filter_map(Result::ok)
+        .collect()
+}
+
+/// Parses a declaration list such as a `style` attribute value, dropping invalid entries.
+pub(super) fn parse_declarations(css: &str) -> Vec<Declaration> {
+    let mut input = ParserInput::new(css);
+    let mut parser = Parser::new(&mut input);
+    RuleBodyParser::new(&mut parser, &mut DeclarationListParser)
+        .filter_map(Result::ok)
+        .collect()
+}
+
+/// Consumes the rest of the input and returns its source.
+fn consume_rest<'i>(input: &mut Parser<'i, '_>) -> &'i str {
+    let start = input.position();
+    while input.next_including_whitespace_and_comments().is_ok() {}
+    input.slice_from(start)
+}
+
+/// Parser for top-level stylesheet rules.
+struct RuleParser;
+
+/// Implements QualifiedRuleParser for RuleParser.
+///
+/// Keeps the selector source and parses the declarations of style rules.
+impl<'i> QualifiedRuleParser<'i> for RuleParser {
+    type Prelude = String;
+    type QualifiedRule = CssRule;
+    type Error = ();
+
+    fn parse_prelude<'t>(
+        &mut self,
+        input: &mut Parser<'i, 't>,
+    ) -> Result<String, ParseError<'i, ()>> {
+        Ok(consume_rest(input).trim().to_string())
+    }
+
+    fn parse_block<'t>(
+        &mut self,
+        selectors: String,
+        _start: &ParserState,
+        input: &mut Parser<'i, 't>,
+    ) -> Result<CssRule, ParseError<'i, ()>> {
+        let body = consume_rest(input);
+        Ok(CssRule::Style {
+            source: format!("{} {{{}}}", selectors, body),
+            declarations: parse_declarations(body),
+            selectors,
+        })
+    }
+}
+
+/// Implements AtRuleParser for RuleParser.
+///
+/// Keeps at-rules, such as `@media`, as their source text.
+impl<'i> AtRuleParser<'i> for RuleParser {
+    type Prelude = String;
+    type AtRule = CssRule;
+    type Error = ();
+
+    fn parse_prelude<'t>(
+        &mut self,
+        name: CowRcStr<'i>,
+        input: &mut Parser<'i, 't>,
+    ) -> Result<String, ParseError<'i, ()>> {
+        Ok(format!("@{} {}", name, consume_rest(input).trim()))
+    }
+
+    fn rule_without_block(&mut self, prelude: String, _start: &ParserState) -> Result<CssRule, ()> {
+        Ok(CssRule::Other(format!("{};", prelude)))
+    }
+
+    fn parse_block<'t>(
+        &mut self,
+        prelude: String,
+        _start: &ParserState,
+        input: &mut Parser<'i, 't>,
+    ) -> Result<CssRule, ParseError<'i, ()>> {
+        Ok(CssRule::Other(format!(
+            "{} {{{}}}",
+            prelude,
+            consume_rest(input)
+        )))
+    }
+}
+
+/// Parser for declaration lists.
+struct DeclarationListParser;
+
+/// Implements DeclarationParser for DeclarationListParser.
+///
+/// Keeps the value source and splits off a trailing `!important`.
+impl<'i> DeclarationParser<'i> for DeclarationListParser {
+    type Declaration = Declaration;
+    type Error = ();
+
+    fn parse_value<'t>(
+        &mut self,
+        name: CowRcStr<'i>,
+        input: &mut Parser<'i, 't>,
+        _declaration_start: &ParserState,
+    ) -> Result<Declaration, ParseError<'i, ()>> {
+        let mut value = consume_rest(input).trim();
+        let mut important = false;
+        if let Some(index) = value.rfind('!') {
+            if value[index + 1..].trim().eq_ignore_ascii_case("important") {
+                important = true;
+                value = value[..index].trim_end();
+            }
+        }
+        Ok(Declaration {
+            name: if name.starts_with("--") {
+                name.to_string()
+            } else {
+                name.to_ascii_lowercase()
+            },
+            value: value.to_string(),
+            important,
+        })
+    }
+}
+
+/// Implements AtRuleParser for DeclarationListParser.
+///
+/// Rejects at-rules inside declaration lists.
+impl<'i> AtRuleParser<'i> for DeclarationListParser {
+    type Prelude = ();
+    type AtRule = Declaration;
+    type Error = ();
+}
+
+/// Implements QualifiedRuleParser for DeclarationListParser.
+///
+/// Rejects nested rules inside declaration lists.
+impl<'i> QualifiedRuleParser<'i> for DeclarationListParser {
+    type Prelude = ();
+    type QualifiedRule = Declaration;
+    type Error = ();
+}
+
+/// Implements RuleBodyItemParser for DeclarationListParser.
+///
+/// Parses declarations only.
+impl<'i> RuleBodyItemParser<'i, Declaration, ()> for DeclarationListParser {
+    fn parse_declarations(&self) -> bool {
+        true
+    }
+
+    fn parse_qualified(&self) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Tests parsing a stylesheet with style rules and at-rules.
+    ///
+    /// Verifies that selectors, declarations and at-rule sources are kept,
+    /// and that invalid declarations are dropped.
+    #[test]
+    fn stylesheet() {
+        let rules = parse_stylesheet(
+            "p, .a { COLOR: red; --Gap: 1px; : bad; margin: 0 !IMPORTANT }\n\
+             @media print { p { color: black } }",
+        );
+        assert_eq!(rules.len(), 2);
+        match &rules[0] {
+            CssRule::Style {
+                selectors,
+                declarations,
+                ..
+            } => {
+                assert_eq!(selectors, "p, .a");
+                let parsed: Vec<_> = declarations
+                    .iter()
+                    .map(|d| (d.name.as_str(), d.value.as_str(), d.important))
+                    .collect();
+                assert_eq!(
+                    parsed,
+                    vec![
+                        ("color", "red", false),
+                        ("--Gap", "1px", false),
+                        ("margin", "0", true),
+                    ]
+                );
+            }
+            other => panic!("expected a style rule, found {:?}", other),
+        }
+        assert_eq!(
+            rules[1],
+            CssRule::Other("@media print { p { color: black } }".to_string())
+        );
+    }
+}
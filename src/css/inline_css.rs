@@ -0,0 +1,250 @@
+use super::css_rule::{parse_declarations, parse_stylesheet, CssRule, Declaration};
+use super::InlineCssOptions;
+use crate::iter::NodeIterator;
+use crate::node_data_ref::NodeDataRef;
+use crate::select::{Selectors, Specificity};
+use crate::tree::{ElementData, NodeRef};
+
+/// Pseudo-classes that depend on user interaction and cannot be inlined.
+const DYNAMIC_PSEUDO_CLASSES: [&str; 4] = [":hover", ":active", ":focus", ":visited"];
+
+/// Inlines the rules of `<style>` elements into `style` attributes.
+///
+/// This is equivalent to [`inline_css_with_options()`] with default options.
+///
+/// # Examples
+///
+/// ```
+/// use brik::css::inline_css;
+/// use brik::parse_html;
+/// use brik::traits::*;
+///
+/// let doc = parse_html().one(
+///     "<style>p { color: red; margin: 0 } .note { color: blue }</style>\
+///      <p class=note style='margin: 4px'>Hi</p>",
+/// );
+/// inline_css(&doc);
+///
+/// let p = doc.select_first("p").unwrap();
+/// assert_eq!(p.attributes.borrow().get("style"), Some("color: blue; margin: 4px"));
+/// assert!(doc.select_first("style").is_err());
+/// ```
+pub fn inline_css(root: &NodeRef) {
+    inline_css_with_options(root, &InlineCssOptions::default())
+}
+
+/// Inlines the rules of `<style>` elements into `style` attributes, with options.
+///
+/// Style rules are matched with brik's selector engine against the `<body>`
+/// element and its descendants, or against every element if there is no
+/// `<body>`. For each element, declarations from matching rules and from its
+/// existing `style` attribute are combined following the cascade: `!important`
+/// first, then inline styles, then selector specificity, then source order.
+/// The winning declarations are written back to the `style` attribute, in
+/// cascade order.
+///
+/// Only `<style>` elements with no `media` attribute, or with `media` set to
+/// `all` or `screen`, are inlined. At-rules such as `@media`, rules that fail
+/// to compile, and rules using `:hover`, `:active`, `:focus` or `:visited`
+/// are left in their `<style>` element.
+pub fn inline_css_with_options(root: &NodeRef, options: &InlineCssOptions) {
+    let style_elements: Vec<NodeDataRef<ElementData>> = root
+        .descendants()
+        .elements()
+        .filter(|element| {
+            element.name.ns == ns!(html)
+                && element.name.local == local_name!("style")
+                && element
+                    .attributes
+                    .borrow()
+                    .get_trimmed("media")
+                    .is_none_or(|media| {
+                        media.eq_ignore_ascii_case("all") || media.eq_ignore_ascii_case("screen")
+                    })
+        })
+        .collect();
+
+    // Split the rules of each style element into inlinable and remaining rules.
+    let mut inlinable: Vec<(Selectors, Vec<Declaration>)> = Vec::new();
+    let mut remaining: Vec<Vec<String>> = Vec::new();
+    for style in &style_elements {
+        let mut kept = Vec::new();
+        for rule in parse_stylesheet(&style.as_node().text_contents()) {
+            match rule {
+                CssRule::Style {
+                    selectors,
+                    declarations,
+                    source,
+                } => match Selectors::compile(&selectors) {
+                    Ok(compiled) if is_inlinable(&compiled) => {
+                        inlinable.push((compiled, declarations))
+                    }
+                    _ => kept.push(source),
+                },
+                CssRule::Other(source) => kept.push(source),
+            }
+        }
+        remaining.push(kept);
+    }
+
+    let scope = root
+        .inclusive_descendants()
+        .elements()
+        .find(|element| element.name.ns == ns!(html) && element.name.local == local_name!("body"))
+        .map_or_else(|| root.clone(), |body| body.as_node().clone());
+    if !inlinable.is_empty() {
+        for element in scope.inclusive_descendants().elements() {
+            apply_rules(&element, &inlinable);
+        }
+    }
+
+    if !options.keep_style_elements {
+        for (style, kept) in style_elements.iter().zip(remaining) {
+            if kept.is_empty() {
+                style.as_node().detach();
+            } else {
+                let node = style.as_node();
+                while let Some(child) = node.first_child() {
+                    child.detach();
+                }
+                node.append(NodeRef::new_text(kept.join("\n")));
+            }
+        }
+    }
+}
+
+/// Returns whether every selector in a list can be matched statically.
+fn is_inlinable(selectors: &Selectors) -> bool {
+    selectors.0.iter().all(|selector| {
+        let source = selector.to_string();
+        !DYNAMIC_PSEUDO_CLASSES
+            .iter()
+            .any(|pseudo| source.contains(pseudo))
+    })
+}
+
+/// Cascades the matching rules and inline style of an element into its `style` attribute.
+fn apply_rules(element: &NodeDataRef<ElementData>, rules: &[(Selectors, Vec<Declaration>)]) {
+    // Cascade precedence: importance, inline origin, specificity, source order.
+    type Key = (bool, bool, Option<Specificity>, usize);
+    let mut candidates: Vec<(Key, &Declaration)> = Vec::new();
+    let mut order = 0;
+    for (selectors, declarations) in rules {
+        if let Some(found) = selectors.match_with_specificity(element) {
+            let specificity = found.specificity;
+            for declaration in declarations {
+                candidates.push((
+                    (declaration.important, false, Some(specificity), order),
+                    declaration,
+                ));
+                order += 1;
+            }
+        }
+    }
+    if candidates.is_empty() {
+        return;
+    }
+
+    let mut attributes = element.attributes.borrow_mut();
+    let inline = parse_declarations(attributes.get("style").unwrap_or_default());
+    for declaration in &inline {
+        candidates.push(((declaration.important, true, None, order), declaration));
+        order += 1;
+    }
+
+    candidates.sort_by_key(|(key, _)| *key);
+    let mut winners: Vec<&Declaration> = Vec::new();
+    for (_, declaration) in candidates.into_iter().rev() {
+        if !winners.iter().any(|winner| winner.name == declaration.name) {
+            winners.push(declaration);
+        }
+    }
+    let style = winners
+        .iter()
+        .rev()
+        .map(|declaration| {
+            if declaration.important {
+                format!("{}: {} !important", declaration.name, declaration.value)
+            } else {
+                format!("{}: {}", declaration.name, declaration.value)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("; ");
+    attributes.insert("style", style);
+    element.as_node().mark_dirty();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::html5ever::tendril::TendrilSink;
+    use crate::parse_html;
+
+    /// Returns the style attribute of the first element matching a selector.
+    fn style_of(doc: &NodeRef, selector: &str) -> Option<String> {
+        let element = doc.select_first(selector).unwrap();
+        let attributes = element.attributes.borrow();
+        attributes.get("style").map(str::to_string)
+    }
+
+    /// Tests cascade resolution between rules and inline styles.
+    ///
+    /// Verifies that specificity beats source order, inline styles beat
+    /// rules, and `!important` rules beat inline styles.
+    #[test]
+    fn cascade() {
+        let doc = parse_html().one(
+            "<style>#x { color: red } p { color: blue; margin: 0 } \
+             p { padding: 1px !important }</style>\
+             <p id=x style='padding: 2px; margin: 3px'>a</p><p>b</p>",
+        );
+        inline_css(&doc);
+
+        assert_eq!(
+            style_of(&doc, "#x").as_deref(),
+            Some("color: red; margin: 3px; padding: 1px !important")
+        );
+        assert_eq!(
+            style_of(&doc, "p:not(#x)").as_deref(),
+            Some("color: blue; margin: 0; padding: 1px !important")
+        );
+    }
+
+    /// Tests which rules stay in style elements.
+    ///
+    /// Verifies that at-rules and dynamic pseudo-class rules are kept, that
+    /// print-only style elements are not inlined, and that keep_style_elements
+    /// leaves style elements untouched.
+    #[test]
+    fn remaining_rules() {
+        let html = "<style>a { color: red } a:hover { color: blue } \
+                    @media (max-width: 600px) { a { color: green } }</style>\
+                    <style media=print>a { color: black }</style><a href=#>x</a>";
+
+        let doc = parse_html().one(html);
+        inline_css(&doc);
+        assert_eq!(style_of(&doc, "a").as_deref(), Some("color: red"));
+        let styles: Vec<String> = doc
+            .select("style")
+            .unwrap()
+            .map(|style| style.text_contents())
+            .collect();
+        assert_eq!(
+            styles,
+            vec![
+                "a:hover { color: blue }\n@media (max-width: 600px) { a { color: green } }",
+                "a { color: black }",
+            ]
+        );
+
+        let doc = parse_html().one(html);
+        let options = InlineCssOptions {
+            keep_style_elements: true,
+        };
+        inline_css_with_options(&doc, &options);
+        assert_eq!(style_of(&doc, "a").as_deref(), Some("color: red"));
+        let first = doc.select_first("style").unwrap();
+        assert!(first.text_contents().starts_with("a { color: red }"));
+    }
+}
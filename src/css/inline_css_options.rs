@@ -0,0 +1,9 @@
+/// Options for [`inline_css_with_options()`](super::inline_css_with_options).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct InlineCssOptions {
+    /// Whether to leave `<style>` elements untouched after inlining.
+    ///
+    /// By default, inlined rules are removed from their `<style>` element,
+    /// and `<style>` elements left without rules are removed entirely.
+    pub keep_style_elements: bool,
+}
@@ -0,0 +1,11 @@
+//! CSS processing over node trees.
+
+/// Style rule and declaration parsing.
+mod css_rule;
+/// CSS inlining.
+mod inline_css;
+/// CSS inlining options.
+mod inline_css_options;
+
+pub use inline_css::{inline_css, inline_css_with_options};
+pub use inline_css_options::InlineCssOptions;
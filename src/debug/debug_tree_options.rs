@@ -0,0 +1,13 @@
+/// Options for [`NodeRef::debug_tree_with_options()`](crate::NodeRef::debug_tree_with_options).
+///
+/// The defaults show element names with their `#id` and `.class` suffixes
+/// and print the whole subtree.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DebugTreeOptions {
+    /// Also show every attribute of each element as `name="value"`.
+    pub show_attributes: bool,
+    /// Maximum depth to print below the starting node.
+    ///
+    /// Children beyond this depth are replaced by a single `…` line.
+    pub max_depth: Option<usize>,
+}
@@ -0,0 +1,21 @@
+//! Debugging aids for inspecting node trees.
+//!
+//! [`NodeRef::debug_tree()`](crate::NodeRef::debug_tree) prints an indented
+//! outline of a subtree, [`NodeRef::to_dot()`](crate::NodeRef::to_dot)
+//! exports it as a Graphviz graph, and
+//! [`NodeRef::stats()`](crate::NodeRef::stats) counts its nodes and
+//! estimates its memory use.
+
+/// Debug tree options.
+mod debug_tree_options;
+/// Indented text rendering for NodeRef.
+mod node_ref_debug_tree;
+/// Graphviz DOT export for NodeRef.
+mod node_ref_dot;
+/// Node and memory statistics for NodeRef.
+mod node_ref_stats;
+/// Tree statistics.
+mod tree_stats;
+
+pub use debug_tree_options::DebugTreeOptions;
+pub use tree_stats::TreeStats;
@@ -0,0 +1,257 @@
+//! Indented text rendering of node trees.
+
+use std::fmt::Write;
+
+use super::DebugTreeOptions;
+use crate::iter::{NodeEdge, Traverse};
+use crate::tree::{NodeData, NodeRef};
+
+/// Debug tree methods for NodeRef.
+///
+/// Provides a readable, indented outline of a subtree for diagnosing
+/// tree-shape problems.
+impl NodeRef {
+    /// Renders this node and its descendants as an indented outline.
+    ///
+    /// Each node is printed on its own line, indented two spaces per level.
+    /// Elements show their tag name with `#id` and `.class` suffixes, text is
+    /// quoted and escaped, and template contents appear under a `#template`
+    /// line.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::parse_html;
+    /// use brik::traits::*;
+    ///
+    /// let doc = parse_html().one("<div class=container>Hi<br></div>");
+    /// let div = doc.select_first("div").unwrap();
+    /// assert_eq!(div.as_node().debug_tree(), "div.container\n  \"Hi\"\n  br\n");
+    /// ```
+    pub fn debug_tree(&self) -> String {
+        self.debug_tree_with_options(&DebugTreeOptions::default())
+    }
+
+    /// Renders this node and its descendants as an indented outline, with options.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::debug::DebugTreeOptions;
+    /// use brik::parse_html;
+    /// use brik::traits::*;
+    ///
+    /// let doc = parse_html().one("<a href=/x><b>deep</b></a>");
+    /// let a = doc.select_first("a").unwrap();
+    /// let options = DebugTreeOptions {
+    ///     show_attributes: true,
+    ///     max_depth: Some(1),
+    /// };
+    /// assert_eq!(
+    ///     a.as_node().debug_tree_with_options(&options),
+    ///     "a [href=\"/x\"]\n  b\n    …\n"
+    /// );
+    /// ```
+    pub fn debug_tree_with_options(&self, options: &DebugTreeOptions) -> String {
+        let mut out = String::new();
+        let elided = |depth: usize| {
+            options
+                .max_depth
+                .is_some_and(|max_depth| depth >= max_depth)
+        };
+        // The depth of the next node started.
+        let mut depth = 0;
+        // The node whose descendants are being passed over, past the
+        // depth limit.
+        let mut skipping: Option<NodeRef> = None;
+        // The walks in progress; each template's contents are walked after
+        // its children, two levels deeper, below a `#template` line.
+        let mut walks: Vec<Traverse> = vec![self.traverse_inclusive()];
+        while let Some(walk) = walks.last_mut() {
+            match walk.next() {
+                Some(NodeEdge::Start(node)) => {
+                    depth += 1;
+                    if skipping.is_some() {
+                        continue;
+                    }
+                    let _ = writeln!(out, "{}{}", "  ".repeat(depth - 1), label(&node, options));
+                    if has_children(&node) && elided(depth - 1) {
+                        let _ = writeln!(out, "{}…", "  ".repeat(depth));
+                        skipping = Some(node);
+                    }
+                }
+                Some(NodeEdge::End(node)) => {
+                    depth -= 1;
+                    if skipping.as_ref() == Some(&node) {
+                        skipping = None;
+                        continue;
+                    }
+                    if skipping.is_some() {
+                        continue;
+                    }
+                    if let Some(contents) = template_contents(&node) {
+                        let _ = writeln!(out, "{}#template", "  ".repeat(depth + 1));
+                        if elided(depth + 1) {
+                            let _ = writeln!(out, "{}…", "  ".repeat(depth + 2));
+                        } else {
+                            depth += 2;
+                            walks.push(contents.traverse());
+                        }
+                    }
+                }
+                None => {
+                    walks.pop();
+                    depth = depth.saturating_sub(2);
+                }
+            }
+        }
+        out
+    }
+}
+
+/// Returns the contents of a template element, if it has any.
+fn template_contents(node: &NodeRef) -> Option<NodeRef> {
+    node.as_element()
+        .and_then(|element| element.template_contents.clone())
+        .filter(|contents| contents.first_child().is_some())
+}
+
+/// Returns whether a node has children or template contents to outline.
+fn has_children(node: &NodeRef) -> bool {
+    node.first_child().is_some() || template_contents(node).is_some()
+}
+
+/// Returns the outline label for a node.
+fn label(node: &NodeRef, options: &DebugTreeOptions) -> String {
+    match node.data() {
+        NodeData::Document(_) => "#document".to_string(),
+        NodeData::DocumentFragment => "#document-fragment".to_string(),
+        NodeData::Doctype(doctype) => format!("<!DOCTYPE {}>", doctype.name),
+        NodeData::Element(element) => {
+            let mut label = element.name.local.to_string();
+            let attributes = element.attributes.borrow();
+            if let Some(id) = attributes.get("id") {
+                label.push('#');
+                label.push_str(id);
+            }
+            for class in attributes.get_tokens("class") {
+                label.push('.');
+                label.push_str(class);
+            }
+            if options.show_attributes && !attributes.map.is_empty() {
+                let rendered: Vec<String> = attributes
+                    .map
+                    .iter()
+                    .map(|(name, attribute)| match &attribute.prefix {
+                        Some(prefix) => {
+                            format!("{}:{}={:?}", prefix, name.local, &*attribute.value)
+                        }
+                        None => format!("{}={:?}", name.local, &*attribute.value),
+                    })
+                    .collect();
+                let _ = write!(label, " [{}]", rendered.join(" "));
+            }
+            label
+        }
+        NodeData::Text(text) => format!("{:?}", &**text.borrow()),
+        NodeData::Comment(text) => format!("<!--{}-->", text.borrow()),
+        NodeData::ProcessingInstruction(contents) => {
+            let contents = contents.borrow();
+            format!("<?{} {}?>", contents.0, contents.1)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::html5ever::tendril::TendrilSink;
+    use crate::parse_html;
+
+    /// Tests the outline of a whole document.
+    ///
+    /// Verifies indentation, node labels for each kind, text escaping, and
+    /// that template contents are grouped under a `#template` line.
+    #[test]
+    fn document() {
+        let doc = parse_html().one(
+            "<!DOCTYPE html><html><head></head><body id=b>a\nb<!--c-->\
+             <template><i>t</i></template></body></html>",
+        );
+        let expected = [
+            "#document",
+            "  <!DOCTYPE html>",
+            "  html",
+            "    head",
+            "    body#b",
+            "      \"a\\nb\"",
+            "      <!--c-->",
+            "      template",
+            "        #template",
+            "          i",
+            "            \"t\"",
+        ];
+        assert_eq!(doc.debug_tree(), expected.join("\n") + "\n");
+    }
+
+    /// Tests depth limits and attribute display.
+    ///
+    /// Edge case: a depth limit of zero elides all children, and prefixed
+    /// attributes are shown with their prefix.
+    #[test]
+    fn options() {
+        let doc = parse_html().one("<svg><use xlink:href='#i'/></svg>");
+        let svg = doc.select_first("svg").unwrap();
+
+        let elided = DebugTreeOptions {
+            max_depth: Some(0),
+            ..DebugTreeOptions::default()
+        };
+        assert_eq!(svg.as_node().debug_tree_with_options(&elided), "svg\n  …\n");
+
+        let attributes = DebugTreeOptions {
+            show_attributes: true,
+            ..DebugTreeOptions::default()
+        };
+        assert_eq!(
+            svg.as_node().debug_tree_with_options(&attributes),
+            "svg\n  use [xlink:href=\"#i\"]\n"
+        );
+    }
+
+    /// Tests the outline of a deeply nested tree.
+    ///
+    /// Edge case: nesting deeper than the call stack allows is written
+    /// without recursion, and a depth limit still elides it.
+    #[test]
+    fn deep_nesting() {
+        // Deep enough to overflow a recursive walk, shallow enough that the
+        // indentation stays small.
+        const DEPTH: usize = 10_000;
+        let root = NodeRef::new_element(
+            html5ever::QualName::new(None, ns!(html), local_name!("div")),
+            None,
+        );
+        let mut parent = root.clone();
+        for _ in 0..DEPTH {
+            let child = NodeRef::new_element(
+                html5ever::QualName::new(None, ns!(html), local_name!("div")),
+                None,
+            );
+            parent.append(child.clone());
+            parent = child;
+        }
+        let outline = root.debug_tree();
+        assert_eq!(outline.lines().count(), DEPTH + 1);
+        assert!(outline.ends_with(&format!("{}div\n", "  ".repeat(DEPTH))));
+
+        let options = DebugTreeOptions {
+            max_depth: Some(1),
+            ..DebugTreeOptions::default()
+        };
+        assert_eq!(
+            root.debug_tree_with_options(&options),
+            "div\n  div\n    …\n"
+        );
+    }
+}
@@ -0,0 +1,204 @@
+//! Graphviz DOT export of node trees.
+
+use std::fmt::Write;
+
+use crate::iter::{NodeEdge, Traverse};
+use crate::tree::{NodeData, NodeRef};
+
+/// Maximum number of characters of text shown in a DOT node label.
+const MAX_TEXT_LEN: usize = 20;
+
+/// DOT export methods for NodeRef.
+///
+/// Provides rendering of a subtree as a Graphviz graph for visual debugging.
+impl NodeRef {
+    /// Renders this node and its descendants as a Graphviz DOT graph.
+    ///
+    /// Each node is labelled with its kind: elements show their tag name with
+    /// `#id` and `.class` suffixes, and text, comments and processing
+    /// instructions show their content truncated to 20 characters.
+    /// Template contents are drawn with dashed edges.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::parse_html;
+    /// use brik::traits::*;
+    ///
+    /// let doc = parse_html().one("<div id=main class='a b'>Hello</div>");
+    /// let div = doc.select_first("div").unwrap();
+    /// assert_eq!(
+    ///     div.as_node().to_dot(),
+    ///     "digraph {\n  node [shape=box];\n  n0 [label=\"div#main.a.b\"];\n  \
+    ///      n1 [label=\"\\\"Hello\\\"\"];\n  n0 -> n1;\n}\n"
+    /// );
+    /// ```
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph {\n  node [shape=box];\n");
+        let mut next_id = 0;
+        // The identifiers of the nodes being written, outermost first.
+        let mut ids: Vec<usize> = Vec::new();
+        // The walks in progress, with the template and contents each walks.
+        let mut walks: Vec<(Traverse, Option<(NodeRef, NodeRef)>)> =
+            vec![(self.traverse_inclusive(), None)];
+        while let Some((walk, _)) = walks.last_mut() {
+            match walk.next() {
+                Some(NodeEdge::Start(node)) => {
+                    let _ = writeln!(dot, "  n{} [label=\"{}\"];", next_id, escape(&label(&node)));
+                    ids.push(next_id);
+                    next_id += 1;
+                }
+                Some(NodeEdge::End(node)) => {
+                    match node
+                        .as_element()
+                        .and_then(|element| element.template_contents.clone())
+                    {
+                        Some(contents) => walks.push((contents.traverse(), Some((node, contents)))),
+                        None => end_dot_node(&node, &mut ids, &walks, &mut dot),
+                    }
+                }
+                None => {
+                    if let Some((_, Some((template, _)))) = walks.pop() {
+                        end_dot_node(&template, &mut ids, &walks, &mut dot);
+                    }
+                }
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+/// Finishes writing a node, linking it to its parent once its descendants
+/// are written.
+///
+/// Children of template contents are linked to the template with dashed
+/// edges.
+fn end_dot_node(
+    node: &NodeRef,
+    ids: &mut Vec<usize>,
+    walks: &[(Traverse, Option<(NodeRef, NodeRef)>)],
+    dot: &mut String,
+) {
+    let id = ids.pop().expect("node was started");
+    if let Some(parent) = ids.last() {
+        let in_contents = walks
+            .last()
+            .and_then(|(_, template)| template.as_ref())
+            .is_some_and(|(_, contents)| node.parent().as_ref() == Some(contents));
+        let style = if in_contents { " [style=dashed]" } else { "" };
+        let _ = writeln!(dot, "  n{} -> n{}{};", parent, id, style);
+    }
+}
+
+/// Returns the unescaped label for a node.
+fn label(node: &NodeRef) -> String {
+    match node.data() {
+        NodeData::Document(_) => "#document".to_string(),
+        NodeData::DocumentFragment => "#document-fragment".to_string(),
+        NodeData::Doctype(doctype) => format!("<!DOCTYPE {}>", doctype.name),
+        NodeData::Element(element) => {
+            let mut label = element.name.local.to_string();
+            let attributes = element.attributes.borrow();
+            if let Some(id) = attributes.get("id") {
+                label.push('#');
+                label.push_str(id);
+            }
+            for class in attributes.get_tokens("class") {
+                label.push('.');
+                label.push_str(class);
+            }
+            label
+        }
+        NodeData::Text(text) => format!("\"{}\"", truncate(&text.borrow())),
+        NodeData::Comment(text) => format!("<!--{}-->", truncate(&text.borrow())),
+        NodeData::ProcessingInstruction(contents) => {
+            let contents = contents.borrow();
+            format!("<?{} {}?>", contents.0, truncate(&contents.1))
+        }
+    }
+}
+
+/// Truncates text to at most `MAX_TEXT_LEN` characters, marking the cut with an ellipsis.
+fn truncate(text: &str) -> String {
+    match text.char_indices().nth(MAX_TEXT_LEN) {
+        Some((index, _)) => format!("{}…", &text[..index]),
+        None => text.to_string(),
+    }
+}
+
+/// Escapes a label for use inside a double-quoted DOT string.
+fn escape(label: &str) -> String {
+    let mut escaped = String::with_capacity(label.len());
+    for c in label.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => {}
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::html5ever::tendril::TendrilSink;
+    use crate::parse_html;
+
+    /// Tests DOT output for a whole document.
+    ///
+    /// Verifies node labels for each node kind and that template contents
+    /// are linked with dashed edges.
+    #[test]
+    fn document() {
+        let doc = parse_html().one("<!DOCTYPE html><template><b>x</b></template><!--c-->");
+        let dot = doc.to_dot();
+
+        assert!(dot.starts_with("digraph {\n  node [shape=box];\n  n0 [label=\"#document\"];\n"));
+        assert!(dot.contains("[label=\"<!DOCTYPE html>\"];"));
+        assert!(dot.contains("[label=\"<!--c-->\"];"));
+        assert!(dot.contains("[style=dashed];"));
+        assert!(dot.ends_with("}\n"));
+    }
+
+    /// Tests truncation and escaping of text labels.
+    ///
+    /// Edge case: long text is cut at 20 characters, and quotes, backslashes
+    /// and newlines are escaped.
+    #[test]
+    fn text_labels() {
+        assert_eq!(truncate("short"), "short");
+        assert_eq!(
+            truncate("abcdefghijklmnopqrstuvwxyz"),
+            "abcdefghijklmnopqrst…"
+        );
+        assert_eq!(escape("a\"b\\c\nd"), "a\\\"b\\\\c\\nd");
+    }
+
+    /// Tests DOT output for a deeply nested tree.
+    ///
+    /// Edge case: nesting deeper than the call stack allows is written
+    /// without recursion.
+    #[test]
+    fn deep_nesting() {
+        let root = NodeRef::new_element(
+            html5ever::QualName::new(None, ns!(html), local_name!("div")),
+            None,
+        );
+        let mut parent = root.clone();
+        for _ in 0..100_000 {
+            let child = NodeRef::new_element(
+                html5ever::QualName::new(None, ns!(html), local_name!("div")),
+                None,
+            );
+            parent.append(child.clone());
+            parent = child;
+        }
+        let dot = root.to_dot();
+        assert!(dot.contains("  n99999 -> n100000;\n"));
+        assert_eq!(dot.lines().count(), 2 + 100_001 + 100_000 + 1);
+    }
+}
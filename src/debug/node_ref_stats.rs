@@ -0,0 +1,130 @@
+//! Node and memory statistics for node trees.
+
+use std::mem::size_of;
+
+use html5ever::tendril::StrTendril;
+
+use super::TreeStats;
+use crate::attributes::{Attribute, ExpandedName};
+use crate::tree::{Node, NodeData, NodeRef};
+
+/// Longest string a tendril stores inline, without a heap buffer.
+const MAX_INLINE_TENDRIL_LEN: usize = 8;
+
+/// Statistics methods for NodeRef.
+///
+/// Provides node counts and memory estimates for capacity planning.
+impl NodeRef {
+    /// Returns node counts per kind, attribute and text totals, the maximum
+    /// depth and an estimate of the heap memory used by this subtree.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::parse_html;
+    /// use brik::traits::*;
+    ///
+    /// let doc = parse_html().one("<p class=a>Hello <b>World</b></p>");
+    /// let stats = doc.stats();
+    /// assert_eq!(stats.elements, 5); // html, head, body, p, b
+    /// assert_eq!(stats.attributes, 1);
+    /// assert_eq!(stats.text_bytes, 11);
+    /// assert_eq!(stats.max_depth, 5); // the text inside <b>
+    /// ```
+    pub fn stats(&self) -> TreeStats {
+        let mut stats = TreeStats::default();
+        let mut stack = vec![(self.clone(), 0)];
+        while let Some((node, depth)) = stack.pop() {
+            stats.max_depth = stats.max_depth.max(depth);
+            // The node and its two reference counts.
+            stats.estimated_heap_bytes += size_of::<Node>() + 2 * size_of::<usize>();
+            match node.data() {
+                NodeData::Element(element) => {
+                    stats.elements += 1;
+                    let attributes = element.attributes.borrow();
+                    stats.attributes += attributes.map.len();
+                    // Each entry also stores its hash and an index slot.
+                    stats.estimated_heap_bytes += attributes.map.capacity()
+                        * (size_of::<(ExpandedName, Attribute)>() + 2 * size_of::<usize>());
+                    for attribute in attributes.map.values() {
+                        stats.estimated_heap_bytes += tendril_heap_bytes(&attribute.value);
+                    }
+                    if let Some(contents) = &element.template_contents {
+                        stack.push((contents.clone(), depth + 1));
+                    }
+                }
+                NodeData::Text(text) => {
+                    let text = text.borrow();
+                    stats.text_nodes += 1;
+                    stats.text_bytes += text.len();
+                    stats.estimated_heap_bytes += tendril_heap_bytes(&text);
+                }
+                NodeData::Comment(text) => {
+                    stats.comments += 1;
+                    stats.estimated_heap_bytes += tendril_heap_bytes(&text.borrow());
+                }
+                NodeData::ProcessingInstruction(value) => {
+                    let value = value.borrow();
+                    stats.processing_instructions += 1;
+                    stats.estimated_heap_bytes += value.0.capacity() + value.1.capacity();
+                }
+                NodeData::Doctype(doctype) => {
+                    stats.doctypes += 1;
+                    stats.estimated_heap_bytes += doctype.name.capacity()
+                        + doctype.public_id.capacity()
+                        + doctype.system_id.capacity();
+                }
+                NodeData::Document(_) => stats.documents += 1,
+                NodeData::DocumentFragment => stats.document_fragments += 1,
+            }
+            stack.extend(node.children().map(|child| (child, depth + 1)));
+        }
+        stats
+    }
+}
+
+/// Returns the out-of-line buffer size of a tendril, or zero if it is inline.
+fn tendril_heap_bytes(tendril: &StrTendril) -> usize {
+    if tendril.len() > MAX_INLINE_TENDRIL_LEN {
+        tendril.len()
+    } else {
+        0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::debug::TreeStats;
+    use crate::html5ever::tendril::TendrilSink;
+    use crate::parse_html;
+
+    /// Tests statistics for a parsed document.
+    ///
+    /// Verifies per-kind counts, including template contents, along with the
+    /// attribute, text and depth totals and a non-zero heap estimate.
+    #[test]
+    fn stats_counts_kinds() {
+        let doc = parse_html()
+            .one("<!DOCTYPE html><p id=a class=b>one<!--c--></p><template><i>two</i></template>");
+        let stats = doc.stats();
+
+        assert_eq!(
+            stats,
+            TreeStats {
+                elements: 6,
+                text_nodes: 2,
+                comments: 1,
+                doctypes: 1,
+                documents: 1,
+                document_fragments: 1,
+                attributes: 2,
+                text_bytes: 6,
+                max_depth: 6,
+                estimated_heap_bytes: stats.estimated_heap_bytes,
+                ..TreeStats::default()
+            }
+        );
+        assert_eq!(stats.node_count(), 12);
+        assert!(stats.estimated_heap_bytes > 12 * std::mem::size_of::<crate::Node>());
+    }
+}
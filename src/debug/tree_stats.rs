@@ -0,0 +1,48 @@
+/// Node and memory statistics for a tree, returned by
+/// [`NodeRef::stats()`](crate::NodeRef::stats).
+///
+/// Counts include template contents. The heap estimate covers node
+/// allocations, attribute storage and out-of-line string data; values shared
+/// between nodes are counted once per node.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TreeStats {
+    /// Number of element nodes.
+    pub elements: usize,
+    /// Number of text nodes.
+    pub text_nodes: usize,
+    /// Number of comment nodes.
+    pub comments: usize,
+    /// Number of processing instruction nodes.
+    pub processing_instructions: usize,
+    /// Number of doctype nodes.
+    pub doctypes: usize,
+    /// Number of document nodes.
+    pub documents: usize,
+    /// Number of document fragment nodes, including template contents.
+    pub document_fragments: usize,
+    /// Total number of attributes across all elements.
+    pub attributes: usize,
+    /// Total length in bytes of all text nodes.
+    pub text_bytes: usize,
+    /// Depth of the deepest node below the starting node, which has depth 0.
+    pub max_depth: usize,
+    /// Estimated heap usage of the tree, in bytes.
+    pub estimated_heap_bytes: usize,
+}
+
+/// Methods for TreeStats.
+///
+/// Provides totals derived from the per-kind counts.
+impl TreeStats {
+    /// Returns the total number of nodes of every kind.
+    #[inline]
+    pub fn node_count(&self) -> usize {
+        self.elements
+            + self.text_nodes
+            + self.comments
+            + self.processing_instructions
+            + self.doctypes
+            + self.documents
+            + self.document_fragments
+    }
+}
@@ -0,0 +1,15 @@
+use crate::node_data_ref::NodeDataRef;
+use crate::tree::ElementData;
+
+/// The main content of a document, as found by [`extract_article()`](super::extract_article).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Article {
+    /// The article title, if one was found.
+    pub title: Option<String>,
+    /// The author byline, if one was found.
+    pub byline: Option<String>,
+    /// The element containing the main content.
+    ///
+    /// This is an element of the original document, which is left unchanged.
+    pub content: NodeDataRef<ElementData>,
+}
@@ -0,0 +1,67 @@
+/// Options for [`remove_boilerplate_with_options()`](super::remove_boilerplate_with_options).
+///
+/// The defaults target common page chrome: navigation, site headers and
+/// footers, sidebars, cookie banners, ads and share widgets.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BoilerplateOptions {
+    /// Tag names of elements to remove.
+    ///
+    /// `<header>` and `<footer>` are only removed when they are not inside an
+    /// `<article>`, `<aside>`, `<main>`, `<nav>` or `<section>`, where they
+    /// belong to the surrounding content rather than to the page.
+    pub tags: Vec<String>,
+    /// ARIA `role` values of elements to remove.
+    pub roles: Vec<String>,
+    /// Words that mark an element for removal when they appear in its `class`
+    /// or `id`.
+    ///
+    /// Class names and ids are split into words on `-` and `_`, and each word
+    /// is compared ignoring ASCII case, so `cookie` matches `cookie-banner`
+    /// but not `cookies`.
+    pub patterns: Vec<String>,
+    /// Words in `class` or `id` that protect an element from removal, even if
+    /// it also matches `patterns`.
+    pub keep_patterns: Vec<String>,
+}
+
+/// Implements Default for BoilerplateOptions.
+///
+/// Provides the built-in page chrome heuristics.
+impl Default for BoilerplateOptions {
+    fn default() -> Self {
+        let strings = |words: &[&str]| words.iter().map(|word| word.to_string()).collect();
+        BoilerplateOptions {
+            tags: strings(&["nav", "header", "footer", "aside"]),
+            roles: strings(&[
+                "navigation",
+                "banner",
+                "contentinfo",
+                "complementary",
+                "search",
+            ]),
+            patterns: strings(&[
+                "ad",
+                "ads",
+                "advert",
+                "advertisement",
+                "banner",
+                "breadcrumb",
+                "breadcrumbs",
+                "consent",
+                "cookie",
+                "gdpr",
+                "menu",
+                "navbar",
+                "newsletter",
+                "popup",
+                "promo",
+                "share",
+                "sidebar",
+                "social",
+                "sponsor",
+                "sponsored",
+            ]),
+            keep_patterns: strings(&["article", "content", "main", "post"]),
+        }
+    }
+}
@@ -0,0 +1,233 @@
+use super::heuristics::{
+    class_and_id, link_density, matches_any, normalized_text, NEGATIVE_PATTERNS, POSITIVE_PATTERNS,
+};
+use super::Article;
+use crate::iter::NodeIterator;
+use crate::node_data_ref::NodeDataRef;
+use crate::tree::{ElementData, NodeRef};
+
+/// Minimum length of a paragraph's text for it to count towards scoring.
+const MIN_PARAGRAPH_LEN: usize = 25;
+
+/// Maximum length of a byline.
+const MAX_BYLINE_LEN: usize = 100;
+
+/// Finds the main content of a document, in the manner of Mozilla Readability.
+///
+/// Paragraph-like elements (`<p>`, `<pre>`, `<td>` and `<blockquote>`) with
+/// enough text are scored by their length and number of commas. Each score is
+/// added to the paragraph's parent, and half of it to its grandparent. Parents
+/// start with a bonus or penalty for their tag name, and for `class` and `id`
+/// values that suggest content (such as `article` or `content`) or page chrome
+/// (such as `nav`, `sidebar` or `comment`). Final scores are scaled down by
+/// the share of text inside links, and the highest-scoring element is the
+/// content. If nothing scores, the `<body>` is used.
+///
+/// The title is taken from `<meta property="og:title">`, `<title>` or the
+/// first `<h1>`. The byline is taken from `<meta name="author">`, or from a
+/// short element whose `class`, `id` or `rel` mentions the author or byline.
+///
+/// Returns `None` only if the document has neither scoring content nor a
+/// `<body>`.
+///
+/// # Examples
+///
+/// ```
+/// use brik::extract::extract_article;
+/// use brik::parse_html;
+/// use brik::traits::*;
+///
+/// let doc = parse_html().one(
+///     "<title>News</title><nav><a href=/>Home</a></nav>\
+///      <div class=story><p>First paragraph, with some commas, and text.</p>\
+///      <p>Second paragraph of the story, which is long enough.</p></div>",
+/// );
+/// let article = extract_article(&doc).unwrap();
+/// assert_eq!(article.title.as_deref(), Some("News"));
+/// assert_eq!(&*article.content.name.local, "div");
+/// ```
+pub fn extract_article(document: &NodeRef) -> Option<Article> {
+    let content = top_candidate(document).or_else(|| find_element(document, "body"))?;
+    Some(Article {
+        title: find_title(document),
+        byline: find_byline(document),
+        content,
+    })
+}
+
+/// Scores candidate containers and returns the best one.
+fn top_candidate(document: &NodeRef) -> Option<NodeDataRef<ElementData>> {
+    let mut candidates: Vec<(NodeDataRef<ElementData>, f64)> = Vec::new();
+    for paragraph in document.descendants().elements() {
+        if !matches!(&*paragraph.name.local, "p" | "pre" | "td" | "blockquote")
+            || is_unlikely(&paragraph)
+        {
+            continue;
+        }
+        let text = normalized_text(paragraph.as_node());
+        let length = text.chars().count();
+        if length < MIN_PARAGRAPH_LEN {
+            continue;
+        }
+        let score = 1.0 + text.matches(',').count() as f64 + (length as f64 / 100.0).min(3.0);
+
+        let mut ancestors = paragraph.as_node().ancestors().elements();
+        for share in [1.0, 0.5] {
+            let Some(ancestor) = ancestors.next() else {
+                break;
+            };
+            match candidates.iter_mut().find(|(node, _)| *node == ancestor) {
+                Some((_, total)) => *total += score * share,
+                None => {
+                    let initial = initial_score(&ancestor);
+                    candidates.push((ancestor, initial + score * share));
+                }
+            }
+        }
+    }
+    candidates
+        .into_iter()
+        .map(|(node, score)| {
+            let density = link_density(node.as_node());
+            (node, score * (1.0 - density))
+        })
+        .max_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map(|(node, _)| node)
+}
+
+/// Returns whether an element or one of its ancestors looks like page chrome.
+fn is_unlikely(element: &NodeDataRef<ElementData>) -> bool {
+    element
+        .as_node()
+        .inclusive_ancestors()
+        .elements()
+        .any(|ancestor| {
+            let names = class_and_id(&ancestor);
+            matches_any(&names, &NEGATIVE_PATTERNS) && !matches_any(&names, &POSITIVE_PATTERNS)
+        })
+}
+
+/// Returns the starting score of a candidate container.
+fn initial_score(element: &NodeDataRef<ElementData>) -> f64 {
+    let tag = match &*element.name.local {
+        "article" | "main" => 10.0,
+        "div" => 5.0,
+        "pre" | "td" | "blockquote" => 3.0,
+        "address" | "ol" | "ul" | "dl" | "dd" | "dt" | "li" | "form" => -3.0,
+        "h1" | "h2" | "h3" | "h4" | "h5" | "h6" | "th" => -5.0,
+        _ => 0.0,
+    };
+    let names = class_and_id(element);
+    let mut weight = 0.0;
+    if matches_any(&names, &POSITIVE_PATTERNS) {
+        weight += 25.0;
+    }
+    if matches_any(&names, &NEGATIVE_PATTERNS) {
+        weight -= 25.0;
+    }
+    tag + weight
+}
+
+/// Returns the first element with the given local name.
+fn find_element(document: &NodeRef, local: &str) -> Option<NodeDataRef<ElementData>> {
+    document
+        .descendants()
+        .elements()
+        .find(|element| &*element.name.local == local)
+}
+
+/// Returns the `content` of the first `<meta>` whose given attribute has the given value.
+fn meta_content(document: &NodeRef, attribute: &str, value: &str) -> Option<String> {
+    document.descendants().elements().find_map(|element| {
+        if element.name.local != local_name!("meta") {
+            return None;
+        }
+        let attributes = element.attributes.borrow();
+        if !attributes
+            .get(attribute)
+            .is_some_and(|found| found.trim().eq_ignore_ascii_case(value))
+        {
+            return None;
+        }
+        attributes
+            .get_trimmed(local_name!("content"))
+            .filter(|content| !content.is_empty())
+            .map(str::to_owned)
+    })
+}
+
+/// Returns the document title.
+fn find_title(document: &NodeRef) -> Option<String> {
+    meta_content(document, "property", "og:title").or_else(|| {
+        ["title", "h1"].iter().find_map(|local| {
+            find_element(document, local)
+                .map(|element| normalized_text(element.as_node()))
+                .filter(|title| !title.is_empty())
+        })
+    })
+}
+
+/// Returns the author byline.
+fn find_byline(document: &NodeRef) -> Option<String> {
+    meta_content(document, "name", "author").or_else(|| {
+        document.descendants().elements().find_map(|element| {
+            let names = class_and_id(&element);
+            let is_author = element
+                .attributes
+                .borrow()
+                .get_tokens(local_name!("rel"))
+                .any(|rel| rel.eq_ignore_ascii_case("author"));
+            if !is_author && !names.contains("byline") && !names.contains("author") {
+                return None;
+            }
+            let text = normalized_text(element.as_node());
+            let length = text.chars().count();
+            (length > 0 && length <= MAX_BYLINE_LEN).then_some(text)
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::html5ever::tendril::TendrilSink;
+    use crate::parse_html;
+
+    /// Tests extraction from a page with navigation, sidebar and footer.
+    ///
+    /// Verifies that the content container wins over link-heavy and
+    /// negatively named regions, and that the title and byline are found.
+    #[test]
+    fn page() {
+        let doc = parse_html().one(
+            "<head><meta property=og:title content='The Story'><title>Site | Story</title></head>\
+             <body><nav><ul><li><a href=/1>A long navigation link, with commas, here</a></li></ul></nav>\
+             <div class=sidebar><p>Sidebar text that is long enough, with a comma, to score.</p></div>\
+             <div id=main-content><span class=byline>By Sam Doe</span>\
+             <p>The first paragraph of the story, with a comma, and more words.</p>\
+             <p>The second paragraph continues, at some length, with details.</p>\
+             <p>The third paragraph ends the story, with a final comma.</p></div>\
+             <footer class=footer><p>Copyright notice that is also long enough.</p></footer></body>",
+        );
+        let article = extract_article(&doc).unwrap();
+
+        assert_eq!(article.title.as_deref(), Some("The Story"));
+        assert_eq!(article.byline.as_deref(), Some("By Sam Doe"));
+        let attributes = article.content.attributes.borrow();
+        assert_eq!(attributes.get("id"), Some("main-content"));
+    }
+
+    /// Tests extraction without scoring paragraphs.
+    ///
+    /// Edge case: when no paragraph is long enough, the body is returned and
+    /// the title falls back to the first heading.
+    #[test]
+    fn fallback() {
+        let doc = parse_html().one("<h1> Short </h1><p>Tiny.</p>");
+        let article = extract_article(&doc).unwrap();
+
+        assert_eq!(article.title.as_deref(), Some("Short"));
+        assert_eq!(article.byline, None);
+        assert_eq!(&*article.content.name.local, "body");
+    }
+}
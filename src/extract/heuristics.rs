@@ -0,0 +1,69 @@
+//! Class name and text heuristics shared by extraction transforms.
+
+use crate::iter::NodeIterator;
+use crate::node_data_ref::NodeDataRef;
+use crate::tree::{ElementData, NodeRef};
+
+/// Class and id fragments suggesting page chrome rather than content.
+pub(super) const NEGATIVE_PATTERNS: [&str; 16] = [
+    "banner",
+    "combx",
+    "comment",
+    "community",
+    "cookie",
+    "footer",
+    "header",
+    "menu",
+    "nav",
+    "popup",
+    "promo",
+    "related",
+    "share",
+    "sidebar",
+    "social",
+    "sponsor",
+];
+
+/// Class and id fragments suggesting main content.
+pub(super) const POSITIVE_PATTERNS: [&str; 7] = [
+    "article", "body", "content", "entry", "main", "post", "text",
+];
+
+/// Returns the lowercased `class` and `id` attributes of an element, joined by a space.
+pub(super) fn class_and_id(element: &NodeDataRef<ElementData>) -> String {
+    let attributes = element.attributes.borrow();
+    format!(
+        "{} {}",
+        attributes.get(local_name!("class")).unwrap_or_default(),
+        attributes.get(local_name!("id")).unwrap_or_default()
+    )
+    .to_ascii_lowercase()
+}
+
+/// Returns whether any pattern occurs in the given lowercased text.
+pub(super) fn matches_any(text: &str, patterns: &[&str]) -> bool {
+    patterns.iter().any(|pattern| text.contains(pattern))
+}
+
+/// Returns the text content of a node with whitespace runs collapsed.
+pub(super) fn normalized_text(node: &NodeRef) -> String {
+    node.text_contents()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Returns the share of a node's text that is inside links, from 0 to 1.
+pub(super) fn link_density(node: &NodeRef) -> f64 {
+    let total = normalized_text(node).chars().count();
+    if total == 0 {
+        return 0.0;
+    }
+    let linked: usize = node
+        .descendants()
+        .elements()
+        .filter(|element| element.name.local == local_name!("a"))
+        .map(|link| normalized_text(link.as_node()).chars().count())
+        .sum();
+    linked as f64 / total as f64
+}
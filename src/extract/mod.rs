@@ -0,0 +1,17 @@
+//! Heuristic extraction of content from documents.
+
+/// Extracted article.
+mod article;
+/// Boilerplate removal options.
+mod boilerplate_options;
+/// Main content extraction.
+mod extract_article;
+/// Shared scoring heuristics.
+mod heuristics;
+/// Boilerplate removal.
+mod remove_boilerplate;
+
+pub use article::Article;
+pub use boilerplate_options::BoilerplateOptions;
+pub use extract_article::extract_article;
+pub use remove_boilerplate::{remove_boilerplate, remove_boilerplate_with_options};
@@ -0,0 +1,152 @@
+use super::BoilerplateOptions;
+use crate::node_data_ref::NodeDataRef;
+use crate::tree::{ElementData, NodeRef};
+
+/// Removes page chrome such as navigation, headers, footers and ads.
+///
+/// This is equivalent to [`remove_boilerplate_with_options()`] with default options.
+///
+/// # Examples
+///
+/// ```
+/// use brik::extract::remove_boilerplate;
+/// use brik::parse_html;
+/// use brik::traits::*;
+///
+/// let doc = parse_html().one(
+///     "<header>Site</header><nav>Menu</nav>\
+///      <article><header>Title</header><p>Text</p></article>\
+///      <div class='cookie-banner'>Accept?</div><footer>Links</footer>",
+/// );
+/// let removed = remove_boilerplate(&doc);
+/// assert_eq!(removed.len(), 4);
+/// assert_eq!(doc.select_first("body").unwrap().text_contents(), "TitleText");
+/// ```
+pub fn remove_boilerplate(root: &NodeRef) -> Vec<NodeRef> {
+    remove_boilerplate_with_options(root, &BoilerplateOptions::default())
+}
+
+/// Removes page chrome from a tree, with options.
+///
+/// The tree is cleaned in place: every element matching the options is
+/// detached, and the detached elements are returned in document order.
+/// Descendants of a removed element are not examined separately. The
+/// `<html>`, `<head>`, `<body>`, `<main>` and `<article>` elements are never
+/// removed.
+pub fn remove_boilerplate_with_options(
+    root: &NodeRef,
+    options: &BoilerplateOptions,
+) -> Vec<NodeRef> {
+    let mut removed = Vec::new();
+    let mut descendants = root.descendants();
+    while let Some(node) = descendants.next() {
+        if let Some(element) = node.clone().into_element_ref() {
+            if is_boilerplate(&element, options) {
+                descendants.skip_subtree();
+                removed.push(node);
+            }
+        }
+    }
+    for node in &removed {
+        node.detach();
+    }
+    removed
+}
+
+/// Returns whether an element is page chrome under the given options.
+fn is_boilerplate(element: &NodeDataRef<ElementData>, options: &BoilerplateOptions) -> bool {
+    if element.name.ns != ns!(html)
+        || matches!(
+            &*element.name.local,
+            "html" | "head" | "body" | "main" | "article"
+        )
+    {
+        return false;
+    }
+
+    let attributes = element.attributes.borrow();
+    let words: Vec<String> = attributes
+        .get_tokens(local_name!("class"))
+        .chain(attributes.get(local_name!("id")))
+        .flat_map(|token| token.split(['-', '_']))
+        .filter(|word| !word.is_empty())
+        .map(str::to_ascii_lowercase)
+        .collect();
+    let has_word = |patterns: &[String]| {
+        patterns
+            .iter()
+            .any(|pattern| words.iter().any(|word| pattern.eq_ignore_ascii_case(word)))
+    };
+    if has_word(&options.keep_patterns) {
+        return false;
+    }
+
+    let local = &*element.name.local;
+    let tag_matches = options
+        .tags
+        .iter()
+        .any(|tag| tag.eq_ignore_ascii_case(local))
+        && (!matches!(local, "header" | "footer") || !in_sectioning_content(element));
+    let role_matches = attributes
+        .get_tokens(local_name!("role"))
+        .any(|role| options.roles.iter().any(|r| r.eq_ignore_ascii_case(role)));
+    tag_matches || role_matches || has_word(&options.patterns)
+}
+
+/// Returns whether an element is inside sectioning content or `<main>`.
+fn in_sectioning_content(element: &NodeDataRef<ElementData>) -> bool {
+    element.as_node().ancestors().any(|ancestor| {
+        ancestor.as_element().is_some_and(|ancestor| {
+            ancestor.name.ns == ns!(html)
+                && matches!(
+                    &*ancestor.name.local,
+                    "article" | "aside" | "main" | "nav" | "section"
+                )
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::html5ever::tendril::TendrilSink;
+    use crate::parse_html;
+
+    /// Tests removal by role, class words and keep patterns.
+    ///
+    /// Verifies that class names are matched by whole words, that keep
+    /// patterns protect content, and that removed subtrees are returned once.
+    #[test]
+    fn heuristics() {
+        let doc = parse_html().one(
+            "<div role=navigation><div class=ad>x</div></div>\
+             <div class='main-menu post-content'>kept</div>\
+             <div class=cookies>kept</div><div id=Share_Bar>y</div>\
+             <section><footer>kept</footer></section>",
+        );
+        let removed = remove_boilerplate(&doc);
+
+        assert_eq!(removed.len(), 2);
+        assert_eq!(
+            doc.select_first("body").unwrap().text_contents(),
+            "keptkeptkept"
+        );
+    }
+
+    /// Tests custom options.
+    ///
+    /// Verifies that only the configured tags and patterns are removed.
+    #[test]
+    fn custom_options() {
+        let doc = parse_html().one("<nav>n</nav><div class=widget>w</div><aside>a</aside>");
+        let options = BoilerplateOptions {
+            tags: vec!["aside".to_string()],
+            roles: Vec::new(),
+            patterns: vec!["widget".to_string()],
+            keep_patterns: Vec::new(),
+        };
+        remove_boilerplate_with_options(&doc, &options);
+
+        assert_eq!(doc.select_first("body").unwrap().text_contents(), "n");
+    }
+}
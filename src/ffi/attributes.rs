@@ -0,0 +1,79 @@
+use super::brik_node::node_arg;
+use super::c_str::{into_c_string, str_arg};
+use super::BrikNode;
+use std::os::raw::c_char;
+
+/// Returns the value of an element's attribute, to be freed with
+/// [`brik_string_free()`](super::brik_string_free).
+///
+/// Returns null if the node is not an element, the attribute is missing,
+/// or an argument is null or not UTF-8.
+///
+/// # Safety
+///
+/// `node` must be null or a live handle, and `name` null or a
+/// NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn brik_node_get_attribute(
+    node: *const BrikNode,
+    name: *const c_char,
+) -> *mut c_char {
+    let element = node_arg(node).and_then(|node| node.as_element());
+    let value = match (element, str_arg(name)) {
+        (Some(element), Some(name)) => element.attributes.borrow().get(name).map(str::to_string),
+        _ => None,
+    };
+    value.map_or(std::ptr::null_mut(), into_c_string)
+}
+
+/// Sets an element's attribute, returning whether it was set.
+///
+/// Returns false if the node is not an element or an argument is null or
+/// not UTF-8.
+///
+/// # Safety
+///
+/// `node` must be null or a live handle, and `name` and `value` null or
+/// NUL-terminated strings.
+#[no_mangle]
+pub unsafe extern "C" fn brik_node_set_attribute(
+    node: *const BrikNode,
+    name: *const c_char,
+    value: *const c_char,
+) -> bool {
+    let node = node_arg(node);
+    let element = node.and_then(|node| node.as_element());
+    match (node, element, str_arg(name), str_arg(value)) {
+        (Some(node), Some(element), Some(name), Some(value)) => {
+            element.attributes.borrow_mut().insert(name, value);
+            node.mark_dirty();
+            true
+        }
+        _ => false,
+    }
+}
+
+/// Removes an element's attribute, returning whether it was present.
+///
+/// # Safety
+///
+/// `node` must be null or a live handle, and `name` null or a
+/// NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn brik_node_remove_attribute(
+    node: *const BrikNode,
+    name: *const c_char,
+) -> bool {
+    let node = node_arg(node);
+    let element = node.and_then(|node| node.as_element());
+    match (node, element, str_arg(name)) {
+        (Some(node), Some(element), Some(name)) => {
+            let removed = element.attributes.borrow_mut().remove(name).is_some();
+            if removed {
+                node.mark_dirty();
+            }
+            removed
+        }
+        _ => false,
+    }
+}
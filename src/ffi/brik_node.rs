@@ -0,0 +1,84 @@
+use crate::tree::NodeRef;
+
+/// An opaque handle to a node, given to C as `BrikNode *`.
+///
+/// Free it with [`brik_node_free()`].
+#[derive(Debug)]
+pub struct BrikNode {
+    /// The node.
+    pub(super) node: NodeRef,
+    /// The root of the node's tree, held because nodes only hold their
+    /// parents weakly.
+    pub(super) _root: NodeRef,
+}
+
+/// Moves a node to C as a handle that keeps its whole tree alive.
+pub(super) fn into_handle(node: NodeRef) -> *mut BrikNode {
+    let root = root_of(&node);
+    Box::into_raw(Box::new(BrikNode { node, _root: root }))
+}
+
+/// Returns the root of the tree containing `node`.
+pub(super) fn root_of(node: &NodeRef) -> NodeRef {
+    node.inclusive_ancestors()
+        .last()
+        .unwrap_or_else(|| node.clone())
+}
+
+/// Borrows the node of a handle.
+///
+/// Returns `None` if `node` is null.
+///
+/// # Safety
+///
+/// `node` must be null or a live handle that is not freed during `'a`.
+pub(super) unsafe fn node_arg<'a>(node: *const BrikNode) -> Option<&'a NodeRef> {
+    node.as_ref().map(|handle| &handle.node)
+}
+
+/// Frees a node handle.
+///
+/// The tree stays alive while other handles to any of its nodes exist.
+///
+/// # Safety
+///
+/// `node` must be null or a handle returned by a brik function that has
+/// not been freed.
+#[no_mangle]
+pub unsafe extern "C" fn brik_node_free(node: *mut BrikNode) {
+    if !node.is_null() {
+        drop(Box::from_raw(node));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ffi::*;
+    use std::ffi::{CStr, CString};
+
+    /// Tests navigating from a node after freeing the document handle.
+    ///
+    /// Verifies that a node handle keeps its ancestors and siblings alive,
+    /// so the tree is not truncated once the document handle is gone.
+    #[test]
+    fn node_handle_keeps_tree_alive() {
+        let html = CString::new("<div><p>a</p><p>b</p></div>").unwrap();
+        let selector = CString::new("p").unwrap();
+        unsafe {
+            let doc = brik_parse_html(html.as_ptr());
+            let first = brik_select_first(doc, selector.as_ptr());
+            brik_node_free(doc);
+
+            let node = &(*first).node;
+            let div = node.parent().unwrap();
+            assert_eq!(div.as_element().unwrap().name.local.as_ref(), "div");
+            assert_eq!(node.next_sibling().unwrap().text_contents(), "b");
+            assert!(node.ancestors().last().unwrap().as_document().is_some());
+
+            let serialized = brik_node_serialize(first);
+            assert_eq!(CStr::from_ptr(serialized).to_str(), Ok("<p>a</p>"));
+            brik_string_free(serialized);
+            brik_node_free(first);
+        }
+    }
+}
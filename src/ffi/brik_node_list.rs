@@ -0,0 +1,58 @@
+use super::brik_node::into_handle;
+use super::BrikNode;
+use crate::tree::NodeRef;
+
+/// An opaque handle to a list of nodes, given to C as `BrikNodeList *`.
+///
+/// Free it with [`brik_node_list_free()`].
+#[derive(Debug)]
+pub struct BrikNodeList {
+    /// The nodes.
+    pub(super) nodes: Vec<NodeRef>,
+    /// The root of the nodes' tree, held so that handles taken from the
+    /// list keep the whole tree alive.
+    pub(super) _root: NodeRef,
+}
+
+/// Returns the number of nodes in a list, or 0 if `list` is null.
+///
+/// # Safety
+///
+/// `list` must be null or a live handle returned by a brik function.
+#[no_mangle]
+pub unsafe extern "C" fn brik_node_list_len(list: *const BrikNodeList) -> usize {
+    list.as_ref().map_or(0, |list| list.nodes.len())
+}
+
+/// Returns a new handle to the node at `index` in a list, or null if
+/// `list` is null or `index` is out of bounds.
+///
+/// The returned handle must be freed with
+/// [`brik_node_free()`](super::brik_node_free), independently of the list.
+///
+/// # Safety
+///
+/// `list` must be null or a live handle returned by a brik function.
+#[no_mangle]
+pub unsafe extern "C" fn brik_node_list_get(
+    list: *const BrikNodeList,
+    index: usize,
+) -> *mut BrikNode {
+    match list.as_ref().and_then(|list| list.nodes.get(index)) {
+        Some(node) => into_handle(node.clone()),
+        None => std::ptr::null_mut(),
+    }
+}
+
+/// Frees a node list handle.
+///
+/// # Safety
+///
+/// `list` must be null or a handle returned by a brik function that has
+/// not been freed.
+#[no_mangle]
+pub unsafe extern "C" fn brik_node_list_free(list: *mut BrikNodeList) {
+    if !list.is_null() {
+        drop(Box::from_raw(list));
+    }
+}
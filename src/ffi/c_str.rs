@@ -0,0 +1,40 @@
+use std::ffi::{CStr, CString};
+use std::os::raw::c_char;
+
+/// Borrows a NUL-terminated UTF-8 string from C.
+///
+/// Returns `None` if `ptr` is null or the string is not UTF-8.
+///
+/// # Safety
+///
+/// `ptr` must be null or point to a NUL-terminated string that stays valid
+/// and unchanged for `'a`.
+pub(super) unsafe fn str_arg<'a>(ptr: *const c_char) -> Option<&'a str> {
+    if ptr.is_null() {
+        return None;
+    }
+    CStr::from_ptr(ptr).to_str().ok()
+}
+
+/// Moves a string to C, replacing NUL characters with U+FFFD.
+pub(super) fn into_c_string(value: String) -> *mut c_char {
+    let value = if value.contains('\0') {
+        value.replace('\0', "\u{FFFD}")
+    } else {
+        value
+    };
+    CString::new(value).map_or(std::ptr::null_mut(), CString::into_raw)
+}
+
+/// Frees a string returned by brik.
+///
+/// # Safety
+///
+/// `value` must be null or a string returned by a brik function that has
+/// not been freed.
+#[no_mangle]
+pub unsafe extern "C" fn brik_string_free(value: *mut c_char) {
+    if !value.is_null() {
+        drop(CString::from_raw(value));
+    }
+}
@@ -0,0 +1,42 @@
+//! C ABI for embedding brik in other languages.
+//!
+//! Documents, nodes and node lists are passed to C as opaque handles that
+//! the caller frees with [`brik_node_free()`] and [`brik_node_list_free()`];
+//! strings returned by brik are freed with [`brik_string_free()`]. Node and
+//! node list handles hold the root of their tree as well as their nodes, so
+//! a handle keeps its whole tree alive, ancestors and siblings included,
+//! and handles may be freed in any order.
+//! Strings passed in must be NUL-terminated UTF-8. The matching C
+//! declarations are in `include/brik.h`.
+//!
+//! Handles are not thread-safe: use each tree from a single thread.
+//!
+//! **Note:** This module requires the `ffi` feature and is not available
+//! with the `safe` feature.
+
+/// Attribute access functions.
+mod attributes;
+/// Node handles.
+mod brik_node;
+/// Node list handles.
+mod brik_node_list;
+/// C string conversions.
+mod c_str;
+/// Parsing functions.
+mod parse;
+/// Selection functions.
+mod select;
+/// Serialization functions.
+mod serialize;
+
+pub use attributes::{
+    brik_node_get_attribute, brik_node_remove_attribute, brik_node_set_attribute,
+};
+pub use brik_node::{brik_node_free, BrikNode};
+pub use brik_node_list::{
+    brik_node_list_free, brik_node_list_get, brik_node_list_len, BrikNodeList,
+};
+pub use c_str::brik_string_free;
+pub use parse::{brik_parse_fragment, brik_parse_html};
+pub use select::{brik_select, brik_select_first};
+pub use serialize::{brik_node_serialize, brik_node_text_contents};
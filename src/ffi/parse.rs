@@ -0,0 +1,39 @@
+use super::brik_node::into_handle;
+use super::c_str::str_arg;
+use super::BrikNode;
+use html5ever::tendril::TendrilSink;
+use html5ever::QualName;
+use std::os::raw::c_char;
+
+/// Parses an HTML document, returning a handle to the document node.
+///
+/// Returns null if `html` is null or not UTF-8.
+///
+/// # Safety
+///
+/// `html` must be null or a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn brik_parse_html(html: *const c_char) -> *mut BrikNode {
+    match str_arg(html) {
+        Some(html) => into_handle(crate::parse_html().one(html)),
+        None => std::ptr::null_mut(),
+    }
+}
+
+/// Parses an HTML fragment in a `<body>` context, returning a handle to a
+/// document fragment holding the parsed nodes.
+///
+/// Returns null if `html` is null or not UTF-8.
+///
+/// # Safety
+///
+/// `html` must be null or a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn brik_parse_fragment(html: *const c_char) -> *mut BrikNode {
+    let html = match str_arg(html) {
+        Some(html) => html,
+        None => return std::ptr::null_mut(),
+    };
+    let context = QualName::new(None, ns!(html), local_name!("body"));
+    into_handle(crate::parse_fragment_nodes(context, Vec::new(), html))
+}
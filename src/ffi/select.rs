@@ -0,0 +1,89 @@
+use super::brik_node::{into_handle, node_arg, root_of};
+use super::c_str::str_arg;
+use super::{BrikNode, BrikNodeList};
+use std::os::raw::c_char;
+
+/// Returns the elements in a node's subtree matching `selectors`, in
+/// document order.
+///
+/// Returns null if an argument is null or not UTF-8, or if `selectors` is
+/// not a valid selector list.
+///
+/// # Safety
+///
+/// `node` must be null or a live handle, and `selectors` null or a
+/// NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn brik_select(
+    node: *const BrikNode,
+    selectors: *const c_char,
+) -> *mut BrikNodeList {
+    let (node, selectors) = match (node_arg(node), str_arg(selectors)) {
+        (Some(node), Some(selectors)) => (node, selectors),
+        _ => return std::ptr::null_mut(),
+    };
+    match node.select(selectors) {
+        Ok(matches) => Box::into_raw(Box::new(BrikNodeList {
+            nodes: matches.map(|element| element.as_node().clone()).collect(),
+            _root: root_of(node),
+        })),
+        Err(_) => std::ptr::null_mut(),
+    }
+}
+
+/// Returns the first element in a node's subtree matching `selectors`.
+///
+/// Returns null if there is no match, if an argument is null or not UTF-8,
+/// or if `selectors` is not a valid selector list.
+///
+/// # Safety
+///
+/// `node` must be null or a live handle, and `selectors` null or a
+/// NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn brik_select_first(
+    node: *const BrikNode,
+    selectors: *const c_char,
+) -> *mut BrikNode {
+    let (node, selectors) = match (node_arg(node), str_arg(selectors)) {
+        (Some(node), Some(selectors)) => (node, selectors),
+        _ => return std::ptr::null_mut(),
+    };
+    match node.select_first(selectors) {
+        Ok(element) => into_handle(element.as_node().clone()),
+        Err(()) => std::ptr::null_mut(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ffi::*;
+    use std::ffi::CString;
+
+    /// Tests selecting through handles.
+    ///
+    /// Verifies that list items get handles of their own that outlive the
+    /// list and the document handle, and that an invalid selector returns
+    /// null.
+    #[test]
+    fn select_handles_outlive_document() {
+        let html = CString::new("<p>a</p><p>b</p>").unwrap();
+        let selector = CString::new("p").unwrap();
+        let invalid = CString::new("p[").unwrap();
+        unsafe {
+            let doc = brik_parse_html(html.as_ptr());
+            let list = brik_select(doc, selector.as_ptr());
+            assert_eq!(brik_node_list_len(list), 2);
+            let second = brik_node_list_get(list, 1);
+            assert!(brik_node_list_get(list, 2).is_null());
+            assert!(brik_select(doc, invalid.as_ptr()).is_null());
+            brik_node_list_free(list);
+            brik_node_free(doc);
+
+            let text = brik_node_text_contents(second);
+            assert_eq!(std::ffi::CStr::from_ptr(text).to_str(), Ok("b"));
+            brik_string_free(text);
+            brik_node_free(second);
+        }
+    }
+}
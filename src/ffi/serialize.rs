@@ -0,0 +1,83 @@
+use super::brik_node::node_arg;
+use super::c_str::into_c_string;
+use super::BrikNode;
+use std::os::raw::c_char;
+
+/// Serializes a node and its descendants as HTML, to be freed with
+/// [`brik_string_free()`](super::brik_string_free).
+///
+/// Returns null if `node` is null.
+///
+/// # Safety
+///
+/// `node` must be null or a live handle.
+#[no_mangle]
+pub unsafe extern "C" fn brik_node_serialize(node: *const BrikNode) -> *mut c_char {
+    node_arg(node).map_or(std::ptr::null_mut(), |node| into_c_string(node.to_string()))
+}
+
+/// Returns the text content of a node, to be freed with
+/// [`brik_string_free()`](super::brik_string_free).
+///
+/// Returns null if `node` is null.
+///
+/// # Safety
+///
+/// `node` must be null or a live handle.
+#[no_mangle]
+pub unsafe extern "C" fn brik_node_text_contents(node: *const BrikNode) -> *mut c_char {
+    node_arg(node).map_or(std::ptr::null_mut(), |node| {
+        into_c_string(node.text_contents())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ffi::*;
+    use std::ffi::{CStr, CString};
+
+    /// Tests editing attributes and serializing through handles.
+    ///
+    /// Edge case: attribute functions on a non-element node fail instead
+    /// of crashing, and NUL characters in values are replaced.
+    #[test]
+    fn serialize_after_attribute_edits() {
+        let html = CString::new("<a href=/x>link</a>").unwrap();
+        let name = CString::new("href").unwrap();
+        let value = CString::new("/y").unwrap();
+        unsafe {
+            let fragment = brik_parse_fragment(html.as_ptr());
+            let first = brik_select_first(fragment, CString::new("a").unwrap().as_ptr());
+
+            let old = brik_node_get_attribute(first, name.as_ptr());
+            assert_eq!(CStr::from_ptr(old).to_str(), Ok("/x"));
+            brik_string_free(old);
+            assert!(brik_node_set_attribute(
+                first,
+                name.as_ptr(),
+                value.as_ptr()
+            ));
+            assert!(!brik_node_set_attribute(
+                fragment,
+                name.as_ptr(),
+                value.as_ptr()
+            ));
+            (*first)
+                .node
+                .as_element()
+                .unwrap()
+                .attributes
+                .borrow_mut()
+                .insert("title", "a\0b");
+
+            let serialized = brik_node_serialize(fragment);
+            assert_eq!(
+                CStr::from_ptr(serialized).to_str(),
+                Ok("<a href=\"/y\" title=\"a\u{FFFD}b\">link</a>")
+            );
+            brik_string_free(serialized);
+            brik_node_free(first);
+            brik_node_free(fragment);
+        }
+    }
+}
@@ -0,0 +1,249 @@
+use super::frozen_tree::FrozenEntry;
+use super::{FrozenNodeData, FrozenTree};
+use crate::attributes::ExpandedName;
+use crate::select::Selectors;
+use html5ever::{LocalName, QualName};
+use indexmap::IndexMap;
+use std::fmt;
+use std::iter;
+
+/// A handle to a node in a [`FrozenTree`].
+///
+/// Handles are cheap to copy and offer the read-only queries of
+/// [`NodeRef`](crate::NodeRef): navigation, descendants, text content and
+/// CSS selector matching.
+#[derive(Clone, Copy)]
+pub struct FrozenNode<'a> {
+    /// The tree holding the node.
+    tree: &'a FrozenTree,
+    /// The arena index of the node.
+    index: usize,
+}
+
+/// Methods for FrozenNode.
+///
+/// Provides navigation, inspection and selector queries.
+impl<'a> FrozenNode<'a> {
+    /// Creates a handle to the node at `index`.
+    pub(super) fn new(tree: &'a FrozenTree, index: usize) -> Self {
+        FrozenNode { tree, index }
+    }
+
+    /// Returns the arena entry of this node.
+    fn entry(&self) -> &'a FrozenEntry {
+        &self.tree.nodes[self.index]
+    }
+
+    /// Returns a handle to the node at an optional index.
+    fn at(&self, index: Option<usize>) -> Option<FrozenNode<'a>> {
+        index.map(|index| FrozenNode::new(self.tree, index))
+    }
+
+    /// Returns the tree holding this node.
+    #[inline]
+    pub fn tree(&self) -> &'a FrozenTree {
+        self.tree
+    }
+
+    /// Returns the arena index of this node.
+    #[inline]
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the data of this node.
+    #[inline]
+    pub fn data(&self) -> &'a FrozenNodeData {
+        &self.entry().data
+    }
+
+    /// Returns the parent of this node, unless it is a root.
+    #[inline]
+    pub fn parent(&self) -> Option<FrozenNode<'a>> {
+        self.at(self.entry().parent)
+    }
+
+    /// Returns the first child of this node, if any.
+    #[inline]
+    pub fn first_child(&self) -> Option<FrozenNode<'a>> {
+        self.at(self.entry().first_child)
+    }
+
+    /// Returns the last child of this node, if any.
+    #[inline]
+    pub fn last_child(&self) -> Option<FrozenNode<'a>> {
+        self.at(self.entry().last_child)
+    }
+
+    /// Returns the previous sibling of this node, if any.
+    #[inline]
+    pub fn previous_sibling(&self) -> Option<FrozenNode<'a>> {
+        self.at(self.entry().previous_sibling)
+    }
+
+    /// Returns the next sibling of this node, if any.
+    #[inline]
+    pub fn next_sibling(&self) -> Option<FrozenNode<'a>> {
+        self.at(self.entry().next_sibling)
+    }
+
+    /// Returns the template contents fragment, if this is a `<template>` element.
+    #[inline]
+    pub fn template_contents(&self) -> Option<FrozenNode<'a>> {
+        self.at(self.entry().template_contents)
+    }
+
+    /// Returns true if this node is an element.
+    #[inline]
+    pub fn is_element(&self) -> bool {
+        matches!(self.data(), FrozenNodeData::Element { .. })
+    }
+
+    /// Returns the name of this node, if it is an element.
+    #[inline]
+    pub fn name(&self) -> Option<&'a QualName> {
+        match self.data() {
+            FrozenNodeData::Element { name, .. } => Some(name),
+            _ => None,
+        }
+    }
+
+    /// Returns the attributes of this node, if it is an element.
+    #[inline]
+    pub fn attributes(&self) -> Option<&'a IndexMap<ExpandedName, String>> {
+        match self.data() {
+            FrozenNodeData::Element { attributes, .. } => Some(attributes),
+            _ => None,
+        }
+    }
+
+    /// Returns the value of an attribute in no namespace, if this is an element.
+    #[inline]
+    pub fn attr(&self, name: &str) -> Option<&'a str> {
+        self.attributes()?
+            .get(&ExpandedName::new(ns!(), LocalName::from(name)))
+            .map(String::as_str)
+    }
+
+    /// Returns an iterator over the children of this node.
+    pub fn children(&self) -> impl Iterator<Item = FrozenNode<'a>> + 'a {
+        iter::successors(self.first_child(), FrozenNode::next_sibling)
+    }
+
+    /// Returns an iterator over this node and its descendants, in document order.
+    ///
+    /// Template contents are not included.
+    pub fn inclusive_descendants(&self) -> impl Iterator<Item = FrozenNode<'a>> + 'a {
+        let root = *self;
+        iter::successors(Some(root), move |node| node.next_in_subtree(root))
+    }
+
+    /// Returns an iterator over the descendants of this node, in document order.
+    ///
+    /// Template contents are not included.
+    pub fn descendants(&self) -> impl Iterator<Item = FrozenNode<'a>> + 'a {
+        self.inclusive_descendants().skip(1)
+    }
+
+    /// Returns the node after this one in document order, within `root`.
+    fn next_in_subtree(&self, root: FrozenNode<'a>) -> Option<FrozenNode<'a>> {
+        if let Some(child) = self.first_child() {
+            return Some(child);
+        }
+        let mut node = *self;
+        loop {
+            if node.index == root.index {
+                return None;
+            }
+            if let Some(next) = node.next_sibling() {
+                return Some(next);
+            }
+            node = node.parent()?;
+        }
+    }
+
+    /// Returns the concatenation of all text nodes in this subtree.
+    pub fn text_contents(&self) -> String {
+        self.inclusive_descendants()
+            .filter_map(|node| match node.data() {
+                FrozenNodeData::Text(text) => Some(text.as_str()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Returns true if this node is an element matching any of the selectors.
+    pub fn matches(&self, selectors: &Selectors) -> bool {
+        self.is_element()
+            && selectors
+                .0
+                .iter()
+                .any(|selector| selector.matches_in_scope(self, None))
+    }
+
+    /// Returns the elements in this subtree, including this node, that match
+    /// the selectors.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(())` if the selector string fails to parse.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::parse_html;
+    /// use brik::traits::*;
+    ///
+    /// let frozen = parse_html().one("<ul><li class=a>1</li><li>2</li></ul>").freeze();
+    /// let texts: Vec<String> = frozen
+    ///     .root()
+    ///     .select("li:not(.a)")
+    ///     .unwrap()
+    ///     .map(|li| li.text_contents())
+    ///     .collect();
+    /// assert_eq!(texts, ["2"]);
+    /// ```
+    pub fn select(&self, selectors: &str) -> Result<impl Iterator<Item = FrozenNode<'a>> + 'a, ()> {
+        let selectors = Selectors::compile(selectors)?;
+        Ok(self
+            .inclusive_descendants()
+            .filter(move |node| node.matches(&selectors)))
+    }
+
+    /// Returns the first element in this subtree, including this node, that
+    /// matches the selectors.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(())` if the selector string fails to parse or if no
+    /// element matches.
+    pub fn select_first(&self, selectors: &str) -> Result<FrozenNode<'a>, ()> {
+        self.select(selectors)?.next().ok_or(())
+    }
+}
+
+/// Implements PartialEq for FrozenNode.
+///
+/// Handles are equal when they refer to the same node of the same tree.
+impl PartialEq for FrozenNode<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::eq(self.tree, other.tree) && self.index == other.index
+    }
+}
+
+/// Implements Eq for FrozenNode.
+///
+/// Equality is node identity, which is reflexive.
+impl Eq for FrozenNode<'_> {}
+
+/// Implements Debug for FrozenNode.
+///
+/// Shows the arena index and data of the node rather than the whole tree.
+impl fmt::Debug for FrozenNode<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FrozenNode")
+            .field("index", &self.index)
+            .field("data", self.data())
+            .finish()
+    }
+}
@@ -0,0 +1,39 @@
+use crate::attributes::ExpandedName;
+use crate::tree::Doctype;
+use html5ever::tree_builder::QuirksMode;
+use html5ever::QualName;
+use indexmap::IndexMap;
+
+/// The data of a node in a [`FrozenTree`](super::FrozenTree).
+///
+/// Mirrors [`NodeData`](crate::NodeData) without interior mutability.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FrozenNodeData {
+    /// An element with its name and attributes.
+    Element {
+        /// The namespace and local name of the element.
+        name: QualName,
+        /// The attribute values of the element, in source order.
+        attributes: IndexMap<ExpandedName, String>,
+    },
+    /// A text node.
+    Text(String),
+    /// A comment node.
+    Comment(String),
+    /// A processing instruction node.
+    ProcessingInstruction {
+        /// The target of the processing instruction.
+        target: String,
+        /// The data of the processing instruction.
+        data: String,
+    },
+    /// A doctype node.
+    Doctype(Doctype),
+    /// The root of a document.
+    Document {
+        /// The quirks mode recorded by the parser.
+        quirks_mode: QuirksMode,
+    },
+    /// The root of a document fragment, such as template contents.
+    DocumentFragment,
+}
@@ -0,0 +1,131 @@
+//! Parallel iteration over frozen trees.
+
+use super::FrozenNode;
+use crate::select::Selectors;
+use rayon::prelude::*;
+
+/// Parallel query methods for FrozenNode.
+///
+/// Frozen trees store each subtree as a contiguous run of the arena, so a
+/// subtree can be split across threads by index without walking it first.
+impl<'a> FrozenNode<'a> {
+    /// Returns the arena index just past the last node of this subtree.
+    fn subtree_end(&self) -> usize {
+        let mut last = *self;
+        while let Some(child) = last.last_child() {
+            last = child;
+        }
+        last.index() + 1
+    }
+
+    /// Returns a parallel iterator over this node and its descendants.
+    ///
+    /// Template contents are not included. Collecting the iterator keeps
+    /// document order.
+    pub fn par_inclusive_descendants(
+        &self,
+    ) -> impl IndexedParallelIterator<Item = FrozenNode<'a>> + 'a {
+        let tree = self.tree();
+        (self.index()..self.subtree_end())
+            .into_par_iter()
+            .map(move |index| FrozenNode::new(tree, index))
+    }
+
+    /// Returns a parallel iterator over the descendants of this node.
+    ///
+    /// Template contents are not included. Collecting the iterator keeps
+    /// document order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::frozen::FrozenNodeData;
+    /// use brik::parse_html;
+    /// use brik::traits::*;
+    /// use rayon::prelude::*;
+    ///
+    /// let frozen = parse_html().one("<p>one</p><p>two three</p>").freeze();
+    /// let words: usize = frozen
+    ///     .root()
+    ///     .par_descendants()
+    ///     .map(|node| match node.data() {
+    ///         FrozenNodeData::Text(text) => text.split_whitespace().count(),
+    ///         _ => 0,
+    ///     })
+    ///     .sum();
+    /// assert_eq!(words, 3);
+    /// ```
+    pub fn par_descendants(&self) -> impl IndexedParallelIterator<Item = FrozenNode<'a>> + 'a {
+        self.par_inclusive_descendants().skip(1)
+    }
+
+    /// Returns a parallel iterator over the elements in this subtree,
+    /// including this node, that match the selectors.
+    ///
+    /// Collecting the iterator keeps document order.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(())` if the selector string fails to parse.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::parse_html;
+    /// use brik::traits::*;
+    /// use rayon::prelude::*;
+    ///
+    /// let frozen = parse_html().one("<ul><li class=a>1</li><li>2</li></ul>").freeze();
+    /// let texts: Vec<String> = frozen
+    ///     .root()
+    ///     .par_select("li")
+    ///     .unwrap()
+    ///     .map(|li| li.text_contents())
+    ///     .collect();
+    /// assert_eq!(texts, ["1", "2"]);
+    /// ```
+    pub fn par_select(
+        &self,
+        selectors: &str,
+    ) -> Result<impl ParallelIterator<Item = FrozenNode<'a>> + 'a, ()> {
+        let selectors = Selectors::compile(selectors)?;
+        Ok(self
+            .par_inclusive_descendants()
+            .filter(move |node| node.matches(&selectors)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::html5ever::tendril::TendrilSink;
+    use crate::parse_html;
+    use rayon::prelude::*;
+
+    /// Tests that parallel iteration matches sequential iteration.
+    ///
+    /// Verifies that descendants and selector matches of an inner node are
+    /// the same, in the same order, and that template contents and
+    /// following siblings are left out.
+    #[test]
+    fn matches_sequential_iteration() {
+        let frozen = parse_html()
+            .one(
+                "<div><p>a<b>b</b></p><template><p>t</p></template><p>c</p></div>\
+                 <p>after</p>",
+            )
+            .freeze();
+        let div = frozen.root().select_first("div").unwrap();
+
+        let sequential: Vec<usize> = div.descendants().map(|node| node.index()).collect();
+        let parallel: Vec<usize> = div.par_descendants().map(|node| node.index()).collect();
+        assert_eq!(parallel, sequential);
+
+        let texts: Vec<String> = div
+            .par_select("p")
+            .unwrap()
+            .map(|p| p.text_contents())
+            .collect();
+        assert_eq!(texts, ["ab", "c"]);
+        assert!(div.par_select("p[").is_err());
+    }
+}
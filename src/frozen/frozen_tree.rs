@@ -0,0 +1,92 @@
+use super::{FrozenNode, FrozenNodeData};
+
+/// An immutable snapshot of a node tree, created by
+/// [`NodeRef::freeze()`](crate::NodeRef::freeze).
+///
+/// Nodes are stored in a single arena and linked by index, in document
+/// order, with the root at index 0. Template contents are stored after the
+/// tree they belong to. Use [`FrozenTree::root()`] to start querying.
+///
+/// # Examples
+///
+/// ```
+/// use brik::parse_html;
+/// use brik::traits::*;
+/// use std::sync::Arc;
+///
+/// let frozen = Arc::new(parse_html().one("<p>a</p><p>b</p>").freeze());
+/// let handles: Vec<_> = (0..2)
+///     .map(|_| {
+///         let frozen = Arc::clone(&frozen);
+///         std::thread::spawn(move || frozen.root().select("p").unwrap().count())
+///     })
+///     .collect();
+/// for handle in handles {
+///     assert_eq!(handle.join().unwrap(), 2);
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct FrozenTree {
+    /// The nodes, indexed by position.
+    pub(super) nodes: Vec<FrozenEntry>,
+}
+
+/// A node in the arena, with the indices of its relatives.
+#[derive(Debug, Clone)]
+pub(super) struct FrozenEntry {
+    /// The node data.
+    pub(super) data: FrozenNodeData,
+    /// The index of the parent node.
+    pub(super) parent: Option<usize>,
+    /// The index of the previous sibling.
+    pub(super) previous_sibling: Option<usize>,
+    /// The index of the next sibling.
+    pub(super) next_sibling: Option<usize>,
+    /// The index of the first child.
+    pub(super) first_child: Option<usize>,
+    /// The index of the last child.
+    pub(super) last_child: Option<usize>,
+    /// The index of the template contents fragment, for templates.
+    pub(super) template_contents: Option<usize>,
+}
+
+/// Methods for FrozenEntry.
+///
+/// Provides construction of unlinked entries.
+impl FrozenEntry {
+    /// Creates an entry with the given data and parent, and no other links.
+    pub(super) fn new(data: FrozenNodeData, parent: Option<usize>) -> Self {
+        FrozenEntry {
+            data,
+            parent,
+            previous_sibling: None,
+            next_sibling: None,
+            first_child: None,
+            last_child: None,
+            template_contents: None,
+        }
+    }
+}
+
+/// Methods for FrozenTree.
+///
+/// Provides access to the nodes of the snapshot.
+impl FrozenTree {
+    /// Returns the root node, the node that was frozen.
+    #[inline]
+    pub fn root(&self) -> FrozenNode<'_> {
+        FrozenNode::new(self, 0)
+    }
+
+    /// Returns the node at the given arena index, if there is one.
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<FrozenNode<'_>> {
+        (index < self.nodes.len()).then(|| FrozenNode::new(self, index))
+    }
+
+    /// Returns the number of nodes, including template contents.
+    #[inline]
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+}
@@ -0,0 +1,27 @@
+//! Immutable, thread-safe snapshots of node trees.
+//!
+//! [`NodeRef::freeze()`](crate::NodeRef::freeze) copies a tree into a
+//! [`FrozenTree`], a flat arena of nodes linked by index. Frozen trees are
+//! `Send + Sync`, so a document can be parsed once and queried from many
+//! threads at the same time. With the `rayon` feature,
+//! `FrozenNode::par_descendants()` and `FrozenNode::par_select()` split
+//! a single query across threads.
+
+// Selector queries return `Result<_, ()>` like their `NodeRef` counterparts.
+#![allow(clippy::result_unit_err)]
+
+/// Handle to a node in a frozen tree.
+mod frozen_node;
+/// Node data stored in a frozen tree.
+mod frozen_node_data;
+/// Parallel iteration for FrozenNode.
+#[cfg(feature = "rayon")]
+mod frozen_node_par;
+/// Arena of frozen nodes.
+mod frozen_tree;
+/// Freezing for NodeRef.
+mod node_ref_freeze;
+
+pub use frozen_node::FrozenNode;
+pub use frozen_node_data::FrozenNodeData;
+pub use frozen_tree::FrozenTree;
@@ -0,0 +1,181 @@
+//! Freezing of node trees into immutable snapshots.
+
+use super::frozen_tree::FrozenEntry;
+use super::{FrozenNodeData, FrozenTree};
+use crate::iter::NodeEdge;
+use crate::tree::{NodeData, NodeRef};
+
+/// Freezing methods for NodeRef.
+///
+/// Provides conversion of a subtree into a thread-safe snapshot.
+impl NodeRef {
+    /// Returns an immutable, `Send + Sync` snapshot of this node and its
+    /// descendants, including template contents.
+    ///
+    /// The snapshot is a copy: later changes to this tree are not reflected
+    /// in it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::parse_html;
+    /// use brik::traits::*;
+    ///
+    /// let doc = parse_html().one("<p>Hello <b>World</b></p>");
+    /// let frozen = doc.freeze();
+    /// let p = frozen.root().select_first("p").unwrap();
+    /// assert_eq!(p.text_contents(), "Hello World");
+    /// ```
+    pub fn freeze(&self) -> FrozenTree {
+        let mut nodes: Vec<FrozenEntry> = Vec::new();
+        let mut pending: Vec<(NodeRef, Option<usize>)> = vec![(self.clone(), None)];
+        while let Some((root, owner)) = pending.pop() {
+            if let Some(owner) = owner {
+                nodes[owner].template_contents = Some(nodes.len());
+            }
+            let mut ancestors: Vec<usize> = Vec::new();
+            for edge in root.traverse_inclusive() {
+                let node = match edge {
+                    NodeEdge::Start(node) => node,
+                    NodeEdge::End(_) => {
+                        ancestors.pop();
+                        continue;
+                    }
+                };
+                let index = nodes.len();
+                let parent = ancestors.last().copied();
+                let mut entry = FrozenEntry::new(freeze_data(node.data()), parent);
+                if let Some(parent) = parent {
+                    match nodes[parent].last_child.replace(index) {
+                        Some(previous) => {
+                            nodes[previous].next_sibling = Some(index);
+                            entry.previous_sibling = Some(previous);
+                        }
+                        None => nodes[parent].first_child = Some(index),
+                    }
+                }
+                if let Some(contents) = node
+                    .as_element()
+                    .and_then(|element| element.template_contents.clone())
+                {
+                    pending.push((contents, Some(index)));
+                }
+                nodes.push(entry);
+                ancestors.push(index);
+            }
+        }
+        FrozenTree { nodes }
+    }
+}
+
+/// Copies node data into its frozen form.
+fn freeze_data(data: &NodeData) -> FrozenNodeData {
+    match data {
+        NodeData::Element(element) => FrozenNodeData::Element {
+            name: element.name.clone(),
+            attributes: element
+                .attributes
+                .borrow()
+                .map
+                .iter()
+                .map(|(name, attribute)| (name.clone(), attribute.value.to_string()))
+                .collect(),
+        },
+        NodeData::Text(text) => FrozenNodeData::Text(text.borrow().to_string()),
+        NodeData::Comment(text) => FrozenNodeData::Comment(text.borrow().to_string()),
+        NodeData::ProcessingInstruction(value) => {
+            let (target, data) = value.borrow().clone();
+            FrozenNodeData::ProcessingInstruction { target, data }
+        }
+        NodeData::Doctype(doctype) => FrozenNodeData::Doctype(doctype.clone()),
+        NodeData::Document(document) => FrozenNodeData::Document {
+            quirks_mode: document.quirks_mode(),
+        },
+        NodeData::DocumentFragment => FrozenNodeData::DocumentFragment,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::frozen::{FrozenNodeData, FrozenTree};
+    use crate::html5ever::tendril::TendrilSink;
+    use crate::parse_html;
+
+    /// Asserts at compile time that a type is `Send + Sync`.
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    /// Tests that a frozen tree mirrors the original.
+    ///
+    /// Verifies that navigation, descendants, text and selector results
+    /// match those of the mutable tree, and that the snapshot is unaffected
+    /// by later changes.
+    #[test]
+    fn freeze_mirrors_tree() {
+        assert_send_sync::<FrozenTree>();
+        let html =
+            "<div id=a><p class=x>1</p><!--c--><p>2<i>3</i></p></div><ul><li>4</li><li>5</li></ul>";
+        let doc = parse_html().one(html);
+        let frozen = doc.freeze();
+        doc.select_first("ul").unwrap().as_node().detach();
+
+        let root = frozen.root();
+        assert_eq!(root.text_contents(), "12345");
+        assert_eq!(
+            root.descendants().count(),
+            doc.descendants().count() + 5,
+            "the snapshot keeps the detached list"
+        );
+        for selector in [
+            "p",
+            "div > p:last-child",
+            "#a *",
+            ":root",
+            "li:nth-child(2)",
+            "p:empty",
+        ] {
+            let frozen_texts: Vec<String> = root
+                .select(selector)
+                .unwrap()
+                .map(|node| node.text_contents())
+                .collect();
+            let texts: Vec<String> = parse_html()
+                .one(html)
+                .select(selector)
+                .unwrap()
+                .map(|node| node.text_contents())
+                .collect();
+            assert_eq!(frozen_texts, texts, "selector {}", selector);
+        }
+
+        let comment = root.select_first("p").unwrap().next_sibling().unwrap();
+        assert_eq!(comment.data(), &FrozenNodeData::Comment("c".to_string()));
+        assert_eq!(comment.previous_sibling().unwrap().attr("class"), Some("x"));
+        assert_eq!(
+            comment
+                .parent()
+                .unwrap()
+                .last_child()
+                .unwrap()
+                .text_contents(),
+            "23"
+        );
+    }
+
+    /// Tests freezing template contents.
+    ///
+    /// Edge case: template contents are reachable through the template but
+    /// are not descendants, matching the mutable tree.
+    #[test]
+    fn freeze_template_contents() {
+        let doc = parse_html().one("<template><b>t</b></template><b>u</b>");
+        let frozen = doc.freeze();
+        let template = frozen.root().select_first("template").unwrap();
+
+        assert_eq!(frozen.root().select("b").unwrap().count(), 1);
+        let contents = template.template_contents().unwrap();
+        assert_eq!(contents.data(), &FrozenNodeData::DocumentFragment);
+        assert_eq!(contents.text_contents(), "t");
+        assert!(contents.parent().is_none());
+        assert_eq!(frozen.get(contents.index()), Some(contents));
+    }
+}
@@ -0,0 +1,29 @@
+use crate::node_data_ref::NodeDataRef;
+use crate::tree::{ElementData, NodeRef};
+use html5ever::LocalName;
+
+/// Scriptable content removed by
+/// [`NodeRef::strip_active_content()`](crate::NodeRef::strip_active_content).
+#[derive(Debug, Clone, PartialEq)]
+pub enum ActiveContent {
+    /// An event handler attribute, such as `onclick`.
+    EventHandler {
+        /// The element the attribute was removed from.
+        element: NodeDataRef<ElementData>,
+        /// The attribute's local name.
+        attribute: LocalName,
+        /// The attribute's value.
+        value: String,
+    },
+    /// A URL attribute with a `javascript:` or `data:text/html` URL.
+    Url {
+        /// The element the attribute was removed from.
+        element: NodeDataRef<ElementData>,
+        /// The attribute's local name.
+        attribute: LocalName,
+        /// The attribute's value.
+        value: String,
+    },
+    /// A `<script>` element, now detached.
+    Script(NodeRef),
+}
@@ -0,0 +1,166 @@
+use std::ops::Deref;
+
+use super::resolve_url;
+use crate::node_data_ref::NodeDataRef;
+use crate::tree::ElementData;
+
+/// A typed view of an HTML `<a>` or `<area>` element.
+///
+/// Dereferences to the underlying [`NodeDataRef<ElementData>`], so all element
+/// and node methods remain available.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AnchorRef(NodeDataRef<ElementData>);
+
+/// Methods for AnchorRef.
+///
+/// Provides construction and semantic accessors for hyperlink attributes.
+impl AnchorRef {
+    /// Wraps an element as an anchor.
+    ///
+    /// Returns `None` if the element is not an HTML `<a>` or `<area>` element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::html::AnchorRef;
+    /// use brik::parse_html;
+    /// use brik::traits::*;
+    ///
+    /// let doc = parse_html().one("<a href='/about'>About</a><p>Text</p>");
+    /// assert!(AnchorRef::new(doc.select_first("a").unwrap()).is_some());
+    /// assert!(AnchorRef::new(doc.select_first("p").unwrap()).is_none());
+    /// ```
+    pub fn new(element: NodeDataRef<ElementData>) -> Option<Self> {
+        if element.name.ns == ns!(html)
+            && matches!(element.name.local, local_name!("a") | local_name!("area"))
+        {
+            Some(AnchorRef(element))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the wrapped element.
+    #[inline]
+    pub fn into_element(self) -> NodeDataRef<ElementData> {
+        self.0
+    }
+
+    /// Returns the `href` attribute, if present.
+    pub fn href(&self) -> Option<String> {
+        self.attributes
+            .borrow()
+            .get(local_name!("href"))
+            .map(str::to_owned)
+    }
+
+    /// Returns the `href` attribute resolved against an absolute base URL.
+    ///
+    /// Returns `None` if there is no `href` attribute or `base` is not absolute.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::html::AnchorRef;
+    /// use brik::parse_html;
+    /// use brik::traits::*;
+    ///
+    /// let doc = parse_html().one("<a href='../about'>About</a>");
+    /// let anchor = AnchorRef::new(doc.select_first("a").unwrap()).unwrap();
+    /// assert_eq!(
+    ///     anchor.resolved_url("https://example.com/docs/index.html").as_deref(),
+    ///     Some("https://example.com/about")
+    /// );
+    /// ```
+    pub fn resolved_url(&self, base: &str) -> Option<String> {
+        resolve_url(base, &self.href()?)
+    }
+
+    /// Returns the `href` attribute resolved against the document's base URL.
+    ///
+    /// See [`NodeRef::document_base_url()`](crate::NodeRef::document_base_url).
+    /// Returns `None` if there is no `href` attribute or no base URL.
+    pub fn absolute_url(&self) -> Option<String> {
+        self.resolved_url(&self.as_node().document_base_url()?)
+    }
+
+    /// Returns the `target` attribute, if present.
+    pub fn target(&self) -> Option<String> {
+        self.attributes
+            .borrow()
+            .get(local_name!("target"))
+            .map(str::to_owned)
+    }
+
+    /// Returns the whitespace-separated tokens of the `rel` attribute.
+    ///
+    /// Returns an empty vector if the attribute is absent.
+    pub fn rel(&self) -> Vec<String> {
+        self.attributes
+            .borrow()
+            .get_tokens(local_name!("rel"))
+            .map(str::to_owned)
+            .collect()
+    }
+
+    /// Returns true if the anchor has a `download` attribute.
+    pub fn is_download(&self) -> bool {
+        self.attributes.borrow().contains(local_name!("download"))
+    }
+}
+
+/// Implements Deref for AnchorRef.
+///
+/// Exposes the underlying element reference.
+impl Deref for AnchorRef {
+    type Target = NodeDataRef<ElementData>;
+    #[inline]
+    fn deref(&self) -> &NodeDataRef<ElementData> {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::html5ever::tendril::TendrilSink;
+    use crate::parse_html;
+
+    /// Tests the hyperlink accessors of an anchor.
+    ///
+    /// Verifies href, target, rel tokens, and download detection on both
+    /// `<a>` and `<area>` elements.
+    #[test]
+    fn accessors() {
+        let doc = parse_html().one(
+            "<a href='/a' target='_blank' rel=' noopener  nofollow' download>A</a>\
+             <map><area href='b.html'></map>",
+        );
+        let anchor = AnchorRef::new(doc.select_first("a").unwrap()).unwrap();
+        assert_eq!(anchor.href().as_deref(), Some("/a"));
+        assert_eq!(anchor.target().as_deref(), Some("_blank"));
+        assert_eq!(anchor.rel(), vec!["noopener", "nofollow"]);
+        assert!(anchor.is_download());
+        assert_eq!(anchor.text_contents(), "A");
+
+        let area = AnchorRef::new(doc.select_first("area").unwrap()).unwrap();
+        assert_eq!(
+            area.resolved_url("http://example.com/x/y").as_deref(),
+            Some("http://example.com/x/b.html")
+        );
+        assert!(area.rel().is_empty());
+        assert!(!area.is_download());
+    }
+
+    /// Tests an anchor without an href attribute.
+    ///
+    /// Edge case: placeholder links have no href, so both href() and
+    /// resolved_url() must return None.
+    #[test]
+    fn missing_href() {
+        let doc = parse_html().one("<a name='top'>Top</a>");
+        let anchor = AnchorRef::new(doc.select_first("a").unwrap()).unwrap();
+        assert_eq!(anchor.href(), None);
+        assert_eq!(anchor.resolved_url("http://example.com/"), None);
+    }
+}
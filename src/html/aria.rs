@@ -0,0 +1,292 @@
+use super::{AriaRole, AriaTristate};
+use crate::node_data_ref::NodeDataRef;
+use crate::tree::ElementData;
+use html5ever::LocalName;
+
+/// Typed access to the `role` and `aria-*` attributes of an element,
+/// returned by [`NodeDataRef::aria()`](crate::NodeDataRef::aria).
+///
+/// Getters return `None` when the attribute is absent or its value is not
+/// valid for the property. Setters take `None` to remove the attribute,
+/// and mark the element dirty when they change it.
+#[derive(Debug, Clone, Copy)]
+pub struct Aria<'a>(pub(super) &'a NodeDataRef<ElementData>);
+
+/// Methods for Aria.
+///
+/// Provides typed getters and setters for ARIA roles, states and
+/// properties, and the computed role of native elements.
+impl<'a> Aria<'a> {
+    /// Returns the explicit role from the `role` attribute.
+    ///
+    /// The attribute may list fallback roles; the first known role is used,
+    /// or the first token if none are known.
+    pub fn role(&self) -> Option<AriaRole> {
+        let attributes = self.0.attributes.borrow();
+        let roles: Vec<AriaRole> = attributes
+            .get_tokens(local_name!("role"))
+            .map(AriaRole::from_token)
+            .collect();
+        roles
+            .iter()
+            .find(|role| !matches!(role, AriaRole::Other(_)))
+            .or_else(|| roles.first())
+            .cloned()
+    }
+
+    /// Sets or removes the `role` attribute.
+    pub fn set_role(&self, role: Option<AriaRole>) {
+        self.set(local_name!("role"), role.as_ref().map(AriaRole::as_str));
+    }
+
+    /// Returns the explicit role, or the implicit role of a native HTML
+    /// element, such as `link` for `<a href>` or `checkbox` for
+    /// `<input type=checkbox>`.
+    ///
+    /// Implicit roles that depend on the element's ancestors are not
+    /// computed: `<header>` and `<footer>` are always `banner` and
+    /// `contentinfo`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::html::AriaRole;
+    /// use brik::parse_html;
+    /// use brik::traits::*;
+    ///
+    /// let doc = parse_html().one("<a href=/>Home</a><div role='tab button'></div>");
+    /// let link = doc.select_first("a").unwrap();
+    /// let div = doc.select_first("div").unwrap();
+    /// assert_eq!(link.aria().role(), None);
+    /// assert_eq!(link.aria().computed_role(), Some(AriaRole::Link));
+    /// assert_eq!(div.aria().computed_role(), Some(AriaRole::Tab));
+    /// ```
+    pub fn computed_role(&self) -> Option<AriaRole> {
+        self.role().or_else(|| self.implicit_role())
+    }
+
+    /// Returns the `aria-label` attribute.
+    pub fn label(&self) -> Option<String> {
+        self.get("aria-label")
+    }
+
+    /// Sets or removes the `aria-label` attribute.
+    pub fn set_label(&self, label: Option<&str>) {
+        self.set("aria-label", label);
+    }
+
+    /// Returns the ids listed in the `aria-labelledby` attribute.
+    pub fn labelled_by(&self) -> Vec<String> {
+        self.ids("aria-labelledby")
+    }
+
+    /// Returns the ids listed in the `aria-describedby` attribute.
+    pub fn described_by(&self) -> Vec<String> {
+        self.ids("aria-describedby")
+    }
+
+    /// Returns the `aria-hidden` state.
+    pub fn hidden(&self) -> Option<bool> {
+        self.get_bool("aria-hidden")
+    }
+
+    /// Sets or removes the `aria-hidden` state.
+    pub fn set_hidden(&self, hidden: Option<bool>) {
+        self.set_bool("aria-hidden", hidden);
+    }
+
+    /// Returns the `aria-disabled` state.
+    pub fn disabled(&self) -> Option<bool> {
+        self.get_bool("aria-disabled")
+    }
+
+    /// Sets or removes the `aria-disabled` state.
+    pub fn set_disabled(&self, disabled: Option<bool>) {
+        self.set_bool("aria-disabled", disabled);
+    }
+
+    /// Returns the `aria-expanded` state.
+    pub fn expanded(&self) -> Option<bool> {
+        self.get_bool("aria-expanded")
+    }
+
+    /// Sets or removes the `aria-expanded` state.
+    pub fn set_expanded(&self, expanded: Option<bool>) {
+        self.set_bool("aria-expanded", expanded);
+    }
+
+    /// Returns the `aria-selected` state.
+    pub fn selected(&self) -> Option<bool> {
+        self.get_bool("aria-selected")
+    }
+
+    /// Sets or removes the `aria-selected` state.
+    pub fn set_selected(&self, selected: Option<bool>) {
+        self.set_bool("aria-selected", selected);
+    }
+
+    /// Returns the `aria-checked` state.
+    pub fn checked(&self) -> Option<AriaTristate> {
+        self.get("aria-checked")
+            .and_then(|value| AriaTristate::from_value(&value))
+    }
+
+    /// Sets or removes the `aria-checked` state.
+    pub fn set_checked(&self, checked: Option<AriaTristate>) {
+        self.set("aria-checked", checked.map(AriaTristate::as_str));
+    }
+
+    /// Returns the `aria-pressed` state.
+    pub fn pressed(&self) -> Option<AriaTristate> {
+        self.get("aria-pressed")
+            .and_then(|value| AriaTristate::from_value(&value))
+    }
+
+    /// Sets or removes the `aria-pressed` state.
+    pub fn set_pressed(&self, pressed: Option<AriaTristate>) {
+        self.set("aria-pressed", pressed.map(AriaTristate::as_str));
+    }
+
+    /// Returns the `aria-level` property.
+    pub fn level(&self) -> Option<u64> {
+        self.0.attributes.borrow().get_u64("aria-level")
+    }
+
+    /// Sets or removes the `aria-level` property.
+    pub fn set_level(&self, level: Option<u64>) {
+        self.set(
+            "aria-level",
+            level.map(|level| level.to_string()).as_deref(),
+        );
+    }
+
+    /// Returns the value of an attribute.
+    fn get(&self, name: &str) -> Option<String> {
+        self.0.attributes.borrow().get(name).map(str::to_string)
+    }
+
+    /// Sets an attribute, or removes it if `value` is `None`.
+    fn set<A: Into<LocalName>>(&self, name: A, value: Option<&str>) {
+        let mut attributes = self.0.attributes.borrow_mut();
+        match value {
+            Some(value) => {
+                attributes.insert(name, value);
+            }
+            None => {
+                if attributes.remove(name).is_none() {
+                    return;
+                }
+            }
+        }
+        self.0.as_node().mark_dirty();
+    }
+
+    /// Returns a `true`/`false` attribute value.
+    fn get_bool(&self, name: &str) -> Option<bool> {
+        match self.0.attributes.borrow().get_trimmed(name) {
+            Some(value) if value.eq_ignore_ascii_case("true") => Some(true),
+            Some(value) if value.eq_ignore_ascii_case("false") => Some(false),
+            _ => None,
+        }
+    }
+
+    /// Sets a `true`/`false` attribute value, or removes the attribute.
+    fn set_bool(&self, name: &str, value: Option<bool>) {
+        self.set(
+            name,
+            value.map(|value| if value { "true" } else { "false" }),
+        );
+    }
+
+    /// Returns the ids of an id reference list attribute.
+    fn ids(&self, name: &str) -> Vec<String> {
+        self.0
+            .attributes
+            .borrow()
+            .get_tokens(name)
+            .map(str::to_string)
+            .collect()
+    }
+
+    /// Returns the implicit role of a native HTML element.
+    fn implicit_role(&self) -> Option<AriaRole> {
+        let element: &ElementData = self.0;
+        if element.name.ns != ns!(html) {
+            return None;
+        }
+        let attributes = element.attributes.borrow();
+        let role = match element.name.local {
+            local_name!("a") | local_name!("area") if attributes.contains("href") => AriaRole::Link,
+            local_name!("article") => AriaRole::Article,
+            local_name!("aside") => AriaRole::Complementary,
+            local_name!("blockquote") => AriaRole::Blockquote,
+            local_name!("button") => AriaRole::Button,
+            local_name!("dialog") => AriaRole::Dialog,
+            local_name!("fieldset") => AriaRole::Group,
+            local_name!("figure") => AriaRole::Figure,
+            local_name!("footer") => AriaRole::ContentInfo,
+            local_name!("form") => AriaRole::Form,
+            local_name!("h1")
+            | local_name!("h2")
+            | local_name!("h3")
+            | local_name!("h4")
+            | local_name!("h5")
+            | local_name!("h6") => AriaRole::Heading,
+            local_name!("header") => AriaRole::Banner,
+            local_name!("hr") => AriaRole::Separator,
+            local_name!("img") if attributes.get("alt") == Some("") => AriaRole::Presentation,
+            local_name!("img") => AriaRole::Img,
+            local_name!("input") => {
+                let kind = attributes
+                    .get_trimmed("type")
+                    .unwrap_or_default()
+                    .to_ascii_lowercase();
+                match &*kind {
+                    "button" | "image" | "reset" | "submit" => AriaRole::Button,
+                    "checkbox" => AriaRole::Checkbox,
+                    "number" => AriaRole::SpinButton,
+                    "radio" => AriaRole::Radio,
+                    "range" => AriaRole::Slider,
+                    "search" if !attributes.contains("list") => AriaRole::SearchBox,
+                    "" | "email" | "tel" | "text" | "url" if !attributes.contains("list") => {
+                        AriaRole::TextBox
+                    }
+                    "" | "email" | "search" | "tel" | "text" | "url" => AriaRole::Combobox,
+                    _ => return None,
+                }
+            }
+            local_name!("li") => AriaRole::ListItem,
+            local_name!("main") => AriaRole::Main,
+            local_name!("math") => AriaRole::Math,
+            local_name!("menu") | local_name!("ol") | local_name!("ul") => AriaRole::List,
+            local_name!("meter") => AriaRole::Meter,
+            local_name!("nav") => AriaRole::Navigation,
+            local_name!("option") => AriaRole::Option,
+            local_name!("output") => AriaRole::Status,
+            local_name!("p") => AriaRole::Paragraph,
+            local_name!("progress") => AriaRole::ProgressBar,
+            local_name!("search") => AriaRole::Search,
+            local_name!("section")
+                if attributes.contains("aria-label") || attributes.contains("aria-labelledby") =>
+            {
+                AriaRole::Region
+            }
+            local_name!("select")
+                if attributes.contains("multiple") || attributes.get_u64("size") > Some(1) =>
+            {
+                AriaRole::ListBox
+            }
+            local_name!("select") => AriaRole::Combobox,
+            local_name!("table") => AriaRole::Table,
+            local_name!("tbody") | local_name!("tfoot") | local_name!("thead") => {
+                AriaRole::RowGroup
+            }
+            local_name!("td") => AriaRole::Cell,
+            local_name!("textarea") => AriaRole::TextBox,
+            local_name!("th") => AriaRole::ColumnHeader,
+            local_name!("tr") => AriaRole::Row,
+            _ => return None,
+        };
+        Some(role)
+    }
+}
@@ -0,0 +1,139 @@
+use std::fmt;
+
+/// Defines [`AriaRole`] with the token of each known role.
+macro_rules! aria_roles {
+    ($($variant: ident => $token: literal,)*) => {
+        /// A WAI-ARIA role, as found in a `role` attribute.
+        ///
+        /// Known roles have their own variants; any other token is kept as
+        /// [`AriaRole::Other`].
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        pub enum AriaRole {
+            $(
+                #[doc = concat!("The `", $token, "` role.")]
+                $variant,
+            )*
+            /// A role token that is not a known WAI-ARIA role.
+            Other(String),
+        }
+
+        /// Methods for AriaRole.
+        ///
+        /// Provides conversion between roles and `role` attribute tokens.
+        impl AriaRole {
+            /// Returns the role for a `role` attribute token.
+            ///
+            /// Tokens are matched ASCII case-insensitively; unknown tokens
+            /// become [`AriaRole::Other`].
+            pub fn from_token(token: &str) -> AriaRole {
+                $(
+                    if token.eq_ignore_ascii_case($token) {
+                        return AriaRole::$variant;
+                    }
+                )*
+                AriaRole::Other(token.to_string())
+            }
+
+            /// Returns the `role` attribute token of this role.
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $(AriaRole::$variant => $token,)*
+                    AriaRole::Other(token) => token,
+                }
+            }
+        }
+    };
+}
+
+aria_roles! {
+    Alert => "alert",
+    AlertDialog => "alertdialog",
+    Application => "application",
+    Article => "article",
+    Banner => "banner",
+    Blockquote => "blockquote",
+    Button => "button",
+    Caption => "caption",
+    Cell => "cell",
+    Checkbox => "checkbox",
+    Code => "code",
+    ColumnHeader => "columnheader",
+    Combobox => "combobox",
+    Complementary => "complementary",
+    ContentInfo => "contentinfo",
+    Definition => "definition",
+    Deletion => "deletion",
+    Dialog => "dialog",
+    Document => "document",
+    Emphasis => "emphasis",
+    Feed => "feed",
+    Figure => "figure",
+    Form => "form",
+    Generic => "generic",
+    Grid => "grid",
+    GridCell => "gridcell",
+    Group => "group",
+    Heading => "heading",
+    Img => "img",
+    Insertion => "insertion",
+    Link => "link",
+    List => "list",
+    ListBox => "listbox",
+    ListItem => "listitem",
+    Log => "log",
+    Main => "main",
+    Marquee => "marquee",
+    Math => "math",
+    Menu => "menu",
+    MenuBar => "menubar",
+    MenuItem => "menuitem",
+    MenuItemCheckbox => "menuitemcheckbox",
+    MenuItemRadio => "menuitemradio",
+    Meter => "meter",
+    Navigation => "navigation",
+    None => "none",
+    Note => "note",
+    Option => "option",
+    Paragraph => "paragraph",
+    Presentation => "presentation",
+    ProgressBar => "progressbar",
+    Radio => "radio",
+    RadioGroup => "radiogroup",
+    Region => "region",
+    Row => "row",
+    RowGroup => "rowgroup",
+    RowHeader => "rowheader",
+    ScrollBar => "scrollbar",
+    Search => "search",
+    SearchBox => "searchbox",
+    Separator => "separator",
+    Slider => "slider",
+    SpinButton => "spinbutton",
+    Status => "status",
+    Strong => "strong",
+    Subscript => "subscript",
+    Superscript => "superscript",
+    Switch => "switch",
+    Tab => "tab",
+    Table => "table",
+    TabList => "tablist",
+    TabPanel => "tabpanel",
+    Term => "term",
+    TextBox => "textbox",
+    Time => "time",
+    Timer => "timer",
+    Toolbar => "toolbar",
+    Tooltip => "tooltip",
+    Tree => "tree",
+    TreeGrid => "treegrid",
+    TreeItem => "treeitem",
+}
+
+/// Implements Display for AriaRole.
+///
+/// Formats the role as its `role` attribute token.
+impl fmt::Display for AriaRole {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
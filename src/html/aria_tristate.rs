@@ -0,0 +1,54 @@
+use std::fmt;
+
+/// The value of a tristate ARIA state, such as `aria-checked` or
+/// `aria-pressed`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AriaTristate {
+    /// The `false` value.
+    False,
+    /// The `true` value.
+    True,
+    /// The `mixed` value, for partially checked or pressed controls.
+    Mixed,
+}
+
+/// Methods for AriaTristate.
+///
+/// Provides conversion between tristate values and attribute values.
+impl AriaTristate {
+    /// Returns the tristate for an attribute value, or `None` if the value
+    /// is not `true`, `false` or `mixed`.
+    ///
+    /// Values are matched ASCII case-insensitively, ignoring surrounding
+    /// whitespace.
+    pub fn from_value(value: &str) -> Option<AriaTristate> {
+        let value = value.trim_matches(|c: char| c.is_ascii_whitespace());
+        if value.eq_ignore_ascii_case("true") {
+            Some(AriaTristate::True)
+        } else if value.eq_ignore_ascii_case("false") {
+            Some(AriaTristate::False)
+        } else if value.eq_ignore_ascii_case("mixed") {
+            Some(AriaTristate::Mixed)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the attribute value of this tristate.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            AriaTristate::False => "false",
+            AriaTristate::True => "true",
+            AriaTristate::Mixed => "mixed",
+        }
+    }
+}
+
+/// Implements Display for AriaTristate.
+///
+/// Formats the tristate as its attribute value.
+impl fmt::Display for AriaTristate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
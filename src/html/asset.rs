@@ -0,0 +1,39 @@
+use super::{resolve_url, AssetKind};
+use crate::node_data_ref::NodeDataRef;
+use crate::tree::ElementData;
+
+/// A stylesheet, script or image resource referenced by a document.
+///
+/// Returned by [`NodeRef::assets()`](crate::NodeRef::assets).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Asset {
+    /// The kind of resource.
+    pub kind: AssetKind,
+    /// The `href` or `src` attribute, as written.
+    ///
+    /// This is `None` for inline styles and scripts, and for images without
+    /// a `src` attribute.
+    pub url: Option<String>,
+    /// The `media` attribute of stylesheets and styles.
+    pub media: Option<String>,
+    /// The `integrity` attribute of external stylesheets and scripts.
+    pub integrity: Option<String>,
+    /// The text content of inline styles and scripts.
+    pub content: Option<String>,
+    /// The element that references the resource.
+    pub element: NodeDataRef<ElementData>,
+}
+
+/// Methods for Asset.
+///
+/// Provides resolution of the asset's URL.
+impl Asset {
+    /// Returns the asset's URL resolved against the document's base URL.
+    ///
+    /// See [`NodeRef::document_base_url()`](crate::NodeRef::document_base_url).
+    /// Returns `None` for inline assets and when there is no base URL.
+    pub fn resolved_url(&self) -> Option<String> {
+        let base = self.element.as_node().document_base_url()?;
+        resolve_url(&base, self.url.as_deref()?)
+    }
+}
@@ -0,0 +1,14 @@
+/// The kind of resource an [`Asset`](super::Asset) refers to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AssetKind {
+    /// An external stylesheet, from `<link rel="stylesheet">`.
+    Stylesheet,
+    /// An inline `<style>` element.
+    InlineStyle,
+    /// An external script, from `<script src>`.
+    Script,
+    /// An inline `<script>` element.
+    InlineScript,
+    /// An image, from `<img>`.
+    Image,
+}
@@ -0,0 +1,111 @@
+use html5ever::QualName;
+
+use crate::parser::parse_fragment_nodes;
+use crate::tree::NodeRef;
+
+/// A parsed legacy Internet Explorer conditional comment.
+///
+/// Two forms are recognized:
+///
+/// - Downlevel-hidden, `<!--[if IE 6]>markup<![endif]-->`, where the markup is
+///   inside the comment.
+/// - The opening comment of downlevel-revealed blocks,
+///   `<!--[if !IE]><!-->markup<!--<![endif]-->`, where the markup follows as
+///   regular nodes and the comment itself has no content.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConditionalComment {
+    /// The condition, such as `IE 6` or `lt IE 9`.
+    pub condition: String,
+    /// The markup inside a downlevel-hidden comment.
+    ///
+    /// This is `None` for the opening comment of a downlevel-revealed block.
+    pub content: Option<String>,
+}
+
+/// Methods for ConditionalComment.
+///
+/// Provides parsing of comment text and of the enclosed markup.
+impl ConditionalComment {
+    /// Parses the text of a comment node as a conditional comment.
+    ///
+    /// Returns `None` if the text is not a conditional comment opener.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::html::ConditionalComment;
+    ///
+    /// let hidden = ConditionalComment::parse("[if lt IE 9]><script src=x.js></script><![endif]").unwrap();
+    /// assert_eq!(hidden.condition, "lt IE 9");
+    /// assert_eq!(hidden.content.as_deref(), Some("<script src=x.js></script>"));
+    ///
+    /// let revealed = ConditionalComment::parse("[if !IE]><!").unwrap();
+    /// assert_eq!(revealed.condition, "!IE");
+    /// assert_eq!(revealed.content, None);
+    ///
+    /// assert_eq!(ConditionalComment::parse(" plain comment "), None);
+    /// ```
+    pub fn parse(text: &str) -> Option<ConditionalComment> {
+        let rest = text.trim_start().strip_prefix("[if ")?;
+        let (condition, rest) = rest.split_once("]>")?;
+        let condition = condition.trim().to_string();
+        if rest.trim() == "<!" {
+            return Some(ConditionalComment {
+                condition,
+                content: None,
+            });
+        }
+        let content = rest.trim_end().strip_suffix("<![endif]")?;
+        Some(ConditionalComment {
+            condition,
+            content: Some(content.to_string()),
+        })
+    }
+
+    /// Parses the markup inside a downlevel-hidden comment.
+    ///
+    /// The markup is parsed as a fragment in a `<body>` context and returned
+    /// as a document fragment node. Returns `None` for downlevel-revealed
+    /// openers, which have no content.
+    pub fn parse_content(&self) -> Option<NodeRef> {
+        let content = self.content.as_ref()?;
+        Some(parse_fragment_nodes(
+            QualName::new(None, ns!(html), local_name!("body")),
+            Vec::new(),
+            content,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::html5ever::tendril::TendrilSink;
+    use crate::parse_html;
+
+    /// Tests parsing conditional comments from a parsed document.
+    ///
+    /// Verifies both comment forms, the closing comment of revealed blocks,
+    /// and parsing of the enclosed markup.
+    #[test]
+    fn from_document() {
+        let doc = parse_html().one(
+            "<!--[if IE 6]><p class=ie>Old</p><![endif]-->\
+             <!--[if !IE]><!--><p>Modern</p><!--<![endif]-->",
+        );
+        let comments: Vec<Option<ConditionalComment>> = doc
+            .descendants()
+            .filter_map(|node| node.as_comment().map(|text| text.borrow().clone()))
+            .map(|text| ConditionalComment::parse(&text))
+            .collect();
+
+        assert_eq!(comments.len(), 3);
+        let hidden = comments[0].as_ref().unwrap();
+        assert_eq!(hidden.condition, "IE 6");
+        let content = hidden.parse_content().unwrap();
+        assert_eq!(content.to_string(), "<p class=\"ie\">Old</p>");
+        assert_eq!(comments[1].as_ref().unwrap().condition, "!IE");
+        assert_eq!(comments[1].as_ref().unwrap().parse_content(), None);
+        assert_eq!(comments[2], None);
+    }
+}
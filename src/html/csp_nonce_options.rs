@@ -0,0 +1,11 @@
+/// Options for
+/// [`NodeRef::add_csp_nonce_with_options()`](crate::NodeRef::add_csp_nonce_with_options).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CspNonceOptions {
+    /// Whether to remove `on*` event handler attributes.
+    ///
+    /// A Content Security Policy with a nonce blocks inline event handlers,
+    /// as they cannot carry one, so removing them keeps the output from
+    /// relying on handlers that will not run.
+    pub strip_event_handlers: bool,
+}
@@ -0,0 +1,103 @@
+//! ARIA attribute access for elements.
+
+use super::Aria;
+use crate::node_data_ref::NodeDataRef;
+use crate::tree::ElementData;
+
+/// ARIA methods for element references.
+///
+/// Provides typed access to the `role` and `aria-*` attributes.
+impl NodeDataRef<ElementData> {
+    /// Returns typed accessors for this element's `role` and `aria-*`
+    /// attributes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::html::AriaTristate;
+    /// use brik::parse_html;
+    /// use brik::traits::*;
+    ///
+    /// let doc = parse_html().one("<div role=checkbox aria-checked=mixed>All</div>");
+    /// let div = doc.select_first("div").unwrap();
+    /// assert_eq!(div.aria().checked(), Some(AriaTristate::Mixed));
+    ///
+    /// div.aria().set_checked(Some(AriaTristate::True));
+    /// div.aria().set_label(Some("Select all"));
+    /// assert_eq!(
+    ///     div.as_node().to_string(),
+    ///     r#"<div role="checkbox" aria-checked="true" aria-label="Select all">All</div>"#
+    /// );
+    /// ```
+    pub fn aria(&self) -> Aria<'_> {
+        Aria(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::html::{AriaRole, AriaTristate};
+    use crate::html5ever::tendril::TendrilSink;
+    use crate::parse_html;
+
+    /// Tests computed roles of native elements.
+    ///
+    /// Verifies that explicit roles win, that unknown fallback tokens are
+    /// skipped, and that implicit roles depend on attributes such as
+    /// `href`, `type`, `alt` and `multiple`.
+    #[test]
+    fn aria_computed_role() {
+        let doc = parse_html().one(
+            "<a>plain</a><a href=/>link</a><input type=checkbox><input list=l>\
+             <img alt=''><select multiple></select><b role='fancy button'></b>\
+             <i role=fancy></i><section>s</section>",
+        );
+        let roles: Vec<Option<AriaRole>> = doc
+            .select("a, input, img, select, b, i, section")
+            .unwrap()
+            .map(|element| element.aria().computed_role())
+            .collect();
+
+        assert_eq!(
+            roles,
+            [
+                None,
+                Some(AriaRole::Link),
+                Some(AriaRole::Checkbox),
+                Some(AriaRole::Combobox),
+                Some(AriaRole::Presentation),
+                Some(AriaRole::ListBox),
+                Some(AriaRole::Button),
+                Some(AriaRole::Other("fancy".to_string())),
+                None,
+            ]
+        );
+    }
+
+    /// Tests reading and writing ARIA states and properties.
+    ///
+    /// Edge case: invalid values read as `None`, and setting `None`
+    /// removes the attribute.
+    #[test]
+    fn aria_states() {
+        let doc = parse_html().one(
+            "<button aria-pressed=maybe aria-hidden=' TRUE ' aria-labelledby='a b'>x</button>",
+        );
+        let button = doc.select_first("button").unwrap();
+        let aria = button.aria();
+
+        assert_eq!(aria.pressed(), None);
+        assert_eq!(aria.hidden(), Some(true));
+        assert_eq!(aria.labelled_by(), ["a", "b"]);
+
+        aria.set_pressed(Some(AriaTristate::Mixed));
+        aria.set_hidden(None);
+        aria.set_level(Some(2));
+        aria.set_role(Some(AriaRole::Switch));
+        assert_eq!(
+            button.as_node().to_string(),
+            "<button aria-pressed=\"mixed\" aria-labelledby=\"a b\" aria-level=\"2\" \
+             role=\"switch\">x</button>"
+        );
+    }
+}
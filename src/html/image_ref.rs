@@ -0,0 +1,209 @@
+use std::convert::TryFrom;
+use std::ops::Deref;
+
+use super::{resolve_url, Sizes, Srcset};
+use crate::node_data_ref::NodeDataRef;
+use crate::tree::ElementData;
+
+/// A typed view of an HTML `<img>` element.
+///
+/// Dereferences to the underlying [`NodeDataRef<ElementData>`], so all element
+/// and node methods remain available.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImageRef(NodeDataRef<ElementData>);
+
+/// Methods for ImageRef.
+///
+/// Provides construction and semantic accessors for image attributes.
+impl ImageRef {
+    /// Wraps an element as an image.
+    ///
+    /// Returns `None` if the element is not an HTML `<img>` element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::html::ImageRef;
+    /// use brik::parse_html;
+    /// use brik::traits::*;
+    ///
+    /// let doc = parse_html().one("<img src='logo.png' alt='Logo'>");
+    /// let image = ImageRef::new(doc.select_first("img").unwrap()).unwrap();
+    /// assert_eq!(image.alt().as_deref(), Some("Logo"));
+    /// ```
+    pub fn new(element: NodeDataRef<ElementData>) -> Option<Self> {
+        if element.name.ns == ns!(html) && element.name.local == local_name!("img") {
+            Some(ImageRef(element))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the wrapped element.
+    #[inline]
+    pub fn into_element(self) -> NodeDataRef<ElementData> {
+        self.0
+    }
+
+    /// Returns the `src` attribute, if present.
+    pub fn src(&self) -> Option<String> {
+        self.attributes
+            .borrow()
+            .get(local_name!("src"))
+            .map(str::to_owned)
+    }
+
+    /// Returns the `src` attribute resolved against an absolute base URL.
+    ///
+    /// Returns `None` if there is no `src` attribute or `base` is not absolute.
+    pub fn resolved_url(&self, base: &str) -> Option<String> {
+        resolve_url(base, &self.src()?)
+    }
+
+    /// Returns the `src` attribute resolved against the document's base URL.
+    ///
+    /// See [`NodeRef::document_base_url()`](crate::NodeRef::document_base_url).
+    /// Returns `None` if there is no `src` attribute or no base URL.
+    pub fn absolute_url(&self) -> Option<String> {
+        self.resolved_url(&self.as_node().document_base_url()?)
+    }
+
+    /// Returns the `alt` attribute, if present.
+    ///
+    /// An empty string is distinct from `None`: it marks a decorative image.
+    pub fn alt(&self) -> Option<String> {
+        self.attributes
+            .borrow()
+            .get(local_name!("alt"))
+            .map(str::to_owned)
+    }
+
+    /// Returns the parsed `srcset` attribute, if present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::html::ImageRef;
+    /// use brik::parse_html;
+    /// use brik::traits::*;
+    ///
+    /// let doc = parse_html().one("<img src='a.png' srcset='a.png 1x, a@2x.png 2x'>");
+    /// let image = ImageRef::new(doc.select_first("img").unwrap()).unwrap();
+    /// let mut srcset = image.srcset().unwrap();
+    /// srcset.map_urls(|url| format!("/static/{url}"));
+    /// image.set_srcset(&srcset);
+    /// assert_eq!(
+    ///     image.attributes.borrow().get("srcset"),
+    ///     Some("/static/a.png 1x, /static/a@2x.png 2x")
+    /// );
+    /// ```
+    pub fn srcset(&self) -> Option<Srcset> {
+        self.attributes
+            .borrow()
+            .get(local_name!("srcset"))
+            .map(Srcset::parse)
+    }
+
+    /// Replaces the `srcset` attribute with the given candidate list.
+    pub fn set_srcset(&self, srcset: &Srcset) {
+        self.attributes_mut()
+            .insert(local_name!("srcset"), srcset.to_string());
+    }
+
+    /// Returns the parsed `sizes` attribute, if present.
+    pub fn sizes(&self) -> Option<Sizes> {
+        self.attributes
+            .borrow()
+            .get(local_name!("sizes"))
+            .map(Sizes::parse)
+    }
+
+    /// Returns the `width` attribute parsed as a non-negative integer.
+    ///
+    /// Uses the same rules as
+    /// [`Attributes::get_u64()`](crate::Attributes::get_u64), so `"100px"`
+    /// yields 100. Returns `None` if the attribute is absent, has no leading
+    /// digits, or does not fit in a `u32`.
+    pub fn width(&self) -> Option<u32> {
+        self.dimension(local_name!("width"))
+    }
+
+    /// Returns the `height` attribute parsed as a non-negative integer.
+    ///
+    /// Uses the same rules as
+    /// [`Attributes::get_u64()`](crate::Attributes::get_u64), so `"100px"`
+    /// yields 100. Returns `None` if the attribute is absent, has no leading
+    /// digits, or does not fit in a `u32`.
+    pub fn height(&self) -> Option<u32> {
+        self.dimension(local_name!("height"))
+    }
+
+    /// Parses a dimension attribute.
+    fn dimension(&self, name: html5ever::LocalName) -> Option<u32> {
+        let value = self.attributes.borrow().get_u64(name)?;
+        u32::try_from(value).ok()
+    }
+}
+
+/// Implements Deref for ImageRef.
+///
+/// Exposes the underlying element reference.
+impl Deref for ImageRef {
+    type Target = NodeDataRef<ElementData>;
+    #[inline]
+    fn deref(&self) -> &NodeDataRef<ElementData> {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::html5ever::tendril::TendrilSink;
+    use crate::parse_html;
+
+    /// Tests the accessors of an image.
+    ///
+    /// Verifies src, resolved URL, alt text, and numeric dimensions.
+    #[test]
+    fn accessors() {
+        let doc = parse_html().one("<img src='img/a.png' alt='' width=' 640 ' height='auto'>");
+        let image = ImageRef::new(doc.select_first("img").unwrap()).unwrap();
+        assert_eq!(image.src().as_deref(), Some("img/a.png"));
+        assert_eq!(
+            image
+                .resolved_url("https://example.com/blog/post")
+                .as_deref(),
+            Some("https://example.com/blog/img/a.png")
+        );
+        assert_eq!(image.alt().as_deref(), Some(""));
+        assert_eq!(image.width(), Some(640));
+        assert_eq!(image.height(), None);
+    }
+
+    /// Tests that non-image elements are rejected.
+    ///
+    /// Edge case: an SVG `<image>` element is not an HTML `<img>` element.
+    #[test]
+    fn rejects_other_elements() {
+        let doc = parse_html().one("<svg><image href='a.png'/></svg>");
+        assert!(ImageRef::new(doc.select_first("image").unwrap()).is_none());
+    }
+
+    /// Tests dimensions written the way browsers accept them.
+    ///
+    /// Edge case: units and a leading `+` are allowed, as with
+    /// `Attributes::get_u64()`, but values beyond `u32` are rejected.
+    #[test]
+    fn lenient_dimensions() {
+        let doc = parse_html().one("<img width='100px' height='+5'><img width='4294967296'>");
+        let mut images = doc
+            .select("img")
+            .unwrap()
+            .map(|img| ImageRef::new(img).unwrap());
+        let image = images.next().unwrap();
+        assert_eq!(image.width(), Some(100));
+        assert_eq!(image.height(), Some(5));
+        assert_eq!(images.next().unwrap().width(), None);
+    }
+}
@@ -0,0 +1,120 @@
+use std::ops::Deref;
+
+use crate::node_data_ref::NodeDataRef;
+use crate::tree::ElementData;
+
+/// A typed view of an HTML `<meta>` element.
+///
+/// Dereferences to the underlying [`NodeDataRef<ElementData>`], so all element
+/// and node methods remain available.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MetaRef(NodeDataRef<ElementData>);
+
+/// Methods for MetaRef.
+///
+/// Provides construction and semantic accessors for metadata attributes.
+impl MetaRef {
+    /// Wraps an element as a meta element.
+    ///
+    /// Returns `None` if the element is not an HTML `<meta>` element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::html::MetaRef;
+    /// use brik::parse_html;
+    /// use brik::traits::*;
+    ///
+    /// let doc = parse_html().one("<meta name='description' content='A page'>");
+    /// let meta = MetaRef::new(doc.select_first("meta").unwrap()).unwrap();
+    /// assert_eq!(meta.name().as_deref(), Some("description"));
+    /// assert_eq!(meta.content().as_deref(), Some("A page"));
+    /// ```
+    pub fn new(element: NodeDataRef<ElementData>) -> Option<Self> {
+        if element.name.ns == ns!(html) && element.name.local == local_name!("meta") {
+            Some(MetaRef(element))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the wrapped element.
+    #[inline]
+    pub fn into_element(self) -> NodeDataRef<ElementData> {
+        self.0
+    }
+
+    /// Returns the `name` attribute, if present.
+    pub fn name(&self) -> Option<String> {
+        self.attribute(local_name!("name"))
+    }
+
+    /// Returns the `property` attribute, if present.
+    ///
+    /// This is used by RDFa and Open Graph metadata instead of `name`.
+    pub fn property(&self) -> Option<String> {
+        self.attribute(local_name!("property"))
+    }
+
+    /// Returns the `content` attribute, if present.
+    pub fn content(&self) -> Option<String> {
+        self.attribute(local_name!("content"))
+    }
+
+    /// Returns the `http-equiv` attribute, if present.
+    pub fn http_equiv(&self) -> Option<String> {
+        self.attribute(local_name!("http-equiv"))
+    }
+
+    /// Returns the `charset` attribute, if present.
+    pub fn charset(&self) -> Option<String> {
+        self.attribute(local_name!("charset"))
+    }
+
+    /// Returns an attribute value by local name.
+    fn attribute(&self, name: html5ever::LocalName) -> Option<String> {
+        self.attributes.borrow().get(name).map(str::to_owned)
+    }
+}
+
+/// Implements Deref for MetaRef.
+///
+/// Exposes the underlying element reference.
+impl Deref for MetaRef {
+    type Target = NodeDataRef<ElementData>;
+    #[inline]
+    fn deref(&self) -> &NodeDataRef<ElementData> {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::html5ever::tendril::TendrilSink;
+    use crate::parse_html;
+
+    /// Tests the accessors of meta elements.
+    ///
+    /// Verifies charset, http-equiv, and Open Graph property metadata, with
+    /// absent attributes reported as None.
+    #[test]
+    fn accessors() {
+        let doc = parse_html().one(
+            "<meta charset='utf-8'>\
+             <meta http-equiv='refresh' content='5'>\
+             <meta property='og:title' content='Title'>",
+        );
+        let metas: Vec<MetaRef> = doc
+            .select("meta")
+            .unwrap()
+            .map(|meta| MetaRef::new(meta).unwrap())
+            .collect();
+        assert_eq!(metas[0].charset().as_deref(), Some("utf-8"));
+        assert_eq!(metas[0].content(), None);
+        assert_eq!(metas[1].http_equiv().as_deref(), Some("refresh"));
+        assert_eq!(metas[1].content().as_deref(), Some("5"));
+        assert_eq!(metas[2].property().as_deref(), Some("og:title"));
+        assert_eq!(metas[2].name(), None);
+    }
+}
@@ -0,0 +1,87 @@
+//! Typed views of common HTML elements.
+//!
+//! Each view wraps a [`NodeDataRef<ElementData>`](crate::NodeDataRef) for a specific
+//! HTML element and exposes semantic accessors instead of raw attribute strings.
+//! Views are constructed with `new()`, which returns `None` if the element is not
+//! of the expected type.
+
+/// Scriptable content removed from a tree.
+mod active_content;
+/// Typed view of `<a>` and `<area>` elements.
+mod anchor_ref;
+/// Typed ARIA attribute access.
+mod aria;
+/// WAI-ARIA roles.
+mod aria_role;
+/// Tristate ARIA values.
+mod aria_tristate;
+/// Resource referenced by a document.
+mod asset;
+/// Kind of a referenced resource.
+mod asset_kind;
+/// Legacy conditional comments.
+mod conditional_comment;
+/// Options for CSP nonce injection.
+mod csp_nonce_options;
+/// ARIA accessors for element references.
+mod element_data_aria;
+/// Typed view of `<img>` elements.
+mod image_ref;
+/// Typed view of `<meta>` elements.
+mod meta_ref;
+/// Asset inventory for NodeRef.
+mod node_ref_assets;
+/// Document base URL for NodeRef.
+mod node_ref_base_url;
+/// CSP nonce injection for NodeRef.
+mod node_ref_csp_nonce;
+/// Form control setters for NodeRef.
+mod node_ref_form_controls;
+/// Document head conveniences for NodeRef.
+mod node_ref_head;
+/// Document outline for NodeRef.
+mod node_ref_outline;
+/// Redirect detection for NodeRef.
+mod node_ref_redirect;
+/// Active content removal for NodeRef.
+mod node_ref_strip_active_content;
+/// Section of a document outline.
+mod outline_section;
+/// Redirect declared by a document.
+mod redirect;
+/// Relative URL resolution.
+mod resolve_url;
+/// Typed view of `<script>` elements.
+mod script_ref;
+/// Parsed `sizes` attribute.
+mod sizes;
+/// Entry of a `sizes` attribute.
+mod source_size;
+/// Parsed `srcset` attribute.
+mod srcset;
+/// Image candidate of a `srcset` attribute.
+mod srcset_candidate;
+/// Descriptor of a `srcset` image candidate.
+mod srcset_descriptor;
+
+pub use active_content::ActiveContent;
+pub use anchor_ref::AnchorRef;
+pub use aria::Aria;
+pub use aria_role::AriaRole;
+pub use aria_tristate::AriaTristate;
+pub use asset::Asset;
+pub use asset_kind::AssetKind;
+pub use conditional_comment::ConditionalComment;
+pub use csp_nonce_options::CspNonceOptions;
+pub use image_ref::ImageRef;
+pub use meta_ref::MetaRef;
+pub(crate) use node_ref_base_url::apply_base_element;
+pub use outline_section::OutlineSection;
+pub use redirect::Redirect;
+pub use resolve_url::resolve_url;
+pub use script_ref::ScriptRef;
+pub use sizes::Sizes;
+pub use source_size::SourceSize;
+pub use srcset::Srcset;
+pub use srcset_candidate::SrcsetCandidate;
+pub use srcset_descriptor::SrcsetDescriptor;
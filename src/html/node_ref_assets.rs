@@ -0,0 +1,155 @@
+//! Asset inventory of node trees.
+
+use super::{Asset, AssetKind};
+use crate::iter::NodeIterator;
+use crate::tree::NodeRef;
+
+/// Asset methods for NodeRef.
+///
+/// Provides an inventory of the resources referenced by a document.
+impl NodeRef {
+    /// Returns the stylesheets, scripts and images referenced in this subtree.
+    ///
+    /// Assets are listed in document order and include `<link rel="stylesheet">`,
+    /// `<style>`, `<script>` with and without `src`, and `<img>` elements.
+    /// Template contents are not searched. Image `srcset` candidates are
+    /// available through [`ImageRef::srcset()`](crate::html::ImageRef::srcset)
+    /// on the asset's element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::html::AssetKind;
+    /// use brik::parse_html;
+    /// use brik::traits::*;
+    ///
+    /// let doc = parse_html().one(
+    ///     "<link rel=stylesheet href=/a.css media=print integrity=sha384-x>\
+    ///      <script src=/b.js></script><img src=/c.png>",
+    /// );
+    /// let assets = doc.assets();
+    /// let kinds: Vec<_> = assets.iter().map(|asset| asset.kind).collect();
+    /// assert_eq!(kinds, [AssetKind::Stylesheet, AssetKind::Script, AssetKind::Image]);
+    /// assert_eq!(assets[0].url.as_deref(), Some("/a.css"));
+    /// assert_eq!(assets[0].media.as_deref(), Some("print"));
+    /// assert_eq!(assets[0].integrity.as_deref(), Some("sha384-x"));
+    /// ```
+    pub fn assets(&self) -> Vec<Asset> {
+        let mut assets = Vec::new();
+        for element in self.descendants().elements() {
+            if element.name.ns != ns!(html) {
+                continue;
+            }
+            let attributes = element.attributes.borrow();
+            let get = |name| attributes.get(name).map(str::to_owned);
+            let (kind, url, content) = match element.name.local {
+                local_name!("link")
+                    if attributes
+                        .get_tokens(local_name!("rel"))
+                        .any(|rel| rel.eq_ignore_ascii_case("stylesheet")) =>
+                {
+                    match get(local_name!("href")) {
+                        Some(href) => (AssetKind::Stylesheet, Some(href), None),
+                        None => continue,
+                    }
+                }
+                local_name!("style") => {
+                    (AssetKind::InlineStyle, None, Some(element.text_contents()))
+                }
+                local_name!("script") => match get(local_name!("src")) {
+                    Some(src) => (AssetKind::Script, Some(src), None),
+                    None => (AssetKind::InlineScript, None, Some(element.text_contents())),
+                },
+                local_name!("img") => (AssetKind::Image, get(local_name!("src")), None),
+                _ => continue,
+            };
+            let media = match kind {
+                AssetKind::Stylesheet | AssetKind::InlineStyle => get(local_name!("media")),
+                _ => None,
+            };
+            let integrity = match kind {
+                AssetKind::Stylesheet | AssetKind::Script => get(local_name!("integrity")),
+                _ => None,
+            };
+            drop(attributes);
+            assets.push(Asset {
+                kind,
+                url,
+                media,
+                integrity,
+                content,
+                element,
+            });
+        }
+        assets
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::html5ever::tendril::TendrilSink;
+    use crate::parse_html;
+
+    /// Tests the asset inventory of a document.
+    ///
+    /// Verifies the kinds, URLs and inline content of each asset, in
+    /// document order, and that non-stylesheet links are skipped.
+    #[test]
+    fn inventory() {
+        let doc = parse_html().one(
+            "<link rel=icon href=/i.ico><link rel='Alternate StyleSheet' href=/alt.css>\
+             <style media=screen>p{}</style><script>go()</script>\
+             <script src=/m.js integrity=sha256-y type=module></script><img alt=x>\
+             <template><img src=/hidden.png></template>",
+        );
+        let assets: Vec<_> = doc
+            .assets()
+            .into_iter()
+            .map(|asset| {
+                (
+                    asset.kind,
+                    asset.url,
+                    asset.media,
+                    asset.integrity,
+                    asset.content,
+                )
+            })
+            .collect();
+
+        assert_eq!(
+            assets,
+            vec![
+                (
+                    AssetKind::Stylesheet,
+                    Some("/alt.css".into()),
+                    None,
+                    None,
+                    None
+                ),
+                (
+                    AssetKind::InlineStyle,
+                    None,
+                    Some("screen".into()),
+                    None,
+                    Some("p{}".into())
+                ),
+                (
+                    AssetKind::InlineScript,
+                    None,
+                    None,
+                    None,
+                    Some("go()".into())
+                ),
+                (
+                    AssetKind::Script,
+                    Some("/m.js".into()),
+                    None,
+                    Some("sha256-y".into()),
+                    None
+                ),
+                (AssetKind::Image, None, None, None, None),
+            ]
+        );
+    }
+}
@@ -0,0 +1,111 @@
+//! Document base URL lookup.
+
+use super::resolve_url;
+use crate::iter::NodeIterator;
+use crate::tree::NodeRef;
+
+/// Base URL methods for NodeRef.
+///
+/// Provides the base URL of the document a node belongs to.
+impl NodeRef {
+    /// Returns the base URL of the document containing this node.
+    ///
+    /// This is the [`DocumentData::base_url()`](crate::DocumentData::base_url)
+    /// of the root of this node's tree, or `None` if the root is not a
+    /// document or has no base URL.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::html::AnchorRef;
+    /// use brik::{parse_html_with_options, ParseOpts};
+    /// use brik::traits::*;
+    ///
+    /// let opts = ParseOpts {
+    ///     base_url: Some("https://example.com/docs/".to_string()),
+    ///     ..ParseOpts::default()
+    /// };
+    /// let doc = parse_html_with_options(opts).one("<base href=v2/><a href=intro>Intro</a>");
+    /// let anchor = AnchorRef::new(doc.select_first("a").unwrap()).unwrap();
+    ///
+    /// assert_eq!(
+    ///     anchor.as_node().document_base_url().as_deref(),
+    ///     Some("https://example.com/docs/v2/")
+    /// );
+    /// assert_eq!(
+    ///     anchor.absolute_url().as_deref(),
+    ///     Some("https://example.com/docs/v2/intro")
+    /// );
+    /// ```
+    pub fn document_base_url(&self) -> Option<String> {
+        self.inclusive_ancestors().last()?.as_document()?.base_url()
+    }
+}
+
+/// Sets the base URL of `document` from its first `<base href>`.
+///
+/// A relative `href` is resolved against the current base URL. The base URL
+/// is unchanged if there is no such element or the result is not absolute.
+pub(crate) fn apply_base_element(document: &NodeRef) {
+    let data = match document.as_document() {
+        Some(data) => data,
+        None => return,
+    };
+    let href = document.descendants().elements().find_map(|element| {
+        if element.name.ns != ns!(html) || element.name.local != local_name!("base") {
+            return None;
+        }
+        let href = element.attributes.borrow().get_trimmed("href")?.to_string();
+        Some(href)
+    });
+    let href = match href {
+        Some(href) => href,
+        None => return,
+    };
+    let resolved = match data.base_url() {
+        Some(base) => resolve_url(&base, &href),
+        None => resolve_url(&href, ""),
+    };
+    if let Some(resolved) = resolved {
+        data.set_base_url(Some(&resolved));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::html5ever::tendril::TendrilSink;
+    use crate::parse_html;
+
+    /// Tests base URLs from `<base href>` and from the document data.
+    ///
+    /// Verifies that an absolute `<base href>` sets the base URL without a
+    /// parse option, that a relative one is ignored, that assets resolve
+    /// against the base URL, and that a detached subtree has none.
+    #[test]
+    fn document_base_url_sources() {
+        let doc = parse_html().one("<base href='https://a.test/x/'><script src=app.js></script>");
+        let relative = parse_html().one("<base href=/x/><p>");
+
+        assert_eq!(
+            doc.document_base_url().as_deref(),
+            Some("https://a.test/x/")
+        );
+        assert_eq!(relative.document_base_url(), None);
+        assert_eq!(
+            doc.assets()[0].resolved_url().as_deref(),
+            Some("https://a.test/x/app.js")
+        );
+
+        relative
+            .as_document()
+            .unwrap()
+            .set_base_url(Some("https://b.test/"));
+        let paragraph = relative.select_first("p").unwrap();
+        assert_eq!(
+            paragraph.as_node().document_base_url().as_deref(),
+            Some("https://b.test/")
+        );
+        paragraph.as_node().detach();
+        assert_eq!(paragraph.as_node().document_base_url(), None);
+    }
+}
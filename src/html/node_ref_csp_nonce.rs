@@ -0,0 +1,103 @@
+//! Content Security Policy nonce injection for node trees.
+
+use super::node_ref_strip_active_content::is_event_handler;
+use super::CspNonceOptions;
+use crate::iter::NodeIterator;
+use crate::tree::NodeRef;
+
+/// CSP nonce methods for NodeRef.
+///
+/// Provides marking of scripts and styles with a Content Security Policy
+/// nonce.
+impl NodeRef {
+    /// Sets the `nonce` attribute of every `<script>` and `<style>` element
+    /// in this subtree, returning the number of elements marked.
+    ///
+    /// This is equivalent to [`add_csp_nonce_with_options()`] with default
+    /// options.
+    ///
+    /// [`add_csp_nonce_with_options()`]: NodeRef::add_csp_nonce_with_options
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::parse_html;
+    /// use brik::traits::*;
+    ///
+    /// let doc = parse_html().one("<style>p{}</style><script src=/a.js></script>");
+    /// assert_eq!(doc.add_csp_nonce("r4nd0m"), 2);
+    /// assert_eq!(
+    ///     doc.select_first("head").unwrap().as_node().to_string(),
+    ///     r#"<head><style nonce="r4nd0m">p{}</style><script src="/a.js" nonce="r4nd0m"></script></head>"#
+    /// );
+    /// ```
+    pub fn add_csp_nonce(&self, nonce: &str) -> usize {
+        self.add_csp_nonce_with_options(nonce, &CspNonceOptions::default())
+    }
+
+    /// Sets the `nonce` attribute of every `<script>` and `<style>` element
+    /// in this subtree, with options, returning the number of elements
+    /// marked.
+    ///
+    /// Existing nonces are replaced. Template contents are included, so
+    /// scripts cloned from templates at runtime are allowed too.
+    pub fn add_csp_nonce_with_options(&self, nonce: &str, options: &CspNonceOptions) -> usize {
+        let mut marked = 0;
+        for element in self.descendants_with_templates().elements() {
+            let mut attributes = element.attributes.borrow_mut();
+            let mut changed = false;
+            if options.strip_event_handlers {
+                let count = attributes.map.len();
+                attributes
+                    .map
+                    .retain(|name, _| !is_event_handler(&name.local));
+                changed = attributes.map.len() != count;
+            }
+            if element.name.ns == ns!(html)
+                && matches!(
+                    element.name.local,
+                    local_name!("script") | local_name!("style")
+                )
+            {
+                attributes.insert(local_name!("nonce"), nonce);
+                marked += 1;
+                changed = true;
+            }
+            if changed {
+                element.as_node().mark_dirty();
+            }
+        }
+        marked
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::html::CspNonceOptions;
+    use crate::html5ever::tendril::TendrilSink;
+    use crate::parse_html;
+
+    /// Tests adding a nonce while stripping event handlers.
+    ///
+    /// Verifies that existing nonces are replaced, that template contents
+    /// are marked, that SVG scripts are left alone, and that handlers are
+    /// removed from every element.
+    #[test]
+    fn add_csp_nonce_strips_handlers() {
+        let doc = parse_html().one(
+            "<script nonce=old>a()</script><p onclick=b() class=x>p</p>\
+             <template><style>i{}</style></template><svg><script></script></svg>",
+        );
+        let options = CspNonceOptions {
+            strip_event_handlers: true,
+        };
+
+        assert_eq!(doc.add_csp_nonce_with_options("n", &options), 2);
+        assert_eq!(
+            doc.to_string(),
+            "<html><head><script nonce=\"n\">a()</script></head><body><p class=\"x\">p</p>\
+             <template><style nonce=\"n\">i{}</style></template><svg><script></script></svg>\
+             </body></html>"
+        );
+    }
+}
@@ -0,0 +1,263 @@
+//! Value setters for form controls.
+
+use crate::iter::NodeIterator;
+use crate::node_data_ref::NodeDataRef;
+use crate::tree::{ElementData, NodeRef};
+use html5ever::LocalName;
+
+/// Form control methods for NodeRef.
+///
+/// Provides setters that update form controls the way a user would, using
+/// each control's attribute or child semantics.
+impl NodeRef {
+    /// Sets the value of a form control, returning whether this node is a
+    /// control whose value could be set.
+    ///
+    /// An `<input>` gets a `value` attribute, a `<textarea>` gets its text
+    /// content replaced, and a `<select>` has the matching option selected
+    /// as by [`select_option()`](NodeRef::select_option). Other nodes are
+    /// left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::parse_html;
+    /// use brik::traits::*;
+    ///
+    /// let doc = parse_html().one("<input name=q><textarea>old</textarea>");
+    /// let input = doc.select_first("input").unwrap();
+    /// let textarea = doc.select_first("textarea").unwrap();
+    ///
+    /// assert!(input.as_node().set_input_value("rust"));
+    /// assert!(textarea.as_node().set_input_value("new"));
+    /// assert_eq!(input.as_node().to_string(), r#"<input name="q" value="rust">"#);
+    /// assert_eq!(textarea.as_node().to_string(), "<textarea>new</textarea>");
+    /// ```
+    pub fn set_input_value(&self, value: &str) -> bool {
+        let element = match html_element(self) {
+            Some(element) => element,
+            None => return false,
+        };
+        match element.name.local {
+            local_name!("input") => {
+                element
+                    .attributes
+                    .borrow_mut()
+                    .insert(local_name!("value"), value);
+                self.mark_dirty();
+                true
+            }
+            local_name!("textarea") => {
+                self.set_text_content(value);
+                true
+            }
+            local_name!("select") => self.select_option(value),
+            _ => false,
+        }
+    }
+
+    /// Selects the option of a `<select>` with the given value, returning
+    /// whether a matching option was found.
+    ///
+    /// An option's value is its `value` attribute, or its text with
+    /// whitespace collapsed if it has none. Options inside `<optgroup>`
+    /// elements are included. Unless the select has the `multiple`
+    /// attribute, the `selected` attribute is removed from the other
+    /// options. Nothing changes if this is not a `<select>` or no option
+    /// matches.
+    pub fn select_option(&self, value: &str) -> bool {
+        let select = match html_element(self) {
+            Some(select) if select.name.local == local_name!("select") => select,
+            _ => return false,
+        };
+        let options: Vec<NodeDataRef<ElementData>> = self
+            .descendants()
+            .elements()
+            .filter(|element| is_html(element, local_name!("option")))
+            .collect();
+        let index = match options
+            .iter()
+            .position(|option| option_value(option) == value)
+        {
+            Some(index) => index,
+            None => return false,
+        };
+        let multiple = select.attributes.borrow().contains("multiple");
+        for (i, option) in options.iter().enumerate() {
+            let mut attributes = option.attributes.borrow_mut();
+            if i == index {
+                attributes.insert(local_name!("selected"), "");
+                option.as_node().mark_dirty();
+            } else if !multiple && attributes.remove(local_name!("selected")).is_some() {
+                option.as_node().mark_dirty();
+            }
+        }
+        true
+    }
+
+    /// Checks or unchecks a checkbox or radio button, returning whether
+    /// this node is one.
+    ///
+    /// Adds or removes the `checked` attribute. Checking a radio button
+    /// unchecks the other radio buttons with the same `name` in the same
+    /// `<form>`, or in the same tree if it has no form ancestor.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::parse_html;
+    /// use brik::traits::*;
+    ///
+    /// let doc = parse_html().one(
+    ///     "<input type=radio name=size value=s checked><input type=radio name=size value=m>",
+    /// );
+    /// let medium = doc.select_first("[value=m]").unwrap();
+    /// assert!(medium.as_node().set_checked(true));
+    ///
+    /// let checked: Vec<_> = doc
+    ///     .select("[checked]")
+    ///     .unwrap()
+    ///     .map(|input| input.attributes.borrow().get("value").unwrap().to_string())
+    ///     .collect();
+    /// assert_eq!(checked, ["m"]);
+    /// ```
+    pub fn set_checked(&self, checked: bool) -> bool {
+        let element = match html_element(self) {
+            Some(element) if element.name.local == local_name!("input") => element,
+            _ => return false,
+        };
+        let (kind, name) = {
+            let attributes = element.attributes.borrow();
+            (
+                attributes
+                    .get_trimmed(local_name!("type"))
+                    .unwrap_or_default()
+                    .to_ascii_lowercase(),
+                attributes.get(local_name!("name")).map(str::to_string),
+            )
+        };
+        if kind != "checkbox" && kind != "radio" {
+            return false;
+        }
+        if !checked {
+            let removed = element
+                .attributes
+                .borrow_mut()
+                .remove(local_name!("checked"));
+            if removed.is_some() {
+                self.mark_dirty();
+            }
+            return true;
+        }
+        if let (true, Some(name)) = (kind == "radio", name.filter(|name| !name.is_empty())) {
+            let owner = self
+                .ancestors()
+                .find(|ancestor| {
+                    html_element(ancestor)
+                        .is_some_and(|form| form.name.local == local_name!("form"))
+                })
+                .or_else(|| self.ancestors().last())
+                .unwrap_or_else(|| self.clone());
+            for other in owner.descendants().elements() {
+                if !is_html(&other, local_name!("input")) {
+                    continue;
+                }
+                let mut attributes = other.attributes.borrow_mut();
+                let same_group = attributes
+                    .get_trimmed(local_name!("type"))
+                    .is_some_and(|kind| kind.eq_ignore_ascii_case("radio"))
+                    && attributes.get(local_name!("name")) == Some(&*name);
+                if same_group && attributes.remove(local_name!("checked")).is_some() {
+                    other.as_node().mark_dirty();
+                }
+            }
+        }
+        element
+            .attributes
+            .borrow_mut()
+            .insert(local_name!("checked"), "");
+        self.mark_dirty();
+        true
+    }
+}
+
+/// Returns the element data of an HTML element node.
+fn html_element(node: &NodeRef) -> Option<&ElementData> {
+    node.as_element()
+        .filter(|element| element.name.ns == ns!(html))
+}
+
+/// Returns whether `element` is the HTML element `local_name`.
+fn is_html(element: &ElementData, local_name: LocalName) -> bool {
+    element.name.ns == ns!(html) && element.name.local == local_name
+}
+
+/// Returns the value an `<option>` submits.
+fn option_value(option: &NodeDataRef<ElementData>) -> String {
+    if let Some(value) = option.attributes.borrow().get(local_name!("value")) {
+        return value.to_string();
+    }
+    option
+        .text_contents()
+        .split_ascii_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::html5ever::tendril::TendrilSink;
+    use crate::parse_html;
+
+    /// Tests selecting options by value.
+    ///
+    /// Verifies that options without a `value` match by their collapsed
+    /// text, that options in groups are found, that other options are
+    /// deselected in a single select but kept in a multiple select, and
+    /// that an unknown value changes nothing.
+    #[test]
+    fn select_option_updates_selected() {
+        let doc = parse_html().one(
+            "<select id=one><option selected>A</option><optgroup><option> New\n York </option>\
+             </optgroup></select><select id=many multiple><option value=1 selected>1</option>\
+             <option value=2>2</option></select>",
+        );
+        let one = doc.select_first("#one").unwrap();
+        let many = doc.select_first("#many").unwrap();
+
+        assert!(one.as_node().select_option("New York"));
+        assert!(many.as_node().set_input_value("2"));
+        assert!(!one.as_node().select_option("Boston"));
+
+        let selected: Vec<String> = doc
+            .select("option[selected]")
+            .unwrap()
+            .map(|option| option.text_contents())
+            .collect();
+        assert_eq!(selected, [" New\n York ", "1", "2"]);
+    }
+
+    /// Tests checking radio buttons in forms.
+    ///
+    /// Edge case: radio buttons with the same name in another form keep
+    /// their state, and unchecking does not touch the group.
+    #[test]
+    fn set_checked_scopes_radio_groups() {
+        let doc = parse_html().one(
+            "<form><input type=radio name=r id=a checked><input type=radio name=r id=b></form>\
+             <form><input type=radio name=r id=c checked></form><p id=p>",
+        );
+        let checked = || -> Vec<String> {
+            doc.select("[checked]")
+                .unwrap()
+                .map(|input| input.attributes.borrow().get("id").unwrap().to_string())
+                .collect()
+        };
+
+        assert!(doc.select_first("#b").unwrap().as_node().set_checked(true));
+        assert_eq!(checked(), ["b", "c"]);
+        assert!(doc.select_first("#c").unwrap().as_node().set_checked(false));
+        assert_eq!(checked(), ["b"]);
+        assert!(!doc.select_first("#p").unwrap().as_node().set_checked(true));
+    }
+}
@@ -0,0 +1,269 @@
+//! Document head conveniences.
+
+use crate::iter::NodeIterator;
+use crate::node_data_ref::NodeDataRef;
+use crate::tree::{ElementData, NodeRef};
+use html5ever::{LocalName, QualName};
+
+/// Document head methods for NodeRef.
+///
+/// Provides access to the `<head>` and `<body>` of a document and to its
+/// title, named metadata and canonical URL, creating missing elements when
+/// setting values.
+impl NodeRef {
+    /// Returns the `<head>` child of the document's `<html>` element.
+    ///
+    /// This node may be the document or its `<html>` element.
+    pub fn head(&self) -> Option<NodeDataRef<ElementData>> {
+        html_child(&self.document_element()?, local_name!("head"))
+    }
+
+    /// Returns the `<body>` child of the document's `<html>` element.
+    ///
+    /// This node may be the document or its `<html>` element.
+    pub fn body(&self) -> Option<NodeDataRef<ElementData>> {
+        html_child(&self.document_element()?, local_name!("body"))
+    }
+
+    /// Returns the text of the document's `<title>`, with whitespace
+    /// stripped and collapsed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::parse_html;
+    /// use brik::traits::*;
+    ///
+    /// let doc = parse_html().one("<meta charset=utf-8><p>Hello");
+    /// assert_eq!(doc.title(), None);
+    ///
+    /// doc.set_title("Home");
+    /// doc.set_meta("description", "The home page");
+    /// doc.set_canonical("https://example.com/");
+    /// assert_eq!(doc.title().as_deref(), Some("Home"));
+    /// assert_eq!(
+    ///     doc.head().unwrap().as_node().to_string(),
+    ///     "<head><meta charset=\"utf-8\"><title>Home</title>\
+    ///      <meta name=\"description\" content=\"The home page\">\
+    ///      <link rel=\"canonical\" href=\"https://example.com/\"></head>"
+    /// );
+    /// ```
+    pub fn title(&self) -> Option<String> {
+        let title = find_in_head(self, |element| element.name.local == local_name!("title"))?;
+        Some(
+            title
+                .text_contents()
+                .split_ascii_whitespace()
+                .collect::<Vec<_>>()
+                .join(" "),
+        )
+    }
+
+    /// Sets the text of the document's `<title>`.
+    ///
+    /// A missing `<title>` is created after any `<meta charset>` at the
+    /// start of the `<head>`, and a missing `<head>` is created at the start
+    /// of the `<html>` element. Does nothing if there is no `<html>`
+    /// element.
+    pub fn set_title(&self, title: &str) {
+        if let Some(element) =
+            find_in_head(self, |element| element.name.local == local_name!("title"))
+        {
+            element.as_node().set_text_content(title);
+            return;
+        }
+        let head = match self.head_or_create() {
+            Some(head) => head,
+            None => return,
+        };
+        let element = new_html_element(local_name!("title"), &[]);
+        element.set_text_content(title);
+        let charset = head
+            .children()
+            .elements()
+            .take_while(|child| {
+                child.name.local == local_name!("meta")
+                    && child.attributes.borrow().contains("charset")
+            })
+            .last();
+        match charset {
+            Some(charset) => charset.as_node().insert_after(element),
+            None => head.prepend(element),
+        }
+    }
+
+    /// Returns the `content` of the first `<meta>` in the `<head>` whose
+    /// `name` matches `name` ASCII case-insensitively.
+    pub fn meta(&self, name: &str) -> Option<String> {
+        let meta = find_in_head(self, |element| is_named_meta(element, name))?;
+        let content = meta.attributes.borrow().get("content").map(str::to_string);
+        content
+    }
+
+    /// Sets the `content` of the `<meta>` named `name`, appending one to
+    /// the `<head>` if missing.
+    ///
+    /// A missing `<head>` is created as for [`set_title()`]. Does nothing
+    /// if there is no `<html>` element.
+    ///
+    /// [`set_title()`]: NodeRef::set_title
+    pub fn set_meta(&self, name: &str, content: &str) {
+        if let Some(meta) = find_in_head(self, |element| is_named_meta(element, name)) {
+            meta.attributes_mut()
+                .insert(local_name!("content"), content);
+            return;
+        }
+        if let Some(head) = self.head_or_create() {
+            head.append(new_html_element(
+                local_name!("meta"),
+                &[
+                    (local_name!("name"), name),
+                    (local_name!("content"), content),
+                ],
+            ));
+        }
+    }
+
+    /// Returns the `href` of the first `<link rel=canonical>` in the
+    /// `<head>`.
+    pub fn canonical(&self) -> Option<String> {
+        let link = find_in_head(self, is_canonical_link)?;
+        let href = link.attributes.borrow().get("href").map(str::to_string);
+        href
+    }
+
+    /// Sets the `href` of the `<link rel=canonical>`, appending one to the
+    /// `<head>` if missing.
+    ///
+    /// A missing `<head>` is created as for [`set_title()`]. Does nothing
+    /// if there is no `<html>` element.
+    ///
+    /// [`set_title()`]: NodeRef::set_title
+    pub fn set_canonical(&self, href: &str) {
+        if let Some(link) = find_in_head(self, is_canonical_link) {
+            link.attributes_mut().insert(local_name!("href"), href);
+            return;
+        }
+        if let Some(head) = self.head_or_create() {
+            head.append(new_html_element(
+                local_name!("link"),
+                &[
+                    (local_name!("rel"), "canonical"),
+                    (local_name!("href"), href),
+                ],
+            ));
+        }
+    }
+
+    /// Returns the `<html>` element, which is either this node or one of
+    /// its children.
+    fn document_element(&self) -> Option<NodeRef> {
+        if is_html_element(self, local_name!("html")) {
+            return Some(self.clone());
+        }
+        html_child(self, local_name!("html")).map(|html| html.as_node().clone())
+    }
+
+    /// Returns the `<head>`, creating it at the start of the `<html>`
+    /// element if missing.
+    fn head_or_create(&self) -> Option<NodeRef> {
+        let html = self.document_element()?;
+        if let Some(head) = html_child(&html, local_name!("head")) {
+            return Some(head.as_node().clone());
+        }
+        let head = new_html_element(local_name!("head"), &[]);
+        html.prepend(head.clone());
+        Some(head)
+    }
+}
+
+/// Returns the first child of `node` that is the HTML element `local_name`.
+fn html_child(node: &NodeRef, local_name: LocalName) -> Option<NodeDataRef<ElementData>> {
+    node.children()
+        .elements()
+        .find(|child| is_html_element(child.as_node(), local_name.clone()))
+}
+
+/// Returns whether `node` is the HTML element `local_name`.
+fn is_html_element(node: &NodeRef, local_name: LocalName) -> bool {
+    node.as_element()
+        .is_some_and(|element| element.name.ns == ns!(html) && element.name.local == local_name)
+}
+
+/// Returns the first HTML element in the document's `<head>` matching
+/// `predicate`.
+fn find_in_head<F>(node: &NodeRef, predicate: F) -> Option<NodeDataRef<ElementData>>
+where
+    F: Fn(&ElementData) -> bool,
+{
+    node.head()?
+        .as_node()
+        .descendants()
+        .elements()
+        .find(|element| element.name.ns == ns!(html) && predicate(element))
+}
+
+/// Returns whether `element` is a `<meta>` named `name`.
+fn is_named_meta(element: &ElementData, name: &str) -> bool {
+    element.name.local == local_name!("meta")
+        && element
+            .attributes
+            .borrow()
+            .get_trimmed("name")
+            .is_some_and(|value| value.eq_ignore_ascii_case(name))
+}
+
+/// Returns whether `element` is a `<link>` with the `canonical` relation.
+fn is_canonical_link(element: &ElementData) -> bool {
+    element.name.local == local_name!("link")
+        && element
+            .attributes
+            .borrow()
+            .get_tokens("rel")
+            .any(|rel| rel.eq_ignore_ascii_case("canonical"))
+}
+
+/// Creates an HTML element with the given attributes.
+fn new_html_element(local_name: LocalName, attributes: &[(LocalName, &str)]) -> NodeRef {
+    let element = NodeRef::new_element(QualName::new(None, ns!(html), local_name), None);
+    if let Some(data) = element.as_element() {
+        let mut map = data.attributes.borrow_mut();
+        for (name, value) in attributes {
+            map.insert(name.clone(), *value);
+        }
+    }
+    element
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::html5ever::tendril::TendrilSink;
+    use crate::parse_html;
+
+    /// Tests updating existing head elements in place.
+    ///
+    /// Verifies that titles are read with collapsed whitespace, that meta
+    /// names and link relations match case-insensitively, and that setters
+    /// update the existing elements instead of adding new ones.
+    #[test]
+    fn head_setters_update_existing() {
+        let doc = parse_html().one(
+            "<title> A\n  page </title><meta name=Robots content=index>\
+             <link rel='Canonical alternate' href=/a>",
+        );
+
+        assert_eq!(doc.title().as_deref(), Some("A page"));
+        assert_eq!(doc.meta("robots").as_deref(), Some("index"));
+        assert_eq!(doc.canonical().as_deref(), Some("/a"));
+
+        doc.set_title("B");
+        doc.set_meta("robots", "noindex");
+        doc.set_canonical("/b");
+        assert_eq!(
+            doc.head().unwrap().as_node().to_string(),
+            "<head><title>B</title><meta name=\"Robots\" content=\"noindex\">\
+             <link rel=\"Canonical alternate\" href=\"/b\"></head>"
+        );
+        assert_eq!(doc.body().unwrap().as_node().to_string(), "<body></body>");
+    }
+}
@@ -0,0 +1,398 @@
+//! Document outline extraction.
+
+use super::OutlineSection;
+use crate::iter::{NodeEdge, NodeIterator};
+use crate::node_data_ref::NodeDataRef;
+use crate::tree::{ElementData, Node, NodeData, NodeRef};
+use std::collections::HashMap;
+
+/// Outline methods for NodeRef.
+///
+/// Provides the nested outline of headings and sectioning elements.
+impl NodeRef {
+    /// Returns the outline of this subtree, following the HTML outline
+    /// algorithm.
+    ///
+    /// `<article>`, `<aside>`, `<nav>` and `<section>` open explicit
+    /// sections; headings (`<h1>`–`<h6>` and `<hgroup>`) name the current
+    /// section or open implied sections according to their rank. Sectioning
+    /// roots such as `<blockquote>` and `<td>` have outlines of their own
+    /// and are left out. Elements with the `hidden` attribute are skipped.
+    ///
+    /// This node is the outline root; for a document, its `<body>` is used.
+    /// The returned list holds the top-level sections of the root.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::parse_html;
+    /// use brik::traits::*;
+    ///
+    /// let doc = parse_html().one(
+    ///     "<h1>Guide</h1><h2>Install</h2><section><h1>Usage</h1></section><h2>FAQ</h2>",
+    /// );
+    /// let outline = doc.outline();
+    ///
+    /// let title = |section: &brik::html::OutlineSection| {
+    ///     section.heading.as_ref().unwrap().text_contents()
+    /// };
+    /// assert_eq!(outline.len(), 1);
+    /// assert_eq!(title(&outline[0]), "Guide");
+    /// let titles: Vec<_> = outline[0].subsections.iter().map(title).collect();
+    /// assert_eq!(titles, ["Install", "Usage", "FAQ"]);
+    /// ```
+    pub fn outline(&self) -> Vec<OutlineSection> {
+        let root = match self.data() {
+            NodeData::Document(_) => self
+                .descendants()
+                .elements()
+                .find(|element| {
+                    element.name.ns == ns!(html) && element.name.local == local_name!("body")
+                })
+                .map(|body| body.as_node().clone())
+                .unwrap_or_else(|| self.clone()),
+            _ => self.clone(),
+        };
+        let mut builder = Builder::new(root.clone());
+        for edge in root.traverse() {
+            match edge {
+                NodeEdge::Start(node) => {
+                    if let Some(element) = node.into_element_ref() {
+                        builder.enter(element);
+                    }
+                }
+                NodeEdge::End(node) => {
+                    if let Some(element) = node.into_element_ref() {
+                        builder.exit(&element);
+                    }
+                }
+            }
+        }
+        builder.finish()
+    }
+}
+
+/// How an element takes part in the outline algorithm.
+#[derive(Clone, Copy, PartialEq)]
+enum Kind {
+    /// `<article>`, `<aside>`, `<nav>` or `<section>`.
+    SectioningContent,
+    /// An element with an outline of its own, such as `<blockquote>`.
+    SectioningRoot,
+    /// A heading with its rank, where 1 is the highest.
+    Heading(u8),
+    /// Any other element.
+    Other,
+}
+
+/// Returns how `element` takes part in the outline algorithm.
+fn kind(element: &NodeDataRef<ElementData>) -> Kind {
+    if element.name.ns != ns!(html) {
+        return Kind::Other;
+    }
+    match element.name.local {
+        local_name!("article")
+        | local_name!("aside")
+        | local_name!("nav")
+        | local_name!("section") => Kind::SectioningContent,
+        local_name!("blockquote")
+        | local_name!("body")
+        | local_name!("details")
+        | local_name!("dialog")
+        | local_name!("fieldset")
+        | local_name!("figure")
+        | local_name!("td") => Kind::SectioningRoot,
+        local_name!("hgroup") => Kind::Heading(
+            element
+                .as_node()
+                .descendants()
+                .elements()
+                .filter_map(|heading| heading_rank(&heading))
+                .min()
+                .unwrap_or(1),
+        ),
+        _ => heading_rank(element).map_or(Kind::Other, Kind::Heading),
+    }
+}
+
+/// Returns the rank of an `<h1>`–`<h6>` element.
+fn heading_rank(element: &NodeDataRef<ElementData>) -> Option<u8> {
+    if element.name.ns != ns!(html) {
+        return None;
+    }
+    match element.name.local {
+        local_name!("h1") => Some(1),
+        local_name!("h2") => Some(2),
+        local_name!("h3") => Some(3),
+        local_name!("h4") => Some(4),
+        local_name!("h5") => Some(5),
+        local_name!("h6") => Some(6),
+        _ => None,
+    }
+}
+
+/// A section under construction, stored by index in the builder.
+///
+/// A section is always created after its containing section, so nested
+/// sections have higher indexes.
+struct Section {
+    /// The heading and its rank, if any.
+    heading: Option<(NodeDataRef<ElementData>, u8)>,
+    /// The sectioning element that created the section.
+    element: Option<NodeDataRef<ElementData>>,
+    /// Indexes of the nested sections.
+    subsections: Vec<usize>,
+    /// Index of the containing section.
+    parent: Option<usize>,
+}
+
+/// State of the outline algorithm.
+struct Builder {
+    /// All sections created so far.
+    sections: Vec<Section>,
+    /// Top-level section indexes of each outline target, by node identity.
+    outlines: HashMap<*const Node, Vec<usize>>,
+    /// The outline root.
+    root: NodeRef,
+    /// The current outline target.
+    target: NodeRef,
+    /// Index of the current section.
+    current: usize,
+    /// Suspended outline targets.
+    stack: Vec<NodeRef>,
+    /// Heading or hidden element whose descendants are skipped, with the
+    /// nesting depth inside it.
+    skipping: Option<(NodeDataRef<ElementData>, usize)>,
+}
+
+/// Returns the identity key of a node.
+fn key(node: &NodeRef) -> *const Node {
+    &**node as *const Node
+}
+
+impl Builder {
+    /// Creates a builder with `root` as the outline root, treated as a
+    /// sectioning root.
+    fn new(root: NodeRef) -> Builder {
+        let mut builder = Builder {
+            sections: Vec::new(),
+            outlines: HashMap::new(),
+            root: root.clone(),
+            target: root.clone(),
+            current: 0,
+            stack: Vec::new(),
+            skipping: None,
+        };
+        builder.current = builder.new_section(root.into_element_ref());
+        builder
+            .outlines
+            .insert(key(&builder.root), vec![builder.current]);
+        builder
+    }
+
+    /// Creates a section and returns its index.
+    fn new_section(&mut self, element: Option<NodeDataRef<ElementData>>) -> usize {
+        self.sections.push(Section {
+            heading: None,
+            element,
+            subsections: Vec::new(),
+            parent: None,
+        });
+        self.sections.len() - 1
+    }
+
+    /// Handles the start of `element`.
+    fn enter(&mut self, element: NodeDataRef<ElementData>) {
+        if let Some((_, depth)) = &mut self.skipping {
+            *depth += 1;
+            return;
+        }
+        if element.attributes.borrow().contains("hidden") {
+            self.skipping = Some((element, 0));
+            return;
+        }
+        match kind(&element) {
+            Kind::SectioningContent | Kind::SectioningRoot => {
+                let node = element.as_node().clone();
+                self.stack
+                    .push(std::mem::replace(&mut self.target, node.clone()));
+                self.current = self.new_section(Some(element));
+                self.outlines.insert(key(&node), vec![self.current]);
+            }
+            Kind::Heading(rank) => {
+                self.heading(element.clone(), rank);
+                self.skipping = Some((element, 0));
+            }
+            Kind::Other => {}
+        }
+    }
+
+    /// Handles a heading of `rank` in the current section.
+    fn heading(&mut self, element: NodeDataRef<ElementData>, rank: u8) {
+        if self.sections[self.current].heading.is_none() {
+            self.sections[self.current].heading = Some((element, rank));
+            return;
+        }
+        let last = self.last_target_section();
+        let outranks_last = self.sections[last]
+            .heading
+            .as_ref()
+            .is_none_or(|(_, last_rank)| rank <= *last_rank);
+        if outranks_last {
+            self.current = self.new_section(None);
+            self.sections[self.current].heading = Some((element, rank));
+            let current = self.current;
+            self.outlines
+                .get_mut(&key(&self.target))
+                .expect("outline targets have an outline")
+                .push(current);
+            return;
+        }
+        let mut candidate = self.current;
+        loop {
+            let below_candidate = self.sections[candidate]
+                .heading
+                .as_ref()
+                .is_none_or(|(_, candidate_rank)| rank > *candidate_rank);
+            if below_candidate {
+                self.current = self.new_section(None);
+                self.sections[self.current].heading = Some((element, rank));
+                self.sections[self.current].parent = Some(candidate);
+                self.sections[candidate].subsections.push(self.current);
+                return;
+            }
+            candidate = self.sections[candidate]
+                .parent
+                .expect("the last top-level section is outranked");
+        }
+    }
+
+    /// Handles the end of `element`.
+    fn exit(&mut self, element: &NodeDataRef<ElementData>) {
+        if let Some((skipped, depth)) = &mut self.skipping {
+            if *depth == 0 && skipped == element {
+                self.skipping = None;
+            } else {
+                *depth -= 1;
+            }
+            return;
+        }
+        let kind = kind(element);
+        if !matches!(kind, Kind::SectioningContent | Kind::SectioningRoot) {
+            return;
+        }
+        let target = match self.stack.pop() {
+            Some(target) => target,
+            None => return,
+        };
+        let exited = self
+            .outlines
+            .remove(&key(element.as_node()))
+            .unwrap_or_default();
+        self.target = target;
+        self.current = self.last_target_section();
+        if kind == Kind::SectioningContent {
+            for section in exited {
+                self.sections[section].parent = Some(self.current);
+                self.sections[self.current].subsections.push(section);
+            }
+        } else {
+            while let Some(&last) = self.sections[self.current].subsections.last() {
+                self.current = last;
+            }
+        }
+    }
+
+    /// Returns the last top-level section of the current outline target.
+    fn last_target_section(&self) -> usize {
+        *self.outlines[&key(&self.target)]
+            .last()
+            .expect("outline targets have a section")
+    }
+
+    /// Returns the outline of the root as nested sections.
+    fn finish(mut self) -> Vec<OutlineSection> {
+        let top = self.outlines.remove(&key(&self.root)).unwrap_or_default();
+        let mut built: Vec<Option<OutlineSection>> = Vec::new();
+        built.resize_with(self.sections.len(), || None);
+        for (index, section) in self.sections.into_iter().enumerate().rev() {
+            let subsections = section
+                .subsections
+                .iter()
+                .filter_map(|&index| built[index].take())
+                .collect();
+            built[index] = Some(OutlineSection {
+                heading: section.heading.map(|(heading, _)| heading),
+                element: section.element,
+                subsections,
+            });
+        }
+        top.into_iter()
+            .filter_map(|index| built[index].take())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::html::OutlineSection;
+    use crate::html5ever::tendril::TendrilSink;
+    use crate::parse_html;
+
+    /// Returns the heading texts of an outline as an indented list.
+    fn render(sections: &[OutlineSection], depth: usize, out: &mut Vec<String>) {
+        for section in sections {
+            let heading = section
+                .heading
+                .as_ref()
+                .map_or_else(|| "(untitled)".to_string(), |h| h.text_contents());
+            out.push(format!("{}{}", "  ".repeat(depth), heading));
+            render(&section.subsections, depth + 1, out);
+        }
+    }
+
+    /// Tests outlining sectioning elements and implied sections.
+    ///
+    /// Verifies that untitled sections get implied headings, that a
+    /// sectioning element nests under the last top-level section, that
+    /// sectioning roots and hidden elements are left out, and that an
+    /// `hgroup` takes the rank of its highest heading.
+    #[test]
+    fn outline_nests_sections() {
+        let doc = parse_html().one(
+            "<hgroup><h2>Sub</h2><h1>Title</h1></hgroup>\
+             <nav><ul><li>menu</li></ul></nav>\
+             <h2>Part</h2><blockquote><h1>Quoted</h1></blockquote>\
+             <article><h3>Post</h3><h4>Detail</h4><h3>Next</h3></article>\
+             <h3>Tail</h3><div hidden><h2>Secret</h2></div><h1>End</h1>",
+        );
+
+        let mut lines = Vec::new();
+        render(&doc.outline(), 0, &mut lines);
+
+        assert_eq!(
+            lines,
+            [
+                "SubTitle",
+                "  (untitled)",
+                "  Part",
+                "  Post",
+                "    Detail",
+                "  Next",
+                "  Tail",
+                "End",
+            ]
+        );
+        let outline = doc.outline();
+        assert_eq!(
+            outline[0].subsections[0]
+                .element
+                .as_ref()
+                .unwrap()
+                .name
+                .local,
+            local_name!("nav")
+        );
+        assert!(outline[1].element.is_none());
+    }
+}
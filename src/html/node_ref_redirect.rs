@@ -0,0 +1,169 @@
+//! Redirect detection for documents.
+
+use super::{resolve_url, Redirect};
+use crate::iter::NodeIterator;
+use crate::tree::NodeRef;
+
+/// Redirect methods for NodeRef.
+///
+/// Provides detection of meta refresh and canonical link redirects.
+impl NodeRef {
+    /// Returns the redirect this document declares, if any.
+    ///
+    /// The first `<meta http-equiv="refresh">` with a valid `content` is
+    /// reported as [`Redirect::Refresh`]. Otherwise, a
+    /// [`canonical()`](NodeRef::canonical) link is reported as
+    /// [`Redirect::Canonical`], unless it names the document's own
+    /// [base URL](NodeRef::document_base_url). URLs are resolved against
+    /// the base URL when there is one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::html::Redirect;
+    /// use brik::parse_html;
+    /// use brik::traits::*;
+    ///
+    /// let doc = parse_html().one(
+    ///     "<meta http-equiv=Refresh content=\"5; URL='https://example.com/new'\">",
+    /// );
+    /// assert_eq!(
+    ///     doc.detect_redirect(),
+    ///     Some(Redirect::Refresh {
+    ///         delay: 5,
+    ///         url: Some("https://example.com/new".to_string()),
+    ///     })
+    /// );
+    /// ```
+    pub fn detect_redirect(&self) -> Option<Redirect> {
+        let base = self.document_base_url();
+        let resolve = |url: String| match &base {
+            Some(base) => resolve_url(base, &url).unwrap_or(url),
+            None => url,
+        };
+        let refresh = self.descendants().elements().find_map(|element| {
+            if element.name.ns != ns!(html) || element.name.local != local_name!("meta") {
+                return None;
+            }
+            let attributes = element.attributes.borrow();
+            let http_equiv = attributes.get_trimmed(local_name!("http-equiv"))?;
+            if !http_equiv.eq_ignore_ascii_case("refresh") {
+                return None;
+            }
+            parse_refresh(attributes.get(local_name!("content"))?)
+        });
+        if let Some((delay, url)) = refresh {
+            return Some(Redirect::Refresh {
+                delay,
+                url: url.map(resolve),
+            });
+        }
+        let canonical = resolve(self.canonical()?);
+        if base.as_deref() == Some(&*canonical) {
+            return None;
+        }
+        Some(Redirect::Canonical { url: canonical })
+    }
+}
+
+/// Parses the `content` of a meta refresh into its delay and URL, following
+/// the HTML declarative refresh steps.
+///
+/// Returns `None` if the content does not start with a delay.
+fn parse_refresh(content: &str) -> Option<(u64, Option<String>)> {
+    let is_space = |c: char| c.is_ascii_whitespace();
+    let rest = content.trim_start_matches(is_space);
+    let digits = rest
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(rest.len());
+    let delay = if digits == 0 {
+        if !rest.starts_with('.') {
+            return None;
+        }
+        0
+    } else {
+        rest[..digits].parse().unwrap_or(u64::MAX)
+    };
+    let rest = rest[digits..].trim_start_matches(|c: char| c.is_ascii_digit() || c == '.');
+    if !rest.is_empty() && !rest.starts_with(|c: char| is_space(c) || c == ';' || c == ',') {
+        return None;
+    }
+    let rest = rest.trim_start_matches(is_space);
+    let rest = rest
+        .strip_prefix(|c: char| c == ';' || c == ',')
+        .unwrap_or(rest)
+        .trim_start_matches(is_space);
+    let rest = match rest.get(..3) {
+        Some(prefix) if prefix.eq_ignore_ascii_case("url") => {
+            match rest[3..].trim_start_matches(is_space).strip_prefix('=') {
+                Some(after) => after.trim_start_matches(is_space),
+                None => rest,
+            }
+        }
+        _ => rest,
+    };
+    let url = match rest.chars().next() {
+        Some(quote @ ('"' | '\'')) => {
+            let quoted = &rest[1..];
+            quoted.split(quote).next().unwrap_or(quoted)
+        }
+        _ => rest,
+    };
+    let url = url.trim_end_matches(is_space);
+    Some((
+        delay,
+        Some(url).filter(|url| !url.is_empty()).map(str::to_owned),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_refresh;
+    use crate::html::Redirect;
+    use crate::html5ever::tendril::TendrilSink;
+    use crate::{parse_html, parse_html_with_options, ParseOpts};
+
+    /// Tests parsing meta refresh content.
+    ///
+    /// Verifies the separators, the optional `url=` prefix, quoting and
+    /// fractional delays that browsers accept, and rejection of content
+    /// without a delay.
+    #[test]
+    fn parse_refresh_forms() {
+        let url = |content| parse_refresh(content).and_then(|(_, url)| url);
+        assert_eq!(parse_refresh(" 3 "), Some((3, None)));
+        assert_eq!(parse_refresh("0;url=/a").unwrap().1.as_deref(), Some("/a"));
+        assert_eq!(url("1.5, URL = '/b c' ignored").as_deref(), Some("/b c"));
+        assert_eq!(url(".5 /c").as_deref(), Some("/c"));
+        assert_eq!(url("2;urlish").as_deref(), Some("urlish"));
+        assert_eq!(parse_refresh("soon; url=/d"), None);
+        assert_eq!(parse_refresh("5x"), None);
+    }
+
+    /// Tests canonical links as redirects.
+    ///
+    /// Edge case: a canonical link naming the document itself is not a
+    /// redirect, and relative canonical URLs are resolved.
+    #[test]
+    fn detect_redirect_canonical() {
+        let parse = |html: &str| {
+            let opts = ParseOpts {
+                base_url: Some("https://a.test/page".to_string()),
+                ..ParseOpts::default()
+            };
+            parse_html_with_options(opts).one(html)
+        };
+
+        assert_eq!(
+            parse("<link rel=canonical href=page>").detect_redirect(),
+            None
+        );
+        assert_eq!(
+            parse("<link rel=canonical href=other>").detect_redirect(),
+            Some(Redirect::Canonical {
+                url: "https://a.test/other".to_string()
+            })
+        );
+        assert_eq!(parse_html().one("<p>").detect_redirect(), None);
+    }
+}
@@ -0,0 +1,176 @@
+//! Removal of scriptable content from node trees.
+
+use super::ActiveContent;
+use crate::iter::NodeIterator;
+use crate::tree::NodeRef;
+
+/// Active content methods for NodeRef.
+///
+/// Provides removal of scripts, event handlers and script URLs.
+impl NodeRef {
+    /// Removes scripts, event handler attributes and script URLs from this
+    /// subtree, returning what was removed in document order.
+    ///
+    /// `<script>` elements are detached, in any namespace. Attributes whose
+    /// name starts with `on` are removed, as are URL attributes such as
+    /// `href`, `src` and `action` whose value is a `javascript:` or
+    /// `data:text/html` URL, ignoring case and the whitespace and control
+    /// characters browsers skip. Template contents are included.
+    ///
+    /// This is lighter than a full sanitizer: it leaves styles, iframes,
+    /// forms and unknown elements in place, so it suits display of
+    /// untrusted content that is otherwise trusted to be well-behaved.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::parse_html;
+    /// use brik::traits::*;
+    ///
+    /// let doc = parse_html().one(
+    ///     "<a href='JavaScript:alert(1)' onclick=go()>x</a><script>evil()</script>",
+    /// );
+    /// let removed = doc.strip_active_content();
+    /// assert_eq!(removed.len(), 3);
+    /// assert_eq!(doc.select_first("body").unwrap().as_node().to_string(), "<body><a>x</a></body>");
+    /// ```
+    pub fn strip_active_content(&self) -> Vec<ActiveContent> {
+        let mut removed = Vec::new();
+        for element in self.descendants_with_templates().elements() {
+            if element.name.local == local_name!("script") {
+                removed.push(ActiveContent::Script(element.as_node().clone()));
+                continue;
+            }
+            let mut attributes = element.attributes.borrow_mut();
+            let mut stripped = Vec::new();
+            attributes.map.retain(|name, attribute| {
+                if is_event_handler(&name.local) {
+                    stripped.push((true, name.local.clone(), attribute.value.to_string()));
+                    false
+                } else if is_url_attribute(&name.local) && is_script_url(&attribute.value) {
+                    stripped.push((false, name.local.clone(), attribute.value.to_string()));
+                    false
+                } else {
+                    true
+                }
+            });
+            drop(attributes);
+            if !stripped.is_empty() {
+                element.as_node().mark_dirty();
+            }
+            removed.extend(stripped.into_iter().map(|(handler, attribute, value)| {
+                let element = element.clone();
+                if handler {
+                    ActiveContent::EventHandler {
+                        element,
+                        attribute,
+                        value,
+                    }
+                } else {
+                    ActiveContent::Url {
+                        element,
+                        attribute,
+                        value,
+                    }
+                }
+            }));
+        }
+        for content in &removed {
+            if let ActiveContent::Script(script) = content {
+                script.detach();
+            }
+        }
+        removed
+    }
+}
+
+/// Returns whether an attribute is an event handler, such as `onclick`.
+pub(super) fn is_event_handler(name: &str) -> bool {
+    name.len() > 2
+        && name
+            .get(..2)
+            .is_some_and(|prefix| prefix.eq_ignore_ascii_case("on"))
+}
+
+/// Returns whether an attribute holds a URL that may be navigated to or
+/// loaded.
+fn is_url_attribute(name: &str) -> bool {
+    matches!(
+        name,
+        "href"
+            | "src"
+            | "action"
+            | "formaction"
+            | "data"
+            | "poster"
+            | "background"
+            | "cite"
+            | "longdesc"
+    )
+}
+
+/// Returns whether a URL is a `javascript:` or `data:text/html` URL.
+///
+/// Leading whitespace and control characters, and tabs and newlines
+/// anywhere, are ignored, as browsers do.
+fn is_script_url(url: &str) -> bool {
+    let url: String = url
+        .trim_start_matches(|c: char| c <= ' ')
+        .chars()
+        .filter(|c| !matches!(c, '\t' | '\n' | '\r'))
+        .take(14)
+        .collect::<String>()
+        .to_ascii_lowercase();
+    url.starts_with("javascript:") || url.starts_with("data:text/html")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::html::ActiveContent;
+    use crate::html5ever::tendril::TendrilSink;
+    use crate::parse_html;
+
+    /// Tests stripping active content from a document.
+    ///
+    /// Verifies that scripts in HTML, SVG and template contents are
+    /// detached, that handlers and script URLs are removed with obfuscated
+    /// schemes, and that safe URLs and data images are kept.
+    #[test]
+    fn strip_active_content() {
+        let doc = parse_html().one(
+            "<img src=data:image/png;base64,AA onerror=x() alt=a>\
+             <a href=' java&#9;script:x()'>1</a><a href=/ok>2</a>\
+             <iframe src='DATA:text/html,<b>'></iframe>\
+             <svg><script>s()</script><a xlink:href=javascript:y()></a></svg>\
+             <template><form action=javascript:z()><script></script></form></template>",
+        );
+
+        let removed = doc.strip_active_content();
+
+        let summary: Vec<String> = removed
+            .iter()
+            .map(|content| match content {
+                ActiveContent::EventHandler { attribute, .. } => format!("on:{}", attribute),
+                ActiveContent::Url { attribute, .. } => format!("url:{}", attribute),
+                ActiveContent::Script(_) => "script".to_string(),
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                "on:onerror",
+                "url:href",
+                "url:src",
+                "script",
+                "url:href",
+                "url:action",
+                "script"
+            ]
+        );
+        assert_eq!(
+            doc.select_first("body").unwrap().as_node().to_string(),
+            "<body><img src=\"data:image/png;base64,AA\" alt=\"a\"><a>1</a><a href=\"/ok\">2</a>\
+             <iframe></iframe><svg><a></a></svg><template><form></form></template></body>"
+        );
+    }
+}
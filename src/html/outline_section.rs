@@ -0,0 +1,16 @@
+use crate::node_data_ref::NodeDataRef;
+use crate::tree::ElementData;
+
+/// A section of a document outline, built by
+/// [`NodeRef::outline()`](crate::NodeRef::outline).
+#[derive(Debug, Clone, PartialEq)]
+pub struct OutlineSection {
+    /// The heading of the section, or `None` if the section has an implied
+    /// heading.
+    pub heading: Option<NodeDataRef<ElementData>>,
+    /// The sectioning element that created the section, or `None` for a
+    /// section implied by a heading.
+    pub element: Option<NodeDataRef<ElementData>>,
+    /// The nested sections, in document order.
+    pub subsections: Vec<OutlineSection>,
+}
@@ -0,0 +1,19 @@
+/// A redirect declared by a document, found by
+/// [`NodeRef::detect_redirect()`](crate::NodeRef::detect_redirect).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Redirect {
+    /// A `<meta http-equiv="refresh">` directive.
+    Refresh {
+        /// The delay before navigating, in whole seconds.
+        delay: u64,
+        /// The target URL, resolved against the document's base URL when
+        /// it has one, or `None` if the page refreshes itself.
+        url: Option<String>,
+    },
+    /// A `<link rel="canonical">` naming another URL for the document.
+    Canonical {
+        /// The canonical URL, resolved against the document's base URL
+        /// when it has one.
+        url: String,
+    },
+}
@@ -0,0 +1,239 @@
+/// The components of a URI reference, per RFC 3986 § 3.
+struct UriParts<'a> {
+    /// Scheme, without the trailing `:`.
+    scheme: Option<&'a str>,
+    /// Authority, without the leading `//`.
+    authority: Option<&'a str>,
+    /// Path, possibly empty.
+    path: &'a str,
+    /// Query, without the leading `?`.
+    query: Option<&'a str>,
+    /// Fragment, without the leading `#`.
+    fragment: Option<&'a str>,
+}
+
+/// Splits a URI reference into its components.
+fn split(uri: &str) -> UriParts<'_> {
+    let (rest, fragment) = match uri.split_once('#') {
+        Some((rest, fragment)) => (rest, Some(fragment)),
+        None => (uri, None),
+    };
+    let (rest, query) = match rest.split_once('?') {
+        Some((rest, query)) => (rest, Some(query)),
+        None => (rest, None),
+    };
+    let (scheme, rest) = match rest.split_once(':') {
+        Some((scheme, rest))
+            if scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+                && scheme
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.')) =>
+        {
+            (Some(scheme), rest)
+        }
+        _ => (None, rest),
+    };
+    let (authority, path) = match rest.strip_prefix("//") {
+        Some(rest) => match rest.find('/') {
+            Some(slash) => (Some(&rest[..slash]), &rest[slash..]),
+            None => (Some(rest), ""),
+        },
+        None => (None, rest),
+    };
+    UriParts {
+        scheme,
+        authority,
+        path,
+        query,
+        fragment,
+    }
+}
+
+/// Removes `.` and `..` segments from a path, per RFC 3986 § 5.2.4.
+fn remove_dot_segments(path: &str) -> String {
+    let mut output: Vec<&str> = Vec::new();
+    let absolute = path.starts_with('/');
+    let segments: Vec<&str> = path.split('/').collect();
+    let last = segments.len() - 1;
+    for (i, segment) in segments.iter().enumerate() {
+        match *segment {
+            "." => {
+                if i == last {
+                    output.push("");
+                }
+            }
+            ".." => {
+                if output.len() > usize::from(absolute) {
+                    output.pop();
+                }
+                if i == last {
+                    output.push("");
+                }
+            }
+            _ => output.push(segment),
+        }
+    }
+    let joined = output.join("/");
+    if absolute && !joined.starts_with('/') {
+        format!("/{joined}")
+    } else {
+        joined
+    }
+}
+
+/// Resolves a URL reference against an absolute base URL.
+///
+/// This follows the reference resolution algorithm of RFC 3986 § 5.2,
+/// after trimming ASCII whitespace from the reference as HTML does for
+/// URL-valued attributes.
+///
+/// Returns `None` if `base` is not an absolute URL (it has no scheme).
+///
+/// # Examples
+///
+/// ```
+/// use brik::html::resolve_url;
+///
+/// let base = "https://example.com/docs/guide/index.html";
+/// assert_eq!(
+///     resolve_url(base, "../api.html?v=2").as_deref(),
+///     Some("https://example.com/docs/api.html?v=2")
+/// );
+/// assert_eq!(
+///     resolve_url(base, "//cdn.example.com/app.js").as_deref(),
+///     Some("https://cdn.example.com/app.js")
+/// );
+/// assert_eq!(resolve_url("/relative/base", "page.html"), None);
+/// ```
+pub fn resolve_url(base: &str, reference: &str) -> Option<String> {
+    let base = split(base.trim());
+    let base_scheme = base.scheme?;
+    let reference = split(reference.trim_matches(|c: char| c.is_ascii_whitespace()));
+
+    let (scheme, authority, path, query);
+    if let Some(ref_scheme) = reference.scheme {
+        scheme = ref_scheme;
+        authority = reference.authority;
+        path = remove_dot_segments(reference.path);
+        query = reference.query;
+    } else {
+        scheme = base_scheme;
+        if reference.authority.is_some() {
+            authority = reference.authority;
+            path = remove_dot_segments(reference.path);
+            query = reference.query;
+        } else {
+            authority = base.authority;
+            if reference.path.is_empty() {
+                path = base.path.to_string();
+                query = reference.query.or(base.query);
+            } else {
+                path = if reference.path.starts_with('/') {
+                    remove_dot_segments(reference.path)
+                } else if base.authority.is_some() && base.path.is_empty() {
+                    remove_dot_segments(&format!("/{}", reference.path))
+                } else {
+                    let directory = base.path.rfind('/').map_or("", |i| &base.path[..=i]);
+                    remove_dot_segments(&format!("{directory}{}", reference.path))
+                };
+                query = reference.query;
+            }
+        }
+    }
+
+    let mut result = format!("{scheme}:");
+    if let Some(authority) = authority {
+        result.push_str("//");
+        result.push_str(authority);
+    }
+    result.push_str(&path);
+    if let Some(query) = query {
+        result.push('?');
+        result.push_str(query);
+    }
+    if let Some(fragment) = reference.fragment {
+        result.push('#');
+        result.push_str(fragment);
+    }
+    Some(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Tests the normal examples from RFC 3986 § 5.4.1.
+    ///
+    /// Verifies resolution of relative paths, dot segments, queries,
+    /// fragments, and network-path references against the RFC base URI.
+    #[test]
+    fn rfc3986_normal_examples() {
+        let base = "http://a/b/c/d;p?q";
+        let cases = [
+            ("g:h", "g:h"),
+            ("g", "http://a/b/c/g"),
+            ("./g", "http://a/b/c/g"),
+            ("g/", "http://a/b/c/g/"),
+            ("/g", "http://a/g"),
+            ("//g", "http://g"),
+            ("?y", "http://a/b/c/d;p?y"),
+            ("g?y", "http://a/b/c/g?y"),
+            ("#s", "http://a/b/c/d;p?q#s"),
+            ("g#s", "http://a/b/c/g#s"),
+            ("", "http://a/b/c/d;p?q"),
+            (".", "http://a/b/c/"),
+            ("./", "http://a/b/c/"),
+            ("..", "http://a/b/"),
+            ("../", "http://a/b/"),
+            ("../g", "http://a/b/g"),
+            ("../..", "http://a/"),
+            ("../../g", "http://a/g"),
+        ];
+        for (reference, expected) in cases {
+            assert_eq!(
+                resolve_url(base, reference).as_deref(),
+                Some(expected),
+                "{reference}"
+            );
+        }
+    }
+
+    /// Tests the abnormal examples from RFC 3986 § 5.4.2.
+    ///
+    /// Edge cases: excess `..` segments must not climb above the root, and
+    /// dot-like segment names must be preserved.
+    #[test]
+    fn rfc3986_abnormal_examples() {
+        let base = "http://a/b/c/d;p?q";
+        let cases = [
+            ("../../../g", "http://a/g"),
+            ("/./g", "http://a/g"),
+            ("/../g", "http://a/g"),
+            ("g.", "http://a/b/c/g."),
+            ("..g", "http://a/b/c/..g"),
+            ("./../g", "http://a/b/g"),
+            ("g/./h", "http://a/b/c/g/h"),
+            ("g/../h", "http://a/b/c/h"),
+        ];
+        for (reference, expected) in cases {
+            assert_eq!(
+                resolve_url(base, reference).as_deref(),
+                Some(expected),
+                "{reference}"
+            );
+        }
+    }
+
+    /// Tests resolution against a base with an empty path and a relative base.
+    ///
+    /// Verifies that a root slash is inserted when the base has an authority
+    /// but no path, and that a base without a scheme is rejected.
+    #[test]
+    fn base_edge_cases() {
+        assert_eq!(
+            resolve_url("https://example.com", " page.html\n").as_deref(),
+            Some("https://example.com/page.html")
+        );
+        assert_eq!(resolve_url("example.com/a", "b"), None);
+    }
+}
@@ -0,0 +1,150 @@
+use std::ops::Deref;
+
+use super::resolve_url;
+use crate::node_data_ref::NodeDataRef;
+use crate::tree::ElementData;
+
+/// A typed view of an HTML `<script>` element.
+///
+/// Dereferences to the underlying [`NodeDataRef<ElementData>`], so all element
+/// and node methods remain available.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScriptRef(NodeDataRef<ElementData>);
+
+/// Methods for ScriptRef.
+///
+/// Provides construction and semantic accessors for script attributes.
+impl ScriptRef {
+    /// Wraps an element as a script.
+    ///
+    /// Returns `None` if the element is not an HTML `<script>` element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::html::ScriptRef;
+    /// use brik::parse_html;
+    /// use brik::traits::*;
+    ///
+    /// let doc = parse_html().one("<script type='module' src='app.js'></script>");
+    /// let script = ScriptRef::new(doc.select_first("script").unwrap()).unwrap();
+    /// assert!(script.is_module());
+    /// ```
+    pub fn new(element: NodeDataRef<ElementData>) -> Option<Self> {
+        if element.name.ns == ns!(html) && element.name.local == local_name!("script") {
+            Some(ScriptRef(element))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the wrapped element.
+    #[inline]
+    pub fn into_element(self) -> NodeDataRef<ElementData> {
+        self.0
+    }
+
+    /// Returns the `src` attribute, if present.
+    pub fn src(&self) -> Option<String> {
+        self.attributes
+            .borrow()
+            .get(local_name!("src"))
+            .map(str::to_owned)
+    }
+
+    /// Returns the `src` attribute resolved against an absolute base URL.
+    ///
+    /// Returns `None` if there is no `src` attribute or `base` is not absolute.
+    pub fn resolved_url(&self, base: &str) -> Option<String> {
+        resolve_url(base, &self.src()?)
+    }
+
+    /// Returns the `src` attribute resolved against the document's base URL.
+    ///
+    /// See [`NodeRef::document_base_url()`](crate::NodeRef::document_base_url).
+    /// Returns `None` if there is no `src` attribute or no base URL.
+    pub fn absolute_url(&self) -> Option<String> {
+        self.resolved_url(&self.as_node().document_base_url()?)
+    }
+
+    /// Returns the `type` attribute, if present.
+    pub fn script_type(&self) -> Option<String> {
+        self.attributes
+            .borrow()
+            .get(local_name!("type"))
+            .map(str::to_owned)
+    }
+
+    /// Returns true if this is a module script (`type="module"`).
+    ///
+    /// The comparison ignores surrounding whitespace and ASCII case.
+    pub fn is_module(&self) -> bool {
+        self.attributes
+            .borrow()
+            .get(local_name!("type"))
+            .is_some_and(|t| t.trim().eq_ignore_ascii_case("module"))
+    }
+
+    /// Returns true if the script has an `async` attribute.
+    pub fn is_async(&self) -> bool {
+        self.attributes.borrow().contains(local_name!("async"))
+    }
+
+    /// Returns true if the script has a `defer` attribute.
+    pub fn is_defer(&self) -> bool {
+        self.attributes.borrow().contains(local_name!("defer"))
+    }
+
+    /// Returns the inline source text of the script.
+    pub fn source(&self) -> String {
+        self.text_contents()
+    }
+}
+
+/// Implements Deref for ScriptRef.
+///
+/// Exposes the underlying element reference.
+impl Deref for ScriptRef {
+    type Target = NodeDataRef<ElementData>;
+    #[inline]
+    fn deref(&self) -> &NodeDataRef<ElementData> {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::html5ever::tendril::TendrilSink;
+    use crate::parse_html;
+
+    /// Tests the accessors of external and inline scripts.
+    ///
+    /// Verifies src resolution, loading flags, and inline source text.
+    #[test]
+    fn accessors() {
+        let doc = parse_html().one(
+            "<script src='/js/app.js' async defer></script>\
+             <script type=' Module '>import './x.js';</script>",
+        );
+        let scripts: Vec<ScriptRef> = doc
+            .select("script")
+            .unwrap()
+            .map(|script| ScriptRef::new(script).unwrap())
+            .collect();
+        assert_eq!(
+            scripts[0]
+                .resolved_url("https://example.com/a/b")
+                .as_deref(),
+            Some("https://example.com/js/app.js")
+        );
+        assert!(scripts[0].is_async());
+        assert!(scripts[0].is_defer());
+        assert!(!scripts[0].is_module());
+        assert_eq!(scripts[0].script_type(), None);
+
+        assert!(scripts[1].is_module());
+        assert_eq!(scripts[1].src(), None);
+        assert_eq!(scripts[1].source(), "import './x.js';");
+    }
+}
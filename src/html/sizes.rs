@@ -0,0 +1,143 @@
+use std::fmt;
+
+use super::SourceSize;
+
+/// A parsed `sizes` attribute: a list of source sizes.
+///
+/// Media conditions and lengths are kept as written; they are not evaluated.
+///
+/// # Examples
+///
+/// ```
+/// use brik::html::Sizes;
+///
+/// let sizes = Sizes::parse("(max-width: 600px) 480px, 800px");
+/// assert_eq!(sizes.entries()[0].media.as_deref(), Some("(max-width: 600px)"));
+/// assert_eq!(sizes.entries()[1].length, "800px");
+/// assert_eq!(sizes.to_string(), "(max-width: 600px) 480px, 800px");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Sizes {
+    /// The source sizes, in attribute order.
+    entries: Vec<SourceSize>,
+}
+
+/// Methods for Sizes.
+///
+/// Provides parsing and editing of the source size list.
+impl Sizes {
+    /// Parses a `sizes` attribute value.
+    ///
+    /// Entries are separated by commas outside parentheses. The last component
+    /// of each entry is its length; anything before it is the media condition.
+    /// Empty entries are skipped.
+    pub fn parse(input: &str) -> Self {
+        let mut entries = Vec::new();
+        let mut depth = 0usize;
+        let mut start = 0;
+        for (i, c) in input.char_indices() {
+            match c {
+                '(' => depth += 1,
+                ')' => depth = depth.saturating_sub(1),
+                ',' if depth == 0 => {
+                    entries.extend(Self::parse_entry(&input[start..i]));
+                    start = i + 1;
+                }
+                _ => {}
+            }
+        }
+        entries.extend(Self::parse_entry(&input[start..]));
+        Sizes { entries }
+    }
+
+    /// Parses a single comma-separated entry.
+    fn parse_entry(entry: &str) -> Option<SourceSize> {
+        let entry = entry.trim_matches(|c: char| c.is_ascii_whitespace());
+        if entry.is_empty() {
+            return None;
+        }
+        // The length is the last component; a function such as `calc()` may
+        // contain whitespace, so find the start of the last top-level token.
+        let mut depth = 0usize;
+        let mut split = 0;
+        for (i, c) in entry.char_indices() {
+            match c {
+                '(' => depth += 1,
+                ')' => depth = depth.saturating_sub(1),
+                c if c.is_ascii_whitespace() && depth == 0 => split = i + 1,
+                _ => {}
+            }
+        }
+        let media = entry[..split].trim_end_matches(|c: char| c.is_ascii_whitespace());
+        Some(SourceSize {
+            media: if media.is_empty() {
+                None
+            } else {
+                Some(media.to_string())
+            },
+            length: entry[split..].to_string(),
+        })
+    }
+
+    /// Returns the source sizes, in attribute order.
+    pub fn entries(&self) -> &[SourceSize] {
+        &self.entries
+    }
+
+    /// Returns the source sizes for in-place editing.
+    pub fn entries_mut(&mut self) -> &mut Vec<SourceSize> {
+        &mut self.entries
+    }
+}
+
+/// Implements Display for Sizes.
+///
+/// Formats the entries as a `sizes` attribute value, separated by `", "`.
+impl fmt::Display for Sizes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, entry) in self.entries.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{entry}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Tests parsing of media conditions and function lengths.
+    ///
+    /// Edge case: `calc()` lengths and media conditions both contain
+    /// whitespace and commas inside parentheses.
+    #[test]
+    fn parse_entries() {
+        let sizes = Sizes::parse(
+            "(min-width: 900px) and (orientation: landscape) calc(50vw - 2em), , max(10px, 5vw)",
+        );
+        assert_eq!(
+            sizes.entries(),
+            &[
+                SourceSize {
+                    media: Some("(min-width: 900px) and (orientation: landscape)".to_string()),
+                    length: "calc(50vw - 2em)".to_string(),
+                },
+                SourceSize {
+                    media: None,
+                    length: "max(10px, 5vw)".to_string(),
+                },
+            ]
+        );
+    }
+
+    /// Tests parsing an empty attribute value.
+    ///
+    /// Edge case: whitespace-only input yields no entries.
+    #[test]
+    fn parse_empty() {
+        assert!(Sizes::parse("  ").entries().is_empty());
+    }
+}
@@ -0,0 +1,22 @@
+use std::fmt;
+
+/// An entry of a `sizes` attribute: an optional media condition and a length.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceSize {
+    /// The media condition, such as `(max-width: 600px)`, if any.
+    pub media: Option<String>,
+    /// The source size value, such as `480px` or `calc(100vw - 2em)`.
+    pub length: String,
+}
+
+/// Implements Display for SourceSize.
+///
+/// Formats the entry as it appears in a `sizes` attribute.
+impl fmt::Display for SourceSize {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(ref media) = self.media {
+            write!(f, "{media} ")?;
+        }
+        f.write_str(&self.length)
+    }
+}
@@ -0,0 +1,280 @@
+use std::fmt;
+
+use super::{SrcsetCandidate, SrcsetDescriptor};
+
+/// A parsed `srcset` attribute: a list of image candidates.
+///
+/// Parse an attribute value with [`Srcset::parse`], rewrite or add candidates,
+/// then regenerate the attribute value with `to_string()`.
+///
+/// # Examples
+///
+/// ```
+/// use brik::html::{Srcset, SrcsetDescriptor};
+///
+/// let mut srcset = Srcset::parse("small.jpg 480w, large.jpg 1080w");
+/// assert_eq!(srcset.candidates()[1].descriptor, Some(SrcsetDescriptor::Width(1080)));
+///
+/// srcset.map_urls(|url| format!("https://cdn.example.com/{url}"));
+/// assert_eq!(
+///     srcset.to_string(),
+///     "https://cdn.example.com/small.jpg 480w, https://cdn.example.com/large.jpg 1080w"
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Srcset {
+    /// The image candidates, in attribute order.
+    candidates: Vec<SrcsetCandidate>,
+}
+
+/// Methods for Srcset.
+///
+/// Provides parsing per the HTML "parse a srcset attribute" algorithm and
+/// editing of the candidate list.
+impl Srcset {
+    /// Creates an empty candidate list.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parses a `srcset` attribute value.
+    ///
+    /// Candidates with invalid or conflicting descriptors are dropped, as
+    /// browsers do. Height (`h`) descriptors are accepted but not retained.
+    pub fn parse(input: &str) -> Self {
+        let is_space = |c: char| c.is_ascii_whitespace();
+        let mut candidates = Vec::new();
+        let mut rest = input;
+        loop {
+            rest = rest.trim_start_matches(|c: char| is_space(c) || c == ',');
+            if rest.is_empty() {
+                break;
+            }
+
+            let url_end = rest.find(is_space).unwrap_or(rest.len());
+            let (url, after) = rest.split_at(url_end);
+            rest = after;
+
+            let trimmed_url = url.trim_end_matches(',');
+            let descriptors = if trimmed_url.len() != url.len() {
+                // A URL ending in a comma has no descriptors.
+                Vec::new()
+            } else {
+                let (descriptors, after) = Self::split_descriptors(rest);
+                rest = after;
+                descriptors
+            };
+
+            if let Some(descriptor) = Self::parse_descriptors(&descriptors) {
+                candidates.push(SrcsetCandidate::new(trimmed_url, descriptor));
+            }
+        }
+        Srcset { candidates }
+    }
+
+    /// Splits the descriptor tokens of one candidate from the rest of the input.
+    ///
+    /// Tokens end at whitespace; the list ends at a comma outside parentheses.
+    fn split_descriptors(input: &str) -> (Vec<&str>, &str) {
+        let mut tokens = Vec::new();
+        let mut depth = 0usize;
+        let mut start = None;
+        for (i, c) in input.char_indices() {
+            match c {
+                '(' => depth += 1,
+                ')' => depth = depth.saturating_sub(1),
+                ',' if depth == 0 => {
+                    if let Some(s) = start {
+                        tokens.push(&input[s..i]);
+                    }
+                    return (tokens, &input[i + 1..]);
+                }
+                c if c.is_ascii_whitespace() && depth == 0 => {
+                    if let Some(s) = start.take() {
+                        tokens.push(&input[s..i]);
+                    }
+                    continue;
+                }
+                _ => {}
+            }
+            start.get_or_insert(i);
+        }
+        if let Some(s) = start {
+            tokens.push(&input[s..]);
+        }
+        (tokens, "")
+    }
+
+    /// Parses the descriptor tokens of one candidate.
+    ///
+    /// Returns `None` if the candidate is invalid, or `Some(None)` if it has
+    /// no descriptor.
+    fn parse_descriptors(tokens: &[&str]) -> Option<Option<SrcsetDescriptor>> {
+        let mut width = None;
+        let mut density = None;
+        let mut height = None;
+        for token in tokens {
+            let (index, unit) = token.char_indices().next_back()?;
+            let value = &token[..index];
+            match unit {
+                'w' if width.is_none() && density.is_none() => {
+                    width = Some(Self::parse_positive_integer(value)?);
+                }
+                'h' if height.is_none() && density.is_none() => {
+                    height = Some(Self::parse_positive_integer(value)?);
+                }
+                'x' if width.is_none() && density.is_none() && height.is_none() => {
+                    if value.starts_with('+') {
+                        return None;
+                    }
+                    let value: f64 = value.parse().ok()?;
+                    if !value.is_finite() || value < 0.0 {
+                        return None;
+                    }
+                    density = Some(value);
+                }
+                _ => return None,
+            }
+        }
+        if height.is_some() && width.is_none() {
+            return None;
+        }
+        Some(
+            width
+                .map(SrcsetDescriptor::Width)
+                .or(density.map(SrcsetDescriptor::Density)),
+        )
+    }
+
+    /// Parses a valid non-negative integer that is greater than zero.
+    fn parse_positive_integer(value: &str) -> Option<u32> {
+        if !value.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        value.parse().ok().filter(|&n| n > 0)
+    }
+
+    /// Returns the image candidates, in attribute order.
+    pub fn candidates(&self) -> &[SrcsetCandidate] {
+        &self.candidates
+    }
+
+    /// Returns the image candidates for in-place editing.
+    pub fn candidates_mut(&mut self) -> &mut Vec<SrcsetCandidate> {
+        &mut self.candidates
+    }
+
+    /// Appends a candidate, returning the list for chaining.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::html::{Srcset, SrcsetCandidate, SrcsetDescriptor};
+    ///
+    /// let srcset = Srcset::new()
+    ///     .with(SrcsetCandidate::new("a.png", None))
+    ///     .with(SrcsetCandidate::new("a@2x.png", Some(SrcsetDescriptor::Density(2.0))));
+    /// assert_eq!(srcset.to_string(), "a.png, a@2x.png 2x");
+    /// ```
+    pub fn with(mut self, candidate: SrcsetCandidate) -> Self {
+        self.candidates.push(candidate);
+        self
+    }
+
+    /// Rewrites every candidate URL with the given function.
+    pub fn map_urls<F: FnMut(&str) -> String>(&mut self, mut f: F) {
+        for candidate in &mut self.candidates {
+            candidate.url = f(&candidate.url);
+        }
+    }
+}
+
+/// Implements Display for Srcset.
+///
+/// Formats the candidates as a `srcset` attribute value, separated by `", "`.
+impl fmt::Display for Srcset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, candidate) in self.candidates.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{candidate}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Tests parsing of width and density descriptors.
+    ///
+    /// Verifies that candidates keep their order and that a candidate without
+    /// a descriptor has none.
+    #[test]
+    fn parse_descriptors() {
+        let srcset = Srcset::parse(" a.png,\n b.png 1.5x , c.png 800w ");
+        assert_eq!(
+            srcset.candidates(),
+            &[
+                SrcsetCandidate::new("a.png", None),
+                SrcsetCandidate::new("b.png", Some(SrcsetDescriptor::Density(1.5))),
+                SrcsetCandidate::new("c.png", Some(SrcsetDescriptor::Width(800))),
+            ]
+        );
+    }
+
+    /// Tests URLs that contain commas.
+    ///
+    /// Edge case: commas inside a URL are part of it; only trailing commas
+    /// terminate a candidate.
+    #[test]
+    fn parse_commas_in_urls() {
+        let srcset = Srcset::parse("img.php?a=1,2 1x,data:image/png;base64,AAA=,");
+        let urls: Vec<&str> = srcset.candidates().iter().map(|c| &*c.url).collect();
+        assert_eq!(urls, vec!["img.php?a=1,2", "data:image/png;base64,AAA="]);
+    }
+
+    /// Tests that invalid candidates are dropped.
+    ///
+    /// Verifies rejection of zero widths, mixed width and density, unknown
+    /// descriptors, and a height without a width.
+    #[test]
+    fn parse_invalid_candidates() {
+        let srcset = Srcset::parse("a.png 0w, b.png 100w 2x, c.png 2q, d.png 50h, e.png 100w 50h");
+        assert_eq!(
+            srcset.candidates(),
+            &[SrcsetCandidate::new(
+                "e.png",
+                Some(SrcsetDescriptor::Width(100))
+            )]
+        );
+    }
+
+    /// Tests descriptors ending in a non-ASCII character.
+    ///
+    /// Verifies that such candidates are dropped rather than splitting the
+    /// descriptor inside a character.
+    #[test]
+    fn parse_non_ascii_descriptor() {
+        let srcset = Srcset::parse("a.jpg 1é, b.jpg é, c.jpg 2x");
+        assert_eq!(
+            srcset.candidates(),
+            &[SrcsetCandidate::new(
+                "c.jpg",
+                Some(SrcsetDescriptor::Density(2.0))
+            )]
+        );
+    }
+
+    /// Tests regenerating an attribute value.
+    ///
+    /// Verifies that a parsed value round-trips into canonical form.
+    #[test]
+    fn round_trip() {
+        let srcset = Srcset::parse("a.png  1x,b.png 2x");
+        assert_eq!(srcset.to_string(), "a.png 1x, b.png 2x");
+        assert_eq!(Srcset::parse(&srcset.to_string()), srcset);
+    }
+}
@@ -0,0 +1,40 @@
+use std::fmt;
+
+use super::SrcsetDescriptor;
+
+/// An image candidate from a `srcset` attribute.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SrcsetCandidate {
+    /// The candidate URL, as written in the attribute.
+    pub url: String,
+    /// The width or density descriptor, if any.
+    ///
+    /// A candidate without a descriptor is equivalent to a density of `1x`.
+    pub descriptor: Option<SrcsetDescriptor>,
+}
+
+/// Methods for SrcsetCandidate.
+///
+/// Provides construction of candidates.
+impl SrcsetCandidate {
+    /// Creates a candidate from a URL and an optional descriptor.
+    pub fn new<U: Into<String>>(url: U, descriptor: Option<SrcsetDescriptor>) -> Self {
+        SrcsetCandidate {
+            url: url.into(),
+            descriptor,
+        }
+    }
+}
+
+/// Implements Display for SrcsetCandidate.
+///
+/// Formats the candidate as it appears in a `srcset` attribute.
+impl fmt::Display for SrcsetCandidate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.url)?;
+        if let Some(descriptor) = self.descriptor {
+            write!(f, " {descriptor}")?;
+        }
+        Ok(())
+    }
+}
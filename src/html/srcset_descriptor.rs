@@ -0,0 +1,22 @@
+use std::fmt;
+
+/// The descriptor of an image candidate in a `srcset` attribute.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SrcsetDescriptor {
+    /// A width descriptor, such as `480w`, in CSS pixels.
+    Width(u32),
+    /// A pixel density descriptor, such as `2x`.
+    Density(f64),
+}
+
+/// Implements Display for SrcsetDescriptor.
+///
+/// Formats the descriptor as it appears in a `srcset` attribute.
+impl fmt::Display for SrcsetDescriptor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SrcsetDescriptor::Width(width) => write!(f, "{width}w"),
+            SrcsetDescriptor::Density(density) => write!(f, "{density}x"),
+        }
+    }
+}
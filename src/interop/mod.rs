@@ -0,0 +1,8 @@
+//! Conversions between brik trees and other HTML libraries.
+
+/// Conversions to and from `markup5ever_rcdom` trees.
+#[cfg(feature = "rcdom")]
+mod rcdom_impl;
+/// Conversions to and from `scraper` documents.
+#[cfg(feature = "scraper")]
+mod scraper_impl;
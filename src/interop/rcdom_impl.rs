@@ -0,0 +1,286 @@
+use crate::attributes::{Attribute, ExpandedName};
+use crate::tree::{ElementData, NodeData, NodeRef};
+use html5ever::{local_name, ns, QualName};
+use markup5ever_rcdom::{Handle, Node, NodeData as RcNodeData};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// RcDom conversion methods for NodeRef.
+///
+/// Provides copying trees to and from `markup5ever_rcdom`, for tools built
+/// on html5ever's reference DOM, without a serialize and re-parse round
+/// trip.
+impl NodeRef {
+    /// Copies an `RcDom` node and its descendants into a new brik tree.
+    ///
+    /// A document node becomes a brik document, and template contents
+    /// become the contents of the copied `<template>`. `RcDom` keeps the
+    /// quirks mode on the `RcDom` rather than its document node, so the
+    /// copy is in no-quirks mode.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::NodeRef;
+    /// use html5ever::tendril::TendrilSink;
+    /// use markup5ever_rcdom::RcDom;
+    ///
+    /// let dom = html5ever::parse_document(RcDom::default(), Default::default())
+    ///     .one("<p class=x>caf&eacute;</p>");
+    /// let doc = NodeRef::from_rcdom(&dom.document);
+    /// assert_eq!(
+    ///     doc.to_string(),
+    ///     "<html><head></head><body><p class=\"x\">café</p></body></html>"
+    /// );
+    /// ```
+    pub fn from_rcdom(handle: &Handle) -> NodeRef {
+        let root = match handle.data {
+            RcNodeData::Document => NodeRef::new_document(),
+            _ => copy_from_rcdom(handle),
+        };
+        let mut pending = vec![(handle.clone(), root.clone())];
+        while let Some((source, target)) = pending.pop() {
+            if let RcNodeData::Element {
+                template_contents, ..
+            } = &source.data
+            {
+                let contents = target
+                    .as_element()
+                    .and_then(|element| element.template_contents.clone());
+                if let (Some(source), Some(contents)) =
+                    (template_contents.borrow().clone(), contents)
+                {
+                    pending.push((source, contents));
+                }
+            }
+            for child in source.children.borrow().iter() {
+                let node = copy_from_rcdom(child);
+                target.append(node.clone());
+                pending.push((child.clone(), node));
+            }
+        }
+        root
+    }
+
+    /// Copies this node and its descendants into a new `RcDom` tree,
+    /// returning the handle of the copied root.
+    ///
+    /// Documents and document fragments, including template contents,
+    /// become `RcDom` document nodes, as in trees the `RcDom` sink builds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::{parse_html, NodeRef};
+    /// use brik::traits::*;
+    ///
+    /// let doc = parse_html().one("<p>Hello</p>");
+    /// let handle = doc.to_rcdom();
+    /// assert_eq!(NodeRef::from_rcdom(&handle).to_string(), doc.to_string());
+    /// ```
+    pub fn to_rcdom(&self) -> Handle {
+        let root = copy_to_rcdom(self);
+        let mut pending = vec![(self.clone(), root.clone())];
+        while let Some((source, target)) = pending.pop() {
+            let contents = source
+                .as_element()
+                .and_then(|element| element.template_contents.clone());
+            if let (
+                Some(contents),
+                RcNodeData::Element {
+                    template_contents, ..
+                },
+            ) = (contents, &target.data)
+            {
+                let copy = copy_to_rcdom(&contents);
+                *template_contents.borrow_mut() = Some(copy.clone());
+                pending.push((contents, copy));
+            }
+            for child in source.children() {
+                let copy = copy_to_rcdom(&child);
+                copy.parent.set(Some(Rc::downgrade(&target)));
+                target.children.borrow_mut().push(copy.clone());
+                pending.push((child, copy));
+            }
+        }
+        root
+    }
+}
+
+/// Copies the data of an `RcDom` node into a new brik node, without its
+/// children.
+fn copy_from_rcdom(node: &Handle) -> NodeRef {
+    match &node.data {
+        RcNodeData::Document => NodeRef::new(NodeData::DocumentFragment),
+        RcNodeData::Doctype {
+            name,
+            public_id,
+            system_id,
+        } => NodeRef::new_doctype(name.clone(), public_id.clone(), system_id.clone()),
+        RcNodeData::Text { contents } => NodeRef::new_text(contents.borrow().clone()),
+        RcNodeData::Comment { contents } => NodeRef::new_comment(contents.clone()),
+        RcNodeData::Element { name, attrs, .. } => {
+            let attributes = attrs.borrow().clone().into_iter().map(|attribute| {
+                let QualName { prefix, ns, local } = attribute.name;
+                (
+                    ExpandedName { ns, local },
+                    Attribute {
+                        prefix,
+                        value: attribute.value,
+                        source_name: None,
+                    },
+                )
+            });
+            NodeRef::new_element(name.clone(), attributes)
+        }
+        RcNodeData::ProcessingInstruction { target, contents } => {
+            NodeRef::new_processing_instruction(target.clone(), contents.clone())
+        }
+    }
+}
+
+/// Copies the data of a brik node into a new `RcDom` node, without its
+/// children.
+fn copy_to_rcdom(node: &NodeRef) -> Handle {
+    let data = match node.data() {
+        NodeData::Document(_) | NodeData::DocumentFragment => RcNodeData::Document,
+        NodeData::Doctype(doctype) => RcNodeData::Doctype {
+            name: (&*doctype.name).into(),
+            public_id: (&*doctype.public_id).into(),
+            system_id: (&*doctype.system_id).into(),
+        },
+        NodeData::Text(text) => RcNodeData::Text {
+            contents: RefCell::new(text.borrow().clone()),
+        },
+        NodeData::Comment(text) => RcNodeData::Comment {
+            contents: text.borrow().clone(),
+        },
+        NodeData::ProcessingInstruction(value) => {
+            let value = value.borrow();
+            RcNodeData::ProcessingInstruction {
+                target: (&*value.0).into(),
+                contents: (&*value.1).into(),
+            }
+        }
+        NodeData::Element(element) => RcNodeData::Element {
+            name: element.name.clone(),
+            attrs: RefCell::new(
+                element
+                    .attributes
+                    .borrow()
+                    .map
+                    .iter()
+                    .map(|(name, attribute)| html5ever::Attribute {
+                        name: QualName::new(
+                            attribute.prefix.clone(),
+                            name.ns.clone(),
+                            name.local.clone(),
+                        ),
+                        value: attribute.value.clone(),
+                    })
+                    .collect(),
+            ),
+            template_contents: RefCell::new(None),
+            mathml_annotation_xml_integration_point: is_integration_point(element),
+        },
+    };
+    Node::new(data)
+}
+
+/// Returns whether an element is a MathML `annotation-xml` element that is
+/// an HTML integration point, as the tree builder flags it.
+fn is_integration_point(element: &ElementData) -> bool {
+    element.name.ns == ns!(mathml)
+        && element.name.local == local_name!("annotation-xml")
+        && element
+            .attributes
+            .borrow()
+            .get("encoding")
+            .is_some_and(|encoding| {
+                encoding.eq_ignore_ascii_case("text/html")
+                    || encoding.eq_ignore_ascii_case("application/xhtml+xml")
+            })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::html5ever::tendril::TendrilSink;
+    use crate::{parse_html, NodeRef};
+    use markup5ever_rcdom::{Handle, NodeData, RcDom, SerializableHandle};
+
+    /// Serializes an `RcDom` node and its descendants with html5ever.
+    fn serialize(handle: &Handle) -> String {
+        let mut output = Vec::new();
+        html5ever::serialize(
+            &mut output,
+            &SerializableHandle::from(handle.clone()),
+            Default::default(),
+        )
+        .unwrap();
+        String::from_utf8(output).unwrap()
+    }
+
+    /// Tests round-tripping a document through `RcDom`.
+    ///
+    /// Verifies that namespaced elements, comments, doctypes, template
+    /// contents and integration points survive both conversions, and that
+    /// html5ever serializes the copy like the original.
+    #[test]
+    fn rcdom_round_trip() {
+        let doc = parse_html().one(
+            "<!DOCTYPE html><!-- c --><p>a &amp; b</p><template><i>t</i></template>\
+             <svg viewBox=\"0 0 1 1\"><circle r=1></circle></svg>\
+             <math><annotation-xml encoding=\"text/html\">x</annotation-xml></math>",
+        );
+
+        // html5ever does not serialize template contents from an `RcDom`.
+        let handle = doc.to_rcdom();
+        assert_eq!(serialize(&handle), doc.to_string().replace("<i>t</i>", ""));
+        let template = handle.children.borrow()[2].children.borrow()[1]
+            .children
+            .borrow()[1]
+            .clone();
+        let NodeData::Element {
+            template_contents, ..
+        } = &template.data
+        else {
+            panic!("expected the template element");
+        };
+        let contents = template_contents.borrow().clone().unwrap();
+        assert!(matches!(contents.data, NodeData::Document));
+        assert_eq!(serialize(&contents), "<i>t</i>");
+
+        let annotation = doc
+            .select_first("annotation-xml")
+            .unwrap()
+            .as_node()
+            .to_rcdom();
+        assert!(matches!(
+            annotation.data,
+            NodeData::Element {
+                mathml_annotation_xml_integration_point: true,
+                ..
+            }
+        ));
+
+        let back = NodeRef::from_rcdom(&handle);
+        assert_eq!(back.to_string(), doc.to_string());
+        let template = back.select_first("template").unwrap();
+        assert_eq!(
+            template.template_contents.as_ref().unwrap().to_string(),
+            "<i>t</i>"
+        );
+    }
+
+    /// Tests copying a tree built by the `RcDom` sink.
+    ///
+    /// Verifies that html5ever's own parse result converts to the same
+    /// tree brik parses.
+    #[test]
+    fn from_parsed_rcdom() {
+        let html = "<table><tr><td>1</td></tr></table><template><p>t</p></template>";
+        let dom = html5ever::parse_document(RcDom::default(), Default::default()).one(html);
+        let doc = NodeRef::from_rcdom(&dom.document);
+        assert_eq!(doc.to_string(), parse_html().one(html).to_string());
+    }
+}
@@ -0,0 +1,239 @@
+use crate::attributes::{Attribute, ExpandedName};
+use crate::tree::{NodeData, NodeRef};
+use ego_tree::{NodeId, Tree};
+use html5ever::QualName;
+use scraper::node::{Comment, Doctype, Element, ProcessingInstruction, Text};
+use scraper::{Html, Node};
+
+/// Implements From<&Html> for NodeRef.
+///
+/// Copies a `scraper` document or fragment into a new brik tree, keeping
+/// text, comments, doctypes and attribute values exactly as parsed.
+/// Attribute order follows `scraper`'s attribute map, which is unordered.
+///
+/// # Examples
+///
+/// ```
+/// use brik::NodeRef;
+///
+/// let html = scraper::Html::parse_document("<p class=x>caf&eacute;</p>");
+/// let doc = NodeRef::from(&html);
+/// assert_eq!(
+///     doc.to_string(),
+///     "<html><head></head><body><p class=\"x\">café</p></body></html>"
+/// );
+/// assert_eq!(scraper::Html::from(&doc).html(), html.html());
+/// ```
+impl From<&Html> for NodeRef {
+    fn from(html: &Html) -> NodeRef {
+        let node = NodeRef::from(&html.tree);
+        if let Some(document) = node.as_document() {
+            document._quirks_mode.set(html.quirks_mode);
+        }
+        node
+    }
+}
+
+/// Implements From<&Tree<Node>> for NodeRef.
+///
+/// Copies a `scraper` node tree into a new brik tree. The fragment that
+/// `scraper` stores as the first child of a `<template>` becomes the
+/// template contents.
+impl From<&Tree<Node>> for NodeRef {
+    fn from(tree: &Tree<Node>) -> NodeRef {
+        let root = match tree.root().value() {
+            Node::Document => NodeRef::new_document(),
+            _ => NodeRef::new(NodeData::DocumentFragment),
+        };
+        let mut pending = vec![(tree.root(), root.clone())];
+        while let Some((source, target)) = pending.pop() {
+            for child in source.children() {
+                let node = match child.value() {
+                    Node::Document | Node::Fragment => {
+                        let contents = target
+                            .as_element()
+                            .and_then(|element| element.template_contents.clone());
+                        pending.push((child, contents.unwrap_or_else(|| target.clone())));
+                        continue;
+                    }
+                    Node::Doctype(doctype) => NodeRef::new_doctype(
+                        doctype.name.clone(),
+                        doctype.public_id.clone(),
+                        doctype.system_id.clone(),
+                    ),
+                    Node::Comment(comment) => NodeRef::new_comment(comment.comment.clone()),
+                    Node::Text(text) => NodeRef::new_text(text.text.clone()),
+                    Node::ProcessingInstruction(instruction) => {
+                        NodeRef::new_processing_instruction(
+                            instruction.target.clone(),
+                            instruction.data.clone(),
+                        )
+                    }
+                    Node::Element(element) => {
+                        let attributes = element.attrs.iter().map(|(name, value)| {
+                            (
+                                ExpandedName::new(name.ns.clone(), name.local.clone()),
+                                Attribute {
+                                    prefix: name.prefix.clone(),
+                                    value: value.clone(),
+                                    source_name: None,
+                                },
+                            )
+                        });
+                        NodeRef::new_element(element.name.clone(), attributes)
+                    }
+                };
+                target.append(node.clone());
+                pending.push((child, node));
+            }
+        }
+        root
+    }
+}
+
+/// Implements From<&NodeRef> for Html.
+///
+/// Copies a brik document into a `scraper` document; any other node is
+/// copied into a `scraper` fragment.
+impl From<&NodeRef> for Html {
+    fn from(node: &NodeRef) -> Html {
+        let mut html = match node.as_document() {
+            Some(document) => {
+                let mut html = Html::new_document();
+                html.quirks_mode = document.quirks_mode();
+                html
+            }
+            None => Html::new_fragment(),
+        };
+        html.tree = Tree::from(node);
+        html
+    }
+}
+
+/// Implements From<&NodeRef> for Tree<Node>.
+///
+/// Copies a brik subtree into a `scraper` node tree. A document becomes
+/// the root; a fragment's children, or any other node, are placed under a
+/// fragment root. Template contents become a fragment that is the first
+/// child of the `<template>`, as `scraper` stores them.
+impl From<&NodeRef> for Tree<Node> {
+    fn from(node: &NodeRef) -> Tree<Node> {
+        let mut tree = Tree::new(match node.data() {
+            NodeData::Document(_) => Node::Document,
+            _ => Node::Fragment,
+        });
+        let root = tree.root().id();
+        let mut pending: Vec<(NodeRef, NodeId)> = Vec::new();
+        match node.data() {
+            NodeData::Document(_) | NodeData::DocumentFragment => {
+                pending.push((node.clone(), root));
+            }
+            _ => append_copy(&mut tree, root, node, &mut pending),
+        }
+        while let Some((source, parent)) = pending.pop() {
+            for child in source.children() {
+                append_copy(&mut tree, parent, &child, &mut pending);
+            }
+        }
+        tree
+    }
+}
+
+/// Appends a copy of `node` under `parent`, queueing its children and
+/// template contents in `pending`.
+fn append_copy(
+    tree: &mut Tree<Node>,
+    parent: NodeId,
+    node: &NodeRef,
+    pending: &mut Vec<(NodeRef, NodeId)>,
+) {
+    let value = match node.data() {
+        NodeData::Element(element) => {
+            let attributes = element
+                .attributes
+                .borrow()
+                .map
+                .iter()
+                .map(|(name, attribute)| html5ever::Attribute {
+                    name: QualName::new(
+                        attribute.prefix.clone(),
+                        name.ns.clone(),
+                        name.local.clone(),
+                    ),
+                    value: attribute.value.clone(),
+                })
+                .collect();
+            Node::Element(Element::new(element.name.clone(), attributes))
+        }
+        NodeData::Text(text) => Node::Text(Text {
+            text: text.borrow().clone(),
+        }),
+        NodeData::Comment(text) => Node::Comment(Comment {
+            comment: text.borrow().clone(),
+        }),
+        NodeData::ProcessingInstruction(value) => {
+            let value = value.borrow();
+            Node::ProcessingInstruction(ProcessingInstruction {
+                target: (&*value.0).into(),
+                data: (&*value.1).into(),
+            })
+        }
+        NodeData::Doctype(doctype) => Node::Doctype(Doctype {
+            name: (&*doctype.name).into(),
+            public_id: (&*doctype.public_id).into(),
+            system_id: (&*doctype.system_id).into(),
+        }),
+        NodeData::Document(_) | NodeData::DocumentFragment => {
+            pending.push((node.clone(), parent));
+            return;
+        }
+    };
+    let mut parent = match tree.get_mut(parent) {
+        Some(parent) => parent,
+        None => return,
+    };
+    let mut copy = parent.append(value);
+    if let Some(contents) = node
+        .as_element()
+        .and_then(|element| element.template_contents.clone())
+    {
+        pending.push((contents, copy.append(Node::Fragment).id()));
+    }
+    pending.push((node.clone(), copy.id()));
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::html5ever::tendril::TendrilSink;
+    use crate::{parse_html, NodeRef};
+    use scraper::{Html, Selector};
+
+    /// Tests round-tripping a document through `scraper`.
+    ///
+    /// Verifies that namespaced elements, comments, doctypes, quirks mode
+    /// and template contents survive both conversions, and that `scraper`
+    /// can select in the converted tree.
+    #[test]
+    fn scraper_round_trip() {
+        let doc = parse_html().one(
+            "<!-- c --><p>a &amp; b</p><template><i>t</i></template>\
+             <svg viewBox=\"0 0 1 1\"><circle r=1></circle></svg>",
+        );
+
+        let html = Html::from(&doc);
+        let circles = html.select(&Selector::parse("svg circle").unwrap()).count();
+        let back = NodeRef::from(&html);
+
+        assert_eq!(circles, 1);
+        assert_eq!(back.to_string(), doc.to_string());
+        assert_eq!(
+            back.as_document().unwrap().quirks_mode(),
+            doc.as_document().unwrap().quirks_mode()
+        );
+        let template = back.select_first("template").unwrap();
+        assert_eq!(
+            template.template_contents.as_ref().unwrap().to_string(),
+            "<i>t</i>"
+        );
+    }
+}
@@ -0,0 +1,108 @@
+use super::node_edge::NodeEdge;
+use super::traverse::Traverse;
+use crate::tree::NodeRef;
+
+/// An iterator of references to a node's descendants, in tree order,
+/// that does not descend below a maximum depth.
+///
+/// Children are at depth 1, grandchildren at depth 2, and so on.
+/// Subtrees below the maximum depth are skipped without being walked.
+#[derive(Debug, Clone)]
+pub struct DepthLimitedDescendants {
+    /// The underlying traversal.
+    pub(super) traverse: Traverse,
+    /// Depth of the most recently started node.
+    pub(super) depth: usize,
+    /// Deepest level to yield.
+    pub(super) max_depth: usize,
+}
+
+/// Methods for DepthLimitedDescendants.
+///
+/// Provides control over iteration, such as pruning subtrees.
+impl DepthLimitedDescendants {
+    /// Skip the descendants of the node that was just yielded by `next()`.
+    ///
+    /// See [`Traverse::skip_subtree`] for details.
+    #[inline]
+    pub fn skip_subtree(&mut self) {
+        self.traverse.skip_subtree()
+    }
+}
+
+/// Implements Iterator for DepthLimitedDescendants.
+///
+/// Yields nodes in tree order (depth-first pre-order traversal),
+/// pruning the traversal at the maximum depth.
+impl Iterator for DepthLimitedDescendants {
+    type Item = NodeRef;
+
+    fn next(&mut self) -> Option<NodeRef> {
+        if self.max_depth == 0 {
+            return None;
+        }
+        loop {
+            match self.traverse.next()? {
+                NodeEdge::Start(node) => {
+                    self.depth += 1;
+                    if self.depth >= self.max_depth {
+                        self.traverse.skip_subtree();
+                    }
+                    return Some(node);
+                }
+                NodeEdge::End(_) => self.depth -= 1,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::html5ever::tendril::TendrilSink;
+    use crate::parse_html;
+
+    /// Tests limiting descendants to direct children.
+    ///
+    /// Verifies that a maximum depth of 1 yields only the children of the
+    /// node, including text nodes, and none of the grandchildren.
+    #[test]
+    fn max_depth_one() {
+        let doc = parse_html().one("<div><p><b>1</b></p>text<span>2</span></div>");
+        let div = doc.select_first("div").unwrap();
+
+        let nodes: Vec<_> = div.as_node().descendants_with_max_depth(1).collect();
+
+        assert_eq!(nodes.len(), 3);
+        assert_eq!(nodes[0].as_element().unwrap().name.local.as_ref(), "p");
+        assert!(nodes[1].as_text().is_some());
+        assert_eq!(nodes[2].as_element().unwrap().name.local.as_ref(), "span");
+    }
+
+    /// Tests limiting descendants to two levels.
+    ///
+    /// Verifies that children and grandchildren are yielded in tree order,
+    /// while deeper nodes are pruned.
+    #[test]
+    fn max_depth_two() {
+        let doc = parse_html().one("<div><p><b>1</b></p><span>2</span></div>");
+        let div = doc.select_first("div").unwrap();
+
+        let nodes: Vec<_> = div.as_node().descendants_with_max_depth(2).collect();
+
+        // p, b, span, "2"; the text inside b is at depth 3.
+        assert_eq!(nodes.len(), 4);
+        assert_eq!(nodes[1].as_element().unwrap().name.local.as_ref(), "b");
+        assert_eq!(&**nodes[3].as_text().unwrap().borrow(), "2");
+    }
+
+    /// Tests a maximum depth of zero.
+    ///
+    /// Edge case: no descendant is at depth zero, so nothing is yielded.
+    #[test]
+    fn max_depth_zero() {
+        let doc = parse_html().one("<div><p>1</p></div>");
+        let div = doc.select_first("div").unwrap();
+
+        assert_eq!(div.as_node().descendants_with_max_depth(0).count(), 0);
+    }
+}
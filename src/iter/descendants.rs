@@ -37,3 +37,16 @@ impl Iterator for Descendants {
 impl DoubleEndedIterator for Descendants {
     descendants_next!(next_back);
 }
+
+/// Methods for Descendants.
+///
+/// Provides control over forward iteration, such as pruning subtrees.
+impl Descendants {
+    /// Skip the descendants of the node that was just yielded by `next()`.
+    ///
+    /// See [`Traverse::skip_subtree`] for details.
+    #[inline]
+    pub fn skip_subtree(&mut self) {
+        self.0.skip_subtree()
+    }
+}
@@ -1,5 +1,6 @@
 use crate::node_data_ref::NodeDataRef;
 use crate::tree::{ElementData, NodeRef};
+use html5ever::tendril::StrTendril;
 use std::cell::RefCell;
 
 /// Macro to create filter-map-like iterator wrappers.
@@ -50,10 +51,10 @@ filter_map_like_iterator! {
 
 filter_map_like_iterator! {
     /// A node iterator adaptor that yields comment nodes.
-    Comments: NodeRef::into_comment_ref, NodeRef => NodeDataRef<RefCell<String>>
+    Comments: NodeRef::into_comment_ref, NodeRef => NodeDataRef<RefCell<StrTendril>>
 }
 
 filter_map_like_iterator! {
     /// A node iterator adaptor that yields text nodes.
-    TextNodes: NodeRef::into_text_ref, NodeRef => NodeDataRef<RefCell<String>>
+    TextNodes: NodeRef::into_text_ref, NodeRef => NodeDataRef<RefCell<StrTendril>>
 }
@@ -0,0 +1,15 @@
+/// The result of a [`TreeWalker`](super::TreeWalker) filter callback.
+///
+/// Mirrors the `FILTER_ACCEPT`, `FILTER_REJECT`, and `FILTER_SKIP` constants
+/// of the DOM `NodeFilter` interface.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum FilterResult {
+    /// The node is visited by the walker.
+    Accept,
+
+    /// The node and all of its descendants are ignored by the walker.
+    Reject,
+
+    /// The node is ignored by the walker, but its children are still considered.
+    Skip,
+}
@@ -0,0 +1,65 @@
+use crate::tree::NodeRef;
+
+/// An iterator of the nodes after a given node in document order,
+/// excluding its descendants, like the XPath `following` axis.
+#[derive(Debug, Clone)]
+pub struct Following(pub(super) Option<NodeRef>);
+
+/// Returns the nearest following sibling of the node or of one of its ancestors.
+pub(super) fn next_skipping_children(node: &NodeRef) -> Option<NodeRef> {
+    node.inclusive_ancestors()
+        .find_map(|ancestor| ancestor.next_sibling())
+}
+
+/// Implements Iterator for Following.
+///
+/// Yields nodes in document order, starting with the node right after the
+/// subtree of the original node, until the end of the tree.
+impl Iterator for Following {
+    type Item = NodeRef;
+
+    #[inline]
+    fn next(&mut self) -> Option<NodeRef> {
+        self.0.take().inspect(|node| {
+            self.0 = node.first_child().or_else(|| next_skipping_children(node));
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::html5ever::tendril::TendrilSink;
+    use crate::iter::NodeIterator;
+    use crate::parse_html;
+
+    /// Tests the following axis across ancestor boundaries.
+    ///
+    /// Verifies that following() skips the node's own descendants and then
+    /// yields every later node in document order, leaving the parent subtree.
+    #[test]
+    fn following() {
+        let doc = parse_html()
+            .one("<div><h1 id='start'><i>skip</i></h1><p>1</p></div><section><h2>2</h2></section>");
+        let start = doc.select_first("#start").unwrap();
+
+        let names: Vec<_> = start
+            .as_node()
+            .following()
+            .elements()
+            .map(|e| e.name.local.to_string())
+            .collect();
+
+        assert_eq!(names, vec!["p", "section", "h2"]);
+    }
+
+    /// Tests the following axis from the last node of a document.
+    ///
+    /// Edge case: no node comes after the last text node, so nothing is yielded.
+    #[test]
+    fn following_last_node() {
+        let doc = parse_html().one("<p>last</p>");
+        let last = doc.inclusive_descendants().last().unwrap();
+
+        assert_eq!(last.following().count(), 0);
+    }
+}
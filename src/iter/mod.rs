@@ -6,6 +6,8 @@
 
 /// Ancestor node iterator.
 mod ancestors;
+/// Depth-limited descendant node iterator.
+mod depth_limited_descendants;
 /// Descendant node iterator.
 mod descendants;
 /// Element iterator trait.
@@ -15,30 +17,57 @@ mod element_iterator;
 mod elements_in_namespace;
 /// Filter-map iterators for elements, comments, and text nodes.
 mod filter_iterators;
+/// Tree walker filter result.
+mod filter_result;
+/// Document-order following axis iterator.
+mod following;
 /// Node edge marker for tree traversal.
 mod node_edge;
 /// Node iterator trait.
 mod node_iterator;
 /// NodeRef iterator methods.
 mod node_ref_impls;
+/// Standard iterator traits for NodeRef.
+mod node_ref_iterator_traits;
+/// Document-order preceding axis iterator.
+mod preceding;
 /// Selector-matching iterator.
 mod select;
+/// Selector-matching iterator with shared caches.
+mod select_with_context;
 /// Sibling node iterator.
 mod siblings;
+/// Descendant iterator that enters template contents.
+mod template_descendants;
 /// Tree traversal iterator.
 mod traverse;
+/// DOM-style tree walker cursor.
+mod tree_walker;
+/// Visitor trait for tree traversal.
+mod visit;
+/// Visitor flow control.
+mod visit_control;
 
 pub use ancestors::Ancestors;
+pub use depth_limited_descendants::DepthLimitedDescendants;
 pub use descendants::Descendants;
 pub use element_iterator::ElementIterator;
 #[cfg(feature = "namespaces")]
 pub use elements_in_namespace::ElementsInNamespace;
 pub use filter_iterators::{Comments, Elements, TextNodes};
+pub use filter_result::FilterResult;
+pub use following::Following;
 pub use node_edge::NodeEdge;
 pub use node_iterator::NodeIterator;
+pub use preceding::Preceding;
 pub use select::Select;
+pub use select_with_context::SelectWithContext;
 pub use siblings::Siblings;
+pub use template_descendants::TemplateDescendants;
 pub use traverse::Traverse;
+pub use tree_walker::TreeWalker;
+pub use visit::Visit;
+pub use visit_control::VisitControl;
 
 #[cfg(test)]
 mod tests {
@@ -283,15 +312,15 @@ mod tests {
             .text_nodes()
             .collect::<Vec<_>>();
         assert_eq!(texts.len(), 3);
-        assert_eq!(&*texts[0].borrow(), "Content contains ");
-        assert_eq!(&*texts[1].borrow(), "Important");
-        assert_eq!(&*texts[2].borrow(), " data");
+        assert_eq!(&**texts[0].borrow(), "Content contains ");
+        assert_eq!(&**texts[1].borrow(), "Important");
+        assert_eq!(&**texts[2].borrow(), " data");
         {
             let mut x = texts[0].borrow_mut();
-            x.truncate(0);
-            x.push_str("Content doesn't contain ");
+            x.clear();
+            x.push_slice("Content doesn't contain ");
         }
-        assert_eq!(&*texts[0].borrow(), "Content doesn't contain ");
+        assert_eq!(&**texts[0].borrow(), "Content doesn't contain ");
     }
 
     /// Tests double-ended iteration for Elements iterator.
@@ -339,15 +368,15 @@ mod tests {
 
         // Forward from start.
         let first = comments.next().unwrap();
-        assert_eq!(&*first.borrow(), " first ");
+        assert_eq!(&**first.borrow(), " first ");
 
         // Backward from end.
         let last = comments.next_back().unwrap();
-        assert_eq!(&*last.borrow(), " third ");
+        assert_eq!(&**last.borrow(), " third ");
 
         // Middle comment.
         let middle = comments.next().unwrap();
-        assert_eq!(&*middle.borrow(), " second ");
+        assert_eq!(&**middle.borrow(), " second ");
 
         // Should be exhausted.
         assert!(comments.next().is_none());
@@ -1,9 +1,14 @@
 use super::filter_iterators::Elements;
+use super::following::next_skipping_children;
 use super::node_edge::NodeEdge;
 use super::siblings::State;
-use super::{Ancestors, Descendants, NodeIterator, Select, Siblings, Traverse};
+use super::{
+    Ancestors, DepthLimitedDescendants, Descendants, Following, NodeIterator, Preceding, Select,
+    Siblings, TemplateDescendants, Traverse, Visit, VisitControl,
+};
 use crate::node_data_ref::NodeDataRef;
-use crate::tree::{ElementData, NodeRef};
+use crate::select::Selectors;
+use crate::tree::{ElementData, NodeData, NodeRef};
 use std::iter::Rev;
 
 impl NodeRef {
@@ -111,6 +116,44 @@ impl NodeRef {
         }
     }
 
+    /// Return an iterator of references to the nodes after this node in document order,
+    /// excluding its descendants.
+    ///
+    /// This matches the XPath `following` axis: iteration crosses ancestor boundaries
+    /// and continues to the end of the tree.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::parse_html;
+    /// use brik::traits::*;
+    ///
+    /// let doc = parse_html().one("<div><p id='here'>1</p></div><h2>Next</h2>");
+    /// let here = doc.select_first("#here").unwrap();
+    ///
+    /// let heading = here.as_node().following().select("h2").unwrap().next().unwrap();
+    /// assert_eq!(heading.text_contents(), "Next");
+    /// ```
+    #[inline]
+    pub fn following(&self) -> Following {
+        Following(next_skipping_children(self))
+    }
+
+    /// Return an iterator of references to the nodes before this node in reverse
+    /// document order, excluding its ancestors.
+    ///
+    /// This matches the XPath `preceding` axis. Like `preceding_siblings()`,
+    /// the nearest nodes are yielded first.
+    #[inline]
+    pub fn preceding(&self) -> Preceding {
+        let mut preceding = Preceding {
+            next: None,
+            ancestor: self.parent(),
+        };
+        preceding.next = preceding.step(self);
+        preceding
+    }
+
     /// Return an iterator of references to this node's children.
     #[inline]
     pub fn children(&self) -> Siblings {
@@ -144,6 +187,64 @@ impl NodeRef {
         Descendants(self.traverse())
     }
 
+    /// Return an iterator of references to this node and its descendants, in tree order,
+    /// including the contents of `<template>` elements.
+    ///
+    /// See [`descendants_with_templates`](NodeRef::descendants_with_templates).
+    #[inline]
+    pub fn inclusive_descendants_with_templates(&self) -> TemplateDescendants {
+        TemplateDescendants::new(self, true)
+    }
+
+    /// Return an iterator of references to this node's descendants, in tree order,
+    /// including the contents of `<template>` elements.
+    ///
+    /// Template contents live in a separate document fragment, so `descendants()`
+    /// does not visit them. This iterator visits them right after their template
+    /// element, as the serializer writes them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::parse_html;
+    /// use brik::traits::*;
+    ///
+    /// let doc = parse_html().one("<template><b>Bold</b></template>");
+    /// assert_eq!(doc.descendants().elements().count(), 4);
+    /// assert_eq!(doc.descendants_with_templates().elements().count(), 5);
+    /// ```
+    #[inline]
+    pub fn descendants_with_templates(&self) -> TemplateDescendants {
+        TemplateDescendants::new(self, false)
+    }
+
+    /// Return an iterator of references to this node's descendants, in tree order,
+    /// that does not descend more than `max_depth` levels below this node.
+    ///
+    /// Children are at depth 1, so `descendants_with_max_depth(1)` is equivalent to
+    /// `children()`. Subtrees below the limit are skipped rather than walked and filtered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::parse_html;
+    /// use brik::traits::*;
+    ///
+    /// let doc = parse_html().one("<ul><li><ul><li>Nested</li></ul></li></ul>");
+    /// let list = doc.select_first("ul").unwrap();
+    ///
+    /// let shallow = list.as_node().descendants_with_max_depth(2).elements().count();
+    /// assert_eq!(shallow, 2); // li, ul
+    /// ```
+    #[inline]
+    pub fn descendants_with_max_depth(&self, max_depth: usize) -> DepthLimitedDescendants {
+        DepthLimitedDescendants {
+            traverse: self.traverse(),
+            depth: 0,
+            max_depth,
+        }
+    }
+
     /// Return an iterator of the start and end edges of this node and its descendants,
     /// in tree order.
     #[inline]
@@ -168,6 +269,46 @@ impl NodeRef {
         }
     }
 
+    /// Visit this node and its descendants in tree order, calling the hooks of `visitor`.
+    ///
+    /// Hooks can skip the descendants of an element or stop the visit early
+    /// by returning a [`VisitControl`]. See [`Visit`] for an example.
+    pub fn visit<V: Visit + ?Sized>(&self, visitor: &mut V) {
+        let mut traverse = self.traverse_inclusive();
+        while let Some(edge) = traverse.next() {
+            let control = match edge {
+                NodeEdge::Start(node) => match node.data() {
+                    NodeData::Element(_) => node
+                        .into_element_ref()
+                        .map_or(VisitControl::Continue, |e| visitor.enter_element(&e)),
+                    NodeData::Text(_) => node
+                        .into_text_ref()
+                        .map_or(VisitControl::Continue, |t| visitor.text(&t)),
+                    NodeData::Comment(_) => node
+                        .into_comment_ref()
+                        .map_or(VisitControl::Continue, |c| visitor.comment(&c)),
+                    NodeData::Doctype(_) => node
+                        .into_doctype_ref()
+                        .map_or(VisitControl::Continue, |d| visitor.doctype(&d)),
+                    NodeData::ProcessingInstruction(_) => node
+                        .into_processing_instruction_ref()
+                        .map_or(VisitControl::Continue, |p| {
+                            visitor.processing_instruction(&p)
+                        }),
+                    NodeData::Document(_) | NodeData::DocumentFragment => VisitControl::Continue,
+                },
+                NodeEdge::End(node) => node
+                    .into_element_ref()
+                    .map_or(VisitControl::Continue, |e| visitor.exit_element(&e)),
+            };
+            match control {
+                VisitControl::Continue => {}
+                VisitControl::SkipChildren => traverse.skip_subtree(),
+                VisitControl::Stop => return,
+            }
+        }
+    }
+
     /// Return an iterator of the inclusive descendants element that match the given selector list.
     ///
     /// # Errors
@@ -178,6 +319,80 @@ impl NodeRef {
         self.inclusive_descendants().select(selectors)
     }
 
+    /// Return an iterator of the inclusive descendants element that match the given selector
+    /// list, including elements inside `<template>` contents.
+    ///
+    /// Template contents are not children of the template element, so selectors
+    /// with combinators do not cross into them: `template > p` matches nothing.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(())` if the selector string fails to parse.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::parse_html;
+    /// use brik::traits::*;
+    ///
+    /// let doc = parse_html().one("<template><p>Hidden</p></template><p>Shown</p>");
+    /// assert_eq!(doc.select("p").unwrap().count(), 1);
+    /// assert_eq!(doc.select_with_templates("p").unwrap().count(), 2);
+    /// ```
+    #[inline]
+    pub fn select_with_templates(
+        &self,
+        selectors: &str,
+    ) -> Result<Select<Elements<TemplateDescendants>>, ()> {
+        self.inclusive_descendants_with_templates()
+            .select(selectors)
+    }
+
+    /// Return the elements matching a relative selector list anchored at this node.
+    ///
+    /// The selectors are compiled with [`Selectors::compile_relative()`], so
+    /// `> li` selects children, `+ p` and `~ p` select following siblings,
+    /// and selectors without a leading combinator or `:scope` select
+    /// descendants. `:scope` refers to this node, which must be an element;
+    /// for other nodes, nothing is selected.
+    ///
+    /// Results are in document order, among this node's parent's descendants.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(())` if the selector string fails to parse.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::parse_html;
+    /// use brik::traits::*;
+    ///
+    /// let doc = parse_html().one(
+    ///     "<ul id=menu><li class=active>A<ul><li class=active>B</ul></ul><p>After</p>",
+    /// );
+    /// let menu = doc.select_first("#menu").unwrap();
+    ///
+    /// let direct = menu.as_node().select_relative("> li.active").unwrap();
+    /// assert_eq!(direct.len(), 1);
+    /// let nested = menu.as_node().select_relative("li.active").unwrap();
+    /// assert_eq!(nested.len(), 2);
+    /// let next = menu.as_node().select_relative("+ p").unwrap();
+    /// assert_eq!(next[0].text_contents(), "After");
+    /// ```
+    pub fn select_relative(&self, selectors: &str) -> Result<Vec<NodeDataRef<ElementData>>, ()> {
+        let compiled = Selectors::compile_relative(selectors)?;
+        let Some(scope) = self.clone().into_element_ref() else {
+            return Ok(Vec::new());
+        };
+        let container = self.parent().unwrap_or_else(|| self.clone());
+        Ok(container
+            .descendants()
+            .elements()
+            .filter(|element| compiled.matches_with_scope(element, &scope))
+            .collect())
+    }
+
     /// Return the first inclusive descendants element that match the given selector list.
     ///
     /// # Errors
@@ -188,6 +403,59 @@ impl NodeRef {
         let mut elements = self.select(selectors)?;
         elements.next().ok_or(())
     }
+
+    /// Return whether any inclusive descendant element matches the given selector list.
+    ///
+    /// The selectors are compiled once and the search stops at the first match.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(())` if the selector string fails to parse.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::parse_html;
+    /// use brik::traits::*;
+    ///
+    /// let doc = parse_html().one("<p class=note>Hi</p>");
+    /// assert_eq!(doc.exists("p.note"), Ok(true));
+    /// assert_eq!(doc.exists("table"), Ok(false));
+    /// ```
+    pub fn exists(&self, selectors: &str) -> Result<bool, ()> {
+        let compiled = Selectors::compile(selectors)?;
+        Ok(self
+            .inclusive_descendants()
+            .elements()
+            .any(|element| compiled.matches(&element)))
+    }
+
+    /// Return the number of inclusive descendant elements that match the given selector list.
+    ///
+    /// The selectors are compiled once and the matches are counted without
+    /// being collected.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(())` if the selector string fails to parse.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::parse_html;
+    /// use brik::traits::*;
+    ///
+    /// let doc = parse_html().one("<ul><li>1</li><li>2</li><li>3</li></ul>");
+    /// assert_eq!(doc.count("li"), Ok(3));
+    /// ```
+    pub fn count(&self, selectors: &str) -> Result<usize, ()> {
+        let compiled = Selectors::compile(selectors)?;
+        Ok(self
+            .inclusive_descendants()
+            .elements()
+            .filter(|element| compiled.matches(element))
+            .count())
+    }
 }
 
 #[cfg(test)]
@@ -195,6 +463,25 @@ mod tests {
     use crate::html5ever::tendril::TendrilSink;
     use crate::parse_html;
 
+    /// Tests exists and count selector queries.
+    ///
+    /// Verifies that the root itself is considered, that matches are counted
+    /// across the subtree, and that invalid selectors are rejected.
+    #[test]
+    fn exists_and_count() {
+        let doc = parse_html().one("<div class=a><p class=a>1</p><p>2</p></div>");
+        let div = doc.select_first("div").unwrap();
+        let div = div.as_node();
+
+        assert_eq!(div.count(".a"), Ok(2));
+        assert_eq!(div.count("p"), Ok(2));
+        assert_eq!(div.exists("div.a"), Ok(true));
+        assert_eq!(div.exists("span"), Ok(false));
+        assert_eq!(div.count("span"), Ok(0));
+        assert_eq!(div.exists("p["), Err(()));
+        assert_eq!(div.count("p["), Err(()));
+    }
+
     /// Tests inclusive_preceding_siblings method.
     ///
     /// Verifies that the iterator includes the target node and all siblings
@@ -0,0 +1,83 @@
+//! Standard iterator trait implementations for NodeRef.
+
+use super::Siblings;
+use crate::tree::{NodeData, NodeRef};
+use std::iter::FromIterator;
+
+/// Implements IntoIterator for references to NodeRef.
+///
+/// Iterates over the node's children, as [`NodeRef::children()`] does, so
+/// a node can be used directly in a `for` loop.
+///
+/// # Examples
+///
+/// ```
+/// use brik::parse_html;
+/// use brik::traits::*;
+///
+/// let doc = parse_html().one("<ul><li>a</li><li>b</li></ul>");
+/// let ul = doc.select_first("ul").unwrap();
+/// let mut text = String::new();
+/// for child in ul.as_node() {
+///     text.push_str(&child.text_contents());
+/// }
+/// assert_eq!(text, "ab");
+/// ```
+impl IntoIterator for &NodeRef {
+    type Item = NodeRef;
+    type IntoIter = Siblings;
+
+    #[inline]
+    fn into_iter(self) -> Siblings {
+        self.children()
+    }
+}
+
+/// Implements FromIterator for NodeRef.
+///
+/// Collects nodes into a new document fragment, appending each in order.
+/// Nodes that are already in a tree are moved out of it.
+///
+/// # Examples
+///
+/// ```
+/// use brik::{NodeData, NodeRef};
+///
+/// let fragment: NodeRef = ["one", "two"].iter().map(|text| NodeRef::new_text(*text)).collect();
+/// assert!(matches!(fragment.data(), NodeData::DocumentFragment));
+/// assert_eq!(fragment.to_string(), "onetwo");
+/// ```
+impl FromIterator<NodeRef> for NodeRef {
+    fn from_iter<I: IntoIterator<Item = NodeRef>>(nodes: I) -> NodeRef {
+        let fragment = NodeRef::new(NodeData::DocumentFragment);
+        for node in nodes {
+            fragment.append(node);
+        }
+        fragment
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::html5ever::tendril::TendrilSink;
+    use crate::parse_html;
+    use crate::tree::NodeRef;
+
+    /// Tests collecting selected nodes into a fragment.
+    ///
+    /// Verifies that collected nodes are moved out of their tree in order,
+    /// and that iterating over the fragment yields them back.
+    #[test]
+    fn collect_into_fragment() {
+        let doc = parse_html().one("<ul><li>1</li><li>2</li><li>3</li></ul>");
+        let ul = doc.select_first("ul").unwrap();
+        let fragment: NodeRef = ul.as_node().children().rev().collect();
+
+        assert!(ul.as_node().first_child().is_none());
+        let mut texts = Vec::new();
+        for li in &fragment {
+            texts.push(li.text_contents());
+        }
+        assert_eq!(texts, ["3", "2", "1"]);
+    }
+}
@@ -0,0 +1,93 @@
+use crate::tree::NodeRef;
+
+/// An iterator of the nodes before a given node in reverse document order,
+/// excluding its ancestors, like the XPath `preceding` axis.
+#[derive(Debug, Clone)]
+pub struct Preceding {
+    /// The next node to yield.
+    pub(super) next: Option<NodeRef>,
+    /// The closest ancestor of the original node that has not been passed yet.
+    pub(super) ancestor: Option<NodeRef>,
+}
+
+/// Returns the last node of the subtree rooted at `node`, in document order.
+fn deepest_last_descendant(mut node: NodeRef) -> NodeRef {
+    while let Some(child) = node.last_child() {
+        node = child;
+    }
+    node
+}
+
+/// Methods for Preceding.
+///
+/// Provides the reverse document order stepping shared by construction and iteration.
+impl Preceding {
+    /// Returns the node before `node` in document order, skipping ancestors of the origin.
+    pub(super) fn step(&mut self, node: &NodeRef) -> Option<NodeRef> {
+        let mut node = node.clone();
+        loop {
+            if let Some(sibling) = node.previous_sibling() {
+                return Some(deepest_last_descendant(sibling));
+            }
+            let parent = node.parent()?;
+            if Some(&parent) != self.ancestor.as_ref() {
+                return Some(parent);
+            }
+            self.ancestor = parent.parent();
+            node = parent;
+        }
+    }
+}
+
+/// Implements Iterator for Preceding.
+///
+/// Yields nodes in reverse document order, nearest first, up to the start of the tree.
+impl Iterator for Preceding {
+    type Item = NodeRef;
+
+    #[inline]
+    fn next(&mut self) -> Option<NodeRef> {
+        let node = self.next.take()?;
+        self.next = self.step(&node);
+        Some(node)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::html5ever::tendril::TendrilSink;
+    use crate::iter::NodeIterator;
+    use crate::parse_html;
+
+    /// Tests the preceding axis across ancestor boundaries.
+    ///
+    /// Verifies that preceding() yields earlier nodes nearest first,
+    /// including descendants of earlier siblings, but never ancestors.
+    #[test]
+    fn preceding() {
+        let doc = parse_html().one(
+            "<body><section><h1>1</h1></section><div><p>2</p><span id='start'>x</span></div></body>",
+        );
+        let start = doc.select_first("#start").unwrap();
+
+        let names: Vec<_> = start
+            .as_node()
+            .preceding()
+            .elements()
+            .map(|e| e.name.local.to_string())
+            .collect();
+
+        // div, body, and html are ancestors; head precedes body.
+        assert_eq!(names, vec!["p", "h1", "section", "head"]);
+    }
+
+    /// Tests the preceding axis from the first node of a document.
+    ///
+    /// Edge case: the document node has no preceding nodes.
+    #[test]
+    fn preceding_root() {
+        let doc = parse_html().one("<p>only</p>");
+
+        assert_eq!(doc.preceding().count(), 0);
+    }
+}
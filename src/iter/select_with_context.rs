@@ -0,0 +1,79 @@
+use crate::node_data_ref::NodeDataRef;
+use crate::select::{MatchContext, Selectors};
+use crate::tree::ElementData;
+use std::borrow::Borrow;
+
+/// An element iterator adaptor that yields elements matching given selectors,
+/// sharing a [`MatchContext`] between matches.
+pub struct SelectWithContext<'a, I, S = Selectors>
+where
+    I: Iterator<Item = NodeDataRef<ElementData>>,
+    S: Borrow<Selectors>,
+{
+    /// The underlying iterator.
+    pub iter: I,
+
+    /// The selectors to be matched.
+    pub selectors: S,
+
+    /// The caches shared between matches.
+    pub context: &'a mut MatchContext,
+}
+
+impl<I, S> Iterator for SelectWithContext<'_, I, S>
+where
+    I: Iterator<Item = NodeDataRef<ElementData>>,
+    S: Borrow<Selectors>,
+{
+    type Item = NodeDataRef<ElementData>;
+
+    #[inline]
+    fn next(&mut self) -> Option<NodeDataRef<ElementData>> {
+        let selectors = self.selectors.borrow();
+        let context = &mut *self.context;
+        self.iter
+            .by_ref()
+            .find(|element| selectors.matches_with_context(element, context))
+    }
+}
+
+impl<I, S> DoubleEndedIterator for SelectWithContext<'_, I, S>
+where
+    I: DoubleEndedIterator<Item = NodeDataRef<ElementData>>,
+    S: Borrow<Selectors>,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<NodeDataRef<ElementData>> {
+        let selectors = self.selectors.borrow();
+        let context = &mut *self.context;
+        self.iter
+            .by_ref()
+            .rev()
+            .find(|element| selectors.matches_with_context(element, context))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::html5ever::tendril::TendrilSink;
+    use crate::iter::NodeIterator;
+    use crate::parse_html;
+    use crate::select::{MatchContext, Selectors};
+
+    /// Tests iteration from both ends with a shared context.
+    ///
+    /// Verifies that matches found from the back use the same context as
+    /// matches found from the front.
+    #[test]
+    fn select_both_ends() {
+        let doc = parse_html().one("<p>1</p><p>2</p><p>3</p><p>4</p><p>5</p>");
+        let selectors = Selectors::compile("p:nth-child(odd)").unwrap();
+        let mut context = MatchContext::new();
+
+        let mut select = selectors.filter_with_context(doc.descendants().elements(), &mut context);
+        assert_eq!(select.next().unwrap().text_contents(), "1");
+        assert_eq!(select.next_back().unwrap().text_contents(), "5");
+        assert_eq!(select.next().unwrap().text_contents(), "3");
+        assert!(select.next().is_none());
+    }
+}
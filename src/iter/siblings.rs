@@ -53,3 +53,65 @@ impl Iterator for Siblings {
 impl DoubleEndedIterator for Siblings {
     siblings_next!(next_back, next, previous_sibling);
 }
+
+/// Bulk removal methods for Siblings.
+///
+/// Provides detaching of a whole sibling range at once.
+impl Siblings {
+    /// Detaches the nodes remaining in this iterator from their parent.
+    ///
+    /// Takes precedence over [`NodeIterator::detach_all()`](super::NodeIterator::detach_all):
+    /// the remaining nodes are contiguous, so the range is unlinked from its
+    /// surroundings once instead of one node at a time. Each detached node's
+    /// links are still cleared, so this takes linear time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::parse_html;
+    /// use brik::traits::*;
+    ///
+    /// let doc = parse_html().one("<ul><li>a</li><li>b</li><li>c</li></ul>");
+    /// let first = doc.select_first("li").unwrap();
+    /// first.as_node().following_siblings().detach_all();
+    /// assert_eq!(doc.select_first("ul").unwrap().as_node().to_string(), "<ul><li>a</li></ul>");
+    /// ```
+    pub fn detach_all(self) {
+        if let Some(State { next, next_back }) = self.0 {
+            next.detach_through(&next_back);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::html5ever::tendril::TendrilSink;
+    use crate::parse_html;
+    use crate::NodeRef;
+
+    /// Tests detaching a partially consumed sibling range.
+    ///
+    /// Verifies that only the remaining middle nodes are removed, that the
+    /// surrounding siblings are relinked in both directions, and that the
+    /// detached nodes are left without parent or sibling links.
+    #[test]
+    fn detach_all_range() {
+        let doc = parse_html().one("<p>a<b>b</b>c<i>d</i>e</p>");
+        let p = doc.select_first("p").unwrap();
+        let p = p.as_node();
+
+        let mut children = p.children();
+        let first = children.next().unwrap();
+        let last = children.next_back().unwrap();
+        let middle: Vec<NodeRef> = children.clone().collect();
+        children.detach_all();
+
+        assert_eq!(p.to_string(), "<p>ae</p>");
+        assert_eq!(first.next_sibling().as_ref(), Some(&last));
+        assert_eq!(last.previous_sibling().as_ref(), Some(&first));
+        assert_eq!(middle.len(), 3);
+        assert!(middle.iter().all(|node| node.parent().is_none()
+            && node.previous_sibling().is_none()
+            && node.next_sibling().is_none()));
+    }
+}
@@ -0,0 +1,104 @@
+use crate::tree::NodeRef;
+
+/// An iterator of descendants in tree order that also descends into the
+/// contents of `<template>` elements.
+///
+/// The contents of a template element are visited right after the element,
+/// before any of its regular children.
+#[derive(Debug, Clone)]
+pub struct TemplateDescendants {
+    /// Nodes still to visit, next node last.
+    pub(super) stack: Vec<NodeRef>,
+}
+
+/// Methods for TemplateDescendants.
+///
+/// Provides construction from a root node.
+impl TemplateDescendants {
+    /// Creates an iterator over the descendants of `node`, optionally including `node`.
+    pub(super) fn new(node: &NodeRef, inclusive: bool) -> Self {
+        let mut iter = TemplateDescendants { stack: Vec::new() };
+        if inclusive {
+            iter.stack.push(node.clone());
+        } else {
+            iter.push_children(node);
+        }
+        iter
+    }
+
+    /// Pushes the children of a node, with template contents ahead of regular children.
+    fn push_children(&mut self, node: &NodeRef) {
+        self.stack.extend(node.children().rev());
+        if let Some(contents) = node
+            .as_element()
+            .and_then(|element| element.template_contents.as_ref())
+        {
+            self.stack.extend(contents.children().rev());
+        }
+    }
+}
+
+/// Implements Iterator for TemplateDescendants.
+///
+/// Yields nodes in depth-first pre-order, entering template contents.
+impl Iterator for TemplateDescendants {
+    type Item = NodeRef;
+
+    fn next(&mut self) -> Option<NodeRef> {
+        let node = self.stack.pop()?;
+        self.push_children(&node);
+        Some(node)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::html5ever::tendril::TendrilSink;
+    use crate::parse_html;
+
+    /// Tests that template contents are visited in tree order.
+    ///
+    /// Verifies that elements inside a template appear right after the
+    /// template element, while plain descendants() skips them.
+    #[test]
+    fn visits_template_contents() {
+        let doc = parse_html().one(
+            "<div><template><p>a</p><template><i>b</i></template></template><span></span></div>",
+        );
+        let div = doc.select_first("div").unwrap();
+
+        let names = |iter: &mut dyn Iterator<Item = crate::NodeRef>| {
+            iter.filter_map(|node| node.as_element().map(|e| e.name.local.to_string()))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            names(&mut div.as_node().descendants_with_templates()),
+            vec!["template", "p", "template", "i", "span"]
+        );
+        assert_eq!(
+            names(&mut div.as_node().inclusive_descendants_with_templates()),
+            vec!["div", "template", "p", "template", "i", "span"]
+        );
+        assert_eq!(
+            names(&mut div.as_node().descendants()),
+            vec!["template", "span"]
+        );
+    }
+
+    /// Tests selecting elements inside template contents.
+    ///
+    /// Verifies that select_with_templates() finds elements that select()
+    /// misses.
+    #[test]
+    fn select_with_templates() {
+        let doc = parse_html().one("<template><p class='x'>a</p></template><p class='x'>b</p>");
+        assert_eq!(doc.select(".x").unwrap().count(), 1);
+
+        let texts: Vec<String> = doc
+            .select_with_templates(".x")
+            .unwrap()
+            .map(|p| p.text_contents())
+            .collect();
+        assert_eq!(texts, vec!["a", "b"]);
+    }
+}
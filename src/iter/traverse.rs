@@ -52,6 +52,62 @@ macro_rules! traverse_next {
     };
 }
 
+/// Methods for Traverse.
+///
+/// Provides control over forward traversal, such as pruning subtrees.
+impl Traverse {
+    /// Skip the descendants of the node whose `NodeEdge::Start` was just yielded by `next()`.
+    ///
+    /// The next item returned by `next()` will be the matching `NodeEdge::End` of that node,
+    /// so none of its descendants are visited. This has no effect if the last item yielded
+    /// was an `End` edge, or if the node has no children.
+    ///
+    /// Only forward iteration is affected; do not mix this with `next_back()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::iter::NodeEdge;
+    /// use brik::parse_html;
+    /// use brik::traits::*;
+    ///
+    /// let doc = parse_html().one("<div><svg><rect/></svg><p>Text</p></div>");
+    /// let div = doc.select_first("div").unwrap();
+    ///
+    /// let mut traverse = div.as_node().traverse();
+    /// let mut names = Vec::new();
+    /// while let Some(edge) = traverse.next() {
+    ///     if let NodeEdge::Start(node) = edge {
+    ///         if let Some(element) = node.as_element() {
+    ///             names.push(element.name.local.to_string());
+    ///             if &*element.name.local == "svg" {
+    ///                 traverse.skip_subtree();
+    ///             }
+    ///         }
+    ///     }
+    /// }
+    /// assert_eq!(names, vec!["svg", "p"]);
+    /// ```
+    pub fn skip_subtree(&mut self) {
+        if let Some(ref mut state) = self.0 {
+            // A `Start` edge for a first child is only ever produced right after
+            // the `Start` edge of its parent, so that parent is the node just yielded.
+            let parent = match state.next {
+                NodeEdge::Start(ref node) if node.previous_sibling().is_none() => node.parent(),
+                _ => None,
+            };
+            if let Some(parent) = parent {
+                // Before iteration starts, the parent is outside the traversed range:
+                // it is then an ancestor of the node where the traversal ends.
+                let (NodeEdge::Start(ref back) | NodeEdge::End(ref back)) = state.next_back;
+                if !back.ancestors().any(|ancestor| ancestor == parent) {
+                    state.next = NodeEdge::End(parent);
+                }
+            }
+        }
+    }
+}
+
 /// Implements Iterator for Traverse.
 ///
 /// Yields NodeEdge items representing both the start and end of each node
@@ -68,3 +124,87 @@ impl Iterator for Traverse {
 impl DoubleEndedIterator for Traverse {
     traverse_next!(next_back, next, last_child, previous_sibling, End, Start);
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::html5ever::tendril::TendrilSink;
+    use crate::iter::NodeEdge;
+    use crate::parse_html;
+
+    /// Tests pruning a subtree during traversal.
+    ///
+    /// Verifies that calling skip_subtree() right after a Start edge jumps to
+    /// the matching End edge, and that traversal then continues with the
+    /// following sibling.
+    #[test]
+    fn skip_subtree() {
+        let doc = parse_html().one("<div><table><tr><td>1</td></tr></table><p>2</p></div>");
+        let div = doc.select_first("div").unwrap();
+
+        let mut traverse = div.as_node().traverse();
+        let mut edges = Vec::new();
+        while let Some(edge) = traverse.next() {
+            let (is_start, node) = match edge {
+                NodeEdge::Start(node) => (true, node),
+                NodeEdge::End(node) => (false, node),
+            };
+            if let Some(element) = node.as_element() {
+                edges.push((is_start, element.name.local.to_string()));
+                if is_start && &*element.name.local == "table" {
+                    traverse.skip_subtree();
+                }
+            }
+        }
+
+        assert_eq!(
+            edges,
+            vec![
+                (true, "table".to_string()),
+                (false, "table".to_string()),
+                (true, "p".to_string()),
+                (false, "p".to_string()),
+            ]
+        );
+    }
+
+    /// Tests skip_subtree() after an End edge and before iteration starts.
+    ///
+    /// Edge case: neither situation follows a Start edge, so the call must be a
+    /// no-op and must not escape the traversed range.
+    #[test]
+    fn skip_subtree_no_op() {
+        let doc = parse_html().one("<div><p>1</p><p>2</p></div>");
+        let div = doc.select_first("div").unwrap();
+
+        let mut traverse = div.as_node().traverse();
+        traverse.skip_subtree();
+        assert_eq!(traverse.clone().count(), 8);
+
+        let mut inclusive = div.as_node().traverse_inclusive();
+        inclusive.skip_subtree();
+        assert_eq!(inclusive.count(), 10);
+    }
+
+    /// Tests skip_subtree() through the Descendants iterator.
+    ///
+    /// Verifies that pruning via Descendants skips all nodes inside the
+    /// skipped element.
+    #[test]
+    fn descendants_skip_subtree() {
+        let doc = parse_html().one("<div><svg><rect/><circle/></svg><p>text</p></div>");
+        let div = doc.select_first("div").unwrap();
+
+        let mut descendants = div.as_node().descendants();
+        let mut names = Vec::new();
+        while let Some(node) = descendants.next() {
+            if let Some(element) = node.as_element() {
+                names.push(element.name.local.to_string());
+                if &*element.name.local == "svg" {
+                    descendants.skip_subtree();
+                }
+            }
+        }
+
+        assert_eq!(names, vec!["svg", "p"]);
+    }
+}
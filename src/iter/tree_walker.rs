@@ -0,0 +1,406 @@
+use super::FilterResult;
+use crate::tree::NodeRef;
+
+/// A cursor over the subtree of a root node, mirroring the DOM `TreeWalker`.
+///
+/// Each navigation method moves the cursor to the nearest node in the given
+/// direction that the filter accepts, and returns it. If there is no such node,
+/// the method returns `None` and the cursor does not move.
+///
+/// The filter is called with each candidate node and returns a [`FilterResult`]:
+/// `Reject` prunes the node together with its descendants, while `Skip` only
+/// hides the node itself.
+///
+/// # Examples
+///
+/// ```
+/// use brik::iter::{FilterResult, TreeWalker};
+/// use brik::parse_html;
+/// use brik::traits::*;
+///
+/// let doc = parse_html().one("<div><p>One</p><aside><p>Two</p></aside><p>Three</p></div>");
+/// let div = doc.select_first("div").unwrap();
+///
+/// // Visit elements only, never entering <aside>.
+/// let mut walker = TreeWalker::new(div.as_node().clone(), |node| match node.as_element() {
+///     Some(element) if &*element.name.local == "aside" => FilterResult::Reject,
+///     Some(_) => FilterResult::Accept,
+///     None => FilterResult::Skip,
+/// });
+///
+/// let mut texts = Vec::new();
+/// while let Some(node) = walker.next_node() {
+///     texts.push(node.text_contents());
+/// }
+/// assert_eq!(texts, vec!["One", "Three"]);
+/// ```
+pub struct TreeWalker<F>
+where
+    F: FnMut(&NodeRef) -> FilterResult,
+{
+    /// The root of the walked subtree.
+    root: NodeRef,
+    /// The node the cursor is currently on.
+    current: NodeRef,
+    /// The filter deciding which nodes are visited.
+    filter: F,
+}
+
+/// Navigation methods for TreeWalker.
+///
+/// Provides the DOM `TreeWalker` cursor operations. The root is always a valid
+/// position for the cursor, even if the filter would not accept it.
+impl<F> TreeWalker<F>
+where
+    F: FnMut(&NodeRef) -> FilterResult,
+{
+    /// Create a new tree walker over `root` and its descendants, positioned at `root`.
+    pub fn new(root: NodeRef, filter: F) -> TreeWalker<F> {
+        TreeWalker {
+            current: root.clone(),
+            root,
+            filter,
+        }
+    }
+
+    /// Return the root of the walked subtree.
+    #[inline]
+    pub fn root(&self) -> &NodeRef {
+        &self.root
+    }
+
+    /// Return the node the cursor is currently on.
+    #[inline]
+    pub fn current_node(&self) -> &NodeRef {
+        &self.current
+    }
+
+    /// Move the cursor to the given node, whether or not the filter accepts it.
+    #[inline]
+    pub fn set_current_node(&mut self, node: NodeRef) {
+        self.current = node;
+    }
+
+    /// Move to the closest accepted ancestor of the current node, within the root.
+    pub fn parent_node(&mut self) -> Option<NodeRef> {
+        let mut node = self.current.clone();
+        while node != self.root {
+            node = node.parent()?;
+            if (self.filter)(&node) == FilterResult::Accept {
+                self.current = node.clone();
+                return Some(node);
+            }
+        }
+        None
+    }
+
+    /// Move to the first accepted child of the current node.
+    ///
+    /// Children of skipped nodes are considered in their place.
+    pub fn first_child(&mut self) -> Option<NodeRef> {
+        self.traverse_children(true)
+    }
+
+    /// Move to the last accepted child of the current node.
+    ///
+    /// Children of skipped nodes are considered in their place.
+    pub fn last_child(&mut self) -> Option<NodeRef> {
+        self.traverse_children(false)
+    }
+
+    /// Move to the next accepted sibling of the current node.
+    pub fn next_sibling(&mut self) -> Option<NodeRef> {
+        self.traverse_siblings(true)
+    }
+
+    /// Move to the previous accepted sibling of the current node.
+    pub fn previous_sibling(&mut self) -> Option<NodeRef> {
+        self.traverse_siblings(false)
+    }
+
+    /// Move to the next accepted node in tree order.
+    pub fn next_node(&mut self) -> Option<NodeRef> {
+        let mut node = self.current.clone();
+        let mut result = FilterResult::Accept;
+        loop {
+            while result != FilterResult::Reject {
+                let child = match node.first_child() {
+                    Some(child) => child,
+                    None => break,
+                };
+                node = child;
+                result = (self.filter)(&node);
+                if result == FilterResult::Accept {
+                    self.current = node.clone();
+                    return Some(node);
+                }
+            }
+            let mut temporary = node.clone();
+            loop {
+                if temporary == self.root {
+                    return None;
+                }
+                if let Some(sibling) = temporary.next_sibling() {
+                    node = sibling;
+                    break;
+                }
+                temporary = temporary.parent()?;
+            }
+            result = (self.filter)(&node);
+            if result == FilterResult::Accept {
+                self.current = node.clone();
+                return Some(node);
+            }
+        }
+    }
+
+    /// Move to the previous accepted node in tree order.
+    pub fn previous_node(&mut self) -> Option<NodeRef> {
+        let mut node = self.current.clone();
+        while node != self.root {
+            let mut sibling = node.previous_sibling();
+            while let Some(candidate) = sibling {
+                node = candidate;
+                let mut result = (self.filter)(&node);
+                while result != FilterResult::Reject {
+                    let child = match node.last_child() {
+                        Some(child) => child,
+                        None => break,
+                    };
+                    node = child;
+                    result = (self.filter)(&node);
+                }
+                if result == FilterResult::Accept {
+                    self.current = node.clone();
+                    return Some(node);
+                }
+                sibling = node.previous_sibling();
+            }
+            if node == self.root {
+                return None;
+            }
+            node = node.parent()?;
+            if (self.filter)(&node) == FilterResult::Accept {
+                self.current = node.clone();
+                return Some(node);
+            }
+        }
+        None
+    }
+
+    /// Shared implementation of `first_child()` and `last_child()`.
+    fn traverse_children(&mut self, forward: bool) -> Option<NodeRef> {
+        let first = |node: &NodeRef| {
+            if forward {
+                node.first_child()
+            } else {
+                node.last_child()
+            }
+        };
+        let next = |node: &NodeRef| {
+            if forward {
+                node.next_sibling()
+            } else {
+                node.previous_sibling()
+            }
+        };
+
+        let mut node = first(&self.current)?;
+        loop {
+            match (self.filter)(&node) {
+                FilterResult::Accept => {
+                    self.current = node.clone();
+                    return Some(node);
+                }
+                FilterResult::Skip => {
+                    if let Some(child) = first(&node) {
+                        node = child;
+                        continue;
+                    }
+                }
+                FilterResult::Reject => {}
+            }
+            loop {
+                if let Some(sibling) = next(&node) {
+                    node = sibling;
+                    break;
+                }
+                let parent = node.parent()?;
+                if parent == self.root || parent == self.current {
+                    return None;
+                }
+                node = parent;
+            }
+        }
+    }
+
+    /// Shared implementation of `next_sibling()` and `previous_sibling()`.
+    fn traverse_siblings(&mut self, forward: bool) -> Option<NodeRef> {
+        let first = |node: &NodeRef| {
+            if forward {
+                node.first_child()
+            } else {
+                node.last_child()
+            }
+        };
+        let next = |node: &NodeRef| {
+            if forward {
+                node.next_sibling()
+            } else {
+                node.previous_sibling()
+            }
+        };
+
+        let mut node = self.current.clone();
+        if node == self.root {
+            return None;
+        }
+        loop {
+            let mut sibling = next(&node);
+            while let Some(candidate) = sibling {
+                node = candidate;
+                let result = (self.filter)(&node);
+                if result == FilterResult::Accept {
+                    self.current = node.clone();
+                    return Some(node);
+                }
+                sibling = match result {
+                    FilterResult::Reject => None,
+                    _ => first(&node),
+                }
+                .or_else(|| next(&node));
+            }
+            node = node.parent()?;
+            if node == self.root || (self.filter)(&node) == FilterResult::Accept {
+                return None;
+            }
+        }
+    }
+}
+
+/// Implements Iterator for TreeWalker.
+///
+/// Yields accepted nodes in tree order by repeatedly calling `next_node()`,
+/// starting after the current node.
+impl<F> Iterator for TreeWalker<F>
+where
+    F: FnMut(&NodeRef) -> FilterResult,
+{
+    type Item = NodeRef;
+
+    #[inline]
+    fn next(&mut self) -> Option<NodeRef> {
+        self.next_node()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::html5ever::tendril::TendrilSink;
+    use crate::parse_html;
+
+    /// Filter accepting elements and skipping every other node.
+    fn elements_only(node: &NodeRef) -> FilterResult {
+        if node.as_element().is_some() {
+            FilterResult::Accept
+        } else {
+            FilterResult::Skip
+        }
+    }
+
+    /// Returns the local name of an element node.
+    fn name(node: &NodeRef) -> String {
+        node.as_element().unwrap().name.local.to_string()
+    }
+
+    /// Tests walking forward and backward in tree order.
+    ///
+    /// Verifies that next_node() visits accepted nodes in tree order and that
+    /// previous_node() retraces the same nodes in reverse, ending at the
+    /// accepted root.
+    #[test]
+    fn next_and_previous_node() {
+        let doc = parse_html().one("<div><p><b>1</b></p>text<span>2</span></div>");
+        let div = doc.select_first("div").unwrap();
+        let mut walker = TreeWalker::new(div.as_node().clone(), elements_only);
+
+        let forward: Vec<_> = walker.by_ref().map(|n| name(&n)).collect();
+        assert_eq!(forward, vec!["p", "b", "span"]);
+
+        assert_eq!(name(walker.current_node()), "span");
+        assert_eq!(name(&walker.previous_node().unwrap()), "b");
+        assert_eq!(name(&walker.previous_node().unwrap()), "p");
+        assert_eq!(name(&walker.previous_node().unwrap()), "div");
+        assert!(walker.previous_node().is_none());
+        assert_eq!(name(walker.current_node()), "div");
+    }
+
+    /// Tests the difference between Reject and Skip.
+    ///
+    /// Verifies that rejecting a node prunes its descendants, whereas
+    /// skipping it still exposes its children to the walker.
+    #[test]
+    fn reject_versus_skip() {
+        let doc = parse_html().one("<div><section><p>1</p></section><p>2</p></div>");
+        let div = doc.select_first("div").unwrap();
+
+        let with = |result: FilterResult| {
+            let walker = TreeWalker::new(div.as_node().clone(), move |node: &NodeRef| {
+                match node.as_element() {
+                    Some(e) if &*e.name.local == "section" => result,
+                    Some(_) => FilterResult::Accept,
+                    None => FilterResult::Skip,
+                }
+            });
+            walker.map(|n| n.text_contents()).collect::<Vec<_>>()
+        };
+
+        assert_eq!(with(FilterResult::Reject), vec!["2"]);
+        assert_eq!(with(FilterResult::Skip), vec!["1", "2"]);
+    }
+
+    /// Tests child and sibling cursor movement.
+    ///
+    /// Verifies first_child(), last_child(), next_sibling(), previous_sibling()
+    /// and parent_node(), including that skipped wrappers are transparent.
+    #[test]
+    fn cursor_movement() {
+        let doc = parse_html().one("<div><p>1</p><section><i>2</i><b>3</b></section></div>");
+        let div = doc.select_first("div").unwrap();
+        let mut walker = TreeWalker::new(div.as_node().clone(), |node: &NodeRef| {
+            match node.as_element() {
+                Some(e) if &*e.name.local == "section" => FilterResult::Skip,
+                Some(_) => FilterResult::Accept,
+                None => FilterResult::Skip,
+            }
+        });
+
+        assert_eq!(name(&walker.first_child().unwrap()), "p");
+        assert_eq!(name(&walker.next_sibling().unwrap()), "i");
+        assert_eq!(name(&walker.next_sibling().unwrap()), "b");
+        assert!(walker.next_sibling().is_none());
+        assert_eq!(name(&walker.previous_sibling().unwrap()), "i");
+        assert_eq!(name(&walker.parent_node().unwrap()), "div");
+        assert_eq!(name(&walker.last_child().unwrap()), "b");
+        assert!(walker.first_child().is_none());
+    }
+
+    /// Tests that the walker never leaves the root's subtree.
+    ///
+    /// Edge case: sibling and parent navigation from the root, and forward
+    /// iteration from the last node, must all return None.
+    #[test]
+    fn stays_within_root() {
+        let doc = parse_html().one("<p>before</p><div><b>in</b></div><p>after</p>");
+        let div = doc.select_first("div").unwrap();
+        let mut walker = TreeWalker::new(div.as_node().clone(), elements_only);
+
+        assert!(walker.next_sibling().is_none());
+        assert!(walker.previous_sibling().is_none());
+        assert!(walker.parent_node().is_none());
+        assert_eq!(name(&walker.next_node().unwrap()), "b");
+        assert!(walker.next_node().is_none());
+        assert!(walker.next_sibling().is_none());
+    }
+}
@@ -0,0 +1,197 @@
+use super::VisitControl;
+use crate::node_data_ref::NodeDataRef;
+use crate::tree::{Doctype, ElementData};
+use html5ever::tendril::StrTendril;
+use std::cell::RefCell;
+
+/// Hooks called by [`NodeRef::visit`](crate::NodeRef::visit) for each node of a subtree.
+///
+/// Elements get a pair of `enter_element` and `exit_element` calls surrounding
+/// their descendants, matching the `Start` and `End` edges of a traversal.
+/// Other node types get a single call. Every hook has a default implementation
+/// that does nothing and returns `VisitControl::Continue`, so implementors only
+/// override the hooks they need.
+///
+/// Document and document fragment nodes have no hook; their children are visited.
+///
+/// # Examples
+///
+/// ```
+/// use brik::iter::{Visit, VisitControl};
+/// use brik::parse_html;
+/// use brik::traits::*;
+/// use brik::{ElementData, NodeDataRef, StrTendril};
+/// use std::cell::RefCell;
+///
+/// /// Collects visible text, ignoring scripts.
+/// #[derive(Default)]
+/// struct VisibleText(String);
+///
+/// impl Visit for VisibleText {
+///     fn enter_element(&mut self, element: &NodeDataRef<ElementData>) -> VisitControl {
+///         if &*element.name.local == "script" {
+///             VisitControl::SkipChildren
+///         } else {
+///             VisitControl::Continue
+///         }
+///     }
+///
+///     fn text(&mut self, text: &NodeDataRef<RefCell<StrTendril>>) -> VisitControl {
+///         self.0.push_str(&text.borrow());
+///         VisitControl::Continue
+///     }
+/// }
+///
+/// let doc = parse_html().one("<p>Hello <script>var x;</script>world</p>");
+/// let mut visitor = VisibleText::default();
+/// doc.visit(&mut visitor);
+/// assert_eq!(visitor.0, "Hello world");
+/// ```
+pub trait Visit {
+    /// Called when entering an element, before its descendants.
+    #[inline]
+    fn enter_element(&mut self, _element: &NodeDataRef<ElementData>) -> VisitControl {
+        VisitControl::Continue
+    }
+
+    /// Called when leaving an element, after its descendants.
+    #[inline]
+    fn exit_element(&mut self, _element: &NodeDataRef<ElementData>) -> VisitControl {
+        VisitControl::Continue
+    }
+
+    /// Called for each text node.
+    #[inline]
+    fn text(&mut self, _text: &NodeDataRef<RefCell<StrTendril>>) -> VisitControl {
+        VisitControl::Continue
+    }
+
+    /// Called for each comment node.
+    #[inline]
+    fn comment(&mut self, _comment: &NodeDataRef<RefCell<StrTendril>>) -> VisitControl {
+        VisitControl::Continue
+    }
+
+    /// Called for each doctype node.
+    #[inline]
+    fn doctype(&mut self, _doctype: &NodeDataRef<Doctype>) -> VisitControl {
+        VisitControl::Continue
+    }
+
+    /// Called for each processing instruction node.
+    #[inline]
+    fn processing_instruction(
+        &mut self,
+        _contents: &NodeDataRef<RefCell<(String, String)>>,
+    ) -> VisitControl {
+        VisitControl::Continue
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::html5ever::tendril::TendrilSink;
+    use crate::parse_html;
+
+    /// Visitor recording every hook call as a string.
+    #[derive(Default)]
+    struct Recorder {
+        /// Recorded hook calls.
+        events: Vec<String>,
+        /// Element name to skip the children of.
+        skip: Option<&'static str>,
+        /// Element name to stop at.
+        stop: Option<&'static str>,
+    }
+
+    /// Implements Visit for Recorder.
+    ///
+    /// Records enter, exit, text, and comment events, and applies the
+    /// configured skip and stop controls.
+    impl Visit for Recorder {
+        fn enter_element(&mut self, element: &NodeDataRef<ElementData>) -> VisitControl {
+            let name = &*element.name.local;
+            self.events.push(format!("<{name}>"));
+            if self.stop == Some(name) {
+                VisitControl::Stop
+            } else if self.skip == Some(name) {
+                VisitControl::SkipChildren
+            } else {
+                VisitControl::Continue
+            }
+        }
+
+        fn exit_element(&mut self, element: &NodeDataRef<ElementData>) -> VisitControl {
+            self.events.push(format!("</{}>", &*element.name.local));
+            VisitControl::Continue
+        }
+
+        fn text(&mut self, text: &NodeDataRef<RefCell<StrTendril>>) -> VisitControl {
+            self.events.push(text.borrow().to_string());
+            VisitControl::Continue
+        }
+
+        fn comment(&mut self, comment: &NodeDataRef<RefCell<StrTendril>>) -> VisitControl {
+            self.events.push(format!("<!--{}-->", comment.borrow()));
+            VisitControl::Continue
+        }
+    }
+
+    /// Tests the order of visitor hook calls.
+    ///
+    /// Verifies that enter and exit hooks surround the descendants of each
+    /// element, with text and comment hooks called in tree order.
+    #[test]
+    fn visit_order() {
+        let doc = parse_html().one("<div>a<!--c--><b>b</b></div>");
+        let div = doc.select_first("div").unwrap();
+        let mut recorder = Recorder::default();
+
+        div.as_node().visit(&mut recorder);
+
+        assert_eq!(
+            recorder.events,
+            vec!["<div>", "a", "<!--c-->", "<b>", "b", "</b>", "</div>"]
+        );
+    }
+
+    /// Tests skipping the children of an element.
+    ///
+    /// Verifies that SkipChildren prevents descendants from being visited
+    /// while still calling the exit hook of the skipped element.
+    #[test]
+    fn visit_skip_children() {
+        let doc = parse_html().one("<div><svg><rect/></svg><p>x</p></div>");
+        let div = doc.select_first("div").unwrap();
+        let mut recorder = Recorder {
+            skip: Some("svg"),
+            ..Recorder::default()
+        };
+
+        div.as_node().visit(&mut recorder);
+
+        assert_eq!(
+            recorder.events,
+            vec!["<div>", "<svg>", "</svg>", "<p>", "x", "</p>", "</div>"]
+        );
+    }
+
+    /// Tests stopping a visit early.
+    ///
+    /// Verifies that Stop ends the visit immediately, without calling any
+    /// further hooks, including pending exit hooks.
+    #[test]
+    fn visit_stop() {
+        let doc = parse_html().one("<div><p>x</p><span>y</span></div>");
+        let div = doc.select_first("div").unwrap();
+        let mut recorder = Recorder {
+            stop: Some("p"),
+            ..Recorder::default()
+        };
+
+        div.as_node().visit(&mut recorder);
+
+        assert_eq!(recorder.events, vec!["<div>", "<p>"]);
+    }
+}
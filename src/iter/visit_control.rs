@@ -0,0 +1,16 @@
+/// Controls how [`NodeRef::visit`](crate::NodeRef::visit) proceeds after a [`Visit`](super::Visit) hook.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum VisitControl {
+    /// Continue the visit normally.
+    #[default]
+    Continue,
+
+    /// Do not visit the descendants of the element just entered.
+    ///
+    /// The matching `exit_element` hook is still called.
+    /// When returned from any other hook, this behaves like `Continue`.
+    SkipChildren,
+
+    /// Stop the visit immediately. No further hooks are called.
+    Stop,
+}
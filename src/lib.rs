@@ -22,10 +22,37 @@ extern crate html5ever;
 
 /// Attribute handling and storage.
 mod attributes;
+/// Fluent construction of node trees.
+pub mod builder;
 /// Specialized Cell methods for performance-critical operations.
 mod cell_extras;
+/// Configurable comparison of node trees.
+pub mod compare;
+/// Server-side expansion of custom elements.
+pub mod component;
+/// CSS processing over node trees.
+pub mod css;
+/// Debugging aids for inspecting node trees.
+pub mod debug;
+/// Heuristic extraction of content from documents.
+pub mod extract;
+/// C ABI for embedding brik in other languages.
+#[cfg(all(feature = "ffi", not(feature = "safe")))]
+#[allow(unsafe_code)]
+pub mod ffi;
+/// Immutable, thread-safe snapshots of node trees.
+pub mod frozen;
+/// Typed views of common HTML elements.
+pub mod html;
+/// Conversions between brik trees and other HTML libraries.
+mod interop;
 /// Node iteration and traversal.
 pub mod iter;
+/// Pluggable lint rules over node trees.
+pub mod lint;
+/// Declarative macros for building node trees and selectors.
+#[cfg(feature = "macros")]
+mod macros;
 /// Type-safe node data references.
 mod node_data_ref;
 /// Namespace specifics.
@@ -33,24 +60,57 @@ mod node_data_ref;
 pub mod ns;
 /// HTML parsing into the tree structure.
 pub mod parser;
+/// Ranges of content between two points in a tree.
+pub mod range;
 /// CSS selector matching implementation.
 mod select;
 /// HTML serialization from the tree structure.
 mod serializer;
+/// Serde-compatible snapshots of node trees.
+#[cfg(feature = "serde")]
+pub mod snapshot;
+/// Streaming of HTML as events, without tree construction.
+pub mod stream;
+/// Server-side templating with directives.
+#[cfg(feature = "namespaces")]
+pub mod template;
+/// Search over the text of node trees.
+pub mod text;
 /// DOM tree structure and manipulation.
 mod tree;
+/// Conformance checks for HTML content models.
+pub mod validate;
+/// JavaScript bindings for WebAssembly builds.
+#[cfg(feature = "wasm")]
+pub mod wasm;
 
 pub use attributes::{Attribute, Attributes, ExpandedName};
 pub use node_data_ref::NodeDataRef;
 pub use parser::{
-    parse_fragment, parse_fragment_with_options, parse_html, parse_html_with_options, ParseOpts,
-    Sink,
+    parse_fragment, parse_fragment_in, parse_fragment_in_with_options, parse_fragment_nodes,
+    parse_fragment_nodes_with_options, parse_fragment_with_options, parse_html,
+    parse_html_str_with_options, parse_html_strict, parse_html_strict_with_options,
+    parse_html_with_options, ParseDiagnostic, ParseDiagnostics, ParseOpts, ParseSeverity, Sink,
+};
+pub use select::{
+    sort_by_specificity, MatchContext, QueryCache, Selection, Selector, SelectorContext,
+    SelectorMatch, SelectorParseError, Selectors, Specificity,
+};
+#[cfg(feature = "c14n")]
+pub use serializer::C14nOptions;
+pub use serializer::{
+    AttributeComparator, AttributeOrder, AttributeQuotes, EntityStyle, FilterAction, NodeFilter,
+    NodeFilterFn, SerializeCache, SerializeOptions,
+};
+pub use tree::{
+    Doctype, DocumentData, DocumentOrder, ElementData, ImportOptions, Node, NodeData, NodeRef,
+    TextOptions,
 };
-pub use select::{Selector, SelectorContext, Selectors, Specificity};
-pub use tree::{Doctype, DocumentData, ElementData, Node, NodeData, NodeRef};
 
 // Re-export namespace-related types from html5ever for convenience
 pub use html5ever::{LocalName, Namespace, Prefix};
+// Re-export the string type used for text, comment and attribute values
+pub use html5ever::tendril::StrTendril;
 
 /// This module re-exports a number of traits that are useful when using Brik.
 /// It can be used with:
@@ -0,0 +1,32 @@
+use super::Severity;
+use crate::tree::NodeRef;
+use std::fmt;
+
+/// A problem reported by a lint rule.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Finding {
+    /// The name of the rule that reported the problem.
+    pub rule: String,
+    /// The severity of the rule.
+    pub severity: Severity,
+    /// The offending node.
+    pub node: NodeRef,
+    /// The path to the offending node, as returned by
+    /// [`node_path()`](crate::validate::node_path).
+    pub path: String,
+    /// A description of the problem.
+    pub message: String,
+}
+
+/// Implements Display for Finding.
+///
+/// Formats the finding as `path: severity: message (rule)`.
+impl fmt::Display for Finding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: {}: {} ({})",
+            self.path, self.severity, self.message, self.rule
+        )
+    }
+}
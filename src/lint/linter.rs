@@ -0,0 +1,122 @@
+use super::{Finding, Lint};
+use crate::tree::NodeRef;
+use crate::validate::node_path;
+
+/// Runs a set of lint rules over trees.
+///
+/// # Examples
+///
+/// ```
+/// use brik::lint::{Linter, MaxDepth, NoInlineStyles, NoopenerLinks};
+/// use brik::parse_html;
+/// use brik::traits::*;
+///
+/// let doc = parse_html().one(
+///     "<p style='color: red'><a href=/x target=_blank>x</a></p>",
+/// );
+/// let mut linter = Linter::new();
+/// linter.add(NoInlineStyles).add(NoopenerLinks).add(MaxDepth(8));
+///
+/// let findings = linter.run(&doc);
+/// assert_eq!(findings.len(), 2);
+/// assert_eq!(findings[0].rule, "no-inline-styles");
+/// assert_eq!(findings[1].rule, "noopener-links");
+/// ```
+#[derive(Default)]
+pub struct Linter<'a> {
+    /// The rules to run, in order.
+    lints: Vec<Box<dyn Lint + 'a>>,
+}
+
+/// Methods for Linter.
+///
+/// Provides rule registration and linting.
+impl<'a> Linter<'a> {
+    /// Creates a linter with no rules.
+    pub fn new() -> Linter<'a> {
+        Linter::default()
+    }
+
+    /// Adds a rule.
+    pub fn add<L: Lint + 'a>(&mut self, lint: L) -> &mut Self {
+        self.lints.push(Box::new(lint));
+        self
+    }
+
+    /// Runs the rules over this node and its descendants in a single
+    /// traversal, returning their findings.
+    ///
+    /// Findings are ordered by node in document order, then by the order in
+    /// which their rules were added.
+    pub fn run(&self, root: &NodeRef) -> Vec<Finding> {
+        let mut findings = Vec::new();
+        for node in root.inclusive_descendants() {
+            let mut path = None;
+            for lint in &self.lints {
+                for message in lint.check(&node) {
+                    findings.push(Finding {
+                        rule: lint.name().to_string(),
+                        severity: lint.severity(),
+                        node: node.clone(),
+                        path: path.get_or_insert_with(|| node_path(&node)).clone(),
+                        message,
+                    });
+                }
+            }
+        }
+        findings
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::html5ever::tendril::TendrilSink;
+    use crate::lint::{NoopenerLinks, Severity};
+    use crate::parse_html;
+
+    /// A rule reporting every comment, for testing.
+    struct NoComments;
+
+    /// Implements Lint for NoComments.
+    ///
+    /// Provides an informational finding for each comment node.
+    impl Lint for NoComments {
+        fn name(&self) -> &str {
+            "no-comments"
+        }
+
+        fn severity(&self) -> Severity {
+            Severity::Info
+        }
+
+        fn check(&self, node: &NodeRef) -> Vec<String> {
+            match node.as_comment() {
+                Some(_) => vec!["comment".to_string()],
+                None => Vec::new(),
+            }
+        }
+    }
+
+    /// Tests running custom and built-in rules together.
+    ///
+    /// Verifies that rules see non-element nodes, that findings are in
+    /// document order, and that `rel` tokens are matched ignoring ASCII case.
+    #[test]
+    fn run_in_document_order() {
+        let doc = parse_html().one(
+            "<a href=/a target=_blank rel='NoOpener'>a</a><!--x-->\
+             <a href=/b target=new>b</a><a href=/c target=_self>c</a>",
+        );
+        let findings = Linter::new().add(NoopenerLinks).add(NoComments).run(&doc);
+
+        let summary: Vec<String> = findings.iter().map(ToString::to_string).collect();
+        assert_eq!(
+            summary,
+            [
+                "/html[1]/body[1]/comment()[1]: info: comment (no-comments)",
+                "/html[1]/body[1]/a[2]: warning: <a target=\"new\"> is missing rel=\"noopener\" (noopener-links)",
+            ]
+        );
+    }
+}
@@ -0,0 +1,60 @@
+use super::Lint;
+use crate::iter::NodeIterator;
+use crate::tree::NodeRef;
+
+/// A rule reporting elements nested more deeply than a limit.
+///
+/// Its name is `max-depth`. Depth counts element ancestors, so a root
+/// element has depth 0 and `<body>` in a parsed document has depth 1. Only
+/// elements at exactly one more than the limit are reported, so each
+/// over-deep subtree gives one finding rather than one per descendant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MaxDepth(pub usize);
+
+/// Implements Lint for MaxDepth.
+///
+/// Provides a warning for each element that first exceeds the limit.
+impl Lint for MaxDepth {
+    fn name(&self) -> &str {
+        "max-depth"
+    }
+
+    fn check(&self, node: &NodeRef) -> Vec<String> {
+        let element = match node.as_element() {
+            Some(element) => element,
+            None => return Vec::new(),
+        };
+        let depth = node.ancestors().elements().take(self.0 + 2).count();
+        if depth != self.0 + 1 {
+            return Vec::new();
+        }
+        vec![format!(
+            "<{}> is nested more than {} elements deep",
+            element.name.local, self.0
+        )]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::html5ever::tendril::TendrilSink;
+    use crate::lint::Linter;
+    use crate::parse_html;
+
+    /// Tests the depth limit.
+    ///
+    /// Verifies that only the outermost elements past the limit are
+    /// reported, once per over-deep subtree.
+    #[test]
+    fn reports_outermost_over_deep_elements() {
+        let doc = parse_html().one("<div><p><b><i>x</i></b></p></div><section><p></p></section>");
+        let findings = Linter::new().add(MaxDepth(3)).run(&doc);
+
+        let paths: Vec<&str> = findings
+            .iter()
+            .map(|finding| finding.path.as_str())
+            .collect();
+        assert_eq!(paths, ["/html[1]/body[1]/div[1]/p[1]/b[1]"]);
+    }
+}
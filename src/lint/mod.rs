@@ -0,0 +1,29 @@
+//! Pluggable lint rules over node trees.
+//!
+//! A [`Lint`] checks one node at a time; a [`Linter`] runs a set of rules
+//! over a tree in a single traversal and collects their [`Finding`]s. Custom
+//! policies are written by implementing [`Lint`], and a few common ones are
+//! built in: [`NoInlineStyles`], [`NoopenerLinks`] and [`MaxDepth`].
+
+/// A finding reported by a rule.
+mod finding;
+/// The lint runner.
+mod linter;
+/// The maximum nesting depth rule.
+mod max_depth;
+/// The inline styles rule.
+mod no_inline_styles;
+/// The `rel=noopener` rule.
+mod noopener_links;
+/// The lint rule trait.
+mod rule;
+/// Finding severities.
+mod severity;
+
+pub use finding::Finding;
+pub use linter::Linter;
+pub use max_depth::MaxDepth;
+pub use no_inline_styles::NoInlineStyles;
+pub use noopener_links::NoopenerLinks;
+pub use rule::Lint;
+pub use severity::Severity;
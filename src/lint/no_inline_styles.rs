@@ -0,0 +1,27 @@
+use super::Lint;
+use crate::tree::NodeRef;
+
+/// A rule reporting elements with a `style` attribute.
+///
+/// Its name is `no-inline-styles`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct NoInlineStyles;
+
+/// Implements Lint for NoInlineStyles.
+///
+/// Provides a warning for each element with a `style` attribute, in any
+/// namespace.
+impl Lint for NoInlineStyles {
+    fn name(&self) -> &str {
+        "no-inline-styles"
+    }
+
+    fn check(&self, node: &NodeRef) -> Vec<String> {
+        match node.as_element() {
+            Some(element) if element.attributes.borrow().contains("style") => {
+                vec![format!("<{}> has an inline style", element.name.local)]
+            }
+            _ => Vec::new(),
+        }
+    }
+}
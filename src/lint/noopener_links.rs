@@ -0,0 +1,54 @@
+use super::Lint;
+use crate::tree::NodeRef;
+
+/// A rule reporting links that open a new browsing context without
+/// `rel="noopener"`.
+///
+/// Its name is `noopener-links`. It checks HTML `<a>`, `<area>` and `<form>`
+/// elements with a `target` other than `_self`, `_parent` or `_top`. A `rel`
+/// of `noreferrer`, which implies `noopener`, is also accepted.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct NoopenerLinks;
+
+/// Implements Lint for NoopenerLinks.
+///
+/// Provides a warning for each link that gives the opened page access to
+/// its opener.
+impl Lint for NoopenerLinks {
+    fn name(&self) -> &str {
+        "noopener-links"
+    }
+
+    fn check(&self, node: &NodeRef) -> Vec<String> {
+        let element = match node.as_element() {
+            Some(element)
+                if element.name.ns == ns!(html)
+                    && matches!(&*element.name.local, "a" | "area" | "form") =>
+            {
+                element
+            }
+            _ => return Vec::new(),
+        };
+        let attributes = element.attributes.borrow();
+        let target = match attributes.get("target") {
+            Some(target)
+                if !["_self", "_parent", "_top"]
+                    .iter()
+                    .any(|keyword| keyword.eq_ignore_ascii_case(target)) =>
+            {
+                target
+            }
+            _ => return Vec::new(),
+        };
+        let protected = attributes.get_tokens("rel").any(|token| {
+            token.eq_ignore_ascii_case("noopener") || token.eq_ignore_ascii_case("noreferrer")
+        });
+        if protected {
+            return Vec::new();
+        }
+        vec![format!(
+            "<{} target=\"{}\"> is missing rel=\"noopener\"",
+            element.name.local, target
+        )]
+    }
+}
@@ -0,0 +1,60 @@
+use super::Severity;
+use crate::tree::NodeRef;
+
+/// A lint rule, checking nodes against a policy.
+///
+/// A [`Linter`](super::Linter) calls [`check()`](Lint::check) once for each
+/// node of a tree, in document order, so a rule only needs to look at the
+/// node it is given and whatever of its surroundings the policy needs.
+///
+/// # Examples
+///
+/// ```
+/// use brik::lint::{Lint, Linter, Severity};
+/// use brik::parse_html;
+/// use brik::traits::*;
+/// use brik::NodeRef;
+///
+/// struct NoMarquee;
+///
+/// impl Lint for NoMarquee {
+///     fn name(&self) -> &str {
+///         "no-marquee"
+///     }
+///
+///     fn severity(&self) -> Severity {
+///         Severity::Error
+///     }
+///
+///     fn check(&self, node: &NodeRef) -> Vec<String> {
+///         match node.as_element() {
+///             Some(element) if &*element.name.local == "marquee" => {
+///                 vec!["<marquee> is obsolete".to_string()]
+///             }
+///             _ => Vec::new(),
+///         }
+///     }
+/// }
+///
+/// let doc = parse_html().one("<marquee>Hi</marquee>");
+/// let findings = Linter::new().add(NoMarquee).run(&doc);
+/// assert_eq!(findings.len(), 1);
+/// assert_eq!(
+///     findings[0].to_string(),
+///     "/html[1]/body[1]/marquee[1]: error: <marquee> is obsolete (no-marquee)"
+/// );
+/// ```
+pub trait Lint {
+    /// Returns the rule's name, which identifies its findings.
+    fn name(&self) -> &str;
+
+    /// Returns the severity of the rule's findings.
+    ///
+    /// Defaults to [`Severity::Warning`].
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    /// Checks a node, returning a message for each problem found with it.
+    fn check(&self, node: &NodeRef) -> Vec<String>;
+}
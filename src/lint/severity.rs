@@ -0,0 +1,28 @@
+use std::fmt;
+
+/// How serious a lint [`Finding`](super::Finding) is.
+///
+/// Severities are ordered from [`Info`](Severity::Info) to
+/// [`Error`](Severity::Error), so findings can be filtered by a threshold.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    /// Informational; not a problem by itself.
+    Info,
+    /// A likely problem.
+    Warning,
+    /// A policy violation.
+    Error,
+}
+
+/// Implements Display for Severity.
+///
+/// Formats the severity in lowercase, such as `warning`.
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Severity::Info => "info",
+            Severity::Warning => "warning",
+            Severity::Error => "error",
+        })
+    }
+}
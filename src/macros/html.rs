@@ -0,0 +1,159 @@
+/// Builds a node tree from HTML-like syntax.
+///
+/// The macro expands to [`builder::Element`](crate::builder::Element) calls and
+/// evaluates to the root [`NodeRef`](crate::NodeRef), so namespaces follow the
+/// builder's defaults.
+///
+/// - Elements are written `<name attr="value">...</name>` or `<name attr="value" />`.
+/// - Attribute values are literals or `{expression}`s converting into `String`.
+///   Names that are not Rust identifiers, such as `data-id`, are written as
+///   string literals: `"data-id"="7"`.
+/// - Children are nested elements, literals (written as text), or
+///   `{expression}`s of type `&str`, `String`, `Element` or `NodeRef`.
+///
+/// Closing tag names are not checked against their opening tags. Element
+/// names must be Rust identifiers. Each token is a macro recursion step, so very
+/// large templates may need a higher `#![recursion_limit]`.
+///
+/// **Note:** This macro requires the `macros` feature to be enabled.
+///
+/// # Examples
+///
+/// ```
+/// use brik::html;
+///
+/// let name = "World";
+/// let id = 7;
+/// let node = html!(
+///     <div class="greeting" "data-id"={id.to_string()}>
+///         "Hello, " {name} "!"
+///         <br/>
+///     </div>
+/// );
+/// assert_eq!(
+///     node.to_string(),
+///     r#"<div class="greeting" data-id="7">Hello, World!<br></div>"#
+/// );
+/// ```
+#[macro_export]
+macro_rules! html {
+    ($($tokens:tt)+) => {
+        $crate::__html!(@content [] $($tokens)+)
+    };
+}
+
+/// Implementation of [`html!`]: a token muncher over a stack of open elements.
+///
+/// The stack holds builder expressions, innermost first. `@content` consumes
+/// element contents and closing tags; `@attrs` consumes an opening tag's
+/// attributes.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __html {
+    // Closing tag of the root element.
+    (@content [($($top:tt)*)] < / $tag:ident >) => {
+        $crate::builder::Element::build($($top)*)
+    };
+    // Closing tag of a nested element: append it to its parent.
+    (@content [($($top:tt)*) ($($parent:tt)*) $($stack:tt)*] < / $tag:ident > $($rest:tt)*) => {
+        $crate::__html!(@content [(($($parent)*).child($($top)*)) $($stack)*] $($rest)*)
+    };
+    // Opening tag.
+    (@content [$($stack:tt)*] < $tag:ident $($rest:tt)*) => {
+        $crate::__html!(
+            @attrs [$($stack)*] ($crate::builder::Element::new(stringify!($tag))) $($rest)*
+        )
+    };
+    // Interpolated child.
+    (@content [($($top:tt)*) $($stack:tt)*] { $child:expr } $($rest:tt)*) => {
+        $crate::__html!(@content [(($($top)*).child($child)) $($stack)*] $($rest)*)
+    };
+    // Literal text child.
+    (@content [($($top:tt)*) $($stack:tt)*] $text:literal $($rest:tt)*) => {
+        $crate::__html!(
+            @content [(($($top)*).text(::std::string::ToString::to_string(&$text))) $($stack)*]
+            $($rest)*
+        )
+    };
+    // Attribute with a literal value.
+    (@attrs $stack:tt ($($cur:tt)*) $name:ident = $value:literal $($rest:tt)*) => {
+        $crate::__html!(@attrs $stack (($($cur)*).attr(stringify!($name), $value)) $($rest)*)
+    };
+    (@attrs $stack:tt ($($cur:tt)*) $name:literal = $value:literal $($rest:tt)*) => {
+        $crate::__html!(@attrs $stack (($($cur)*).attr($name, $value)) $($rest)*)
+    };
+    // Attribute with an interpolated value.
+    (@attrs $stack:tt ($($cur:tt)*) $name:ident = { $value:expr } $($rest:tt)*) => {
+        $crate::__html!(@attrs $stack (($($cur)*).attr(stringify!($name), $value)) $($rest)*)
+    };
+    (@attrs $stack:tt ($($cur:tt)*) $name:literal = { $value:expr } $($rest:tt)*) => {
+        $crate::__html!(@attrs $stack (($($cur)*).attr($name, $value)) $($rest)*)
+    };
+    // Self-closing root element.
+    (@attrs [] ($($cur:tt)*) / >) => {
+        $crate::builder::Element::build($($cur)*)
+    };
+    // Self-closing nested element: append it to its parent.
+    (@attrs [($($top:tt)*) $($stack:tt)*] ($($cur:tt)*) / > $($rest:tt)*) => {
+        $crate::__html!(@content [(($($top)*).child($($cur)*)) $($stack)*] $($rest)*)
+    };
+    // End of an opening tag: the element becomes the innermost open element.
+    (@attrs [$($stack:tt)*] ($($cur:tt)*) > $($rest:tt)*) => {
+        $crate::__html!(@content [($($cur)*) $($stack)*] $($rest)*)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::builder::Element;
+    use crate::NodeRef;
+
+    /// Tests interpolation of attributes and children.
+    ///
+    /// Verifies literal and expression attributes, keyword attribute names,
+    /// and children given as strings, builders, and nodes.
+    #[test]
+    fn interpolation() {
+        let class = String::from("c");
+        let node: NodeRef = html!(
+            <script type="module" class={class.clone()}>{"let a = 1;"}</script>
+        );
+        assert_eq!(
+            node.to_string(),
+            r#"<script type="module" class="c">let a = 1;</script>"#
+        );
+
+        let existing = NodeRef::new_comment("note");
+        let node = html!(<p>{Element::new("b").text("x")} {existing} 42</p>);
+        assert_eq!(node.to_string(), "<p><b>x</b><!--note-->42</p>");
+    }
+
+    /// Tests nesting and self-closing elements.
+    ///
+    /// Verifies that siblings and nested elements keep their order, and that
+    /// foreign content gets the SVG namespace.
+    #[test]
+    fn nesting() {
+        let node = html!(
+            <div>
+                <svg viewBox="0 0 1 1"><rect width="1" /></svg>
+                <p>"a"<i>"b"</i>"c"</p>
+                <img src="x.png"/>
+            </div>
+        );
+        assert_eq!(
+            node.to_string(),
+            r#"<div><svg viewBox="0 0 1 1"><rect width="1"></rect></svg><p>a<i>b</i>c</p><img src="x.png"></div>"#
+        );
+        let rect = node.select_first("rect").unwrap();
+        assert_eq!(rect.name.ns, ns!(svg));
+    }
+
+    /// Tests a self-closing root element.
+    ///
+    /// Edge case: the whole macro input is a single void element.
+    #[test]
+    fn self_closing_root() {
+        assert_eq!(html!(<hr class="x" />).to_string(), r#"<hr class="x">"#);
+    }
+}
@@ -0,0 +1,6 @@
+//! Declarative macros for building node trees and selectors.
+
+/// The `html!` macro.
+mod html;
+/// The `selector!` macro.
+mod selector;
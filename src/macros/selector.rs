@@ -0,0 +1,69 @@
+/// Compiles a selector list once and returns it as a `&'static Selectors`.
+///
+/// The selector string must be a literal. It is compiled on first use and
+/// cached in a static, so later evaluations of the same macro invocation
+/// cost only an atomic load.
+///
+/// Invalid selectors are not detected at build time, since compiling a
+/// selector needs brik's selector implementation. Instead, the first use of
+/// an invalid selector panics with the selector text, so a test that runs the
+/// code path catches typos before they reach production.
+///
+/// **Note:** This macro requires the `macros` feature to be enabled.
+///
+/// # Panics
+///
+/// Panics on first use if the selector does not compile.
+///
+/// # Examples
+///
+/// ```
+/// use brik::parse_html;
+/// use brik::selector;
+/// use brik::traits::*;
+///
+/// let doc = parse_html().one("<div class=item><a href=/x>x</a></div>");
+/// let links = selector!("div.item > a[href]");
+/// assert_eq!(links.filter(doc.descendants().elements()).count(), 1);
+/// ```
+#[macro_export]
+macro_rules! selector {
+    ($selectors:literal $(,)?) => {{
+        static SELECTORS: ::std::sync::OnceLock<$crate::Selectors> = ::std::sync::OnceLock::new();
+        SELECTORS.get_or_init(|| match $crate::Selectors::compile($selectors) {
+            ::std::result::Result::Ok(selectors) => selectors,
+            ::std::result::Result::Err(()) => {
+                ::std::panic!("invalid selector: {:?}", $selectors)
+            }
+        })
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Selectors;
+
+    /// Returns the cached selectors of a single macro invocation.
+    fn cached() -> &'static Selectors {
+        selector!("p.a, #b")
+    }
+
+    /// Tests that the selectors are compiled once.
+    ///
+    /// Verifies that repeated evaluations of one invocation return the same
+    /// static value, holding every selector in the list.
+    #[test]
+    fn cached_once() {
+        assert!(std::ptr::eq(cached(), cached()));
+        assert_eq!(cached().0.len(), 2);
+    }
+
+    /// Tests an invalid selector.
+    ///
+    /// Edge case: the first use panics with the selector text.
+    #[test]
+    #[should_panic(expected = "invalid selector: \"div[\"")]
+    fn invalid() {
+        selector!("div[");
+    }
+}
@@ -1,6 +1,8 @@
 use crate::tree::{Doctype, DocumentData, ElementData, Node, NodeRef};
+use html5ever::tendril::StrTendril;
 use std::cell::RefCell;
 use std::fmt;
+use std::hash::{Hash, Hasher};
 use std::ops::Deref;
 
 #[cfg(feature = "safe")]
@@ -35,13 +37,13 @@ impl NodeRef {
 
     /// If this node is a text node, return a strong reference to its contents.
     #[inline]
-    pub fn into_text_ref(self) -> Option<NodeDataRef<RefCell<String>>> {
+    pub fn into_text_ref(self) -> Option<NodeDataRef<RefCell<StrTendril>>> {
         NodeDataRef::new_opt(self, Node::as_text)
     }
 
     /// If this node is a comment, return a strong reference to its contents.
     #[inline]
-    pub fn into_comment_ref(self) -> Option<NodeDataRef<RefCell<String>>> {
+    pub fn into_comment_ref(self) -> Option<NodeDataRef<RefCell<StrTendril>>> {
         NodeDataRef::new_opt(self, Node::as_comment)
     }
 
@@ -71,7 +73,6 @@ impl NodeRef {
 }
 
 /// Holds a strong reference to a node, but dereferences to some component inside of it.
-#[derive(Eq)]
 pub struct NodeDataRef<T> {
     /// Keeps the node alive while this reference exists.
     _keep_alive: NodeRef,
@@ -214,15 +215,15 @@ impl Deref for NodeDataRef<ElementData> {
     }
 }
 
-/// Implements Deref for NodeDataRef<RefCell<String>> (safe mode).
+/// Implements Deref for NodeDataRef<RefCell<StrTendril>> (safe mode).
 ///
 /// Provides safe access to text or comment node contents using runtime
 /// type discrimination.
 #[cfg(feature = "safe")]
-impl Deref for NodeDataRef<RefCell<String>> {
-    type Target = RefCell<String>;
+impl Deref for NodeDataRef<RefCell<StrTendril>> {
+    type Target = RefCell<StrTendril>;
     #[inline]
-    fn deref(&self) -> &RefCell<String> {
+    fn deref(&self) -> &RefCell<StrTendril> {
         match self._kind {
             NodeDataKind::Text => self
                 ._keep_alive
@@ -232,7 +233,7 @@ impl Deref for NodeDataRef<RefCell<String>> {
                 ._keep_alive
                 .as_comment()
                 .expect("NodeDataRef with Comment kind must contain comment"),
-            _ => unreachable!("NodeDataRef<RefCell<String>> must be Text or Comment"),
+            _ => unreachable!("NodeDataRef<RefCell<StrTendril>> must be Text or Comment"),
         }
     }
 }
@@ -306,6 +307,23 @@ impl<T> PartialEq for NodeDataRef<T> {
     }
 }
 
+/// Implements Eq for NodeDataRef.
+///
+/// Equality is pointer identity of the underlying node, which is reflexive.
+// #[derive(Eq)] would have an unnecessary `T: Eq` bound
+impl<T> Eq for NodeDataRef<T> {}
+
+/// Implements Hash for NodeDataRef.
+///
+/// Hashes the underlying NodeRef by pointer identity, consistently with
+/// `PartialEq`. Avoids requiring T: Hash.
+impl<T> Hash for NodeDataRef<T> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self._keep_alive.hash(state);
+    }
+}
+
 /// Implements Clone for NodeDataRef.
 ///
 /// Clones the NodeDataRef by cloning the underlying NodeRef and copying
@@ -356,11 +374,11 @@ impl fmt::Debug for NodeDataRef<ElementData> {
     }
 }
 
-/// Implements Debug for NodeDataRef<RefCell<String>> (safe mode).
+/// Implements Debug for NodeDataRef<RefCell<StrTendril>> (safe mode).
 ///
 /// Formats text or comment node contents for debugging.
 #[cfg(feature = "safe")]
-impl fmt::Debug for NodeDataRef<RefCell<String>> {
+impl fmt::Debug for NodeDataRef<RefCell<StrTendril>> {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
         fmt::Debug::fmt(&**self, f)
@@ -596,7 +614,7 @@ mod tests {
 
         let text_ref = text_node.into_text_ref();
         assert!(text_ref.is_some());
-        assert_eq!(&*text_ref.unwrap().borrow(), "text content");
+        assert_eq!(&**text_ref.unwrap().borrow(), "text content");
     }
 
     /// Tests into_text_ref with non-text node.
@@ -622,7 +640,7 @@ mod tests {
 
         let comment_ref = comment_node.into_comment_ref();
         assert!(comment_ref.is_some());
-        assert_eq!(&*comment_ref.unwrap().borrow(), " comment ");
+        assert_eq!(&**comment_ref.unwrap().borrow(), " comment ");
     }
 
     /// Tests into_comment_ref with non-comment node.
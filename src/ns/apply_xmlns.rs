@@ -28,6 +28,19 @@ pub struct NsOptions {
     /// - `true`: Returns `NsError::UndefinedPrefix` if any prefix is used but not defined
     /// - `false`: Assigns null namespace to undefined prefixes without error
     pub strict: bool,
+
+    /// Prefixes whose names are left untouched.
+    ///
+    /// Element and attribute names with one of these prefixes, such as
+    /// Angular's `ng:`, keep their literal name in their original namespace
+    /// and are never reported as undefined.
+    pub ignored_prefixes: HashSet<String>,
+
+    /// Namespace URIs to replace, mapping each old URI to its new one.
+    ///
+    /// Applied to every namespace resolved from a prefix, and to names that
+    /// were already in a namespace, such as SVG elements.
+    pub remap: HashMap<Namespace, Namespace>,
 }
 
 /// Applies xmlns namespace declarations to elements and attributes (lenient).
@@ -112,6 +125,7 @@ pub fn apply_xmlns(root: &NodeRef) -> NsResult<NodeRef> {
 /// let options = NsOptions {
 ///     namespaces,
 ///     strict: true,
+///     ..NsOptions::default()
 /// };
 ///
 /// match doc.apply_xmlns_opts(&options) {
@@ -128,7 +142,7 @@ pub fn apply_xmlns_opts(root: &NodeRef, options: &NsOptions) -> NsResult<NodeRef
 
     // Step 2: Rebuild the document tree with corrected namespaces
     let mut undefined_prefixes = HashSet::new();
-    let new_root = rebuild_tree(root, &xmlns_map, &mut undefined_prefixes);
+    let new_root = rebuild_tree(root, &xmlns_map, options, &mut undefined_prefixes);
 
     // Step 3: Return result based on strict mode and whether we found undefined prefixes
     if undefined_prefixes.is_empty() || !options.strict {
@@ -183,8 +197,8 @@ pub fn apply_xmlns_strict(root: &NodeRef) -> NsResult<NodeRef> {
     apply_xmlns_opts(
         root,
         &NsOptions {
-            namespaces: HashMap::new(),
             strict: true,
+            ..NsOptions::default()
         },
     )
 }
@@ -195,7 +209,7 @@ pub fn apply_xmlns_strict(root: &NodeRef) -> NsResult<NodeRef> {
 /// HTML xmlns declarations take precedence over options.namespaces when the same
 /// prefix appears in both.
 ///
-/// Returns a map from prefix to namespace URI.
+/// Returns a map from prefix to namespace URI, with `options.remap` applied.
 fn extract_xmlns_declarations(root: &NodeRef, options: &NsOptions) -> HashMap<String, Namespace> {
     // Start with options.namespaces as the base
     let mut xmlns_map = options.namespaces.clone();
@@ -213,7 +227,7 @@ fn extract_xmlns_declarations(root: &NodeRef, options: &NsOptions) -> HashMap<St
                     let local_str = expanded_name.local.as_ref();
                     if let Some(prefix) = local_str.strip_prefix("xmlns:") {
                         // HTML declarations override options
-                        xmlns_map.insert(prefix.to_string(), Namespace::from(attr.value.as_str()));
+                        xmlns_map.insert(prefix.to_string(), Namespace::from(&*attr.value));
                     }
                 }
                 break;
@@ -221,15 +235,67 @@ fn extract_xmlns_declarations(root: &NodeRef, options: &NsOptions) -> HashMap<St
         }
     }
 
+    // The xml prefix is bound by definition and cannot be redeclared
+    xmlns_map.insert("xml".to_string(), ns!(xml));
+    for namespace in xmlns_map.values_mut() {
+        *namespace = remap_namespace(namespace, options);
+    }
     xmlns_map
 }
 
+/// Returns the namespace `namespace` is remapped to, or a copy of it.
+fn remap_namespace(namespace: &Namespace, options: &NsOptions) -> Namespace {
+    options.remap.get(namespace).unwrap_or(namespace).clone()
+}
+
 /// Rebuilds the entire document tree with corrected namespace information.
 ///
 /// Creates new nodes with properly split and namespaced element/attribute names.
+/// The traversal uses an explicit worklist rather than recursion, so deeply
+/// nested documents cannot overflow the stack.
 fn rebuild_tree(
     node: &NodeRef,
     xmlns_map: &HashMap<String, Namespace>,
+    options: &NsOptions,
+    undefined_prefixes: &mut HashSet<String>,
+) -> NodeRef {
+    let new_root = rebuild_node(node, xmlns_map, options, undefined_prefixes);
+
+    // Each entry pairs an original node with the rebuilt node that receives
+    // copies of its children.
+    let mut worklist = vec![(node.clone(), new_root.clone())];
+    while let Some((original, rebuilt)) = worklist.pop() {
+        // The new_element call will have created its own template_contents
+        // (a DocumentFragment) if this is an HTML template element.
+        // Populate it with the rebuilt children of the original contents.
+        let template_contents = original
+            .as_element()
+            .and_then(|element| element.template_contents.clone())
+            .zip(
+                rebuilt
+                    .as_element()
+                    .and_then(|element| element.template_contents.clone()),
+            );
+        if let Some((contents, new_contents)) = template_contents {
+            worklist.push((contents, new_contents));
+        }
+
+        for child in original.children() {
+            let new_child = rebuild_node(&child, xmlns_map, options, undefined_prefixes);
+            rebuilt.append(new_child.clone());
+            worklist.push((child, new_child));
+        }
+    }
+
+    new_root
+}
+
+/// Rebuilds a single node with corrected namespace information, without its
+/// children or template contents.
+fn rebuild_node(
+    node: &NodeRef,
+    xmlns_map: &HashMap<String, Namespace>,
+    options: &NsOptions,
     undefined_prefixes: &mut HashSet<String>,
 ) -> NodeRef {
     use crate::tree::NodeData;
@@ -237,39 +303,15 @@ fn rebuild_tree(
     match node.data() {
         NodeData::Element(element) => {
             // Process element name
-            let new_name = process_qualified_name(&element.name, xmlns_map, undefined_prefixes);
+            let new_name =
+                process_qualified_name(&element.name, xmlns_map, options, undefined_prefixes);
 
             // Process attributes
             let attrs = element.attributes.borrow();
-            let new_attrs = process_attributes(&attrs, xmlns_map, undefined_prefixes);
+            let new_attrs = process_attributes(&attrs, xmlns_map, options, undefined_prefixes);
 
             // Create new element with corrected name and attributes
-            let new_node = NodeRef::new_element(new_name, new_attrs.map);
-
-            // Handle template contents (if this is an HTML <template> element)
-            if let Some(ref template_contents) = element.template_contents {
-                // The new_element will have created its own template_contents
-                // (a DocumentFragment) if it's an HTML template element.
-                // We need to populate it with the rebuilt children from the original.
-                if let Some(new_element) = new_node.as_element() {
-                    if let Some(ref new_template_frag) = new_element.template_contents {
-                        // Rebuild each child of the original template contents
-                        // and append to the new template's fragment
-                        for child in template_contents.children() {
-                            let new_child = rebuild_tree(&child, xmlns_map, undefined_prefixes);
-                            new_template_frag.append(new_child);
-                        }
-                    }
-                }
-            }
-
-            // Recursively rebuild children
-            for child in node.children() {
-                let new_child = rebuild_tree(&child, xmlns_map, undefined_prefixes);
-                new_node.append(new_child);
-            }
-
-            new_node
+            NodeRef::new_element(new_name, new_attrs.map)
         }
         NodeData::Text(text) => NodeRef::new_text(text.borrow().clone()),
         NodeData::Comment(comment) => NodeRef::new_comment(comment.borrow().clone()),
@@ -282,22 +324,8 @@ fn rebuild_tree(
             doctype.public_id.clone(),
             doctype.system_id.clone(),
         ),
-        NodeData::Document(_) => {
-            let new_doc = NodeRef::new_document();
-            for child in node.children() {
-                let new_child = rebuild_tree(&child, xmlns_map, undefined_prefixes);
-                new_doc.append(new_child);
-            }
-            new_doc
-        }
-        NodeData::DocumentFragment => {
-            let new_frag = NodeRef::new(NodeData::DocumentFragment);
-            for child in node.children() {
-                let new_child = rebuild_tree(&child, xmlns_map, undefined_prefixes);
-                new_frag.append(new_child);
-            }
-            new_frag
-        }
+        NodeData::Document(_) => NodeRef::new_document(),
+        NodeData::DocumentFragment => NodeRef::new(NodeData::DocumentFragment),
     }
 }
 
@@ -305,12 +333,16 @@ fn rebuild_tree(
 fn process_qualified_name(
     name: &QualName,
     xmlns_map: &HashMap<String, Namespace>,
+    options: &NsOptions,
     undefined_prefixes: &mut HashSet<String>,
 ) -> QualName {
     let local_str = name.local.as_ref();
 
-    // Check if the local name contains a colon (prefixed name)
-    if let Some(colon_pos) = local_str.find(':') {
+    // Check if the local name contains a colon (prefixed name) to be split
+    let split = local_str
+        .find(':')
+        .filter(|&colon_pos| !options.ignored_prefixes.contains(&local_str[..colon_pos]));
+    if let Some(colon_pos) = split {
         let prefix_str = &local_str[..colon_pos];
         let local_part = &local_str[colon_pos + 1..];
 
@@ -332,8 +364,12 @@ fn process_qualified_name(
             )
         }
     } else {
-        // No prefix - keep original name
-        name.clone()
+        // No prefix or an ignored one - keep original name
+        QualName::new(
+            name.prefix.clone(),
+            remap_namespace(&name.ns, options),
+            name.local.clone(),
+        )
     }
 }
 
@@ -341,6 +377,7 @@ fn process_qualified_name(
 fn process_attributes(
     attrs: &Attributes,
     xmlns_map: &HashMap<String, Namespace>,
+    options: &NsOptions,
     undefined_prefixes: &mut HashSet<String>,
 ) -> Attributes {
     let mut new_map = indexmap::IndexMap::new();
@@ -353,8 +390,11 @@ fn process_attributes(
             continue;
         }
 
-        // Check if the local name contains a colon (prefixed attribute)
-        if let Some(colon_pos) = local_str.find(':') {
+        // Check if the local name contains a colon (prefixed attribute) to be split
+        let split = local_str
+            .find(':')
+            .filter(|&colon_pos| !options.ignored_prefixes.contains(&local_str[..colon_pos]));
+        if let Some(colon_pos) = split {
             let prefix_str = &local_str[..colon_pos];
             let local_part = &local_str[colon_pos + 1..];
 
@@ -373,11 +413,14 @@ fn process_attributes(
                 Attribute {
                     prefix,
                     value: attr.value.clone(),
+                    source_name: attr.source_name.clone(),
                 },
             );
         } else {
-            // No prefix - keep original
-            new_map.insert(expanded_name.clone(), attr.clone());
+            // No prefix or an ignored one - keep original
+            let namespace = remap_namespace(&expanded_name.ns, options);
+            let new_expanded = ExpandedName::new(namespace, expanded_name.local.clone());
+            new_map.insert(new_expanded, attr.clone());
         }
     }
 
@@ -390,6 +433,66 @@ mod tests {
     use crate::parse_html;
     use crate::traits::*;
 
+    /// Tests that `xml:` attributes get the XML namespace.
+    ///
+    /// Verifies that `xml:lang` and `xml:space` are namespaced without a
+    /// declaration, even in strict mode, and still drive language resolution
+    /// and whitespace collapsing.
+    #[test]
+    fn xml_prefix_is_predeclared() {
+        let doc = parse_html()
+            .one(r#"<html lang=en><body><p lang=de xml:lang=fr xml:space=preserve> a  b </p>"#);
+        let options = NsOptions {
+            strict: true,
+            ..NsOptions::default()
+        };
+        let applied = doc.apply_xmlns_opts(&options).unwrap();
+        let p = applied.select_first("p").unwrap();
+        {
+            let attributes = p.attributes.borrow();
+            let lang = ExpandedName::new(ns!(xml), local_name!("lang"));
+            assert_eq!(attributes.map[&lang].prefix.as_deref(), Some("xml"));
+        }
+        assert_eq!(p.as_node().language().as_deref(), Some("fr"));
+        applied.collapse_whitespace();
+        assert_eq!(p.text_contents(), " a  b ");
+    }
+
+    /// Tests ignored prefixes and namespace remapping.
+    ///
+    /// Verifies that names with an ignored prefix stay unsplit and are not
+    /// reported in strict mode, and that remapped URIs replace both
+    /// declared and parser-assigned namespaces.
+    #[test]
+    fn ignored_prefixes_and_remap() {
+        let html = r#"<html xmlns:c="urn:old"><body>
+            <c:widget ng:if="x"></c:widget><ng:view></ng:view><svg></svg>
+        </body></html>"#;
+        let doc = parse_html().one(html);
+        let options = NsOptions {
+            strict: true,
+            ignored_prefixes: HashSet::from(["ng".to_string()]),
+            remap: HashMap::from([
+                (Namespace::from("urn:old"), Namespace::from("urn:new")),
+                (ns!(svg), Namespace::from("urn:svg2")),
+            ]),
+            ..NsOptions::default()
+        };
+        let applied = doc.apply_xmlns_opts(&options).unwrap();
+
+        let widget = applied.select_first("widget").unwrap();
+        assert_eq!(&*widget.name.ns, "urn:new");
+        assert_eq!(widget.attributes.borrow().get("ng:if"), Some("x"));
+        let view = applied
+            .descendants()
+            .elements()
+            .find(|element| &*element.name.local == "ng:view")
+            .unwrap();
+        assert_eq!(view.name.prefix, None);
+        let svg = applied.select_first("svg").unwrap();
+        assert_eq!(&*svg.name.ns, "urn:svg2");
+    }
+
     /// Tests applying xmlns to a document with defined namespaces.
     ///
     /// Verifies that elements with prefixes get properly namespaced when
@@ -448,8 +551,8 @@ mod tests {
 
         let doc = parse_html().one(html);
         let options = NsOptions {
-            namespaces: HashMap::new(),
             strict: true,
+            ..NsOptions::default()
         };
         let err = apply_xmlns_opts(&doc, &options)
             .expect_err("Should return error for undefined prefixes");
@@ -519,6 +622,7 @@ mod tests {
         let options = NsOptions {
             namespaces,
             strict: false,
+            ..NsOptions::default()
         };
 
         let result = apply_xmlns_opts(&doc, &options).unwrap();
@@ -561,6 +665,7 @@ mod tests {
         let options = NsOptions {
             namespaces,
             strict: false,
+            ..NsOptions::default()
         };
 
         let result = apply_xmlns_opts(&doc, &options).unwrap();
@@ -692,12 +797,12 @@ mod tests {
 
             for (name, attr) in &attrs.map {
                 if name.local.as_ref() == "id" && name.ns.as_ref() == "https://example.com/data" {
-                    assert_eq!(attr.value, "123");
+                    assert_eq!(&*attr.value, "123");
                     assert_eq!(attr.prefix.as_ref().unwrap().as_ref(), "data");
                     found_id = true;
                 }
                 if name.local.as_ref() == "type" && name.ns.as_ref() == "https://example.com/data" {
-                    assert_eq!(attr.value, "widget");
+                    assert_eq!(&*attr.value, "widget");
                     assert_eq!(attr.prefix.as_ref().unwrap().as_ref(), "data");
                     found_type = true;
                 }
@@ -837,7 +942,7 @@ mod tests {
                 // Check that children were preserved
                 for child in node.children() {
                     if let Some(text) = child.as_text() {
-                        assert_eq!(text.borrow().as_str(), "Fragment content");
+                        assert_eq!(&**text.borrow(), "Fragment content");
                         found_text = true;
                     }
                 }
@@ -873,4 +978,46 @@ mod tests {
                 .any(|(name, _)| { name.local.as_ref().starts_with("xmlns:") }));
         }
     }
+
+    /// Tests applying xmlns to a pathologically deep document.
+    ///
+    /// Edge case: 100,000 nested prefixed elements are rebuilt without
+    /// overflowing the stack, and every level is namespaced.
+    #[test]
+    fn apply_xmlns_deep_tree() {
+        let root = NodeRef::new_document();
+        let mut parent = root.clone();
+        for _ in 0..100_000 {
+            let child = NodeRef::new_element(QualName::new(None, ns!(html), "c:node".into()), None);
+            parent.append(child.clone());
+            parent = child;
+        }
+        parent.append(NodeRef::new_text("leaf"));
+
+        let mut namespaces = HashMap::new();
+        namespaces.insert(
+            "c".to_string(),
+            Namespace::from("https://example.com/custom"),
+        );
+        let options = NsOptions {
+            namespaces,
+            strict: true,
+            ..NsOptions::default()
+        };
+        let result = apply_xmlns_opts(&root, &options).unwrap();
+
+        let mut depth = 0;
+        let mut node = result.first_child();
+        while let Some(element) = node {
+            let data = element.as_element().unwrap();
+            assert_eq!(&*data.name.local, "node");
+            assert_eq!(&*data.name.ns, "https://example.com/custom");
+            depth += 1;
+            node = element
+                .first_child()
+                .filter(|child| child.as_element().is_some());
+        }
+        assert_eq!(depth, 100_000);
+        assert_eq!(result.text_contents(), "leaf");
+    }
 }
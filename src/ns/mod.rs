@@ -34,6 +34,7 @@
 //! let options = NsOptions {
 //!     namespaces,
 //!     strict: false,
+//!     ..NsOptions::default()
 //! };
 //!
 //! // Apply namespace processing
@@ -58,6 +59,8 @@ mod apply_xmlns;
 pub mod defaults;
 /// Error types for namespace operations.
 mod error;
+/// Namespace lookup for NodeRef.
+mod node_ref_lookup_namespace;
 
 #[allow(deprecated)]
 pub use apply_xmlns::{apply_xmlns, apply_xmlns_opts, apply_xmlns_strict, NsOptions};
@@ -0,0 +1,172 @@
+//! Resolution of namespace prefixes in scope at a node.
+
+use crate::attributes::ExpandedName;
+use crate::tree::NodeRef;
+use html5ever::{ns, Namespace, Prefix};
+
+/// Namespace lookup methods for NodeRef.
+///
+/// Provides the equivalents of the DOM `lookupNamespaceURI()` and
+/// `lookupPrefix()` methods.
+impl NodeRef {
+    /// Returns the namespace bound to `prefix` at this node, or to the
+    /// default namespace if `prefix` is `None` or empty.
+    ///
+    /// This node and its ancestor elements are searched, nearest first. An
+    /// element binds its own prefix to its namespace, as it does after
+    /// [`apply_xmlns()`](crate::ns::apply_xmlns), and binds prefixes with
+    /// `xmlns:prefix` and `xmlns` attributes, whether parsed as HTML or as
+    /// foreign content. An empty declaration unbinds the prefix. The `xml`
+    /// and `xmlns` prefixes are always bound.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::parse_html;
+    /// use brik::traits::*;
+    ///
+    /// let doc = parse_html().one(r#"<div xmlns:c="urn:c"><p>Text</p></div>"#);
+    /// let p = doc.select_first("p").unwrap();
+    ///
+    /// let namespace = p.as_node().lookup_namespace_uri(Some("c")).unwrap();
+    /// assert_eq!(&*namespace, "urn:c");
+    /// assert!(p.as_node().lookup_namespace_uri(Some("d")).is_none());
+    /// ```
+    pub fn lookup_namespace_uri(&self, prefix: Option<&str>) -> Option<Namespace> {
+        let prefix = prefix.filter(|prefix| !prefix.is_empty());
+        match prefix {
+            Some("xml") => return Some(ns!(xml)),
+            Some("xmlns") => return Some(ns!(xmlns)),
+            _ => {}
+        }
+        for node in self.inclusive_ancestors() {
+            let Some(element) = node.as_element() else {
+                continue;
+            };
+            if element.name.ns != ns!() && element.name.prefix.as_deref() == prefix {
+                return Some(element.name.ns.clone());
+            }
+            let attributes = element.attributes.borrow();
+            let declaration = attributes
+                .map
+                .iter()
+                .find(|(name, _)| declared_prefix(name) == Some(prefix));
+            if let Some((_, attribute)) = declaration {
+                return match &*attribute.value {
+                    "" => None,
+                    value => Some(Namespace::from(value)),
+                };
+            }
+        }
+        None
+    }
+
+    /// Returns a prefix bound to `namespace` at this node.
+    ///
+    /// This node and its ancestor elements are searched, nearest first, as
+    /// in [`NodeRef::lookup_namespace_uri()`]. Default namespace
+    /// declarations have no prefix and are not considered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::parse_html;
+    /// use brik::traits::*;
+    /// use brik::Namespace;
+    ///
+    /// let doc = parse_html().one(r#"<div xmlns:c="urn:c"><p>Text</p></div>"#);
+    /// let p = doc.select_first("p").unwrap();
+    ///
+    /// let prefix = p.as_node().lookup_prefix(&Namespace::from("urn:c")).unwrap();
+    /// assert_eq!(&*prefix, "c");
+    /// ```
+    pub fn lookup_prefix(&self, namespace: &Namespace) -> Option<Prefix> {
+        if *namespace == ns!() {
+            return None;
+        }
+        for node in self.inclusive_ancestors() {
+            let Some(element) = node.as_element() else {
+                continue;
+            };
+            if element.name.ns == *namespace {
+                if let Some(prefix) = &element.name.prefix {
+                    return Some(prefix.clone());
+                }
+            }
+            let attributes = element.attributes.borrow();
+            let declaration = attributes
+                .map
+                .iter()
+                .filter(|(_, attribute)| *attribute.value == **namespace)
+                .find_map(|(name, _)| declared_prefix(name).flatten());
+            if let Some(prefix) = declaration {
+                return Some(Prefix::from(prefix));
+            }
+        }
+        None
+    }
+}
+
+/// Returns the prefix declared by an attribute, `Some(None)` for a default
+/// namespace declaration, or `None` if it is not a declaration.
+///
+/// Declarations parsed as HTML keep the whole name in the null namespace,
+/// while those in foreign content are put in the `xmlns` namespace.
+fn declared_prefix(name: &ExpandedName) -> Option<Option<&str>> {
+    let local: &str = &name.local;
+    if name.ns == ns!(xmlns) {
+        return Some((local != "xmlns").then_some(local));
+    }
+    if name.ns != ns!() {
+        return None;
+    }
+    match local {
+        "xmlns" => Some(None),
+        _ => local.strip_prefix("xmlns:").map(Some),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::html5ever::tendril::TendrilSink;
+    use crate::parse_html;
+    use html5ever::{ns, Namespace};
+
+    /// Tests lookups in foreign content and after applying xmlns.
+    ///
+    /// Verifies that declarations parsed into the `xmlns` namespace are
+    /// found, that nearer declarations win, and that elements split by
+    /// `apply_xmlns()` bind their own prefixes.
+    #[test]
+    fn lookup_scopes() {
+        let doc = parse_html().one(
+            r#"<html xmlns:c="urn:outer"><body>
+            <svg xmlns:xlink="http://www.w3.org/1999/xlink"><rect/></svg>
+            <div xmlns:c="urn:inner"><p></p></div>
+            <c:widget></c:widget></body></html>"#,
+        );
+        let rect = doc.select_first("rect").unwrap();
+        let rect = rect.as_node();
+        assert_eq!(rect.lookup_namespace_uri(Some("xlink")), Some(ns!(xlink)));
+        assert_eq!(rect.lookup_namespace_uri(None), Some(ns!(svg)));
+        assert_eq!(rect.lookup_prefix(&ns!(xlink)).as_deref(), Some("xlink"));
+
+        let p = doc.select_first("p").unwrap();
+        let inner = p.as_node().lookup_namespace_uri(Some("c"));
+        assert_eq!(inner, Some(Namespace::from("urn:inner")));
+        assert!(p.as_node().lookup_prefix(&ns!(svg)).is_none());
+
+        let applied = doc.apply_xmlns().unwrap();
+        let widget = applied.select_first("widget").unwrap();
+        let text = crate::NodeRef::new_text("x");
+        widget.as_node().append(text.clone());
+        assert_eq!(
+            text.lookup_namespace_uri(Some("c")),
+            Some(Namespace::from("urn:outer"))
+        );
+        assert_eq!(
+            text.lookup_prefix(&Namespace::from("urn:outer")).as_deref(),
+            Some("c")
+        );
+    }
+}
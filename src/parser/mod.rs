@@ -3,12 +3,30 @@
 //! This module provides HTML parsing using html5ever, with support for both
 //! full document and fragment parsing modes.
 
+mod node_ref_reparse;
+pub mod parse_diagnostic;
+pub mod parse_diagnostics;
 pub mod parse_fragment;
 pub mod parse_html;
 pub mod parse_opts;
+pub mod parse_severity;
 pub mod sink;
+mod source_attribute_names;
+mod source_cdata_sections;
+mod source_markup;
 
-pub use parse_fragment::{parse_fragment, parse_fragment_with_options};
-pub use parse_html::{parse_html, parse_html_with_options};
+pub use parse_diagnostic::ParseDiagnostic;
+pub use parse_diagnostics::ParseDiagnostics;
+pub use parse_fragment::{
+    parse_fragment, parse_fragment_in, parse_fragment_in_with_options, parse_fragment_nodes,
+    parse_fragment_nodes_with_options, parse_fragment_with_options,
+};
+pub use parse_html::{
+    parse_html, parse_html_str_with_options, parse_html_strict, parse_html_strict_with_options,
+    parse_html_with_options,
+};
 pub use parse_opts::ParseOpts;
+pub use parse_severity::ParseSeverity;
 pub use sink::Sink;
+
+pub(crate) use node_ref_reparse::parse_in_context;
@@ -0,0 +1,204 @@
+//! In-place re-parsing of subtrees.
+
+use super::parse_fragment_nodes;
+use crate::tree::{NodeData, NodeRef};
+use html5ever::{Attribute, QualName};
+
+/// Re-parsing methods for NodeRef.
+///
+/// Provides replacement of a subtree with freshly parsed markup.
+impl NodeRef {
+    /// Replaces the children of this node with `html`, parsed as the
+    /// contents of this element.
+    ///
+    /// The element's name and attributes are the parsing context, so table
+    /// rows stay rows inside a `tbody` and elements inside an `svg` get the
+    /// SVG namespace. Other nodes use a `body` context. For a `template`,
+    /// its contents are replaced instead of its children.
+    ///
+    /// Returns `(old, new)` pairs mapping the replaced nodes to the parsed
+    /// nodes at the same position, for callers holding references into the
+    /// old subtree. Nodes are paired by position among their siblings and
+    /// only when they have the same type and, for elements, the same name;
+    /// the children of unpaired nodes are not paired.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::parse_html;
+    /// use brik::traits::*;
+    ///
+    /// let doc = parse_html().one("<table><tbody><tr><td>old</td></tr></tbody></table>");
+    /// let tbody = doc.select_first("tbody").unwrap();
+    /// let old_cell = doc.select_first("td").unwrap();
+    ///
+    /// let mapping = tbody.as_node().reparse_inner("<tr><td>new</td></tr>");
+    ///
+    /// let (_, new_cell) = mapping
+    ///     .iter()
+    ///     .find(|(old, _)| *old == *old_cell.as_node())
+    ///     .unwrap();
+    /// assert_eq!(new_cell.text_contents(), "new");
+    /// assert_eq!(new_cell.parent().unwrap().parent().unwrap(), *tbody.as_node());
+    /// ```
+    pub fn reparse_inner(&self, html: &str) -> Vec<(NodeRef, NodeRef)> {
+        let fragment = parse_in_context(self, html);
+        let container = self
+            .as_element()
+            .and_then(|element| element.template_contents.clone())
+            .unwrap_or_else(|| self.clone());
+        let old = container.splice_children(.., fragment.children().collect::<Vec<_>>());
+        pair_nodes(old, container.children().collect())
+    }
+
+    /// Replaces this node with `html`, parsed as the contents of its parent.
+    ///
+    /// Works like [`reparse_inner()`](NodeRef::reparse_inner), with the
+    /// parent as the parsing context; this node is detached and the parsed
+    /// nodes take its place. The returned pairs map this node and its
+    /// descendants to the parsed nodes at the same position.
+    ///
+    /// Does nothing and returns no pairs if this node has no parent.
+    pub fn reparse_outer(&self, html: &str) -> Vec<(NodeRef, NodeRef)> {
+        let parent = match self.parent() {
+            Some(parent) => parent,
+            None => return Vec::new(),
+        };
+        let fragment = parse_in_context(&parent, html);
+        let new: Vec<NodeRef> = fragment.children().collect();
+        for node in &new {
+            self.insert_before(node.clone());
+        }
+        self.detach();
+        pair_nodes(vec![self.clone()], new)
+    }
+}
+
+/// Parses `html` in the context of `node`, returning a document fragment.
+pub(crate) fn parse_in_context(node: &NodeRef, html: &str) -> NodeRef {
+    let (name, attributes) = match node.as_element() {
+        Some(element) => (
+            element.name.clone(),
+            element
+                .attributes
+                .borrow()
+                .map
+                .iter()
+                .map(|(name, attribute)| Attribute {
+                    name: QualName::new(
+                        attribute.prefix.clone(),
+                        name.ns.clone(),
+                        name.local.clone(),
+                    ),
+                    value: attribute.value.clone(),
+                })
+                .collect(),
+        ),
+        None => (
+            QualName::new(None, ns!(html), local_name!("body")),
+            Vec::new(),
+        ),
+    };
+    parse_fragment_nodes(name, attributes, html)
+}
+
+/// Pairs old nodes with new nodes at the same position, descending into
+/// the children and template contents of each pair.
+fn pair_nodes(old: Vec<NodeRef>, new: Vec<NodeRef>) -> Vec<(NodeRef, NodeRef)> {
+    let mut pairs = Vec::new();
+    let mut pending: Vec<(NodeRef, NodeRef)> = old.into_iter().zip(new).collect();
+    pending.reverse();
+    while let Some((old, new)) = pending.pop() {
+        if !same_kind(old.data(), new.data()) {
+            continue;
+        }
+        let children: Vec<(NodeRef, NodeRef)> = old.children().zip(new.children()).collect();
+        pending.extend(children.into_iter().rev());
+        let contents = |node: &NodeRef| {
+            node.as_element()
+                .and_then(|element| element.template_contents.clone())
+        };
+        if let (Some(old_contents), Some(new_contents)) = (contents(&old), contents(&new)) {
+            pending.push((old_contents, new_contents));
+        }
+        pairs.push((old, new));
+    }
+    pairs
+}
+
+/// Returns whether two nodes have the same type and, for elements, name.
+fn same_kind(old: &NodeData, new: &NodeData) -> bool {
+    match (old, new) {
+        (NodeData::Element(old), NodeData::Element(new)) => old.name == new.name,
+        (NodeData::Text(_), NodeData::Text(_))
+        | (NodeData::Comment(_), NodeData::Comment(_))
+        | (NodeData::ProcessingInstruction(_), NodeData::ProcessingInstruction(_))
+        | (NodeData::Doctype(_), NodeData::Doctype(_))
+        | (NodeData::DocumentFragment, NodeData::DocumentFragment) => true,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::html5ever::tendril::TendrilSink;
+    use crate::parse_html;
+
+    /// Tests re-parsing an element's children.
+    ///
+    /// Verifies that the new markup replaces the old children, and that
+    /// old nodes are paired with new nodes of the same kind by position.
+    #[test]
+    fn reparse_inner_pairs_nodes() {
+        let doc = parse_html().one("<ul><li>a</li><li>b<b>!</b></li></ul>");
+        let ul = doc.select_first("ul").unwrap();
+        let old_items: Vec<_> = doc.select("li").unwrap().collect();
+        let old_bold = doc.select_first("b").unwrap();
+
+        let pairs = ul
+            .as_node()
+            .reparse_inner("<li>x</li><li>y<i>?</i></li><li>z</li>");
+
+        assert_eq!(
+            ul.as_node().to_string(),
+            "<ul><li>x</li><li>y<i>?</i></li><li>z</li></ul>"
+        );
+        let mapped = |old: &crate::NodeRef| {
+            pairs
+                .iter()
+                .find(|(from, _)| from == old)
+                .map(|(_, to)| to.to_string())
+        };
+        assert_eq!(
+            mapped(old_items[0].as_node()).as_deref(),
+            Some("<li>x</li>")
+        );
+        assert_eq!(
+            mapped(old_items[1].as_node()).as_deref(),
+            Some("<li>y<i>?</i></li>")
+        );
+        assert_eq!(mapped(old_bold.as_node()), None);
+        assert!(old_items[0].as_node().parent().is_none());
+    }
+
+    /// Tests re-parsing an element in its parent's context.
+    ///
+    /// Edge case: a table row is parsed in its `tbody` context, so it is
+    /// kept instead of being dropped as it would be in a `body` context.
+    #[test]
+    fn reparse_outer_uses_parent_context() {
+        let doc = parse_html().one("<table><tr id=a><td>1</td></tr><tr><td>2</td></tr></table>");
+        let row = doc.select_first("#a").unwrap();
+
+        let pairs = row.as_node().reparse_outer("<tr id=b><td>3</td></tr>");
+
+        let tbody = doc.select_first("tbody").unwrap();
+        assert_eq!(
+            tbody.as_node().to_string(),
+            "<tbody><tr id=\"b\"><td>3</td></tr><tr><td>2</td></tr></tbody>"
+        );
+        assert_eq!(pairs.len(), 3, "row, cell and text");
+        assert_eq!(pairs[0].0, *row.as_node());
+        assert!(row.as_node().parent().is_none());
+    }
+}
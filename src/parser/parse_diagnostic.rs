@@ -0,0 +1,150 @@
+//! Parse diagnostics collected during parsing.
+
+use super::ParseSeverity;
+use std::borrow::Cow;
+
+/// Message prefixes of the errors reported by the tokenizer.
+const LEXICAL_PREFIXES: &[&str] = &[
+    "Attributes on an end tag",
+    "Bad character",
+    "Duplicate attribute",
+    "EOF after '#' in character reference",
+    "EOF in numeric character reference",
+    "Invalid character reference",
+    "Invalid numeric character reference",
+    "Numeric character reference",
+    "Saw ",
+    "Self-closing end tag",
+    "Semicolon missing after character reference",
+    "Unacknowledged self-closing tag",
+    "Unexpected EOF",
+];
+
+/// A parse error recorded on the document when
+/// [`ParseOpts::collect_diagnostics`](super::ParseOpts::collect_diagnostics)
+/// is set.
+///
+/// Retrieve them with
+/// [`DocumentData::parse_diagnostics()`](crate::DocumentData::parse_diagnostics).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseDiagnostic {
+    /// The message reported by the parser.
+    pub message: Cow<'static, str>,
+    /// How serious the problem is.
+    pub severity: ParseSeverity,
+    /// The offending token or character, when the message names one.
+    ///
+    /// Messages only name tokens when the tokenizer's or tree builder's
+    /// `exact_errors` option is enabled.
+    pub token: Option<String>,
+}
+
+/// Methods for ParseDiagnostic.
+///
+/// Provides classification of raw parser messages.
+impl ParseDiagnostic {
+    /// Classifies a parser message into a diagnostic.
+    pub(super) fn new(message: Cow<'static, str>) -> ParseDiagnostic {
+        let severity = if LEXICAL_PREFIXES
+            .iter()
+            .any(|prefix| message.starts_with(prefix))
+        {
+            ParseSeverity::Warning
+        } else {
+            ParseSeverity::Error
+        };
+        let token = offending_token(&message);
+        ParseDiagnostic {
+            message,
+            severity,
+            token,
+        }
+    }
+}
+
+/// Extracts the offending token or character named in an exact message.
+///
+/// Tag tokens are rendered as markup, such as `</i>`; other tokens are
+/// returned as the parser formatted them.
+fn offending_token(message: &str) -> Option<String> {
+    if let Some(rest) = message.strip_prefix("Unexpected token ") {
+        let (token, _) = rest.rsplit_once(" in insertion mode ")?;
+        return Some(tag_markup(token).unwrap_or_else(|| token.to_string()));
+    }
+    if let Some(rest) = message.strip_prefix("Saw ") {
+        return rest
+            .rsplit_once(" in state ")
+            .map(|(token, _)| token.to_string());
+    }
+    message
+        .strip_prefix("Invalid character reference ")
+        .or_else(|| message.strip_prefix("Bad character "))
+        .map(str::to_string)
+}
+
+/// Renders a debug-formatted tag token, like
+/// `Tag { kind: EndTag, name: Atom('i' type=inline), .. }`, as markup.
+fn tag_markup(token: &str) -> Option<String> {
+    let rest = token.strip_prefix("Tag { kind: ")?;
+    let (kind, rest) = rest.split_once(',')?;
+    let name = rest.split_once("Atom(")?.1.trim_start_matches(['\\', '\'']);
+    let name = &name[..name.find(['\\', '\''])?];
+    match kind {
+        "StartTag" => Some(format!("<{}>", name)),
+        "EndTag" => Some(format!("</{}>", name)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::{parse_html_with_options, ParseOpts, ParseSeverity};
+    use crate::traits::*;
+
+    /// Tests collecting diagnostics from a malformed document.
+    ///
+    /// Verifies that lexical and tree construction errors are classified,
+    /// that exact messages yield the offending token, and that the user
+    /// callback still receives every message.
+    #[test]
+    fn collects_classified_diagnostics() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let seen = Rc::new(RefCell::new(0));
+        let counter = Rc::clone(&seen);
+        let mut opts = ParseOpts {
+            collect_diagnostics: true,
+            on_parse_error: Some(Box::new(move |_| *counter.borrow_mut() += 1)),
+            ..ParseOpts::default()
+        };
+        opts.tokenizer.exact_errors = true;
+        opts.tree_builder.exact_errors = true;
+
+        let doc = parse_html_with_options(opts).one("<!DOCTYPE html></i><p a=1 a=2>");
+        let document = doc.as_document().unwrap();
+        let diagnostics = document.parse_diagnostics();
+
+        assert_eq!(diagnostics.len(), *seen.borrow());
+        let duplicate = diagnostics
+            .iter()
+            .find(|d| d.message == "Duplicate attribute")
+            .unwrap();
+        assert_eq!(duplicate.severity, ParseSeverity::Warning);
+        let stray = diagnostics
+            .iter()
+            .find(|d| d.message.starts_with("Unexpected token"))
+            .unwrap();
+        assert_eq!(stray.severity, ParseSeverity::Error);
+        assert_eq!(stray.token.as_deref(), Some("</i>"));
+    }
+
+    /// Tests that diagnostics are not collected by default.
+    ///
+    /// Edge case: the document records nothing unless collection is enabled.
+    #[test]
+    fn diagnostics_off_by_default() {
+        let doc = crate::parse_html().one("<p a=1 a=2></i>");
+        assert!(doc.as_document().unwrap().parse_diagnostics().is_empty());
+    }
+}
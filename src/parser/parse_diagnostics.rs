@@ -0,0 +1,39 @@
+//! Errors from strict parsing.
+
+use super::ParseDiagnostic;
+use std::error::Error;
+use std::fmt;
+
+/// The parse errors that made strict parsing fail.
+///
+/// Returned by [`parse_html_strict()`](super::parse_html_strict) and
+/// [`parse_html_strict_with_options()`](super::parse_html_strict_with_options)
+/// when the parser reports at least one error.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseDiagnostics {
+    /// The reported errors, in input order. Never empty.
+    pub diagnostics: Vec<ParseDiagnostic>,
+}
+
+/// Implements Display for ParseDiagnostics.
+///
+/// Reports the number of errors and the first message.
+impl fmt::Display for ParseDiagnostics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.diagnostics.first() {
+            Some(first) if self.diagnostics.len() > 1 => write!(
+                f,
+                "{} parse errors, first: {}",
+                self.diagnostics.len(),
+                first.message
+            ),
+            Some(first) => write!(f, "parse error: {}", first.message),
+            None => write!(f, "no parse errors"),
+        }
+    }
+}
+
+/// Implements Error for ParseDiagnostics.
+///
+/// Allows the error to be used with `?` and boxed errors.
+impl Error for ParseDiagnostics {}
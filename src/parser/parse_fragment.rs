@@ -1,9 +1,14 @@
 //! HTML fragment parsing functions.
 
+use super::source_attribute_names::record_source_attribute_names;
+use super::source_cdata_sections::parse_marking_cdata;
+use super::source_markup::record_source_markup;
 use super::{ParseOpts, Sink};
-use crate::tree::NodeRef;
-use html5ever::{Attribute, QualName};
-use std::cell::RefCell;
+use crate::tree::{NodeData, NodeRef};
+use html5ever::tendril::TendrilSink;
+use html5ever::tokenizer::TokenizerOpts;
+use html5ever::tree_builder::{create_element, TreeBuilder};
+use html5ever::{Attribute, LocalName, Namespace, QualName};
 
 /// Parse an HTML fragment with html5ever and the default configuration.
 ///
@@ -36,21 +41,155 @@ pub fn parse_fragment_with_options(
     ctx_name: QualName,
     ctx_attr: Vec<Attribute>,
 ) -> html5ever::Parser<Sink> {
-    let sink = Sink {
-        document_node: NodeRef::new_document(),
-        on_parse_error: RefCell::new(opts.on_parse_error),
-    };
-    let html5opts = html5ever::ParseOpts {
-        tokenizer: opts.tokenizer,
-        tree_builder: opts.tree_builder,
-    };
+    let (sink, html5opts) = Sink::with_options(opts);
     html5ever::parse_fragment(sink, html5opts, ctx_name, ctx_attr, false)
 }
 
+/// Parse an HTML fragment and return just its top-level nodes.
+///
+/// Unlike [`parse_fragment`], which returns a document whose parsed nodes sit
+/// under a generated `html` element, this returns a document fragment node
+/// whose children are the parsed top-level nodes, ready to be appended elsewhere.
+///
+/// # Examples
+///
+/// ```
+/// use brik::parse_fragment_nodes;
+/// use brik::traits::*;
+///
+/// # #[macro_use] extern crate html5ever;
+/// # fn main() {
+/// let ctx_name = html5ever::QualName::new(None, ns!(html), local_name!("body"));
+/// let fragment = parse_fragment_nodes(ctx_name, vec![], "<p>One</p>text<p>Two</p>");
+/// assert_eq!(fragment.children().count(), 3);
+///
+/// let document = brik::parse_html().one("<div id='target'></div>");
+/// let target = document.select_first("#target").unwrap();
+/// for node in fragment.children() {
+///     target.as_node().append(node);
+/// }
+/// assert_eq!(target.as_node().to_string(), r#"<div id="target"><p>One</p>text<p>Two</p></div>"#);
+/// # }
+/// ```
+pub fn parse_fragment_nodes(ctx_name: QualName, ctx_attr: Vec<Attribute>, html: &str) -> NodeRef {
+    parse_fragment_nodes_with_options(ParseOpts::default(), ctx_name, ctx_attr, html)
+}
+
+/// Parse an HTML fragment with custom configuration and return just its top-level nodes.
+///
+/// See [`parse_fragment_nodes`].
+pub fn parse_fragment_nodes_with_options(
+    opts: ParseOpts,
+    ctx_name: QualName,
+    ctx_attr: Vec<Attribute>,
+    html: &str,
+) -> NodeRef {
+    let preserve_attribute_names = opts.preserve_attribute_names;
+    let preserve_cdata = opts.preserve_cdata;
+    let preserve_source = opts.preserve_source;
+    let document = if preserve_cdata {
+        let (sink, html5opts) = Sink::with_options(opts);
+        let context = create_element(&sink, ctx_name, ctx_attr);
+        let builder = TreeBuilder::new_for_fragment(sink, context, None, html5opts.tree_builder);
+        let tokenizer = TokenizerOpts {
+            initial_state: Some(builder.tokenizer_state_for_context_elem(false)),
+            ..html5opts.tokenizer
+        };
+        parse_marking_cdata(builder, tokenizer, html)
+    } else {
+        parse_fragment_with_options(opts, ctx_name, ctx_attr).one(html)
+    };
+    let fragment = NodeRef::new(NodeData::DocumentFragment);
+    // The tree builder puts the parsed nodes under a single generated root element.
+    if let Some(root) = document.first_child() {
+        for child in root.children().collect::<Vec<_>>() {
+            fragment.append(child);
+        }
+    }
+    if preserve_attribute_names {
+        record_source_attribute_names(&fragment, html);
+    }
+    if preserve_source {
+        record_source_markup(&fragment, html);
+    }
+    fragment
+}
+
+/// Parse an HTML fragment as if it were the contents of the given context element.
+///
+/// This is a convenience over [`parse_fragment`] that builds the context name
+/// and attributes. The context decides how the tree builder treats the input:
+/// table rows are kept inside a `tr` context, options inside `select`, and
+/// elements inside an SVG context get the SVG namespace.
+///
+/// As with [`parse_fragment`], the parsed nodes are the children of the `html`
+/// element under the returned document node.
+///
+/// # Examples
+///
+/// ```
+/// use brik::{parse_fragment_in, Namespace};
+/// use brik::traits::*;
+///
+/// let html = Namespace::from("http://www.w3.org/1999/xhtml");
+/// let document = parse_fragment_in("tr", html, [("class", "row")]).one("<td>A</td><td>B</td>");
+/// assert_eq!(document.select("td").unwrap().count(), 2);
+///
+/// let svg = Namespace::from("http://www.w3.org/2000/svg");
+/// let document = parse_fragment_in("g", svg, [("id", "layer")]).one("<rect/>");
+/// let rect = document.select_first("rect").unwrap();
+/// assert_eq!(rect.name.ns.as_ref(), "http://www.w3.org/2000/svg");
+/// ```
+pub fn parse_fragment_in<L, N, I, A, V>(
+    context_local_name: L,
+    context_ns: N,
+    context_attrs: I,
+) -> html5ever::Parser<Sink>
+where
+    L: Into<LocalName>,
+    N: Into<Namespace>,
+    I: IntoIterator<Item = (A, V)>,
+    A: Into<LocalName>,
+    V: Into<String>,
+{
+    parse_fragment_in_with_options(
+        ParseOpts::default(),
+        context_local_name,
+        context_ns,
+        context_attrs,
+    )
+}
+
+/// Parse an HTML fragment in the given context element with custom configuration.
+///
+/// See [`parse_fragment_in`].
+pub fn parse_fragment_in_with_options<L, N, I, A, V>(
+    opts: ParseOpts,
+    context_local_name: L,
+    context_ns: N,
+    context_attrs: I,
+) -> html5ever::Parser<Sink>
+where
+    L: Into<LocalName>,
+    N: Into<Namespace>,
+    I: IntoIterator<Item = (A, V)>,
+    A: Into<LocalName>,
+    V: Into<String>,
+{
+    let ctx_name = QualName::new(None, context_ns.into(), context_local_name.into());
+    let ctx_attr = context_attrs
+        .into_iter()
+        .map(|(name, value)| Attribute {
+            name: QualName::new(None, ns!(), name.into()),
+            value: value.into().into(),
+        })
+        .collect();
+    parse_fragment_with_options(opts, ctx_name, ctx_attr)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::traits::*;
     use html5ever::tree_builder::QuirksMode;
 
     /// Tests parsing an HTML fragment with a specific context.
@@ -72,4 +211,66 @@ mod tests {
             r"<html><tr><td>Test case</td></tr></html>"
         );
     }
+
+    /// Tests fragment parsing in contexts that change tree construction.
+    ///
+    /// Verifies that cells stay in a `tr` context and options stay in a
+    /// `select` context instead of being dropped or relocated.
+    #[test]
+    fn parse_fragment_in_context() {
+        let html_ns = ns!(html);
+        let no_attrs = Vec::<(&str, &str)>::new;
+
+        let document = parse_fragment_in("tr", html_ns.clone(), no_attrs()).one("<td>A</td>");
+        assert_eq!(document.to_string(), "<html><td>A</td></html>");
+
+        let document = parse_fragment_in("div", html_ns.clone(), no_attrs()).one("<td>A</td>");
+        assert_eq!(document.to_string(), "<html>A</html>");
+
+        let document =
+            parse_fragment_in("select", html_ns, no_attrs()).one("<option>1</option><div>x</div>");
+        assert_eq!(document.to_string(), "<html><option>1</option>x</html>");
+    }
+
+    /// Tests fragment parsing in a foreign content context.
+    ///
+    /// Verifies that elements parsed inside an SVG context get the SVG
+    /// namespace, while the same input in an HTML context does not.
+    #[test]
+    fn parse_fragment_in_svg() {
+        let document = parse_fragment_in("g", ns!(svg), [("id", "layer")]).one("<circle/>");
+        assert_eq!(document.select_first("circle").unwrap().name.ns, ns!(svg));
+
+        let document =
+            parse_fragment_in("div", ns!(html), [("id", "layer")]).one("<circle></circle>");
+        assert_eq!(document.select_first("circle").unwrap().name.ns, ns!(html));
+    }
+
+    /// Tests that parse_fragment_nodes() returns only the parsed nodes.
+    ///
+    /// Verifies that the result is a document fragment holding the top-level
+    /// nodes in order, without the generated root element.
+    #[test]
+    fn parse_fragment_nodes_top_level() {
+        let ctx_name = QualName::new(None, ns!(html), local_name!("body"));
+        let fragment = parse_fragment_nodes(ctx_name, vec![], "a<!--c--><b>x</b>");
+        assert!(fragment.as_document_fragment().is_some());
+
+        let children: Vec<NodeRef> = fragment.children().collect();
+        assert_eq!(children.len(), 3);
+        assert!(children[0].as_text().is_some());
+        assert!(children[1].as_comment().is_some());
+        assert_eq!(children[2].to_string(), "<b>x</b>");
+        assert!(children[2].parent().unwrap() == fragment);
+    }
+
+    /// Tests parse_fragment_nodes() with empty input.
+    ///
+    /// Edge case: an empty fragment yields an empty document fragment.
+    #[test]
+    fn parse_fragment_nodes_empty() {
+        let ctx_name = QualName::new(None, ns!(html), local_name!("body"));
+        let fragment = parse_fragment_nodes(ctx_name, vec![], "");
+        assert_eq!(fragment.children().count(), 0);
+    }
 }
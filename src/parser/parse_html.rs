@@ -1,8 +1,12 @@
 //! HTML document parsing functions.
 
-use super::{ParseOpts, Sink};
+use super::source_attribute_names::record_source_attribute_names;
+use super::source_cdata_sections::parse_marking_cdata;
+use super::source_markup::record_source_markup;
+use super::{ParseDiagnostics, ParseOpts, Sink};
 use crate::tree::NodeRef;
-use std::cell::RefCell;
+use html5ever::tendril::TendrilSink;
+use html5ever::tree_builder::TreeBuilder;
 
 /// Parse an HTML document with html5ever and the default configuration.
 ///
@@ -27,21 +31,107 @@ pub fn parse_html() -> html5ever::Parser<Sink> {
 
 /// Parse an HTML document with html5ever with custom configuration.
 pub fn parse_html_with_options(opts: ParseOpts) -> html5ever::Parser<Sink> {
-    let sink = Sink {
-        document_node: NodeRef::new_document(),
-        on_parse_error: RefCell::new(opts.on_parse_error),
+    let (sink, html5opts) = Sink::with_options(opts);
+    html5ever::parse_document(sink, html5opts)
+}
+
+/// Parse an HTML document from a string with custom configuration.
+///
+/// Unlike [`parse_html_with_options`], this has the whole source at once,
+/// so it also honors [`ParseOpts::preserve_attribute_names`],
+/// [`ParseOpts::preserve_cdata`] and [`ParseOpts::preserve_source`].
+///
+/// # Examples
+///
+/// ```
+/// use brik::{parse_html_str_with_options, ParseOpts};
+/// use brik::traits::*;
+///
+/// let opts = ParseOpts {
+///     preserve_attribute_names: true,
+///     ..Default::default()
+/// };
+/// let doc = parse_html_str_with_options(opts, "<button onClick='go()'>Go</button>");
+/// let button = doc.select_first("button").unwrap();
+/// let attributes = button.attributes.borrow();
+/// let attribute = attributes.map.values().next().unwrap();
+/// assert_eq!(attribute.source_name.as_deref(), Some("onClick"));
+/// ```
+pub fn parse_html_str_with_options(opts: ParseOpts, html: &str) -> NodeRef {
+    let preserve_attribute_names = opts.preserve_attribute_names;
+    let preserve_cdata = opts.preserve_cdata;
+    let preserve_source = opts.preserve_source;
+    let document = if preserve_cdata {
+        let (sink, html5opts) = Sink::with_options(opts);
+        let builder = TreeBuilder::new(sink, html5opts.tree_builder);
+        parse_marking_cdata(builder, html5opts.tokenizer, html)
+    } else {
+        parse_html_with_options(opts).one(html)
     };
-    let html5opts = html5ever::ParseOpts {
-        tokenizer: opts.tokenizer,
-        tree_builder: opts.tree_builder,
+    if preserve_attribute_names {
+        record_source_attribute_names(&document, html);
+    }
+    if preserve_source {
+        record_source_markup(&document, html);
+    }
+    document
+}
+
+/// Parse an HTML document, failing if the parser reports any error.
+///
+/// HTML parsing normally recovers from every error. This rejects input that
+/// needed error correction, including a missing doctype, and returns the
+/// reported errors instead of the corrected document.
+///
+/// # Errors
+///
+/// Returns [`ParseDiagnostics`] listing every reported error if there was
+/// at least one.
+///
+/// # Examples
+///
+/// ```
+/// use brik::parse_html_strict;
+///
+/// assert!(parse_html_strict("<!DOCTYPE html><title>Ok</title>").is_ok());
+///
+/// let error = parse_html_strict("<!DOCTYPE html><p></i>").unwrap_err();
+/// assert_eq!(error.diagnostics.len(), 1);
+/// ```
+pub fn parse_html_strict(html: &str) -> Result<NodeRef, ParseDiagnostics> {
+    parse_html_strict_with_options(ParseOpts::default(), html)
+}
+
+/// Parse an HTML document with custom configuration, failing if the parser
+/// reports any error.
+///
+/// See [`parse_html_strict`]. Errors are collected regardless of
+/// [`ParseOpts::collect_diagnostics`], and `on_parse_error` is still called.
+///
+/// # Errors
+///
+/// Returns [`ParseDiagnostics`] listing every reported error if there was
+/// at least one.
+pub fn parse_html_strict_with_options(
+    mut opts: ParseOpts,
+    html: &str,
+) -> Result<NodeRef, ParseDiagnostics> {
+    opts.collect_diagnostics = true;
+    let document = parse_html_str_with_options(opts, html);
+    let diagnostics = match document.as_document() {
+        Some(data) => data._parse_diagnostics.take(),
+        None => Vec::new(),
     };
-    html5ever::parse_document(sink, html5opts)
+    if diagnostics.is_empty() {
+        Ok(document)
+    } else {
+        Err(ParseDiagnostics { diagnostics })
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::traits::*;
     use html5ever::tree_builder::QuirksMode;
     use std::path::Path;
 
@@ -110,4 +200,18 @@ mod tests {
         let document = parse_html().from_utf8().from_file(&path).unwrap();
         assert_eq!(document.to_string(), html);
     }
+
+    /// Tests strict parsing of valid and malformed documents.
+    ///
+    /// Verifies that a conforming document parses, and that a missing
+    /// doctype and a stray end tag are both reported as errors.
+    #[test]
+    fn parse_strict() {
+        let document = parse_html_strict("<!DOCTYPE html><p>One</p>").unwrap();
+        assert_eq!(document.select_first("p").unwrap().text_contents(), "One");
+
+        let error = parse_html_strict("<p>One</i>").unwrap_err();
+        assert_eq!(error.diagnostics.len(), 2);
+        assert!(error.to_string().starts_with("2 parse errors, first: "));
+    }
 }
@@ -13,4 +13,47 @@ pub struct ParseOpts {
 
     /// A callback for HTML parse errors (which are never fatal).
     pub on_parse_error: Option<Box<dyn FnMut(Cow<'static, str>)>>,
+
+    /// Also record parse errors on the returned document, retrievable with
+    /// [`DocumentData::parse_diagnostics()`](crate::DocumentData::parse_diagnostics).
+    pub collect_diagnostics: bool,
+
+    /// The absolute URL of the document, stored as its
+    /// [`DocumentData::base_url()`](crate::DocumentData::base_url).
+    ///
+    /// A `<base href>` in the document takes precedence, resolved against
+    /// this URL if it is relative.
+    pub base_url: Option<String>,
+
+    /// Record the spelling of each attribute name in the source, before
+    /// HTML case folding, as [`Attribute::source_name`](crate::Attribute::source_name).
+    ///
+    /// html5ever lowercases attribute names while tokenizing, so the names
+    /// are recovered by scanning the source text again. This needs the
+    /// whole source and is only done by
+    /// [`parse_html_str_with_options()`](crate::parse_html_str_with_options),
+    /// [`parse_html_strict_with_options()`](crate::parse_html_strict_with_options)
+    /// and [`parse_fragment_nodes_with_options()`](crate::parse_fragment_nodes_with_options).
+    /// Parsers that take their input in chunks ignore it.
+    pub preserve_attribute_names: bool,
+
+    /// Keep CDATA sections in foreign content, such as SVG and MathML, as
+    /// separate text nodes marked with
+    /// [`Node::is_cdata_section()`](crate::Node::is_cdata_section).
+    ///
+    /// html5ever passes the contents of CDATA sections on as plain text,
+    /// so the sections are marked as the tokenizer emits them. This is
+    /// honored by [`parse_html_str_with_options()`](crate::parse_html_str_with_options)
+    /// and [`parse_fragment_nodes_with_options()`](crate::parse_fragment_nodes_with_options),
+    /// and ignored by parsers that take their input in chunks.
+    pub preserve_cdata: bool,
+
+    /// Record the markup of each element's start tag and each text node,
+    /// as written in the source, for
+    /// [`NodeRef::serialize_preserving_source()`](crate::NodeRef::serialize_preserving_source).
+    ///
+    /// Like [`preserve_attribute_names`](ParseOpts::preserve_attribute_names),
+    /// this needs the whole source and is ignored by parsers that take
+    /// their input in chunks.
+    pub preserve_source: bool,
 }
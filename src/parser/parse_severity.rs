@@ -0,0 +1,15 @@
+//! Severity levels for parse diagnostics.
+
+/// How serious a [`ParseDiagnostic`](super::ParseDiagnostic) is.
+///
+/// HTML parse errors are never fatal; the severity describes how far the
+/// resulting tree may differ from what the markup appears to say.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ParseSeverity {
+    /// A lexical problem, such as a bad character, a duplicate attribute or
+    /// an invalid character reference. The tree still reflects the markup.
+    Warning,
+    /// A tree construction problem, such as a misnested or unexpected tag.
+    /// The parser restructured the tree to recover.
+    Error,
+}
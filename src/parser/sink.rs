@@ -1,12 +1,14 @@
 //! TreeSink implementation for building DOM trees during HTML parsing.
 
+use super::{ParseDiagnostic, ParseOpts};
 use crate::attributes;
+use crate::html::apply_base_element;
 use crate::tree::NodeRef;
 use html5ever::tendril::StrTendril;
 use html5ever::tree_builder::{ElementFlags, NodeOrText, QuirksMode, TreeSink};
 use html5ever::{Attribute, ExpandedName, QualName};
 use std::borrow::Cow;
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 
 /// Type alias for the parse error callback handler.
 type ParseErrorHandler = RefCell<Option<Box<dyn FnMut(Cow<'static, str>)>>>;
@@ -17,6 +19,56 @@ pub struct Sink {
     pub(super) document_node: NodeRef,
     /// Optional callback for handling parse errors.
     pub(super) on_parse_error: ParseErrorHandler,
+    /// Whether appended text is the contents of a CDATA section.
+    pub(super) in_cdata_section: Cell<bool>,
+}
+
+/// Construction methods for Sink.
+///
+/// Provides creation of a sink for a new document.
+impl Sink {
+    /// Creates a sink building a new document.
+    ///
+    /// When `collect_diagnostics` is set, each parse error is recorded on
+    /// the document before being passed to `on_parse_error`.
+    pub(super) fn new(
+        mut on_parse_error: Option<Box<dyn FnMut(Cow<'static, str>)>>,
+        collect_diagnostics: bool,
+    ) -> Sink {
+        let document_node = NodeRef::new_document();
+        if collect_diagnostics {
+            let document = document_node.clone();
+            on_parse_error = Some(Box::new(move |message: Cow<'static, str>| {
+                if let Some(data) = document.as_document() {
+                    data._parse_diagnostics
+                        .borrow_mut()
+                        .push(ParseDiagnostic::new(message.clone()));
+                }
+                if let Some(ref mut handler) = on_parse_error {
+                    handler(message)
+                }
+            }));
+        }
+        Sink {
+            document_node,
+            on_parse_error: RefCell::new(on_parse_error),
+            in_cdata_section: Cell::new(false),
+        }
+    }
+
+    /// Creates a sink building a new document configured by `opts`,
+    /// returning it with the options for html5ever.
+    pub(super) fn with_options(opts: ParseOpts) -> (Sink, html5ever::ParseOpts) {
+        let sink = Sink::new(opts.on_parse_error, opts.collect_diagnostics);
+        if let Some(document) = sink.document_node.as_document() {
+            document.set_base_url(opts.base_url.as_deref());
+        }
+        let html5opts = html5ever::ParseOpts {
+            tokenizer: opts.tokenizer,
+            tree_builder: opts.tree_builder,
+        };
+        (sink, html5opts)
+    }
 }
 
 /// Implements TreeSink for Sink.
@@ -28,6 +80,7 @@ impl TreeSink for Sink {
     type Output = NodeRef;
 
     fn finish(self) -> NodeRef {
+        apply_base_element(&self.document_node);
         self.document_node
     }
 
@@ -83,10 +136,13 @@ impl TreeSink for Sink {
                     name: QualName { prefix, ns, local },
                     value,
                 } = attr;
-                let value = String::from(value);
                 (
                     attributes::ExpandedName { ns, local },
-                    attributes::Attribute { prefix, value },
+                    attributes::Attribute {
+                        prefix,
+                        value,
+                        source_name: None,
+                    },
                 )
             }),
         )
@@ -107,9 +163,15 @@ impl TreeSink for Sink {
         match child {
             NodeOrText::AppendNode(node) => parent.append(node),
             NodeOrText::AppendText(text) => {
+                if self.in_cdata_section.get() {
+                    return parent.append(NodeRef::new_cdata_section(text));
+                }
                 if let Some(last_child) = parent.last_child() {
-                    if let Some(existing) = last_child.as_text() {
-                        existing.borrow_mut().push_str(&text);
+                    if let Some(existing) = last_child
+                        .as_text()
+                        .filter(|_| !last_child.is_cdata_section())
+                    {
+                        existing.borrow_mut().push_tendril(&text);
                         return;
                     }
                 }
@@ -123,9 +185,15 @@ impl TreeSink for Sink {
         match child {
             NodeOrText::AppendNode(node) => sibling.insert_before(node),
             NodeOrText::AppendText(text) => {
+                if self.in_cdata_section.get() {
+                    return sibling.insert_before(NodeRef::new_cdata_section(text));
+                }
                 if let Some(previous_sibling) = sibling.previous_sibling() {
-                    if let Some(existing) = previous_sibling.as_text() {
-                        existing.borrow_mut().push_str(&text);
+                    if let Some(existing) = previous_sibling
+                        .as_text()
+                        .filter(|_| !previous_sibling.is_cdata_section())
+                    {
+                        existing.borrow_mut().push_tendril(&text);
                         return;
                     }
                 }
@@ -158,9 +226,10 @@ impl TreeSink for Sink {
             attributes
                 .map
                 .entry(attributes::ExpandedName { ns, local })
-                .or_insert_with(|| {
-                    let value = String::from(value);
-                    attributes::Attribute { prefix, value }
+                .or_insert(attributes::Attribute {
+                    prefix,
+                    value,
+                    source_name: None,
                 });
         }
     }
@@ -222,6 +291,7 @@ mod tests {
         let sink = Sink {
             document_node: NodeRef::new_document(),
             on_parse_error: RefCell::new(None),
+            in_cdata_section: Cell::new(false),
         };
 
         let pi = sink.create_pi(
@@ -243,6 +313,7 @@ mod tests {
         let sink = Sink {
             document_node: NodeRef::new_document(),
             on_parse_error: RefCell::new(None),
+            in_cdata_section: Cell::new(false),
         };
 
         let parent = NodeRef::new_element(
@@ -278,6 +349,7 @@ mod tests {
         let sink = Sink {
             document_node: NodeRef::new_document(),
             on_parse_error: RefCell::new(None),
+            in_cdata_section: Cell::new(false),
         };
 
         let parent = NodeRef::new_element(
@@ -312,6 +384,7 @@ mod tests {
         let sink = Sink {
             document_node: NodeRef::new_document(),
             on_parse_error: RefCell::new(None),
+            in_cdata_section: Cell::new(false),
         };
 
         let parent = NodeRef::new_element(
@@ -353,6 +426,7 @@ mod tests {
         let sink = Sink {
             document_node: NodeRef::new_document(),
             on_parse_error: RefCell::new(None),
+            in_cdata_section: Cell::new(false),
         };
 
         let element = NodeRef::new_element(
@@ -364,7 +438,8 @@ mod tests {
                 },
                 attributes::Attribute {
                     prefix: None,
-                    value: "test".to_string(),
+                    value: "test".into(),
+                    source_name: None,
                 },
             )],
         );
@@ -389,6 +464,7 @@ mod tests {
         let sink = Sink {
             document_node: NodeRef::new_document(),
             on_parse_error: RefCell::new(None),
+            in_cdata_section: Cell::new(false),
         };
 
         let element = NodeRef::new_element(
@@ -400,7 +476,8 @@ mod tests {
                 },
                 attributes::Attribute {
                     prefix: None,
-                    value: "original".to_string(),
+                    value: "original".into(),
+                    source_name: None,
                 },
             )],
         );
@@ -440,6 +517,7 @@ mod tests {
             on_parse_error: RefCell::new(Some(Box::new(move |msg: Cow<'static, str>| {
                 error_messages_clone.lock().unwrap().push(msg.into_owned());
             }))),
+            in_cdata_section: Cell::new(false),
         };
 
         sink.parse_error(Cow::Borrowed("Test error 1"));
@@ -460,6 +538,7 @@ mod tests {
         let sink = Sink {
             document_node: NodeRef::new_document(),
             on_parse_error: RefCell::new(None),
+            in_cdata_section: Cell::new(false),
         };
 
         // Should not panic
@@ -475,6 +554,7 @@ mod tests {
         let sink = Sink {
             document_node: NodeRef::new_document(),
             on_parse_error: RefCell::new(None),
+            in_cdata_section: Cell::new(false),
         };
 
         let parent = NodeRef::new_element(
@@ -523,6 +603,7 @@ mod tests {
         let sink = Sink {
             document_node: NodeRef::new_document(),
             on_parse_error: RefCell::new(None),
+            in_cdata_section: Cell::new(false),
         };
 
         let element = NodeRef::new_element(
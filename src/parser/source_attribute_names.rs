@@ -0,0 +1,98 @@
+//! Recovery of attribute name spelling from HTML source text.
+
+use super::source_markup::scan_markup;
+use crate::tree::NodeRef;
+use std::collections::{HashMap, VecDeque};
+
+/// A start tag's name and attribute names, folded to lowercase.
+type TagKey = (String, Vec<String>);
+
+/// Records the source spelling of attribute names on the elements under `root`.
+///
+/// html5ever lowercases attribute names while tokenizing, so the names are
+/// recovered by scanning the start tags in `html`. Each element is paired
+/// with the first unused start tag with the same tag and attribute names,
+/// ignoring case. Elements the tree builder made up or cloned, or whose
+/// attributes it merged, have no such tag and are left unchanged.
+pub(super) fn record_source_attribute_names(root: &NodeRef, html: &str) {
+    let mut tags: HashMap<TagKey, VecDeque<Vec<String>>> = HashMap::new();
+    for (name, attributes) in scan_start_tags(html) {
+        let mut folded: Vec<String> = attributes.iter().map(|a| a.to_ascii_lowercase()).collect();
+        folded.sort();
+        tags.entry((name, folded))
+            .or_default()
+            .push_back(attributes);
+    }
+
+    for node in root.inclusive_descendants_with_templates() {
+        let Some(element) = node.as_element() else {
+            continue;
+        };
+        let mut attributes = element.attributes.borrow_mut();
+        if attributes.map.is_empty() {
+            continue;
+        }
+        let qualified = |prefix: Option<&str>, local: &str| match prefix {
+            Some(prefix) => format!("{}:{}", prefix, local).to_ascii_lowercase(),
+            None => local.to_ascii_lowercase(),
+        };
+        let mut folded: Vec<String> = attributes
+            .map
+            .iter()
+            .map(|(name, attr)| qualified(attr.prefix.as_deref(), &name.local))
+            .collect();
+        folded.sort();
+        let key = (element.name.local.as_ref().to_ascii_lowercase(), folded);
+        let Some(source_names) = tags.get_mut(&key).and_then(|queue| queue.pop_front()) else {
+            continue;
+        };
+        for (name, attr) in attributes.map.iter_mut() {
+            let folded = qualified(attr.prefix.as_deref(), &name.local);
+            attr.source_name = source_names
+                .iter()
+                .find(|source| source.eq_ignore_ascii_case(&folded))
+                .cloned();
+        }
+    }
+}
+
+/// Returns the lowercased name and the attribute names of each start tag in
+/// `html`, in source order.
+///
+/// Only the first of several attributes with the same name is kept, as the
+/// tokenizer does. Comments, doctypes, end tags and the contents of raw text
+/// elements are skipped.
+fn scan_start_tags(html: &str) -> Vec<(String, Vec<String>)> {
+    scan_markup(html)
+        .into_iter()
+        .filter_map(|markup| markup.start_tag)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Tests scanning start tags.
+    ///
+    /// Verifies that attribute names keep their spelling, that values,
+    /// comments and raw text are skipped, and that repeated names are
+    /// dropped.
+    #[test]
+    fn scan_start_tags_keeps_spelling() {
+        let html = r#"<!-- <a X=1> --><DIV onClick="a>b" data-X=1 ONCLICK>
+            <script>if (a <b) {}</script><svg viewBox='0 0 1 1'/></DIV>"#;
+        let tags = scan_start_tags(html);
+        assert_eq!(
+            tags,
+            [
+                (
+                    "div".to_string(),
+                    vec!["onClick".to_string(), "data-X".to_string()]
+                ),
+                ("script".to_string(), vec![]),
+                ("svg".to_string(), vec!["viewBox".to_string()]),
+            ]
+        );
+    }
+}
@@ -0,0 +1,174 @@
+//! Recording of CDATA sections as they are tokenized.
+
+use super::Sink;
+use crate::tree::NodeRef;
+use html5ever::tendril::StrTendril;
+use html5ever::tokenizer::{
+    BufferQueue, CharacterTokens, Token, TokenSink, TokenSinkResult, Tokenizer, TokenizerOpts,
+};
+use html5ever::tree_builder::{TreeBuilder, TreeSink};
+use html5ever::TokenizerResult;
+use std::cell::Cell;
+
+/// A token sink that marks the text of each CDATA section in foreign
+/// content as its own text node, with
+/// [`Node::is_cdata_section()`](crate::Node::is_cdata_section).
+///
+/// html5ever reads CDATA sections only where the adjusted current node is
+/// not in the HTML namespace, asking the sink just before, and emits the
+/// section contents as the next character token. Elsewhere the markup
+/// becomes a comment, so the comment token comes next instead. Tracking
+/// this pairs sections with the text they produce, wherever the tree
+/// builder puts it. A NUL character ends the marked text early, since
+/// html5ever emits it as a separate token.
+struct CdataTokenSink {
+    /// The tree builder tokens are passed on to.
+    builder: TreeBuilder<NodeRef, Sink>,
+    /// Whether the tokenizer may be starting a CDATA section.
+    pending: Cell<bool>,
+}
+
+/// Implements TokenSink for CdataTokenSink.
+///
+/// Passes every token to the tree builder, marking the text appended for
+/// the contents of a CDATA section.
+impl TokenSink for CdataTokenSink {
+    type Handle = NodeRef;
+
+    fn process_token(&self, token: Token, line_number: u64) -> TokenSinkResult<NodeRef> {
+        let section = self.pending.replace(false) && matches!(token, CharacterTokens(_));
+        self.builder.sink.in_cdata_section.set(section);
+        let result = self.builder.process_token(token, line_number);
+        self.builder.sink.in_cdata_section.set(false);
+        result
+    }
+
+    fn end(&self) {
+        self.builder.end();
+    }
+
+    fn adjusted_current_node_present_but_not_in_html_namespace(&self) -> bool {
+        let foreign = self
+            .builder
+            .adjusted_current_node_present_but_not_in_html_namespace();
+        self.pending.set(foreign);
+        foreign
+    }
+}
+
+/// Parses `html` with `builder`, splitting the CDATA sections in foreign
+/// content out into their own marked text nodes.
+pub(super) fn parse_marking_cdata(
+    builder: TreeBuilder<NodeRef, Sink>,
+    opts: TokenizerOpts,
+    html: &str,
+) -> NodeRef {
+    let sink = CdataTokenSink {
+        builder,
+        pending: Cell::new(false),
+    };
+    let tokenizer = Tokenizer::new(sink, opts);
+    let input = BufferQueue::default();
+    input.push_back(StrTendril::from(html));
+    while let TokenizerResult::Script(_) = tokenizer.feed(&input) {}
+    tokenizer.end();
+    tokenizer.sink.builder.sink.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::html5ever::tendril::TendrilSink;
+    use crate::{parse_fragment_nodes_with_options, parse_html_str_with_options, ParseOpts};
+    use html5ever::{local_name, ns, QualName};
+
+    /// Returns the text and CDATA marker of each child of the first
+    /// element matching `selector` in `html`, parsed preserving CDATA.
+    fn parts(html: &str, selector: &str) -> Vec<(String, bool)> {
+        let opts = ParseOpts {
+            preserve_cdata: true,
+            ..Default::default()
+        };
+        let doc = parse_html_str_with_options(opts, html);
+        let element = doc.select_first(selector).unwrap();
+        let parts = element
+            .as_node()
+            .children()
+            .map(|child| (child.text_contents(), child.is_cdata_section()))
+            .collect();
+        parts
+    }
+
+    /// Tests recovering CDATA sections in SVG.
+    ///
+    /// Verifies that sections are split out of the surrounding text, that
+    /// sections in HTML content and comments are not, and that the text
+    /// content is unchanged.
+    #[test]
+    fn preserve_cdata_in_foreign_content() {
+        let html = "<p><![CDATA[html]]></p><!-- <![CDATA[skip]]> -->\
+                    <svg><style>a<![CDATA[b < c]]><![CDATA[d]]>e</style></svg>";
+        assert_eq!(
+            parts(html, "style"),
+            [
+                ("a".to_string(), false),
+                ("b < c".to_string(), true),
+                ("d".to_string(), true),
+                ("e".to_string(), false),
+            ]
+        );
+        let opts = ParseOpts {
+            preserve_cdata: true,
+            ..Default::default()
+        };
+        let doc = parse_html_str_with_options(opts, html);
+        let p = doc.select_first("p").unwrap();
+        assert!(p.as_node().first_child().unwrap().as_comment().is_some());
+        assert_eq!(
+            doc.descendants()
+                .filter(|node| node.is_cdata_section())
+                .count(),
+            2
+        );
+
+        let plain = crate::parse_html().one(html);
+        let style = plain.select_first("style").unwrap();
+        assert_eq!(style.as_node().children().count(), 1);
+        assert_eq!(style.text_contents(), "ab < cde");
+    }
+
+    /// Tests that sections are not matched to other text by content.
+    ///
+    /// Verifies that earlier text containing a section's contents is left
+    /// whole, and that CDATA markup inside an attribute value is not taken
+    /// for a section.
+    #[test]
+    fn cdata_sections_are_not_found_by_substring() {
+        let html = "<svg><text>xyz</text><style><![CDATA[y]]></style></svg>";
+        assert_eq!(parts(html, "text"), [("xyz".to_string(), false)]);
+        assert_eq!(parts(html, "style"), [("y".to_string(), true)]);
+
+        let html = "<svg><text title='<![CDATA[t]]>'>t</text>\
+                    <style><![CDATA[t]]></style></svg>";
+        assert_eq!(parts(html, "text"), [("t".to_string(), false)]);
+        assert_eq!(parts(html, "style"), [("t".to_string(), true)]);
+    }
+
+    /// Tests marking CDATA sections when parsing a fragment.
+    ///
+    /// Verifies that fragments parsed in a foreign context element mark
+    /// their sections.
+    #[test]
+    fn preserve_cdata_in_fragment() {
+        let opts = ParseOpts {
+            preserve_cdata: true,
+            ..Default::default()
+        };
+        let context = QualName::new(None, ns!(svg), local_name!("svg"));
+        let fragment = parse_fragment_nodes_with_options(opts, context, vec![], "a<![CDATA[<b>]]>");
+        let parts: Vec<(String, bool)> = fragment
+            .children()
+            .map(|child| (child.text_contents(), child.is_cdata_section()))
+            .collect();
+        assert_eq!(parts, [("a".to_string(), false), ("<b>".to_string(), true)]);
+    }
+}
@@ -0,0 +1,378 @@
+//! Recovery of the source markup of elements and text.
+
+use super::parse_in_context;
+use crate::tree::{NodeData, NodeRef, SourceMarkup};
+use std::collections::{HashMap, VecDeque};
+use std::ops::Range;
+
+/// Elements whose contents are scanned as text rather than markup.
+const RAW_TEXT_ELEMENTS: &[&str] = &[
+    "iframe",
+    "noembed",
+    "noframes",
+    "plaintext",
+    "script",
+    "style",
+    "textarea",
+    "title",
+    "xmp",
+];
+
+/// Elements whose text is not decoded by the tokenizer.
+const UNDECODED_TEXT_ELEMENTS: &[&str] = &[
+    "iframe",
+    "noembed",
+    "noframes",
+    "noscript",
+    "plaintext",
+    "script",
+    "style",
+    "xmp",
+];
+
+/// A piece of markup found in the source.
+#[derive(Debug)]
+pub(super) struct Markup {
+    /// Where the markup is in the source.
+    pub(super) range: Range<usize>,
+    /// The lowercased name and the attribute names of a start tag, or
+    /// `None` for other markup.
+    pub(super) start_tag: Option<(String, Vec<String>)>,
+    /// The undecoded value of each attribute of a start tag, with its
+    /// quotes, or an empty string for an attribute without a value.
+    pub(super) values: Vec<String>,
+    /// The index of the end tag closing a start tag, if any.
+    pub(super) end: Option<usize>,
+}
+
+/// Records the start tag of each element and the undecoded text of each
+/// text node under `root`, for writing unchanged nodes as they were.
+///
+/// Elements are paired with start tags as by
+/// [`record_source_attribute_names()`](super::source_attribute_names::record_source_attribute_names),
+/// skipping start tags whose attribute values decode differently, so
+/// elements the tree builder reordered are not given another element's
+/// markup. Each text node is paired with the next run of text in the source that
+/// decodes to its text, without passing the start tag of a later element.
+/// Nodes the tree builder made up, moved or merged are left unrecorded.
+pub(super) fn record_source_markup(root: &NodeRef, html: &str) {
+    let markup = scan_markup(html);
+    let mut tags: HashMap<(String, Vec<String>), VecDeque<usize>> = HashMap::new();
+    for (index, piece) in markup.iter().enumerate() {
+        if let Some((name, attributes)) = &piece.start_tag {
+            let mut folded: Vec<String> =
+                attributes.iter().map(|a| a.to_ascii_lowercase()).collect();
+            folded.sort();
+            tags.entry((name.clone(), folded))
+                .or_default()
+                .push_back(index);
+        }
+    }
+    // The text before markup `i` is gap `i`; the text after the last is the
+    // final gap.
+    let gap = |index: usize| {
+        let start = index
+            .checked_sub(1)
+            .map_or(0, |previous| markup[previous].range.end);
+        let end = markup
+            .get(index)
+            .map_or(html.len(), |piece| piece.range.start);
+        &html[start..end.max(start)]
+    };
+
+    let mut next_gap = 0;
+    for node in root.inclusive_descendants_with_templates() {
+        match node.data() {
+            NodeData::Element(element) => {
+                let attributes = element.attributes.borrow();
+                let mut folded: Vec<String> = attributes
+                    .map
+                    .iter()
+                    .map(|(name, attr)| match &attr.prefix {
+                        Some(prefix) => format!("{}:{}", prefix, name.local),
+                        None => name.local.to_string(),
+                    })
+                    .map(|name| name.to_ascii_lowercase())
+                    .collect();
+                folded.sort();
+                let key = (element.name.local.as_ref().to_ascii_lowercase(), folded);
+                let Some(index) = tags.get_mut(&key).and_then(|queue| {
+                    let position = queue
+                        .iter()
+                        .position(|&index| same_values(&markup[index], &node))?;
+                    queue.remove(position)
+                }) else {
+                    continue;
+                };
+                let end_markup = markup[index]
+                    .end
+                    .map(|end| html[markup[end].range.clone()].to_string());
+                node.set_source(SourceMarkup::StartTag {
+                    markup: html[markup[index].range.clone()].to_string(),
+                    end_markup,
+                    name: element.name.clone(),
+                    attributes: attributes.clone(),
+                });
+                next_gap = index + 1;
+            }
+            NodeData::Text(text) => {
+                let text = text.borrow();
+                for index in next_gap..=markup.len() {
+                    let passes_start_tag = index > next_gap
+                        && markup
+                            .get(index - 1)
+                            .is_some_and(|piece| piece.start_tag.is_some());
+                    if passes_start_tag {
+                        break;
+                    }
+                    let source = gap(index);
+                    if !source.is_empty() && decodes_to(&node, source, &text) {
+                        node.set_source(SourceMarkup::Text {
+                            markup: source.to_string(),
+                            text: text.clone(),
+                        });
+                        next_gap = index + 1;
+                        break;
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Returns whether the attribute values of the start tag `piece` decode to
+/// the attribute values of the element `node`.
+fn same_values(piece: &Markup, node: &NodeRef) -> bool {
+    let (Some((_, names)), Some(element)) = (&piece.start_tag, node.as_element()) else {
+        return false;
+    };
+    let attributes = element.attributes.borrow();
+    names.iter().zip(&piece.values).all(|(name, raw)| {
+        let value = attributes.map.iter().find_map(|(qualified, attribute)| {
+            let matches = match &attribute.prefix {
+                Some(prefix) => {
+                    name.eq_ignore_ascii_case(&format!("{}:{}", prefix, qualified.local))
+                }
+                None => name.eq_ignore_ascii_case(&qualified.local),
+            };
+            matches.then_some(&*attribute.value)
+        });
+        value.is_some_and(|value| decode_value(raw).as_deref() == Some(value))
+    })
+}
+
+/// Decodes an attribute value as written in a start tag, with its quotes.
+fn decode_value(raw: &str) -> Option<String> {
+    let unquoted = ["\"", "'"]
+        .iter()
+        .find_map(|&quote| raw.strip_prefix(quote)?.strip_suffix(quote))
+        .unwrap_or(raw);
+    if !unquoted.contains(['&', '\r', '\0']) {
+        return Some(unquoted.to_string());
+    }
+    let fragment = parse_in_context(&NodeRef::new_document(), &format!("<span a={}>", raw));
+    let span = fragment.first_child()?;
+    let value = span.as_element()?.attributes.borrow().get("a")?.to_string();
+    Some(value)
+}
+
+/// Returns whether the tokenizer decodes `source` to `text` in the parent
+/// of the text node `node`.
+fn decodes_to(node: &NodeRef, source: &str, text: &str) -> bool {
+    let parent = node.parent();
+    let undecoded = parent
+        .as_ref()
+        .and_then(|parent| parent.as_element())
+        .is_some_and(|element| UNDECODED_TEXT_ELEMENTS.contains(&&*element.name.local));
+    if undecoded || !source.contains(['&', '\r', '\0']) {
+        return source == text;
+    }
+    let context = parent.unwrap_or_else(|| node.clone());
+    let fragment = parse_in_context(&context, source);
+    fragment.children().all(|child| child.as_text().is_some()) && fragment.text_contents() == text
+}
+
+/// Returns the markup in `html`, in source order: start and end tags,
+/// comments, doctypes and processing instructions.
+///
+/// Only the first of several attributes with the same name is kept, as the
+/// tokenizer does. The contents of raw text elements are skipped as text.
+/// Each end tag closes the latest open start tag with the same name.
+pub(super) fn scan_markup(html: &str) -> Vec<Markup> {
+    let bytes = html.as_bytes();
+    let mut markup = Vec::new();
+    let mut open: Vec<(String, usize)> = Vec::new();
+    let mut i = 0;
+    while let Some(offset) = html[i..].find('<') {
+        let tag_start = i + offset;
+        i = tag_start + 1;
+        let rest = &html[i..];
+        if let Some(comment) = rest.strip_prefix("!--") {
+            i += comment.find("-->").map_or(rest.len(), |end| end + 6);
+            markup.push(Markup {
+                range: tag_start..i,
+                start_tag: None,
+                values: Vec::new(),
+                end: None,
+            });
+            continue;
+        }
+        if !bytes.get(i).is_some_and(u8::is_ascii_alphabetic) {
+            if matches!(bytes.get(i), Some(b'!' | b'?' | b'/')) {
+                i += rest.find('>').map_or(rest.len(), |end| end + 1);
+                if let Some(end_tag) = rest.strip_prefix('/') {
+                    let length = end_tag.bytes().position(is_tag_delimiter);
+                    let name = end_tag[..length.unwrap_or(end_tag.len())].to_ascii_lowercase();
+                    if let Some(position) = open.iter().rposition(|(open, _)| *open == name) {
+                        markup[open[position].1].end = Some(markup.len());
+                        open.truncate(position);
+                    }
+                }
+                markup.push(Markup {
+                    range: tag_start..i,
+                    start_tag: None,
+                    values: Vec::new(),
+                    end: None,
+                });
+            }
+            continue;
+        }
+
+        let start = i;
+        while i < bytes.len() && !is_tag_delimiter(bytes[i]) {
+            i += 1;
+        }
+        let name = html[start..i].to_ascii_lowercase();
+        let mut attributes: Vec<String> = Vec::new();
+        let mut values: Vec<String> = Vec::new();
+        loop {
+            while i < bytes.len() && (bytes[i].is_ascii_whitespace() || bytes[i] == b'/') {
+                i += 1;
+            }
+            if i >= bytes.len() || bytes[i] == b'>' {
+                i += 1;
+                break;
+            }
+            let start = i;
+            i += 1;
+            while i < bytes.len() && !is_tag_delimiter(bytes[i]) && bytes[i] != b'=' {
+                i += 1;
+            }
+            let attribute = &html[start..i];
+            let first = !attributes.iter().any(|a| a.eq_ignore_ascii_case(attribute));
+            if first {
+                attributes.push(attribute.to_string());
+                values.push(String::new());
+            }
+            while i < bytes.len() && bytes[i].is_ascii_whitespace() {
+                i += 1;
+            }
+            if bytes.get(i) != Some(&b'=') {
+                continue;
+            }
+            i += 1;
+            while i < bytes.len() && bytes[i].is_ascii_whitespace() {
+                i += 1;
+            }
+            let value_start = i;
+            match bytes.get(i) {
+                Some(&quote) if quote == b'"' || quote == b'\'' => {
+                    i += 1;
+                    while i < bytes.len() && bytes[i] != quote {
+                        i += 1;
+                    }
+                    i += 1;
+                }
+                _ => {
+                    while i < bytes.len() && !bytes[i].is_ascii_whitespace() && bytes[i] != b'>' {
+                        i += 1;
+                    }
+                }
+            }
+            if first {
+                let value_end = i.min(bytes.len());
+                if let Some(last) = values.last_mut() {
+                    *last = html[value_start..value_end].to_string();
+                }
+            }
+        }
+        i = i.min(bytes.len());
+        open.push((name.clone(), markup.len()));
+        markup.push(Markup {
+            range: tag_start..i,
+            start_tag: Some((name.clone(), attributes)),
+            values,
+            end: None,
+        });
+
+        if RAW_TEXT_ELEMENTS.contains(&name.as_str()) {
+            let end_tag = format!("</{}", name);
+            i = html[i..]
+                .to_ascii_lowercase()
+                .find(&end_tag)
+                .map_or(bytes.len(), |end| i + end);
+        }
+    }
+    markup
+}
+
+/// Returns whether `byte` ends a tag or attribute name.
+fn is_tag_delimiter(byte: u8) -> bool {
+    byte.is_ascii_whitespace() || byte == b'/' || byte == b'>'
+}
+
+#[cfg(test)]
+mod tests {
+    use super::decode_value;
+    use crate::html5ever::tendril::TendrilSink;
+    use crate::{parse_html, parse_html_str_with_options, ParseOpts};
+
+    /// Tests recording the source of elements and text.
+    ///
+    /// Verifies that start tags and undecoded text are recorded, that raw
+    /// text is recorded as is, and that parsing without the option records
+    /// nothing.
+    #[test]
+    fn records_start_tags_and_text() {
+        let html = "<!DOCTYPE html><DIV id='a'  data-X=1>A &lt; B<br/>\
+                    <script>if (a<b) {}</script>caf&eacute;</DIV>";
+        let opts = ParseOpts {
+            preserve_source: true,
+            ..Default::default()
+        };
+        let doc = parse_html_str_with_options(opts, html);
+        let div = doc.select_first("div").unwrap();
+        let sources: Vec<Option<String>> = div
+            .as_node()
+            .inclusive_descendants()
+            .map(|node| node.source_markup())
+            .collect();
+        assert_eq!(
+            sources,
+            [
+                Some("<DIV id='a'  data-X=1>".to_string()),
+                Some("A &lt; B".to_string()),
+                Some("<br/>".to_string()),
+                Some("<script>".to_string()),
+                Some("if (a<b) {}".to_string()),
+                Some("caf&eacute;".to_string()),
+            ]
+        );
+
+        let doc = parse_html().one(html);
+        assert!(doc.descendants().all(|node| node.source_markup().is_none()));
+    }
+
+    /// Tests decoding attribute values as written in a start tag.
+    ///
+    /// Verifies that quotes are removed and character references decoded
+    /// as in attributes.
+    #[test]
+    fn decodes_attribute_values() {
+        assert_eq!(decode_value("'a b'").as_deref(), Some("a b"));
+        assert_eq!(decode_value("\"x&amp;y\"").as_deref(), Some("x&y"));
+        assert_eq!(decode_value("?a=1&copy=2").as_deref(), Some("?a=1&copy=2"));
+        assert_eq!(decode_value("").as_deref(), Some(""));
+    }
+}
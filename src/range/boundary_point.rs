@@ -0,0 +1,24 @@
+use crate::tree::NodeRef;
+
+/// A position in a tree: a node and an offset within it.
+///
+/// For text, comment and processing instruction nodes, the offset counts
+/// characters (Unicode scalar values, not UTF-16 code units as in the DOM);
+/// for other nodes, it counts children.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BoundaryPoint {
+    /// The node containing the position.
+    pub node: NodeRef,
+    /// The offset within the node.
+    pub offset: usize,
+}
+
+/// Methods for BoundaryPoint.
+///
+/// Provides construction.
+impl BoundaryPoint {
+    /// Creates a boundary point.
+    pub fn new(node: NodeRef, offset: usize) -> BoundaryPoint {
+        BoundaryPoint { node, offset }
+    }
+}
@@ -0,0 +1,179 @@
+use super::tree_position::{compare_points, index, is_inclusive_ancestor, node_length, root};
+use super::{BoundaryPoint, RangeError};
+use crate::tree::{NodeData, NodeRef};
+use std::cmp::Ordering;
+
+/// The content of a tree between two boundary points, like a DOM `Range`.
+///
+/// A range is checked against the tree when it is created and again by
+/// each operation, since it is not updated when the tree changes.
+///
+/// # Examples
+///
+/// ```
+/// use brik::parse_html;
+/// use brik::range::{BoundaryPoint, Range};
+/// use brik::traits::*;
+///
+/// let doc = parse_html().one("<p>Hello <b>bold</b> world</p>");
+/// let p = doc.select_first("p").unwrap();
+/// let hello = p.as_node().first_child().unwrap();
+/// let world = p.as_node().last_child().unwrap();
+///
+/// let range = Range::new(
+///     BoundaryPoint::new(hello, 3),
+///     BoundaryPoint::new(world, 3),
+/// )
+/// .unwrap();
+/// let contents = range.clone_contents().unwrap();
+/// assert_eq!(contents.to_string(), "lo <b>bold</b> wo");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Range {
+    /// The start of the range.
+    pub(super) start: BoundaryPoint,
+    /// The end of the range.
+    pub(super) end: BoundaryPoint,
+}
+
+/// Methods for Range.
+///
+/// Provides construction, validation and boundary access.
+impl Range {
+    /// Creates a range between two boundary points.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RangeError::InvalidNodeType`] if a point is in a doctype,
+    /// [`RangeError::IndexSize`] if an offset is past the end of its node,
+    /// [`RangeError::WrongDocument`] if the points are in different trees,
+    /// and [`RangeError::EndBeforeStart`] if `end` is before `start`.
+    pub fn new(start: BoundaryPoint, end: BoundaryPoint) -> Result<Range, RangeError> {
+        let range = Range { start, end };
+        range.validate()?;
+        Ok(range)
+    }
+
+    /// Creates a range containing exactly `node`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RangeError::InvalidNodeType`] if `node` has no parent.
+    pub fn select_node(node: &NodeRef) -> Result<Range, RangeError> {
+        let parent = node.parent().ok_or(RangeError::InvalidNodeType)?;
+        let offset = index(node);
+        Ok(Range {
+            start: BoundaryPoint::new(parent.clone(), offset),
+            end: BoundaryPoint::new(parent, offset + 1),
+        })
+    }
+
+    /// Creates a range containing the contents of `node`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RangeError::InvalidNodeType`] if `node` is a doctype.
+    pub fn select_node_contents(node: &NodeRef) -> Result<Range, RangeError> {
+        if let NodeData::Doctype(_) = node.data() {
+            return Err(RangeError::InvalidNodeType);
+        }
+        Ok(Range {
+            start: BoundaryPoint::new(node.clone(), 0),
+            end: BoundaryPoint::new(node.clone(), node_length(node)),
+        })
+    }
+
+    /// Returns the start of the range.
+    pub fn start(&self) -> &BoundaryPoint {
+        &self.start
+    }
+
+    /// Returns the end of the range.
+    pub fn end(&self) -> &BoundaryPoint {
+        &self.end
+    }
+
+    /// Returns whether the range is empty, with its start and end at the
+    /// same point.
+    pub fn is_collapsed(&self) -> bool {
+        self.start == self.end
+    }
+
+    /// Returns the deepest node that contains both ends of the range.
+    pub fn common_ancestor(&self) -> NodeRef {
+        self.start
+            .node
+            .inclusive_ancestors()
+            .find(|ancestor| is_inclusive_ancestor(ancestor, &self.end.node))
+            .unwrap_or_else(|| self.start.node.clone())
+    }
+
+    /// Checks that the range still fits its tree.
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors described for [`Range::new()`].
+    pub fn validate(&self) -> Result<(), RangeError> {
+        for point in [&self.start, &self.end].iter() {
+            if let NodeData::Doctype(_) = point.node.data() {
+                return Err(RangeError::InvalidNodeType);
+            }
+            if point.offset > node_length(&point.node) {
+                return Err(RangeError::IndexSize);
+            }
+        }
+        if root(&self.start.node) != root(&self.end.node) {
+            return Err(RangeError::WrongDocument);
+        }
+        let order = compare_points(
+            &self.start.node,
+            self.start.offset,
+            &self.end.node,
+            self.end.offset,
+        );
+        if order == Ordering::Greater {
+            return Err(RangeError::EndBeforeStart);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::html5ever::tendril::TendrilSink;
+    use crate::parse_html;
+
+    /// Tests validation of boundary points.
+    ///
+    /// Verifies that offsets count characters in text and children in other
+    /// nodes, and that ordering and tree membership are checked.
+    #[test]
+    fn validation() {
+        let doc = parse_html().one("<p>é<b>x</b></p>");
+        let p = doc.select_first("p").unwrap().as_node().clone();
+        let text = p.first_child().unwrap();
+        let point = |node: &NodeRef, offset| BoundaryPoint::new(node.clone(), offset);
+
+        assert!(Range::new(point(&text, 1), point(&p, 2)).is_ok());
+        assert_eq!(
+            Range::new(point(&text, 2), point(&p, 2)),
+            Err(RangeError::IndexSize)
+        );
+        assert_eq!(
+            Range::new(point(&p, 1), point(&text, 0)),
+            Err(RangeError::EndBeforeStart)
+        );
+        let other = NodeRef::new_text("y");
+        assert_eq!(
+            Range::new(point(&text, 0), point(&other, 0)),
+            Err(RangeError::WrongDocument)
+        );
+
+        let range = Range::select_node(&p.last_child().unwrap()).unwrap();
+        assert_eq!(range.start(), &point(&p, 1));
+        assert_eq!(range.common_ancestor(), p);
+        text.detach();
+        assert_eq!(range.validate(), Err(RangeError::IndexSize));
+    }
+}
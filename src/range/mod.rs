@@ -0,0 +1,22 @@
+//! Ranges of content between two points in a tree.
+//!
+//! A [`Range`] spans the content between two [`BoundaryPoint`]s, like a
+//! DOM `Range`, and can copy, extract, delete or wrap that content while
+//! splitting the text and elements at its ends. Unlike DOM ranges, ranges
+//! are not updated when the tree changes; each operation checks the range
+//! against the tree and fails with a [`RangeError`] if it no longer fits.
+
+/// Boundary points.
+mod boundary_point;
+/// The range type.
+mod dom_range;
+/// Range content operations.
+mod range_contents;
+/// Range errors.
+mod range_error;
+/// Tree order and node length helpers.
+mod tree_position;
+
+pub use boundary_point::BoundaryPoint;
+pub use dom_range::Range;
+pub use range_error::RangeError;
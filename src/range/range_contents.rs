@@ -0,0 +1,545 @@
+//! Copying, extraction, deletion and wrapping of range contents.
+
+use super::tree_position::{
+    char_data, child_towards, compare_points, index, is_inclusive_ancestor, node_length,
+    remove_data, set_char_data, substring,
+};
+use super::{BoundaryPoint, Range, RangeError};
+use crate::tree::{NodeData, NodeRef};
+use std::cmp::Ordering;
+
+/// Content methods for Range.
+///
+/// Provides the DOM `Range` operations that copy, move and insert content.
+impl Range {
+    /// Returns a document fragment holding a copy of the range's contents.
+    ///
+    /// Nodes partly in the range are copied without the parts outside it:
+    /// text is cut at the boundary points, and elements are copied shallowly
+    /// with only their children in the range.
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`Range::validate()`], or
+    /// [`RangeError::HierarchyRequest`] if the range contains a doctype.
+    pub fn clone_contents(&self) -> Result<NodeRef, RangeError> {
+        self.validate()?;
+        clone_range(&self.start, &self.end)
+    }
+
+    /// Moves the range's contents into a document fragment, returning it,
+    /// and collapses the range to where the contents were.
+    ///
+    /// Nodes partly in the range stay in the tree, losing the parts in the
+    /// range; the fragment holds shallow copies of them with those parts.
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`Range::validate()`], or
+    /// [`RangeError::HierarchyRequest`] if the range contains a doctype.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::parse_html;
+    /// use brik::range::{BoundaryPoint, Range};
+    /// use brik::traits::*;
+    ///
+    /// let doc = parse_html().one("<p>one <b>two three</b> four</p>");
+    /// let p = doc.select_first("p").unwrap();
+    /// let one = p.as_node().first_child().unwrap();
+    /// let two = p.as_node().select_first("b").unwrap().as_node().first_child().unwrap();
+    ///
+    /// let mut range = Range::new(BoundaryPoint::new(one, 2), BoundaryPoint::new(two, 3)).unwrap();
+    /// let extracted = range.extract_contents().unwrap();
+    /// assert_eq!(extracted.to_string(), "e <b>two</b>");
+    /// assert_eq!(p.as_node().to_string(), "<p>on<b> three</b> four</p>");
+    /// assert!(range.is_collapsed());
+    /// ```
+    pub fn extract_contents(&mut self) -> Result<NodeRef, RangeError> {
+        self.validate()?;
+        let (fragment, point) = extract_range(&self.start, &self.end)?;
+        self.start = point.clone();
+        self.end = point;
+        Ok(fragment)
+    }
+
+    /// Removes the range's contents from the tree and collapses the range to
+    /// where they were.
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`Range::validate()`].
+    pub fn delete_contents(&mut self) -> Result<(), RangeError> {
+        self.validate()?;
+        if self.is_collapsed() {
+            return Ok(());
+        }
+        let (start, end) = (self.start.clone(), self.end.clone());
+        if start.node == end.node && char_data(&start.node).is_some() {
+            remove_data(&start.node, start.offset, end.offset);
+            self.end = start;
+            return Ok(());
+        }
+
+        let mut removed = Vec::new();
+        let mut descendants = self.common_ancestor().descendants();
+        while let Some(node) = descendants.next() {
+            if is_contained(&node, &start, &end) {
+                descendants.skip_subtree();
+                removed.push(node);
+            }
+        }
+        let point = collapse_point(&start, &end);
+        if char_data(&start.node).is_some() {
+            remove_data(&start.node, start.offset, node_length(&start.node));
+        }
+        for node in removed {
+            node.detach();
+        }
+        if char_data(&end.node).is_some() {
+            remove_data(&end.node, 0, end.offset);
+        }
+        self.start = point.clone();
+        self.end = point;
+        Ok(())
+    }
+
+    /// Inserts `node` at the start of the range, splitting a text node the
+    /// range starts in.
+    ///
+    /// The node is moved from its current position, if any; a document
+    /// fragment has its children inserted instead. If the range is
+    /// collapsed, it is extended to contain the inserted nodes. Otherwise
+    /// its boundary points are moved as the DOM moves those of live
+    /// ranges, so the range still ends where it did, after the inserted
+    /// nodes.
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`Range::validate()`], or
+    /// [`RangeError::HierarchyRequest`] if the range starts in a comment,
+    /// processing instruction or parentless text node, if `node` is a
+    /// document, or if `node` would become its own descendant.
+    pub fn insert_node(&mut self, node: &NodeRef) -> Result<(), RangeError> {
+        self.validate()?;
+        let start = self.start.clone();
+        let is_text = start.node.as_text().is_some();
+        let invalid_start = match start.node.data() {
+            NodeData::Comment(_) | NodeData::ProcessingInstruction(_) => true,
+            NodeData::Text(_) => start.node.parent().is_none(),
+            _ => false,
+        };
+        if invalid_start || start.node == *node || matches!(node.data(), NodeData::Document(_)) {
+            return Err(RangeError::HierarchyRequest);
+        }
+
+        let mut reference = if is_text {
+            Some(start.node.clone())
+        } else {
+            start.node.children().nth(start.offset)
+        };
+        let parent = match &reference {
+            Some(reference) => reference.parent().ok_or(RangeError::HierarchyRequest)?,
+            None => start.node.clone(),
+        };
+        if is_inclusive_ancestor(node, &parent) {
+            return Err(RangeError::HierarchyRequest);
+        }
+        let collapsed = self.is_collapsed();
+        let end_anchor = end_anchor(&self.end, node);
+        if is_text {
+            reference = start.node.split_text(start.offset);
+        }
+        if reference.as_ref() == Some(node) {
+            reference = node.next_sibling();
+        }
+        node.detach();
+
+        let inserted: Vec<NodeRef> = match node.data() {
+            NodeData::DocumentFragment => node.children().collect(),
+            _ => vec![node.clone()],
+        };
+        let first = reference.as_ref().map_or(node_length(&parent), index);
+        let offset = first + inserted.len();
+        for child in inserted {
+            match &reference {
+                Some(reference) => reference.insert_before(child),
+                None => parent.append(child),
+            }
+        }
+        if !is_text {
+            self.start = BoundaryPoint::new(parent.clone(), first);
+        }
+        if collapsed {
+            self.end = BoundaryPoint::new(parent, offset);
+        } else if let Some((container, child)) = end_anchor {
+            let offset = child.as_ref().map_or(node_length(&container), index);
+            self.end = BoundaryPoint::new(container, offset);
+        } else if let Some(rest) = reference.filter(|_| is_text && self.end.node == start.node) {
+            self.end = BoundaryPoint::new(rest, self.end.offset - start.offset);
+        }
+        Ok(())
+    }
+
+    /// Moves the range's contents into `new_parent`, puts `new_parent` where
+    /// they were, and selects it.
+    ///
+    /// Any children of `new_parent` are removed first.
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`Range::validate()`] and
+    /// [`Range::insert_node()`], [`RangeError::InvalidState`] if the range
+    /// partially contains a node other than a text node, or
+    /// [`RangeError::InvalidNodeType`] if `new_parent` is a document,
+    /// doctype or document fragment.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::parse_html;
+    /// use brik::range::{BoundaryPoint, Range};
+    /// use brik::traits::*;
+    ///
+    /// let doc = parse_html().one("<p>Read the manual first</p>");
+    /// let text = doc.select_first("p").unwrap().as_node().first_child().unwrap();
+    /// let mark = parse_html().one("<mark></mark>").select_first("mark").unwrap();
+    ///
+    /// let mut range = Range::new(
+    ///     BoundaryPoint::new(text.clone(), 5),
+    ///     BoundaryPoint::new(text, 15),
+    /// )
+    /// .unwrap();
+    /// range.surround_contents(mark.as_node()).unwrap();
+    /// assert_eq!(
+    ///     doc.select_first("p").unwrap().as_node().to_string(),
+    ///     "<p>Read <mark>the manual</mark> first</p>"
+    /// );
+    /// ```
+    pub fn surround_contents(&mut self, new_parent: &NodeRef) -> Result<(), RangeError> {
+        self.validate()?;
+        let partial = |from: &NodeRef, other: &NodeRef| {
+            from.inclusive_ancestors()
+                .any(|node| !is_inclusive_ancestor(&node, other) && node.as_text().is_none())
+        };
+        if partial(&self.start.node, &self.end.node) || partial(&self.end.node, &self.start.node) {
+            return Err(RangeError::InvalidState);
+        }
+        if let NodeData::Document(_) | NodeData::Doctype(_) | NodeData::DocumentFragment =
+            new_parent.data()
+        {
+            return Err(RangeError::InvalidNodeType);
+        }
+
+        let fragment = self.extract_contents()?;
+        for child in new_parent.children().collect::<Vec<_>>() {
+            child.detach();
+        }
+        self.insert_node(new_parent)?;
+        append_children(new_parent, &fragment);
+        *self = Range::select_node(new_parent)?;
+        Ok(())
+    }
+}
+
+/// The children of a range's common ancestor that the range touches.
+struct Parts {
+    /// The child containing the start, if the range only partly contains it.
+    first_partial: Option<NodeRef>,
+    /// The children wholly in the range.
+    contained: Vec<NodeRef>,
+    /// The child containing the end, if the range only partly contains it.
+    last_partial: Option<NodeRef>,
+}
+
+/// Finds the parts of the range between `start` and `end`.
+fn parts(start: &BoundaryPoint, end: &BoundaryPoint) -> Result<Parts, RangeError> {
+    let common = start
+        .node
+        .inclusive_ancestors()
+        .find(|ancestor| is_inclusive_ancestor(ancestor, &end.node))
+        .ok_or(RangeError::WrongDocument)?;
+    let first_partial = if is_inclusive_ancestor(&start.node, &end.node) {
+        None
+    } else {
+        child_towards(&common, &start.node)
+    };
+    let last_partial = if is_inclusive_ancestor(&end.node, &start.node) {
+        None
+    } else {
+        child_towards(&common, &end.node)
+    };
+    let contained: Vec<NodeRef> = common
+        .children()
+        .filter(|child| is_contained(child, start, end))
+        .collect();
+    if contained
+        .iter()
+        .any(|child| matches!(child.data(), NodeData::Doctype(_)))
+    {
+        return Err(RangeError::HierarchyRequest);
+    }
+    Ok(Parts {
+        first_partial,
+        contained,
+        last_partial,
+    })
+}
+
+/// Returns whether `node` is wholly inside the range between `start` and
+/// `end`.
+fn is_contained(node: &NodeRef, start: &BoundaryPoint, end: &BoundaryPoint) -> bool {
+    compare_points(node, 0, &start.node, start.offset) == Ordering::Greater
+        && compare_points(node, node_length(node), &end.node, end.offset) == Ordering::Less
+}
+
+/// Returns the container and following child of a range end in a node
+/// with children, for finding its offset again after `moved` is moved.
+///
+/// An end inside `moved` is taken to where `moved` was, as the DOM does
+/// for live ranges. Ends in character data are left alone.
+fn end_anchor(end: &BoundaryPoint, moved: &NodeRef) -> Option<(NodeRef, Option<NodeRef>)> {
+    if char_data(&end.node).is_some() {
+        return None;
+    }
+    if is_inclusive_ancestor(moved, &end.node) {
+        return moved.parent().map(|parent| (parent, moved.next_sibling()));
+    }
+    let mut child = end.node.children().nth(end.offset);
+    if child.as_ref() == Some(moved) {
+        child = moved.next_sibling();
+    }
+    Some((end.node.clone(), child))
+}
+
+/// Returns the point a range collapses to once its contents are removed.
+fn collapse_point(start: &BoundaryPoint, end: &BoundaryPoint) -> BoundaryPoint {
+    if is_inclusive_ancestor(&start.node, &end.node) {
+        return start.clone();
+    }
+    let mut reference = start.node.clone();
+    while let Some(parent) = reference.parent() {
+        if is_inclusive_ancestor(&parent, &end.node) {
+            return BoundaryPoint::new(parent, index(&reference) + 1);
+        }
+        reference = parent;
+    }
+    start.clone()
+}
+
+/// Returns a shallow copy of a node.
+fn shallow_copy(node: &NodeRef) -> NodeRef {
+    node.clone_with(false, &mut str::to_string)
+}
+
+/// Returns a shallow copy of a character data node holding part of its
+/// text.
+fn partial_copy(node: &NodeRef, data: &str, start: usize, end: usize) -> NodeRef {
+    let copy = shallow_copy(node);
+    set_char_data(&copy, &substring(data, start, end));
+    copy
+}
+
+/// Moves the children of `fragment` to the end of `parent`.
+fn append_children(parent: &NodeRef, fragment: &NodeRef) {
+    for child in fragment.children().collect::<Vec<_>>() {
+        parent.append(child);
+    }
+}
+
+/// Copies the contents of the range between `start` and `end`.
+fn clone_range(start: &BoundaryPoint, end: &BoundaryPoint) -> Result<NodeRef, RangeError> {
+    let fragment = NodeRef::new(NodeData::DocumentFragment);
+    if start == end {
+        return Ok(fragment);
+    }
+    if start.node == end.node {
+        if let Some(data) = char_data(&start.node) {
+            fragment.append(partial_copy(&start.node, &data, start.offset, end.offset));
+            return Ok(fragment);
+        }
+    }
+
+    let parts = parts(start, end)?;
+    if let Some(first) = &parts.first_partial {
+        match char_data(first) {
+            Some(data) => {
+                let length = node_length(first);
+                fragment.append(partial_copy(first, &data, start.offset, length));
+            }
+            None => {
+                let copy = shallow_copy(first);
+                fragment.append(copy.clone());
+                let first_end = BoundaryPoint::new(first.clone(), node_length(first));
+                append_children(&copy, &clone_range(start, &first_end)?);
+            }
+        }
+    }
+    for child in &parts.contained {
+        fragment.append(child.clone_with(true, &mut str::to_string));
+    }
+    if let Some(last) = &parts.last_partial {
+        match char_data(last) {
+            Some(data) => fragment.append(partial_copy(last, &data, 0, end.offset)),
+            None => {
+                let copy = shallow_copy(last);
+                fragment.append(copy.clone());
+                let last_start = BoundaryPoint::new(last.clone(), 0);
+                append_children(&copy, &clone_range(&last_start, end)?);
+            }
+        }
+    }
+    Ok(fragment)
+}
+
+/// Moves the contents of the range between `start` and `end` into a
+/// fragment, returning it with the point the range collapses to.
+fn extract_range(
+    start: &BoundaryPoint,
+    end: &BoundaryPoint,
+) -> Result<(NodeRef, BoundaryPoint), RangeError> {
+    let fragment = NodeRef::new(NodeData::DocumentFragment);
+    if start == end {
+        return Ok((fragment, start.clone()));
+    }
+    if start.node == end.node {
+        if let Some(data) = char_data(&start.node) {
+            fragment.append(partial_copy(&start.node, &data, start.offset, end.offset));
+            remove_data(&start.node, start.offset, end.offset);
+            return Ok((fragment, start.clone()));
+        }
+    }
+
+    let parts = parts(start, end)?;
+    let point = collapse_point(start, end);
+    if let Some(first) = &parts.first_partial {
+        match char_data(first) {
+            Some(data) => {
+                let length = node_length(first);
+                fragment.append(partial_copy(first, &data, start.offset, length));
+                remove_data(first, start.offset, length);
+            }
+            None => {
+                let copy = shallow_copy(first);
+                fragment.append(copy.clone());
+                let first_end = BoundaryPoint::new(first.clone(), node_length(first));
+                append_children(&copy, &extract_range(start, &first_end)?.0);
+            }
+        }
+    }
+    for child in parts.contained {
+        fragment.append(child);
+    }
+    if let Some(last) = &parts.last_partial {
+        match char_data(last) {
+            Some(data) => {
+                fragment.append(partial_copy(last, &data, 0, end.offset));
+                remove_data(last, 0, end.offset);
+            }
+            None => {
+                let copy = shallow_copy(last);
+                fragment.append(copy.clone());
+                let last_start = BoundaryPoint::new(last.clone(), 0);
+                append_children(&copy, &extract_range(&last_start, end)?.0);
+            }
+        }
+    }
+    Ok((fragment, point))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::html5ever::tendril::TendrilSink;
+    use crate::parse_html;
+
+    /// Tests deleting a range spanning nested elements.
+    ///
+    /// Verifies that partly selected text and elements keep their parts
+    /// outside the range, and that the range collapses between them.
+    #[test]
+    fn delete_across_elements() {
+        let doc = parse_html().one("<div><p>ab<i>cd</i></p><p>ef</p><p>gh</p></div>");
+        let div = doc.select_first("div").unwrap().as_node().clone();
+        let c = div
+            .select_first("i")
+            .unwrap()
+            .as_node()
+            .first_child()
+            .unwrap();
+        let gh = div.last_child().unwrap().first_child().unwrap();
+
+        let mut range = Range::new(BoundaryPoint::new(c, 1), BoundaryPoint::new(gh, 1)).unwrap();
+        range.delete_contents().unwrap();
+        assert_eq!(div.to_string(), "<div><p>ab<i>c</i></p><p>h</p></div>");
+        assert_eq!(range.start(), &BoundaryPoint::new(div, 1));
+        assert!(range.is_collapsed());
+    }
+
+    /// Tests surrounding a range that cuts through an element.
+    ///
+    /// Edge case: surrounding fails without changing the tree when an
+    /// element is only partly in the range.
+    #[test]
+    fn surround_rejects_partial_elements() {
+        let doc = parse_html().one("<p>ab<b>cd</b></p>");
+        let p = doc.select_first("p").unwrap().as_node().clone();
+        let a = p.first_child().unwrap();
+        let c = p.last_child().unwrap().first_child().unwrap();
+        let span = parse_html()
+            .one("<span></span>")
+            .select_first("span")
+            .unwrap();
+
+        let mut range = Range::new(BoundaryPoint::new(a, 1), BoundaryPoint::new(c, 1)).unwrap();
+        assert_eq!(
+            range.surround_contents(span.as_node()),
+            Err(RangeError::InvalidState)
+        );
+        assert_eq!(p.to_string(), "<p>ab<b>cd</b></p>");
+    }
+
+    /// Tests inserting at the start of a non-collapsed range.
+    ///
+    /// Verifies that a range within one text node ends in the split-off
+    /// text, and that a range of children still ends after the same child
+    /// when the inserted node is moved from inside it.
+    #[test]
+    fn insert_node_keeps_range_valid() {
+        let doc = parse_html().one("<p>Hello world</p>");
+        let text = doc
+            .select_first("p")
+            .unwrap()
+            .as_node()
+            .first_child()
+            .unwrap();
+        let b = NodeRef::new_element(
+            html5ever::QualName::new(None, ns!(html), local_name!("b")),
+            None,
+        );
+        let mut range = Range::new(
+            BoundaryPoint::new(text.clone(), 2),
+            BoundaryPoint::new(text.clone(), 5),
+        )
+        .unwrap();
+        range.insert_node(&b).unwrap();
+        assert_eq!(range.validate(), Ok(()));
+        assert_eq!(range.start(), &BoundaryPoint::new(text, 2));
+        assert_eq!(range.end().offset, 3);
+        assert_eq!(range.clone_contents().unwrap().to_string(), "<b></b>llo");
+
+        let doc = parse_html().one("<p><i>a</i><i>b</i><i>c</i></p>");
+        let p = doc.select_first("p").unwrap().as_node().clone();
+        let c = p.last_child().unwrap();
+        let mut range = Range::new(
+            BoundaryPoint::new(p.clone(), 1),
+            BoundaryPoint::new(p.clone(), 2),
+        )
+        .unwrap();
+        range.insert_node(&c).unwrap();
+        assert_eq!(p.to_string(), "<p><i>a</i><i>c</i><i>b</i></p>");
+        assert_eq!(range.start(), &BoundaryPoint::new(p.clone(), 1));
+        assert_eq!(range.end(), &BoundaryPoint::new(p, 3));
+    }
+}
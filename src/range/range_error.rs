@@ -0,0 +1,46 @@
+use std::error::Error;
+use std::fmt;
+
+/// An error from creating or using a [`Range`](super::Range).
+///
+/// The variants correspond to the DOM exceptions of the same names.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RangeError {
+    /// A boundary point offset is past the end of its node.
+    IndexSize,
+    /// A node cannot be used this way: a boundary point in a doctype, a node
+    /// without a parent to select, or a document, doctype or document
+    /// fragment to surround the range with.
+    InvalidNodeType,
+    /// The boundary points are in different trees.
+    WrongDocument,
+    /// The end of the range is before its start.
+    EndBeforeStart,
+    /// The range contains a doctype, or a node cannot be inserted at the
+    /// start of the range.
+    HierarchyRequest,
+    /// The range partially contains a node other than a text node, so its
+    /// contents cannot be surrounded.
+    InvalidState,
+}
+
+/// Implements Display for RangeError.
+///
+/// Describes the problem with the range.
+impl fmt::Display for RangeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            RangeError::IndexSize => "boundary point offset is past the end of its node",
+            RangeError::InvalidNodeType => "node cannot be used for this range operation",
+            RangeError::WrongDocument => "boundary points are in different trees",
+            RangeError::EndBeforeStart => "range end is before its start",
+            RangeError::HierarchyRequest => "range operation would create an invalid tree",
+            RangeError::InvalidState => "range partially contains a non-text node",
+        })
+    }
+}
+
+/// Implements Error for RangeError.
+///
+/// Allows the error to be used with `?` and boxed errors.
+impl Error for RangeError {}
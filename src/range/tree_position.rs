@@ -0,0 +1,117 @@
+//! Tree order and node length helpers for ranges.
+
+use crate::tree::{NodeData, NodeRef};
+use std::cmp::Ordering;
+
+/// Returns the text of a character data node: a text, comment or
+/// processing instruction node.
+pub(super) fn char_data(node: &NodeRef) -> Option<String> {
+    match node.data() {
+        NodeData::Text(text) | NodeData::Comment(text) => Some(text.borrow().to_string()),
+        NodeData::ProcessingInstruction(contents) => Some(contents.borrow().1.clone()),
+        _ => None,
+    }
+}
+
+/// Sets the text of a character data node, marking it dirty; other nodes
+/// are unchanged.
+pub(super) fn set_char_data(node: &NodeRef, data: &str) {
+    match node.data() {
+        NodeData::Text(text) | NodeData::Comment(text) => *text.borrow_mut() = data.into(),
+        NodeData::ProcessingInstruction(contents) => contents.borrow_mut().1 = data.to_string(),
+        _ => return,
+    }
+    node.mark_dirty();
+}
+
+/// Returns the characters of `data` from `start` to `end`.
+pub(super) fn substring(data: &str, start: usize, end: usize) -> String {
+    data.chars().take(end).skip(start).collect()
+}
+
+/// Removes the characters from `start` to `end` of a character data node.
+pub(super) fn remove_data(node: &NodeRef, start: usize, end: usize) {
+    if let Some(data) = char_data(node) {
+        let kept: String = data
+            .chars()
+            .enumerate()
+            .filter(|(index, _)| *index < start || *index >= end)
+            .map(|(_, c)| c)
+            .collect();
+        set_char_data(node, &kept);
+    }
+}
+
+/// Returns the length of a node as used by boundary point offsets.
+pub(super) fn node_length(node: &NodeRef) -> usize {
+    match node.data() {
+        NodeData::Doctype(_) => 0,
+        _ => match char_data(node) {
+            Some(data) => data.chars().count(),
+            None => node.children().count(),
+        },
+    }
+}
+
+/// Returns the index of a node among its siblings.
+pub(super) fn index(node: &NodeRef) -> usize {
+    node.preceding_siblings().count()
+}
+
+/// Returns the root of a node's tree.
+pub(super) fn root(node: &NodeRef) -> NodeRef {
+    node.inclusive_ancestors()
+        .last()
+        .unwrap_or_else(|| node.clone())
+}
+
+/// Returns whether `ancestor` is `node` or one of its ancestors.
+pub(super) fn is_inclusive_ancestor(ancestor: &NodeRef, node: &NodeRef) -> bool {
+    node.inclusive_ancestors()
+        .any(|candidate| candidate == *ancestor)
+}
+
+/// Returns the child of `ancestor` that is an inclusive ancestor of `node`.
+pub(super) fn child_towards(ancestor: &NodeRef, node: &NodeRef) -> Option<NodeRef> {
+    node.inclusive_ancestors()
+        .find(|candidate| candidate.parent().as_ref() == Some(ancestor))
+}
+
+/// Returns whether `a` comes before `b` in tree order.
+///
+/// Both nodes must be in the same tree.
+fn precedes(a: &NodeRef, b: &NodeRef) -> bool {
+    let chain = |node: &NodeRef| {
+        let mut chain: Vec<NodeRef> = node.inclusive_ancestors().collect();
+        chain.reverse();
+        chain
+    };
+    let (a_chain, b_chain) = (chain(a), chain(b));
+    for (a, b) in a_chain.iter().zip(&b_chain) {
+        if a != b {
+            return index(a) < index(b);
+        }
+    }
+    a_chain.len() < b_chain.len()
+}
+
+/// Compares the positions of two boundary points in the same tree.
+pub(super) fn compare_points(
+    a_node: &NodeRef,
+    a_offset: usize,
+    b_node: &NodeRef,
+    b_offset: usize,
+) -> Ordering {
+    if a_node == b_node {
+        return a_offset.cmp(&b_offset);
+    }
+    if precedes(b_node, a_node) {
+        return compare_points(b_node, b_offset, a_node, a_offset).reverse();
+    }
+    if let Some(child) = child_towards(a_node, b_node) {
+        if index(&child) < a_offset {
+            return Ordering::Greater;
+        }
+    }
+    Ordering::Less
+}
@@ -156,13 +156,14 @@ impl selectors::Element for NodeDataRef<ElementData> {
     ) -> bool {
         let attrs = self.attributes.borrow();
         match *ns {
-            NamespaceConstraint::Any => attrs.map.iter().any(|(name, attr)| {
-                name.local == **local_name && operation.eval_str(attr.value.as_str())
-            }),
+            NamespaceConstraint::Any => attrs
+                .map
+                .iter()
+                .any(|(name, attr)| name.local == **local_name && operation.eval_str(&attr.value)),
             NamespaceConstraint::Specific(ns_url) => attrs
                 .map
                 .get(&ExpandedName::new(ns_url, (**local_name).clone()))
-                .is_some_and(|attr| operation.eval_str(attr.value.as_str())),
+                .is_some_and(|attr| operation.eval_str(&attr.value)),
         }
     }
 
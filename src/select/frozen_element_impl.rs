@@ -0,0 +1,188 @@
+use super::element_impl::SELECTOR_WHITESPACE;
+use super::{AttrValue, BrikSelectors, LocalNameSelector, PseudoClass, PseudoElement};
+use crate::attributes::ExpandedName;
+use crate::frozen::{FrozenNode, FrozenNodeData};
+use html5ever::{local_name, ns, LocalName, Namespace};
+use selectors::attr::{AttrSelectorOperation, CaseSensitivity, NamespaceConstraint};
+use selectors::{matching, OpaqueElement};
+
+/// Implements selectors::Element for FrozenNode.
+///
+/// Provides CSS selector matching on frozen trees with the same semantics
+/// as the implementation for mutable elements. Only element nodes are ever
+/// matched.
+impl selectors::Element for FrozenNode<'_> {
+    type Impl = BrikSelectors;
+
+    #[inline]
+    fn opaque(&self) -> OpaqueElement {
+        OpaqueElement::new(self.data())
+    }
+
+    #[inline]
+    fn is_html_slot_element(&self) -> bool {
+        false
+    }
+    #[inline]
+    fn parent_node_is_shadow_root(&self) -> bool {
+        false
+    }
+    #[inline]
+    fn containing_shadow_host(&self) -> Option<Self> {
+        None
+    }
+
+    #[inline]
+    fn parent_element(&self) -> Option<Self> {
+        self.parent().filter(FrozenNode::is_element)
+    }
+    #[inline]
+    fn prev_sibling_element(&self) -> Option<Self> {
+        std::iter::successors(self.previous_sibling(), FrozenNode::previous_sibling)
+            .find(FrozenNode::is_element)
+    }
+    #[inline]
+    fn next_sibling_element(&self) -> Option<Self> {
+        std::iter::successors(self.next_sibling(), FrozenNode::next_sibling)
+            .find(FrozenNode::is_element)
+    }
+    #[inline]
+    fn first_element_child(&self) -> Option<Self> {
+        self.children().find(FrozenNode::is_element)
+    }
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.children().all(|child| match child.data() {
+            FrozenNodeData::Element { .. } => false,
+            FrozenNodeData::Text(text) => text.is_empty(),
+            _ => true,
+        })
+    }
+    #[inline]
+    fn is_root(&self) -> bool {
+        self.parent()
+            .is_some_and(|parent| matches!(parent.data(), FrozenNodeData::Document { .. }))
+    }
+
+    #[inline]
+    fn is_html_element_in_html_document(&self) -> bool {
+        self.name().is_some_and(|name| name.ns == ns!(html))
+    }
+
+    #[inline]
+    fn has_local_name(&self, name: &LocalName) -> bool {
+        self.name().is_some_and(|own| own.local == *name)
+    }
+    #[inline]
+    fn has_namespace(&self, namespace: &Namespace) -> bool {
+        self.name().is_some_and(|own| own.ns == *namespace)
+    }
+
+    #[inline]
+    fn is_part(&self, _name: &LocalNameSelector) -> bool {
+        false
+    }
+
+    #[inline]
+    fn imported_part(&self, _: &LocalNameSelector) -> Option<LocalNameSelector> {
+        None
+    }
+
+    #[inline]
+    fn is_pseudo_element(&self) -> bool {
+        false
+    }
+
+    #[inline]
+    fn is_same_type(&self, other: &Self) -> bool {
+        self.name() == other.name()
+    }
+
+    #[inline]
+    fn is_link(&self) -> bool {
+        self.name().is_some_and(|name| {
+            name.ns == ns!(html)
+                && matches!(
+                    name.local,
+                    local_name!("a") | local_name!("area") | local_name!("link")
+                )
+        }) && self.attributes().is_some_and(|attributes| {
+            attributes.contains_key(&ExpandedName::new(ns!(), local_name!("href")))
+        })
+    }
+
+    #[inline]
+    fn has_id(&self, id: &LocalNameSelector, case_sensitivity: CaseSensitivity) -> bool {
+        self.attr("id")
+            .is_some_and(|id_attr| case_sensitivity.eq(id.as_bytes(), id_attr.as_bytes()))
+    }
+
+    #[inline]
+    fn has_class(&self, name: &LocalNameSelector, case_sensitivity: CaseSensitivity) -> bool {
+        let name = name.as_bytes();
+        !name.is_empty()
+            && self.attr("class").is_some_and(|class_attr| {
+                class_attr
+                    .split(SELECTOR_WHITESPACE)
+                    .any(|class| case_sensitivity.eq(class.as_bytes(), name))
+            })
+    }
+
+    #[inline]
+    fn attr_matches(
+        &self,
+        ns: &NamespaceConstraint<&Namespace>,
+        local_name: &LocalNameSelector,
+        operation: &AttrSelectorOperation<&AttrValue>,
+    ) -> bool {
+        let attrs = match self.attributes() {
+            Some(attributes) => attributes,
+            None => return false,
+        };
+        match *ns {
+            NamespaceConstraint::Any => attrs
+                .iter()
+                .any(|(name, value)| name.local == **local_name && operation.eval_str(value)),
+            NamespaceConstraint::Specific(ns_url) => attrs
+                .get(&ExpandedName::new(ns_url, (**local_name).clone()))
+                .is_some_and(|value| operation.eval_str(value)),
+        }
+    }
+
+    fn match_pseudo_element(
+        &self,
+        pseudo: &PseudoElement,
+        _context: &mut matching::MatchingContext<BrikSelectors>,
+    ) -> bool {
+        match *pseudo {}
+    }
+
+    fn match_non_ts_pseudo_class(
+        &self,
+        pseudo: &PseudoClass,
+        _context: &mut matching::MatchingContext<BrikSelectors>,
+    ) -> bool {
+        use self::PseudoClass::*;
+        match *pseudo {
+            Active | Focus | Hover | Enabled | Disabled | Checked | Indeterminate | Visited => {
+                false
+            }
+            AnyLink | Link => self.is_link(),
+        }
+    }
+
+    #[inline]
+    fn apply_selector_flags(&self, _flags: matching::ElementSelectorFlags) {
+        // No-op for static DOM
+    }
+
+    #[inline]
+    fn has_custom_state(&self, _name: &LocalNameSelector) -> bool {
+        false
+    }
+
+    #[inline]
+    fn add_element_unique_hashes(&self, _filter: &mut selectors::bloom::BloomFilter) -> bool {
+        false
+    }
+}
@@ -0,0 +1,103 @@
+use selectors::matching::SelectorCaches;
+use std::fmt;
+
+/// Caches shared between selector matches, such as sibling indices for
+/// `:nth-child()` and related pseudo-classes.
+///
+/// Without a context, each match recounts the preceding siblings of the
+/// element, which makes matching `:nth-child()` over every child of a wide
+/// parent quadratic. Passing the same context to
+/// [`Selectors::matches_with_context()`](crate::Selectors::matches_with_context)
+/// or [`Selectors::filter_with_context()`](crate::Selectors::filter_with_context)
+/// reuses the indices already computed.
+///
+/// Entries are keyed by node identity and the context cannot notice changes
+/// to the tree. Call [`MatchContext::clear()`] after mutating the tree, or
+/// matches may use stale indices.
+///
+/// # Examples
+///
+/// ```
+/// use brik::parse_html;
+/// use brik::traits::*;
+/// use brik::{MatchContext, Selectors};
+///
+/// let doc = parse_html().one("<ul><li>1</li><li>2</li><li>3</li><li>4</li></ul>");
+/// let selectors = Selectors::compile("li:nth-child(2n)").unwrap();
+/// let mut context = MatchContext::new();
+///
+/// let even: Vec<_> = selectors
+///     .filter_with_context(doc.descendants().elements(), &mut context)
+///     .map(|li| li.text_contents())
+///     .collect();
+/// assert_eq!(even, ["2", "4"]);
+/// ```
+#[derive(Default)]
+pub struct MatchContext {
+    /// The caches passed to the selectors crate.
+    pub(super) caches: SelectorCaches,
+}
+
+/// Methods for MatchContext.
+///
+/// Provides construction and invalidation.
+impl MatchContext {
+    /// Creates an empty context.
+    pub fn new() -> Self {
+        MatchContext::default()
+    }
+
+    /// Discards all cached entries.
+    pub fn clear(&mut self) {
+        self.caches = SelectorCaches::default();
+    }
+}
+
+/// Implements Debug for MatchContext.
+///
+/// The cached entries are opaque, so only the type name is shown.
+impl fmt::Debug for MatchContext {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("MatchContext").finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::html5ever::tendril::TendrilSink;
+    use crate::iter::NodeIterator;
+    use crate::parse_html;
+    use crate::select::Selectors;
+
+    /// Tests matching with a shared context.
+    ///
+    /// Verifies that a context reused across selectors and elements gives
+    /// the same results as matching without one, and after clearing it
+    /// once the tree has changed.
+    #[test]
+    fn shared_context_matches_uncached() {
+        let items = "<li></li>".repeat(20);
+        let doc = parse_html().one(format!("<ul>{}</ul>", items));
+        let mut context = MatchContext::new();
+
+        for selector in &[
+            "li:nth-child(3n+1)",
+            "li:nth-last-child(odd)",
+            "li:nth-of-type(7)",
+        ] {
+            let selectors = Selectors::compile(selector).unwrap();
+            let cached: Vec<_> = selectors
+                .filter_with_context(doc.descendants().elements(), &mut context)
+                .collect();
+            let uncached: Vec<_> = selectors.filter(doc.descendants().elements()).collect();
+            assert_eq!(cached, uncached);
+        }
+
+        doc.select_first("li").unwrap().as_node().detach();
+        context.clear();
+        let selectors = Selectors::compile("li:nth-child(1)").unwrap();
+        let first = doc.select_first("li").unwrap();
+        assert!(selectors.matches_with_context(&first, &mut context));
+    }
+}
@@ -8,30 +8,57 @@ mod attr_value;
 mod brik_selectors;
 /// Element trait implementation for selector matching.
 mod element_impl;
+/// Element trait implementation for frozen trees.
+mod frozen_element_impl;
 /// CSS local name selector wrapper.
 mod local_name_selector;
+/// Caches shared between selector matches.
+mod match_context;
 /// CSS pseudo-class support.
 mod pseudo_class;
 /// CSS pseudo-element support.
 mod pseudo_element;
+/// Memoized selector queries.
+mod query_cache;
+/// Chainable operations over sets of elements.
+mod selection;
 /// Compiled CSS selector.
 mod selector;
 /// Selector compilation context.
 mod selector_context;
+/// Selector match with specificity.
+mod selector_match;
+/// Detailed selector parse error.
+mod selector_parse_error;
+/// Selector to XPath conversion.
+mod selector_xpath;
 /// Compiled list of CSS selectors.
 mod selectors;
+/// Cascade ordering of rules.
+mod sort_by_specificity;
 /// Selector specificity.
 mod specificity;
+/// Element trait implementation for streamed elements.
+mod stream_element_impl;
+/// Error converting a selector to XPath.
+mod xpath_error;
 
 pub use attr_value::AttrValue;
 pub use brik_selectors::BrikSelectors;
 pub use local_name_selector::LocalNameSelector;
+pub use match_context::MatchContext;
 pub use pseudo_class::PseudoClass;
 pub use pseudo_element::PseudoElement;
+pub use query_cache::QueryCache;
+pub use selection::Selection;
 pub use selector::Selector;
 pub use selector_context::SelectorContext;
+pub use selector_match::SelectorMatch;
+pub use selector_parse_error::SelectorParseError;
 pub use selectors::Selectors;
+pub use sort_by_specificity::sort_by_specificity;
 pub use specificity::Specificity;
+pub use xpath_error::XPathError;
 
 #[cfg(test)]
 mod tests {
@@ -119,6 +146,47 @@ mod tests {
         assert_eq!(elements[0].name.local, local_name!("use"));
     }
 
+    /// Tests any-namespace and no-namespace selectors.
+    ///
+    /// Verifies that `*|` matches elements and attributes in any namespace
+    /// without a prefix mapping, that `|` matches only unnamespaced names,
+    /// and that a default namespace restricts selectors without a type.
+    #[test]
+    #[cfg(feature = "namespaces")]
+    fn namespace_wildcard_selector() {
+        let html = r##"<div href="a"></div>
+<svg xmlns="http://www.w3.org/2000/svg" xmlns:xlink="http://www.w3.org/1999/xlink">
+    <rect/>
+    <use xlink:href="#icon"/>
+</svg>"##;
+        let document = parse_html().one(html);
+        let names = |selectors: Selectors| {
+            selectors
+                .filter(document.descendants().elements())
+                .map(|element| element.name.local.to_string())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(names(Selectors::compile("*|rect").unwrap()), ["rect"]);
+        assert_eq!(
+            names(Selectors::compile("[*|href]").unwrap()),
+            ["div", "use"]
+        );
+        assert_eq!(
+            names(Selectors::compile("*|use[*|href^='#']").unwrap()),
+            ["use"]
+        );
+        assert_eq!(names(Selectors::compile("[|href]").unwrap()), ["div"]);
+        assert!(names(Selectors::compile("|rect").unwrap()).is_empty());
+
+        let mut context = SelectorContext::new();
+        context.set_default_namespace(ns!(html));
+        let selectors = Selectors::compile_with_context("[*|href]", &context).unwrap();
+        assert_eq!(names(selectors), ["div"]);
+        let selectors = Selectors::compile_with_context("*|*[*|href]", &context).unwrap();
+        assert_eq!(names(selectors), ["div", "use"]);
+    }
+
     /// Tests error handling for undefined namespace prefixes.
     ///
     /// Verifies that compiling a selector with an undefined namespace
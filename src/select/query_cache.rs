@@ -0,0 +1,167 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use crate::iter::NodeIterator;
+use crate::node_data_ref::NodeDataRef;
+use crate::select::Selectors;
+use crate::tree::{ElementData, NodeRef};
+
+/// A memo of selector query results over one subtree.
+///
+/// Each distinct selector string is compiled and matched once; later queries
+/// for the same string return the stored result set. This suits analysis
+/// passes that run the same few selectors many times over an unchanging
+/// document.
+///
+/// Results are not updated when the tree changes: after any mutation of
+/// the subtree, stored results are stale until
+/// [`QueryCache::invalidate()`] or [`QueryCache::invalidate_selector()`]
+/// is called. The [dirty flags](crate::Node::is_dirty) on nodes are not
+/// used for this, since they are cleared by
+/// [`SerializeCache`](crate::SerializeCache) and do not track in-place
+/// attribute changes.
+///
+/// # Examples
+///
+/// ```
+/// use brik::parse_html;
+/// use brik::traits::*;
+/// use brik::QueryCache;
+///
+/// let doc = parse_html().one("<nav><a>1</a><a>2</a></nav>");
+/// let cache = QueryCache::new(doc.clone());
+///
+/// let links = cache.select("nav a").unwrap();
+/// assert_eq!(links.len(), 2);
+/// assert!(std::rc::Rc::ptr_eq(&links, &cache.select("nav a").unwrap()));
+///
+/// links[0].as_node().detach();
+/// cache.invalidate();
+/// assert_eq!(cache.select("nav a").unwrap().len(), 1);
+/// ```
+#[derive(Debug)]
+pub struct QueryCache {
+    /// The root of the queried subtree.
+    root: NodeRef,
+    /// Stored result sets, keyed by selector string.
+    results: RefCell<HashMap<String, Rc<[NodeDataRef<ElementData>]>>>,
+}
+
+/// Methods for QueryCache.
+///
+/// Provides cached selection and invalidation.
+impl QueryCache {
+    /// Creates an empty cache for queries over the descendants of `root`.
+    pub fn new(root: NodeRef) -> Self {
+        QueryCache {
+            root,
+            results: RefCell::new(HashMap::new()),
+        }
+    }
+
+    /// Returns the root of the queried subtree.
+    #[inline]
+    pub fn root(&self) -> &NodeRef {
+        &self.root
+    }
+
+    /// Returns the descendant elements of the root matching the selectors, in document order.
+    ///
+    /// The result is computed on the first query for a selector string and
+    /// reused until the cache is invalidated. Failed compilations are not
+    /// cached.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(())` if the selector string fails to compile.
+    pub fn select(&self, selectors: &str) -> Result<Rc<[NodeDataRef<ElementData>]>, ()> {
+        if let Some(found) = self.results.borrow().get(selectors) {
+            return Ok(found.clone());
+        }
+        let compiled = Selectors::compile(selectors)?;
+        let found: Rc<[NodeDataRef<ElementData>]> = compiled
+            .filter(self.root.descendants().elements())
+            .collect();
+        self.results
+            .borrow_mut()
+            .insert(selectors.to_string(), found.clone());
+        Ok(found)
+    }
+
+    /// Discards all stored results.
+    ///
+    /// Call this after mutating the subtree.
+    pub fn invalidate(&self) {
+        self.results.borrow_mut().clear();
+    }
+
+    /// Discards the stored result for one selector string.
+    pub fn invalidate_selector(&self, selectors: &str) {
+        self.results.borrow_mut().remove(selectors);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::html5ever::tendril::TendrilSink;
+    use crate::parse_html;
+
+    /// Tests staleness and targeted invalidation.
+    ///
+    /// Verifies that results are reused without invalidation, that one
+    /// selector can be invalidated alone, and that compile errors are
+    /// reported and not stored.
+    #[test]
+    fn invalidation() {
+        let doc = parse_html().one("<p>1</p><p>2</p><i>3</i>");
+        let cache = QueryCache::new(doc.clone());
+        assert_eq!(cache.select("p").unwrap().len(), 2);
+        assert_eq!(cache.select("i").unwrap().len(), 1);
+
+        let body = doc.select_first("body").unwrap();
+        body.as_node().append(NodeRef::new_element(
+            html5ever::QualName::new(None, ns!(html), local_name!("p")),
+            None,
+        ));
+        body.as_node().append(NodeRef::new_element(
+            html5ever::QualName::new(None, ns!(html), local_name!("i")),
+            None,
+        ));
+        assert_eq!(cache.select("p").unwrap().len(), 2);
+
+        cache.invalidate_selector("p");
+        assert_eq!(cache.select("p").unwrap().len(), 3);
+        assert_eq!(cache.select("i").unwrap().len(), 1);
+
+        assert!(cache.select("p[").is_err());
+        assert!(cache.results.borrow().get("p[").is_none());
+    }
+
+    /// Tests querying after mutating the tree.
+    ///
+    /// Verifies that removed elements and attribute changes are not seen
+    /// until the cache is invalidated.
+    #[test]
+    fn stale_after_mutation() {
+        let doc = parse_html().one("<p class=a>1</p><p>2</p>");
+        let cache = QueryCache::new(doc.clone());
+        let found = cache.select("p.a").unwrap();
+        assert_eq!(found.len(), 1);
+
+        found[0].as_node().detach();
+        doc.select_first("p")
+            .unwrap()
+            .attributes_mut()
+            .insert("class", "a".to_string());
+        let stale = cache.select("p.a").unwrap();
+        assert!(Rc::ptr_eq(&stale, &found));
+        assert_eq!(stale[0].text_contents(), "1");
+
+        cache.invalidate();
+        let fresh = cache.select("p.a").unwrap();
+        assert_eq!(fresh.len(), 1);
+        assert_eq!(fresh[0].text_contents(), "2");
+    }
+}
@@ -0,0 +1,346 @@
+use std::collections::HashSet;
+use std::iter::FromIterator;
+
+use crate::builder::Element;
+use crate::iter::NodeIterator;
+use crate::node_data_ref::NodeDataRef;
+use crate::select::Selectors;
+use crate::tree::{ElementData, Node, NodeRef};
+
+/// An ordered set of elements with chainable, jQuery-style operations.
+///
+/// Traversal operations such as [`Selection::find()`] return new selections
+/// without duplicates. Mutating operations such as [`Selection::add_class()`]
+/// apply to every member and return the selection for chaining.
+///
+/// # Examples
+///
+/// ```
+/// use brik::parse_html;
+/// use brik::traits::*;
+/// use brik::Selection;
+///
+/// let doc = parse_html().one("<ul><li>A</li><li class=ad>B</li><li>C</li></ul>");
+/// let items = Selection::select(&doc, "ul").unwrap().find("li").unwrap();
+///
+/// items.filter(".ad").unwrap().remove();
+/// items.filter(":not(.ad)").unwrap().add_class("item").set_attr("role", "listitem");
+///
+/// assert_eq!(
+///     doc.select_first("ul").unwrap().as_node().to_string(),
+///     r#"<ul><li class="item" role="listitem">A</li><li class="item" role="listitem">C</li></ul>"#
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Selection(Vec<NodeDataRef<ElementData>>);
+
+/// Methods for Selection.
+///
+/// Provides construction, traversal, inspection and mutation of element sets.
+impl Selection {
+    /// Creates a selection from elements, dropping duplicates but keeping order.
+    pub fn new<I>(elements: I) -> Self
+    where
+        I: IntoIterator<Item = NodeDataRef<ElementData>>,
+    {
+        let mut seen = HashSet::new();
+        Selection(
+            elements
+                .into_iter()
+                .filter(|element| seen.insert(node_ptr(element.as_node())))
+                .collect(),
+        )
+    }
+
+    /// Selects the inclusive descendant elements of `root` matching the selectors.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(())` if the selector string fails to parse.
+    pub fn select(root: &NodeRef, selectors: &str) -> Result<Self, ()> {
+        Ok(Selection(root.select(selectors)?.collect()))
+    }
+
+    /// Returns the number of elements.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns true if the selection has no elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns an iterator over the elements.
+    #[inline]
+    pub fn iter(&self) -> std::slice::Iter<'_, NodeDataRef<ElementData>> {
+        self.0.iter()
+    }
+
+    /// Returns the first element, if any.
+    #[inline]
+    pub fn first(&self) -> Option<&NodeDataRef<ElementData>> {
+        self.0.first()
+    }
+
+    /// Returns the elements as a vector.
+    #[inline]
+    pub fn into_vec(self) -> Vec<NodeDataRef<ElementData>> {
+        self.0
+    }
+
+    /// Returns the members matching the selectors.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(())` if the selector string fails to parse.
+    pub fn filter(&self, selectors: &str) -> Result<Self, ()> {
+        let compiled = Selectors::compile(selectors)?;
+        Ok(Selection(
+            self.0
+                .iter()
+                .filter(|element| compiled.matches(element))
+                .cloned()
+                .collect(),
+        ))
+    }
+
+    /// Returns the descendants of the members matching the selectors.
+    ///
+    /// Results follow the order of the members, each followed by its matching
+    /// descendants in document order, without duplicates.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(())` if the selector string fails to parse.
+    pub fn find(&self, selectors: &str) -> Result<Self, ()> {
+        let compiled = Selectors::compile(selectors)?;
+        Ok(Selection::new(self.0.iter().flat_map(|element| {
+            compiled
+                .filter(element.as_node().descendants().elements())
+                .collect::<Vec<_>>()
+        })))
+    }
+
+    /// Returns the parent elements of the members, without duplicates.
+    pub fn parent(&self) -> Self {
+        Selection::new(
+            self.0
+                .iter()
+                .filter_map(|element| element.as_node().parent()?.into_element_ref()),
+        )
+    }
+
+    /// Returns the child elements of the members, without duplicates.
+    pub fn children(&self) -> Self {
+        Selection::new(
+            self.0
+                .iter()
+                .flat_map(|element| element.as_node().children().elements()),
+        )
+    }
+
+    /// Returns an attribute of the first element.
+    pub fn attr(&self, name: &str) -> Option<String> {
+        self.0
+            .first()?
+            .attributes
+            .borrow()
+            .get(name)
+            .map(str::to_owned)
+    }
+
+    /// Sets an attribute on every element.
+    pub fn set_attr(&self, name: &str, value: &str) -> &Self {
+        for element in &self.0 {
+            element.attributes_mut().insert(name, value.to_string());
+        }
+        self
+    }
+
+    /// Removes an attribute from every element.
+    pub fn remove_attr(&self, name: &str) -> &Self {
+        for element in &self.0 {
+            element.attributes_mut().remove(name);
+        }
+        self
+    }
+
+    /// Adds a class to every element that does not already have it.
+    pub fn add_class(&self, class: &str) -> &Self {
+        for element in &self.0 {
+            let mut attributes = element.attributes_mut();
+            if attributes.get_tokens("class").any(|token| token == class) {
+                continue;
+            }
+            let value = match attributes.get_trimmed("class") {
+                Some(existing) if !existing.is_empty() => format!("{} {}", existing, class),
+                _ => class.to_string(),
+            };
+            attributes.insert("class", value);
+        }
+        self
+    }
+
+    /// Removes a class from every element.
+    pub fn remove_class(&self, class: &str) -> &Self {
+        for element in &self.0 {
+            let mut attributes = element.attributes_mut();
+            if !attributes.get_tokens("class").any(|token| token == class) {
+                continue;
+            }
+            let value = attributes
+                .get_tokens("class")
+                .filter(|token| *token != class)
+                .collect::<Vec<_>>()
+                .join(" ");
+            attributes.insert("class", value);
+        }
+        self
+    }
+
+    /// Returns the concatenated text content of the elements.
+    pub fn text(&self) -> String {
+        self.0
+            .iter()
+            .map(|element| element.text_contents())
+            .collect()
+    }
+
+    /// Replaces the children of every element with a text node.
+    pub fn set_text(&self, text: &str) -> &Self {
+        for element in &self.0 {
+            let node = element.as_node();
+            while let Some(child) = node.first_child() {
+                child.detach();
+            }
+            node.append(NodeRef::new_text(text));
+        }
+        self
+    }
+
+    /// Detaches every element from the tree.
+    pub fn remove(&self) -> &Self {
+        for element in &self.0 {
+            element.as_node().detach();
+        }
+        self
+    }
+
+    /// Wraps every element in a new copy of the given element.
+    ///
+    /// Each wrapper is built from `wrapper` and inserted where the element
+    /// was, with the element appended as its last child. Elements without a
+    /// parent are not wrapped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::builder::Element;
+    /// use brik::parse_html;
+    /// use brik::traits::*;
+    /// use brik::Selection;
+    ///
+    /// let doc = parse_html().one("<p><img src=a><img src=b></p>");
+    /// Selection::select(&doc, "img").unwrap().wrap(&Element::new("figure"));
+    /// assert_eq!(doc.select("figure > img").unwrap().count(), 2);
+    /// ```
+    pub fn wrap(&self, wrapper: &Element) -> &Self {
+        for element in &self.0 {
+            let node = element.as_node();
+            if node.parent().is_none() {
+                continue;
+            }
+            let built = wrapper.clone().build();
+            node.insert_before(built.clone());
+            built.append(node.clone());
+        }
+        self
+    }
+}
+
+/// Returns the address of a node, for identity comparisons.
+fn node_ptr(node: &NodeRef) -> *const Node {
+    &**node
+}
+
+/// Implements IntoIterator for Selection.
+///
+/// Yields the elements in order.
+impl IntoIterator for Selection {
+    type Item = NodeDataRef<ElementData>;
+    type IntoIter = std::vec::IntoIter<NodeDataRef<ElementData>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+/// Implements IntoIterator for &Selection.
+///
+/// Yields references to the elements in order.
+impl<'a> IntoIterator for &'a Selection {
+    type Item = &'a NodeDataRef<ElementData>;
+    type IntoIter = std::slice::Iter<'a, NodeDataRef<ElementData>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+/// Implements FromIterator for Selection.
+///
+/// Collects elements, dropping duplicates.
+impl FromIterator<NodeDataRef<ElementData>> for Selection {
+    fn from_iter<I: IntoIterator<Item = NodeDataRef<ElementData>>>(iter: I) -> Self {
+        Selection::new(iter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::html5ever::tendril::TendrilSink;
+    use crate::parse_html;
+
+    /// Tests traversal operations.
+    ///
+    /// Verifies that find, parent and children drop duplicates, and that
+    /// attr reads from the first member.
+    #[test]
+    fn traversal() {
+        let doc = parse_html().one("<div id=a><div id=b><p>1</p></div><p>2</p></div>");
+        let divs = Selection::select(&doc, "div").unwrap();
+
+        let paragraphs = divs.find("p").unwrap();
+        assert_eq!(paragraphs.len(), 2);
+        assert_eq!(paragraphs.text(), "12");
+        assert_eq!(paragraphs.parent().len(), 2);
+        assert_eq!(divs.children().len(), 3);
+        assert_eq!(divs.attr("id").as_deref(), Some("a"));
+        assert_eq!(Selection::default().attr("id"), None);
+    }
+
+    /// Tests class and text mutations.
+    ///
+    /// Verifies that classes are not duplicated, that removing a class keeps
+    /// the others, and that set_text replaces children.
+    #[test]
+    fn mutations() {
+        let doc = parse_html().one("<p class='x  y'><b>old</b></p><p>z</p>");
+        let paragraphs = Selection::select(&doc, "p").unwrap();
+        paragraphs
+            .add_class("y")
+            .remove_class("x")
+            .set_text("new")
+            .remove_attr("id");
+
+        let body = doc.select_first("body").unwrap();
+        assert_eq!(
+            body.as_node().to_string(),
+            r#"<body><p class="y">new</p><p class="y">new</p></body>"#
+        );
+    }
+}
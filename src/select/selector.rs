@@ -1,9 +1,10 @@
-use super::{BrikSelectors, Specificity};
+use super::{BrikSelectors, MatchContext, Specificity};
 use crate::node_data_ref::NodeDataRef;
 use crate::tree::ElementData;
 use selectors::context::QuirksMode;
 use selectors::matching;
 use selectors::parser::Selector as GenericSelector;
+use selectors::Element as SelectorsElement;
 use std::fmt;
 
 /// A pre-compiled CSS Selector.
@@ -16,15 +17,59 @@ impl Selector {
     /// Returns whether the given element matches this selector.
     #[inline]
     pub fn matches(&self, element: &NodeDataRef<ElementData>) -> bool {
+        self.matches_in_scope(element, None)
+    }
+
+    /// Returns whether the given element matches this selector, with `:scope`
+    /// referring to the given scope element.
+    #[inline]
+    pub fn matches_with_scope(
+        &self,
+        element: &NodeDataRef<ElementData>,
+        scope: &NodeDataRef<ElementData>,
+    ) -> bool {
+        self.matches_in_scope(element, Some(scope))
+    }
+
+    /// Returns whether the given element matches this selector, reusing the
+    /// caches in `context` from earlier matches.
+    #[inline]
+    pub fn matches_with_context(
+        &self,
+        element: &NodeDataRef<ElementData>,
+        context: &mut MatchContext,
+    ) -> bool {
+        self.matches_with_caches(element, None, &mut context.caches)
+    }
+
+    /// Matches any selectable element, with an optional scope element for `:scope`.
+    pub(crate) fn matches_in_scope<E>(&self, element: &E, scope: Option<&E>) -> bool
+    where
+        E: SelectorsElement<Impl = BrikSelectors>,
+    {
         let mut selector_caches = matching::SelectorCaches::default();
+        self.matches_with_caches(element, scope, &mut selector_caches)
+    }
+
+    /// Matches any selectable element using the given caches.
+    fn matches_with_caches<E>(
+        &self,
+        element: &E,
+        scope: Option<&E>,
+        selector_caches: &mut matching::SelectorCaches,
+    ) -> bool
+    where
+        E: SelectorsElement<Impl = BrikSelectors>,
+    {
         let mut context = matching::MatchingContext::new(
             matching::MatchingMode::Normal,
             None,
-            &mut selector_caches,
+            selector_caches,
             QuirksMode::NoQuirks,
             matching::NeedsSelectorFlags::No,
             matching::MatchingForInvalidation::No,
         );
+        context.scope_element = scope.map(SelectorsElement::opaque);
         matching::matches_selector(&self.0, 0, None, element, &mut context)
     }
 
@@ -0,0 +1,12 @@
+use super::Specificity;
+
+/// The selector of a list that matched an element, with its specificity.
+///
+/// Returned by [`Selectors::match_with_specificity()`](super::Selectors::match_with_specificity).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SelectorMatch {
+    /// The index of the matching selector in the list.
+    pub index: usize,
+    /// The specificity of the matching selector.
+    pub specificity: Specificity,
+}
@@ -0,0 +1,156 @@
+use cssparser::{BasicParseErrorKind, ParseError, ParseErrorKind, ToCss};
+use selectors::parser::SelectorParseErrorKind;
+use std::error::Error;
+use std::fmt;
+
+/// An error from parsing a selector list, with its position in the source.
+///
+/// Returned by [`Selectors::parse()`](super::Selectors::parse) and
+/// [`Selectors::parse_with_context()`](super::Selectors::parse_with_context).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SelectorParseError {
+    /// The byte offset of the error in the selector string.
+    pub offset: usize,
+    /// The line of the error, starting at 1.
+    pub line: u32,
+    /// The column of the error in characters, starting at 1.
+    pub column: u32,
+    /// The offending token or name, if there is one.
+    pub token: Option<String>,
+    /// An explanation of the error.
+    pub message: String,
+}
+
+/// Methods for SelectorParseError.
+///
+/// Provides conversion from cssparser errors.
+impl SelectorParseError {
+    /// Builds an error from a cssparser error for the given source.
+    pub(super) fn new(source: &str, error: ParseError<'_, SelectorParseErrorKind<'_>>) -> Self {
+        let (token, message) = describe(&error.kind);
+
+        // cssparser lines start at 0, and its columns are 1-based UTF-16 offsets.
+        let line_start: usize = source
+            .split_inclusive('\n')
+            .take(error.location.line as usize)
+            .map(str::len)
+            .sum();
+        let mut offset = line_start;
+        let mut column = 1;
+        let mut utf16 = 1;
+        for c in source[line_start..].chars() {
+            if utf16 >= error.location.column as usize || c == '\n' {
+                break;
+            }
+            utf16 += c.len_utf16();
+            offset += c.len_utf8();
+            column += 1;
+        }
+
+        SelectorParseError {
+            offset,
+            line: error.location.line + 1,
+            column,
+            token,
+            message,
+        }
+    }
+}
+
+/// Returns the offending token and an explanation for an error kind.
+fn describe(kind: &ParseErrorKind<'_, SelectorParseErrorKind<'_>>) -> (Option<String>, String) {
+    match kind {
+        ParseErrorKind::Basic(BasicParseErrorKind::UnexpectedToken(token)) => {
+            (Some(token.to_css_string()), "unexpected token".to_string())
+        }
+        ParseErrorKind::Basic(BasicParseErrorKind::EndOfInput) => {
+            (None, "unexpected end of input".to_string())
+        }
+        ParseErrorKind::Custom(SelectorParseErrorKind::ExpectedNamespace(prefix)) => (
+            Some(prefix.to_string()),
+            format!("undefined namespace prefix '{}'", prefix),
+        ),
+        ParseErrorKind::Custom(SelectorParseErrorKind::UnsupportedPseudoClassOrElement(name)) => (
+            Some(name.to_string()),
+            format!("unsupported pseudo-class or pseudo-element '{}'", name),
+        ),
+        ParseErrorKind::Custom(SelectorParseErrorKind::EmptySelector) => {
+            (None, "empty selector".to_string())
+        }
+        ParseErrorKind::Custom(SelectorParseErrorKind::DanglingCombinator) => {
+            (None, "combinator is not followed by a selector".to_string())
+        }
+        ParseErrorKind::Custom(SelectorParseErrorKind::ClassNeedsIdent(token)) => (
+            Some(token.to_css_string()),
+            "expected a class name after '.'".to_string(),
+        ),
+        ParseErrorKind::Custom(SelectorParseErrorKind::UnexpectedIdent(ident)) => {
+            (Some(ident.to_string()), "unexpected identifier".to_string())
+        }
+        ParseErrorKind::Custom(SelectorParseErrorKind::UnexpectedTokenInAttributeSelector(
+            token,
+        )) => (
+            Some(token.to_css_string()),
+            "unexpected token in attribute selector".to_string(),
+        ),
+        other => (None, format!("invalid selector: {:?}", other)),
+    }
+}
+
+/// Implements Display for SelectorParseError.
+///
+/// Formats the error as `line:column: message`, followed by the token if any.
+impl fmt::Display for SelectorParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}: {}", self.line, self.column, self.message)?;
+        match &self.token {
+            Some(token) if !self.message.contains(token.as_str()) => {
+                write!(f, " (found '{}')", token)
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Implements Error for SelectorParseError.
+impl Error for SelectorParseError {}
+
+#[cfg(test)]
+mod tests {
+    use crate::select::{SelectorContext, Selectors};
+
+    /// Tests error positions and messages.
+    ///
+    /// Verifies the offset, line, column and token of several errors,
+    /// including positions after multi-byte characters.
+    #[test]
+    fn positions() {
+        let error = Selectors::parse("div > ").unwrap_err();
+        assert_eq!(error.message, "combinator is not followed by a selector");
+
+        let error = Selectors::parse("p:nope").unwrap_err();
+        assert_eq!(error.token.as_deref(), Some("nope"));
+        assert_eq!(
+            error.to_string(),
+            "1:3: unsupported pseudo-class or pseudo-element 'nope'"
+        );
+
+        let error = Selectors::parse("a,\n.é ]").unwrap_err();
+        assert_eq!((error.line, error.column, error.offset), (2, 4, 7));
+        assert_eq!(
+            error.to_string(),
+            "2:4: combinator is not followed by a selector"
+        );
+    }
+
+    /// Tests the error for an undefined namespace prefix.
+    ///
+    /// Verifies that the message names the prefix.
+    #[test]
+    fn undefined_prefix() {
+        let error = Selectors::parse_with_context("x|div", &SelectorContext::new()).unwrap_err();
+        assert_eq!(error.message, "undefined namespace prefix 'x'");
+        assert_eq!(error.token.as_deref(), Some("x"));
+        assert_eq!(error.offset, 1);
+    }
+}
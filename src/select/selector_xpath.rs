@@ -0,0 +1,406 @@
+use super::{BrikSelectors, PseudoClass, Selector, XPathError};
+use cssparser::ToCss;
+use selectors::attr::{
+    AttrSelectorOperator, NamespaceConstraint, ParsedAttrSelectorOperation, ParsedCaseSensitivity,
+};
+use selectors::parser::{Combinator, Component, NthSelectorData, NthType, SelectorList};
+
+/// A selector component of brik's selector implementation.
+type BrikComponent = Component<BrikSelectors>;
+
+/// Methods for Selector.
+///
+/// Provides conversion to XPath 1.0 expressions.
+impl Selector {
+    /// Converts this selector to an equivalent XPath 1.0 expression.
+    ///
+    /// The expression selects matching elements anywhere in a document.
+    /// Element and attribute names keep their namespace prefixes, so the
+    /// XPath processor must bind the same prefixes. Dynamic pseudo-classes
+    /// such as `:hover` never match, as in brik's own matching.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`XPathError`] for components without an XPath 1.0
+    /// equivalent, such as pseudo-elements, `:scope`, `:has()` and
+    /// `:nth-child(An+B of S)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::Selectors;
+    ///
+    /// let selectors = Selectors::compile("ul > li:first-child").unwrap();
+    /// assert_eq!(
+    ///     selectors.0[0].to_xpath().unwrap(),
+    ///     "//ul/li[not(preceding-sibling::*)]"
+    /// );
+    /// ```
+    pub fn to_xpath(&self) -> Result<String, XPathError> {
+        // Compounds are stored right to left, each followed by the combinator
+        // joining it to the compound on its left.
+        let mut steps = Vec::new();
+        let mut compound = Vec::new();
+        for component in self.0.iter_raw_match_order() {
+            match component {
+                Component::Combinator(combinator) => {
+                    steps.push((compound_step(&compound)?, Some(*combinator)));
+                    compound.clear();
+                }
+                _ => compound.push(component),
+            }
+        }
+        steps.push((compound_step(&compound)?, None));
+
+        let mut xpath = String::from("//");
+        for (step, combinator) in steps.into_iter().rev() {
+            match combinator {
+                None => {}
+                Some(Combinator::Child) => xpath.push('/'),
+                Some(Combinator::Descendant) => xpath.push_str("//"),
+                Some(Combinator::NextSibling) => xpath.push_str("/following-sibling::*[1]/self::"),
+                Some(Combinator::LaterSibling) => xpath.push_str("/following-sibling::"),
+                Some(other) => {
+                    return Err(XPathError {
+                        component: other.to_css_string(),
+                    })
+                }
+            }
+            xpath.push_str(&step.name);
+            for predicate in &step.predicates {
+                xpath.push('[');
+                xpath.push_str(predicate);
+                xpath.push(']');
+            }
+        }
+        Ok(xpath)
+    }
+}
+
+/// A location step for one compound selector.
+struct Step {
+    /// The node test, such as `div`, `svg:rect` or `*`.
+    name: String,
+    /// The predicates, without brackets.
+    predicates: Vec<String>,
+}
+
+/// Methods for Step.
+///
+/// Provides conversion to a single boolean condition.
+impl Step {
+    /// Returns a condition testing the context node against this step.
+    fn condition(&self) -> String {
+        let mut conditions = Vec::new();
+        if self.name != "*" {
+            conditions.push(format!("self::{}", self.name));
+        }
+        conditions.extend(self.predicates.iter().cloned());
+        match conditions.len() {
+            0 => "true()".to_string(),
+            1 => conditions.remove(0),
+            _ => format!("({})", conditions.join(" and ")),
+        }
+    }
+}
+
+/// Converts the components of a compound selector to a location step.
+fn compound_step(components: &[&BrikComponent]) -> Result<Step, XPathError> {
+    let mut prefix = None;
+    let mut any_namespace = false;
+    let mut no_namespace = false;
+    let mut local = None;
+    for component in components {
+        match component {
+            Component::Namespace(name, _) => prefix = Some(name.to_string()),
+            Component::ExplicitAnyNamespace => any_namespace = true,
+            Component::ExplicitNoNamespace => no_namespace = true,
+            Component::LocalName(name) => local = Some(name.name.to_string()),
+            _ => {}
+        }
+    }
+
+    let mut predicates = Vec::new();
+    let name = if any_namespace || no_namespace {
+        if no_namespace {
+            predicates.push("namespace-uri()=''".to_string());
+        }
+        if let Some(local) = &local {
+            predicates.push(format!("local-name()={}", literal(local)));
+        }
+        "*".to_string()
+    } else {
+        match (prefix, local) {
+            (Some(prefix), Some(local)) => format!("{}:{}", prefix, local),
+            (Some(prefix), None) => format!("{}:*", prefix),
+            (None, Some(local)) => local,
+            (None, None) => "*".to_string(),
+        }
+    };
+
+    for component in components {
+        if let Some(predicate) = component_predicate(component, &name)? {
+            predicates.push(predicate);
+        }
+    }
+    Ok(Step { name, predicates })
+}
+
+/// Converts a simple selector to a predicate, given the step's node test.
+///
+/// Returns `None` for components already expressed by the node test.
+fn component_predicate(
+    component: &BrikComponent,
+    name: &str,
+) -> Result<Option<String>, XPathError> {
+    let predicate = match component {
+        Component::LocalName(_)
+        | Component::ExplicitUniversalType
+        | Component::ExplicitAnyNamespace
+        | Component::ExplicitNoNamespace
+        | Component::DefaultNamespace(_)
+        | Component::Namespace(..) => return Ok(None),
+        Component::ID(id) => format!("@id={}", literal(id)),
+        Component::Class(class) => includes("@class", class),
+        Component::AttributeInNoNamespaceExists { local_name, .. } => format!("@{}", **local_name),
+        Component::AttributeInNoNamespace {
+            local_name,
+            operator,
+            value,
+            case_sensitivity,
+        } => attribute_value(
+            &format!("@{}", **local_name),
+            *operator,
+            value,
+            *case_sensitivity,
+        ),
+        Component::AttributeOther(selector) => {
+            let attribute = match &selector.namespace {
+                None => format!("@{}", *selector.local_name),
+                Some(NamespaceConstraint::Any) => {
+                    format!("@*[local-name()={}]", literal(&selector.local_name))
+                }
+                Some(NamespaceConstraint::Specific((prefix, _))) => {
+                    format!("@{}:{}", **prefix, *selector.local_name)
+                }
+            };
+            match &selector.operation {
+                ParsedAttrSelectorOperation::Exists => attribute,
+                ParsedAttrSelectorOperation::WithValue {
+                    operator,
+                    case_sensitivity,
+                    value,
+                } => attribute_value(&attribute, *operator, value, *case_sensitivity),
+            }
+        }
+        Component::Root => "not(parent::*)".to_string(),
+        Component::Empty => "not(*) and not(text())".to_string(),
+        Component::Nth(data) => nth(data, name, component)?,
+        Component::NonTSPseudoClass(PseudoClass::AnyLink | PseudoClass::Link) => {
+            "(self::a or self::area or self::link) and @href".to_string()
+        }
+        Component::NonTSPseudoClass(_) => "false()".to_string(),
+        Component::Negation(list) => format!("not({})", list_condition(list)?),
+        Component::Is(list) | Component::Where(list) => list_condition(list)?,
+        other => {
+            return Err(XPathError {
+                component: other.to_css_string(),
+            })
+        }
+    };
+    Ok(Some(predicate))
+}
+
+/// Converts a list of compound selectors to a condition matching any of them.
+fn list_condition(list: &SelectorList<BrikSelectors>) -> Result<String, XPathError> {
+    let mut conditions = Vec::new();
+    for selector in list.slice() {
+        let components = selector.iter_raw_match_order().collect::<Vec<_>>();
+        if let Some(combinator) = components.iter().find(|c| c.is_combinator()) {
+            return Err(XPathError {
+                component: combinator.to_css_string(),
+            });
+        }
+        conditions.push(compound_step(&components)?.condition());
+    }
+    Ok(match conditions.len() {
+        1 => conditions.remove(0),
+        _ => format!("({})", conditions.join(" or ")),
+    })
+}
+
+/// Converts an attribute value selector to a predicate.
+fn attribute_value(
+    attribute: &str,
+    operator: AttrSelectorOperator,
+    value: &str,
+    case_sensitivity: ParsedCaseSensitivity,
+) -> String {
+    let (attribute, value) = match case_sensitivity {
+        ParsedCaseSensitivity::AsciiCaseInsensitive => (
+            format!(
+                "translate({}, 'ABCDEFGHIJKLMNOPQRSTUVWXYZ', 'abcdefghijklmnopqrstuvwxyz')",
+                attribute
+            ),
+            value.to_ascii_lowercase(),
+        ),
+        _ => (attribute.to_string(), value.to_string()),
+    };
+    let quoted = literal(&value);
+    match operator {
+        AttrSelectorOperator::Equal => format!("{}={}", attribute, quoted),
+        AttrSelectorOperator::DashMatch => format!(
+            "({}={} or starts-with({}, {}))",
+            attribute,
+            quoted,
+            attribute,
+            literal(&format!("{}-", value))
+        ),
+        // Empty values never match these operators.
+        _ if value.is_empty() => "false()".to_string(),
+        AttrSelectorOperator::Includes => includes(&attribute, &value),
+        AttrSelectorOperator::Prefix => format!("starts-with({}, {})", attribute, quoted),
+        AttrSelectorOperator::Substring => format!("contains({}, {})", attribute, quoted),
+        AttrSelectorOperator::Suffix => format!(
+            "substring({}, string-length({}) - {}) = {}",
+            attribute,
+            attribute,
+            value.chars().count() - 1,
+            quoted
+        ),
+    }
+}
+
+/// Returns a predicate testing whether a whitespace-separated list contains a word.
+fn includes(attribute: &str, word: &str) -> String {
+    if word.is_empty() || word.contains(char::is_whitespace) {
+        return "false()".to_string();
+    }
+    format!(
+        "contains(concat(' ', normalize-space({}), ' '), {})",
+        attribute,
+        literal(&format!(" {} ", word))
+    )
+}
+
+/// Converts a structural pseudo-class to a predicate.
+fn nth(
+    data: &NthSelectorData,
+    name: &str,
+    component: &BrikComponent,
+) -> Result<String, XPathError> {
+    let of_type = matches!(
+        data.ty,
+        NthType::OfType | NthType::LastOfType | NthType::OnlyOfType
+    );
+    let sibling = if of_type {
+        if name == "*" || name.ends_with(":*") {
+            return Err(XPathError {
+                component: component.to_css_string(),
+            });
+        }
+        name
+    } else {
+        "*"
+    };
+    if data.ty.is_only() {
+        return Ok(format!(
+            "not(preceding-sibling::{}) and not(following-sibling::{})",
+            sibling, sibling
+        ));
+    }
+    let axis = match data.ty {
+        NthType::Child | NthType::OfType => "preceding-sibling",
+        _ => "following-sibling",
+    };
+
+    // With `c` siblings before (or after) the element, its position is c + 1,
+    // so An+B matches when c = An + k for some n >= 0, where k = B - 1.
+    let count = format!("count({}::{})", axis, sibling);
+    let (a, k) = (data.an_plus_b.0, data.an_plus_b.1 - 1);
+    Ok(match a {
+        0 if k < 0 => "false()".to_string(),
+        0 if k == 0 => format!("not({}::{})", axis, sibling),
+        0 => format!("{} = {}", count, k),
+        1 if k <= 0 => "true()".to_string(),
+        1 => format!("{} >= {}", count, k),
+        -1 if k < 0 => "false()".to_string(),
+        -1 => format!("{} <= {}", count, k),
+        a if a > 0 && k == 0 => format!("{} mod {} = 0", count, a),
+        a if a > 0 && k < 0 => format!("({} + {}) mod {} = 0", count, -k, a),
+        a if a > 0 => format!("{} >= {} and ({} - {}) mod {} = 0", count, k, count, k, a),
+        _ if k < 0 => "false()".to_string(),
+        a => format!("{} <= {} and ({} - {}) mod {} = 0", count, k, k, count, -a),
+    })
+}
+
+/// Quotes a string as an XPath 1.0 literal.
+///
+/// XPath 1.0 has no escapes, so strings with both quote characters are
+/// built with `concat()`.
+fn literal(value: &str) -> String {
+    if !value.contains('\'') {
+        format!("'{}'", value)
+    } else if !value.contains('"') {
+        format!("\"{}\"", value)
+    } else {
+        let parts = value
+            .split('\'')
+            .map(|part| format!("'{}'", part))
+            .collect::<Vec<_>>();
+        format!("concat({})", parts.join(", \"'\", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Selectors;
+
+    /// Converts a single selector to XPath.
+    fn xpath(selector: &str) -> Result<String, XPathError> {
+        Selectors::compile(selector).unwrap().0[0].to_xpath()
+    }
+
+    /// Tests conversion of combinators and simple selectors.
+    ///
+    /// Verifies the generated expressions for each combinator, classes,
+    /// attribute operators, negation and structural pseudo-classes.
+    #[test]
+    fn to_xpath() {
+        assert_eq!(xpath("div p").unwrap(), "//div//p");
+        assert_eq!(
+            xpath("h1 + p.lead").unwrap(),
+            "//h1/following-sibling::*[1]/self::p[contains(concat(' ', normalize-space(@class), ' '), ' lead ')]"
+        );
+        assert_eq!(
+            xpath("#main > a[href^='http'][target]").unwrap(),
+            "//*[@id='main']/a[starts-with(@href, 'http')][@target]"
+        );
+        assert_eq!(
+            xpath("a[href$='.pdf' i]").unwrap(),
+            "//a[substring(translate(@href, 'ABCDEFGHIJKLMNOPQRSTUVWXYZ', 'abcdefghijklmnopqrstuvwxyz'), string-length(translate(@href, 'ABCDEFGHIJKLMNOPQRSTUVWXYZ', 'abcdefghijklmnopqrstuvwxyz')) - 3) = '.pdf']"
+        );
+        assert_eq!(
+            xpath("li:nth-child(2n+3):not(.x, span)").unwrap(),
+            "//li[count(preceding-sibling::*) >= 2 and (count(preceding-sibling::*) - 2) mod 2 = 0][not((contains(concat(' ', normalize-space(@class), ' '), ' x ') or self::span))]"
+        );
+        assert_eq!(
+            xpath("p:last-of-type ~ [title=\"it's\"]").unwrap(),
+            "//p[not(following-sibling::p)]/following-sibling::*[@title=\"it's\"]"
+        );
+    }
+
+    /// Tests selectors without an XPath equivalent.
+    ///
+    /// Edge case: `:scope`, `:nth-of-type` without an element name and
+    /// complex selectors inside `:not()` are rejected with the offending
+    /// component.
+    #[test]
+    fn to_xpath_unsupported() {
+        assert_eq!(xpath(":scope > p").unwrap_err().component, ":scope");
+        assert!(xpath(".a:first-of-type").is_err());
+        assert!(xpath("p:not(div > p)").is_err());
+        assert_eq!(literal("a'b\"c"), "concat('a', \"'\", 'b\"c')");
+    }
+}
@@ -1,5 +1,8 @@
-use super::{BrikSelectors, Selector, SelectorContext};
-use crate::iter::Select;
+use super::{
+    BrikSelectors, MatchContext, Selector, SelectorContext, SelectorMatch, SelectorParseError,
+    XPathError,
+};
+use crate::iter::{Select, SelectWithContext};
 use crate::node_data_ref::NodeDataRef;
 use crate::tree::ElementData;
 use selectors::parser::{Parser, SelectorList};
@@ -80,6 +83,38 @@ impl<'i, 'a> Parser<'i> for BrikParser<'a> {
     }
 }
 
+/// Splits a selector list at its top-level commas.
+///
+/// Commas inside parentheses, brackets and strings are not separators.
+fn split_selector_list(s: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0usize;
+    let mut quote = None;
+    let mut escaped = false;
+    let mut start = 0;
+    for (index, c) in s.char_indices() {
+        if escaped {
+            escaped = false;
+            continue;
+        }
+        match (quote, c) {
+            (_, '\\') => escaped = true,
+            (Some(open), c) if c == open => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') => quote = Some(c),
+            (None, '(' | '[') => depth += 1,
+            (None, ')' | ']') => depth = depth.saturating_sub(1),
+            (None, ',') if depth == 0 => {
+                parts.push(&s[start..index]);
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(&s[start..]);
+    parts
+}
+
 /// A pre-compiled list of CSS Selectors.
 pub struct Selectors(pub Vec<Selector>);
 
@@ -100,6 +135,13 @@ impl Selectors {
     /// This method allows selectors to use namespace prefixes in both type selectors
     /// (e.g., `svg|rect`) and attribute selectors (e.g., `[tmpl|if]`).
     ///
+    /// The `*|` prefix needs no mapping and matches any namespace, both for
+    /// elements (`*|rect`) and attributes (`[*|href]`), while an empty prefix
+    /// matches only names without a namespace (`|rect`, `[|href]`). When the
+    /// context sets a default namespace, unprefixed type selectors and
+    /// selectors without a type (e.g., `[*|href]`) only match elements in it;
+    /// use `*|*` to lift that restriction.
+    ///
     /// **Note:** Namespace-aware selector features require the `namespaces` feature to be enabled.
     /// Without the feature, namespace prefixes in selectors will fail to parse or match.
     ///
@@ -132,6 +174,45 @@ impl Selectors {
     /// or references undefined namespace prefixes.
     #[inline]
     pub fn compile_with_context(s: &str, context: &SelectorContext) -> Result<Selectors, ()> {
+        Self::parse_with_context(s, context).map_err(|_| ())
+    }
+
+    /// Compile a list of selectors, reporting where and why parsing failed.
+    ///
+    /// This is like [`Selectors::compile()`], with a detailed error.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`SelectorParseError`] with the position, offending token and
+    /// an explanation if the selector string contains syntax errors or
+    /// unsupported selectors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::Selectors;
+    ///
+    /// let error = Selectors::parse("div > p:hovr").unwrap_err();
+    /// assert_eq!(error.offset, 8);
+    /// assert_eq!(error.to_string(), "1:9: unsupported pseudo-class or pseudo-element 'hovr'");
+    /// ```
+    #[inline]
+    pub fn parse(s: &str) -> Result<Selectors, SelectorParseError> {
+        Self::parse_with_context(s, &SelectorContext::default())
+    }
+
+    /// Compile a list of selectors with a selector context, reporting where and why parsing failed.
+    ///
+    /// This is like [`Selectors::compile_with_context()`], with a detailed error.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`SelectorParseError`] if the selector string contains syntax
+    /// errors, unsupported selectors, or references undefined namespace prefixes.
+    pub fn parse_with_context(
+        s: &str,
+        context: &SelectorContext,
+    ) -> Result<Selectors, SelectorParseError> {
         let mut input = cssparser::ParserInput::new(s);
         match SelectorList::parse(
             &BrikParser::new(context),
@@ -141,7 +222,7 @@ impl Selectors {
             Ok(list) => Ok(Selectors(
                 list.slice().iter().cloned().map(Selector).collect(),
             )),
-            Err(_) => Err(()),
+            Err(error) => Err(SelectorParseError::new(s, error)),
         }
     }
 
@@ -151,6 +232,135 @@ impl Selectors {
         self.0.iter().any(|s| s.matches(element))
     }
 
+    /// Returns whether the given element matches this list of selectors, with
+    /// `:scope` referring to the given scope element.
+    #[inline]
+    pub fn matches_with_scope(
+        &self,
+        element: &NodeDataRef<ElementData>,
+        scope: &NodeDataRef<ElementData>,
+    ) -> bool {
+        self.0.iter().any(|s| s.matches_with_scope(element, scope))
+    }
+
+    /// Returns whether the given element matches this list of selectors,
+    /// reusing the caches in `context` from earlier matches.
+    ///
+    /// See [`MatchContext`] for when the caches must be cleared.
+    #[inline]
+    pub fn matches_with_context(
+        &self,
+        element: &NodeDataRef<ElementData>,
+        context: &mut MatchContext,
+    ) -> bool {
+        self.0
+            .iter()
+            .any(|s| s.matches_with_context(element, context))
+    }
+
+    /// Converts this list of selectors to an equivalent XPath 1.0 expression.
+    ///
+    /// Each selector is converted with [`Selector::to_xpath()`] and the
+    /// results are joined with the `|` union operator.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`XPathError`] if any selector has no XPath 1.0 equivalent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::Selectors;
+    ///
+    /// let selectors = Selectors::compile("h1, section > h2#intro").unwrap();
+    /// assert_eq!(
+    ///     selectors.to_xpath().unwrap(),
+    ///     "//h1 | //section/h2[@id='intro']"
+    /// );
+    /// ```
+    pub fn to_xpath(&self) -> Result<String, XPathError> {
+        let expressions = self
+            .0
+            .iter()
+            .map(Selector::to_xpath)
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(expressions.join(" | "))
+    }
+
+    /// Compile a list of relative selectors, anchored at a scope element.
+    ///
+    /// Each selector in the list that starts with a combinator (`>`, `+` or
+    /// `~`) is prefixed with `:scope`, and each one that does not mention
+    /// `:scope` at all is prefixed with `:scope ` to match descendants. The
+    /// result is matched with [`Selectors::matches_with_scope()`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(())` if the resulting selector string fails to compile.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::Selectors;
+    ///
+    /// let selectors = Selectors::compile_relative("> li.active, + p, a").unwrap();
+    /// let sources: Vec<_> = selectors.0.iter().map(|s| s.to_string()).collect();
+    /// assert_eq!(sources, [":scope > li.active", ":scope + p", ":scope a"]);
+    /// ```
+    pub fn compile_relative(s: &str) -> Result<Selectors, ()> {
+        let anchored: Vec<String> = split_selector_list(s)
+            .into_iter()
+            .map(|selector| {
+                let selector = selector.trim();
+                if selector.starts_with(['>', '+', '~']) {
+                    format!(":scope {}", selector)
+                } else if selector.to_ascii_lowercase().contains(":scope") {
+                    selector.to_string()
+                } else {
+                    format!(":scope {}", selector)
+                }
+            })
+            .collect();
+        Self::compile(&anchored.join(", "))
+    }
+
+    /// Returns the most specific selector in this list that matches the given element.
+    ///
+    /// Among matching selectors of equal specificity, the first one is returned.
+    /// Returns `None` if no selector matches.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::parse_html;
+    /// use brik::traits::*;
+    /// use brik::Selectors;
+    ///
+    /// let doc = parse_html().one("<p id=x class=y></p>");
+    /// let p = doc.select_first("p").unwrap();
+    /// let selectors = Selectors::compile("p, #x, .y, div").unwrap();
+    ///
+    /// let found = selectors.match_with_specificity(&p).unwrap();
+    /// assert_eq!(found.index, 1);
+    /// assert_eq!(found.specificity, selectors.0[1].specificity());
+    /// ```
+    pub fn match_with_specificity(
+        &self,
+        element: &NodeDataRef<ElementData>,
+    ) -> Option<SelectorMatch> {
+        let mut best: Option<SelectorMatch> = None;
+        for (index, selector) in self.0.iter().enumerate() {
+            let specificity = selector.specificity();
+            if best.is_some_and(|best| best.specificity >= specificity) {
+                continue;
+            }
+            if selector.matches(element) {
+                best = Some(SelectorMatch { index, specificity });
+            }
+        }
+        best
+    }
+
     /// Filter an element iterator, yielding those matching this list of selectors.
     #[inline]
     pub fn filter<I>(&self, iter: I) -> Select<I, &Selectors>
@@ -162,6 +372,27 @@ impl Selectors {
             selectors: self,
         }
     }
+
+    /// Filter an element iterator, yielding those matching this list of
+    /// selectors and sharing `context` between matches.
+    ///
+    /// This avoids recounting sibling indices for `:nth-child()` and related
+    /// pseudo-classes on every element.
+    #[inline]
+    pub fn filter_with_context<'a, I>(
+        &self,
+        iter: I,
+        context: &'a mut MatchContext,
+    ) -> SelectWithContext<'a, I, &Selectors>
+    where
+        I: Iterator<Item = NodeDataRef<ElementData>>,
+    {
+        SelectWithContext {
+            iter,
+            selectors: self,
+            context,
+        }
+    }
 }
 
 /// Implements FromStr for Selectors.
@@ -232,6 +463,41 @@ mod tests {
         assert_eq!(selectors.0.len(), 3);
     }
 
+    /// Tests match_with_specificity() ties and misses.
+    ///
+    /// Verifies that the first of equally specific matching selectors is
+    /// returned, and that no match gives None.
+    #[test]
+    fn match_with_specificity_ties() {
+        let doc = parse_html().one("<p class='a b'></p>");
+        let p = doc
+            .descendants()
+            .elements()
+            .find(|e| &*e.name.local == "p")
+            .unwrap();
+
+        let selectors = Selectors::compile("div, .a, .b").unwrap();
+        assert_eq!(selectors.match_with_specificity(&p).unwrap().index, 1);
+
+        let selectors = Selectors::compile("div, span").unwrap();
+        assert_eq!(selectors.match_with_specificity(&p), None);
+    }
+
+    /// Tests splitting selector lists for relative compilation.
+    ///
+    /// Edge case: commas inside functional pseudo-classes, attribute
+    /// selectors and strings do not split the list.
+    #[test]
+    fn compile_relative_splitting() {
+        assert_eq!(
+            split_selector_list(r#"a:not(b, c), [x=","], d"#),
+            vec!["a:not(b, c)", r#" [x=","]"#, " d"]
+        );
+        let selectors = Selectors::compile_relative("div :scope > p, ~ i").unwrap();
+        assert_eq!(selectors.0.len(), 2);
+        assert!(Selectors::compile_relative("> ").is_err());
+    }
+
     /// Tests compiling a class selector.
     ///
     /// Verifies that class selectors compile correctly.
@@ -0,0 +1,30 @@
+use super::Specificity;
+
+/// Sorts rules into cascade order: by ascending specificity, then source order.
+///
+/// The slice is assumed to be in source order, and the sort is stable, so
+/// rules of equal specificity keep their relative order. After sorting,
+/// later rules take precedence over earlier ones.
+///
+/// # Examples
+///
+/// ```
+/// use brik::{sort_by_specificity, Selectors};
+///
+/// let mut rules = vec![
+///     (Selectors::compile("#main").unwrap(), "id"),
+///     (Selectors::compile("p").unwrap(), "type"),
+///     (Selectors::compile(".note").unwrap(), "class"),
+///     (Selectors::compile("div").unwrap(), "later type"),
+/// ];
+/// sort_by_specificity(&mut rules, |(selectors, _)| selectors.0[0].specificity());
+///
+/// let order: Vec<_> = rules.iter().map(|(_, name)| *name).collect();
+/// assert_eq!(order, ["type", "later type", "class", "id"]);
+/// ```
+pub fn sort_by_specificity<T, F>(rules: &mut [T], mut specificity: F)
+where
+    F: FnMut(&T) -> Specificity,
+{
+    rules.sort_by_key(|rule| specificity(rule));
+}
@@ -4,5 +4,5 @@
 ///
 /// Determines precedence in the cascading algorithm.
 /// When equal, a rule later in source order takes precedence.
-#[derive(Copy, Clone, Hash, Eq, PartialEq, Ord, PartialOrd)]
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, Ord, PartialOrd)]
 pub struct Specificity(pub(super) u32);
@@ -0,0 +1,177 @@
+use super::element_impl::SELECTOR_WHITESPACE;
+use super::{AttrValue, BrikSelectors, LocalNameSelector, PseudoClass, PseudoElement};
+use crate::stream::StreamElement;
+use html5ever::{local_name, ns, LocalName, Namespace};
+use selectors::attr::{AttrSelectorOperation, CaseSensitivity, NamespaceConstraint};
+use selectors::{matching, OpaqueElement};
+
+/// Implements selectors::Element for StreamElement.
+///
+/// Provides CSS selector matching while streaming. Only ancestors are
+/// known, so sibling combinators, positional pseudo-classes and `:empty`
+/// never match.
+impl selectors::Element for StreamElement<'_> {
+    type Impl = BrikSelectors;
+
+    #[inline]
+    fn opaque(&self) -> OpaqueElement {
+        OpaqueElement::new(self.open())
+    }
+
+    #[inline]
+    fn is_html_slot_element(&self) -> bool {
+        false
+    }
+    #[inline]
+    fn parent_node_is_shadow_root(&self) -> bool {
+        false
+    }
+    #[inline]
+    fn containing_shadow_host(&self) -> Option<Self> {
+        None
+    }
+
+    #[inline]
+    fn parent_element(&self) -> Option<Self> {
+        self.parent()
+    }
+    #[inline]
+    fn prev_sibling_element(&self) -> Option<Self> {
+        None
+    }
+    #[inline]
+    fn next_sibling_element(&self) -> Option<Self> {
+        None
+    }
+    #[inline]
+    fn first_element_child(&self) -> Option<Self> {
+        None
+    }
+    #[inline]
+    fn is_empty(&self) -> bool {
+        false
+    }
+    #[inline]
+    fn is_root(&self) -> bool {
+        self.index == 0
+    }
+
+    #[inline]
+    fn is_html_element_in_html_document(&self) -> bool {
+        self.open().name.ns == ns!(html)
+    }
+
+    #[inline]
+    fn has_local_name(&self, name: &LocalName) -> bool {
+        self.open().name.local == *name
+    }
+    #[inline]
+    fn has_namespace(&self, namespace: &Namespace) -> bool {
+        self.open().name.ns == *namespace
+    }
+
+    #[inline]
+    fn is_part(&self, _name: &LocalNameSelector) -> bool {
+        false
+    }
+
+    #[inline]
+    fn imported_part(&self, _: &LocalNameSelector) -> Option<LocalNameSelector> {
+        None
+    }
+
+    #[inline]
+    fn is_pseudo_element(&self) -> bool {
+        false
+    }
+
+    #[inline]
+    fn is_same_type(&self, other: &Self) -> bool {
+        self.open().name == other.open().name
+    }
+
+    #[inline]
+    fn is_link(&self) -> bool {
+        let name = &self.open().name;
+        name.ns == ns!(html)
+            && matches!(
+                name.local,
+                local_name!("a") | local_name!("area") | local_name!("link")
+            )
+            && self.attr("href").is_some()
+    }
+
+    #[inline]
+    fn has_id(&self, id: &LocalNameSelector, case_sensitivity: CaseSensitivity) -> bool {
+        self.attr("id")
+            .is_some_and(|id_attr| case_sensitivity.eq(id.as_bytes(), id_attr.as_bytes()))
+    }
+
+    #[inline]
+    fn has_class(&self, name: &LocalNameSelector, case_sensitivity: CaseSensitivity) -> bool {
+        let name = name.as_bytes();
+        !name.is_empty()
+            && self.attr("class").is_some_and(|class_attr| {
+                class_attr
+                    .split(SELECTOR_WHITESPACE)
+                    .any(|class| case_sensitivity.eq(class.as_bytes(), name))
+            })
+    }
+
+    #[inline]
+    fn attr_matches(
+        &self,
+        ns: &NamespaceConstraint<&Namespace>,
+        local_name: &LocalNameSelector,
+        operation: &AttrSelectorOperation<&AttrValue>,
+    ) -> bool {
+        // Attributes from the tokenizer are never namespaced.
+        let in_namespace = match *ns {
+            NamespaceConstraint::Any => true,
+            NamespaceConstraint::Specific(ns_url) => *ns_url == ns!(),
+        };
+        in_namespace
+            && self
+                .open()
+                .attributes
+                .iter()
+                .any(|(name, value)| *name == **local_name && operation.eval_str(value))
+    }
+
+    fn match_pseudo_element(
+        &self,
+        pseudo: &PseudoElement,
+        _context: &mut matching::MatchingContext<BrikSelectors>,
+    ) -> bool {
+        match *pseudo {}
+    }
+
+    fn match_non_ts_pseudo_class(
+        &self,
+        pseudo: &PseudoClass,
+        _context: &mut matching::MatchingContext<BrikSelectors>,
+    ) -> bool {
+        use self::PseudoClass::*;
+        match *pseudo {
+            Active | Focus | Hover | Enabled | Disabled | Checked | Indeterminate | Visited => {
+                false
+            }
+            AnyLink | Link => self.is_link(),
+        }
+    }
+
+    #[inline]
+    fn apply_selector_flags(&self, _flags: matching::ElementSelectorFlags) {
+        // No-op for streamed elements
+    }
+
+    #[inline]
+    fn has_custom_state(&self, _name: &LocalNameSelector) -> bool {
+        false
+    }
+
+    #[inline]
+    fn add_element_unique_hashes(&self, _filter: &mut selectors::bloom::BloomFilter) -> bool {
+        false
+    }
+}
@@ -0,0 +1,27 @@
+use std::error::Error;
+use std::fmt;
+
+/// An error from converting a selector to XPath.
+///
+/// Returned by [`Selector::to_xpath()`](super::Selector::to_xpath) and
+/// [`Selectors::to_xpath()`](super::Selectors::to_xpath) when part of a
+/// selector has no XPath 1.0 equivalent.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct XPathError {
+    /// The selector component that could not be converted, as CSS.
+    pub component: String,
+}
+
+/// Implements Display for XPathError.
+///
+/// Names the component that could not be converted.
+impl fmt::Display for XPathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "'{}' cannot be expressed in XPath 1.0", self.component)
+    }
+}
+
+/// Implements Error for XPathError.
+///
+/// Allows the error to be used with `?` and boxed errors.
+impl Error for XPathError {}
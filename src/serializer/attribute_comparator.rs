@@ -0,0 +1,9 @@
+//! Attribute name comparator type.
+
+use std::cmp::Ordering;
+use std::rc::Rc;
+
+use crate::attributes::ExpandedName;
+
+/// A shared comparator of attribute names, used by [`AttributeOrder::Custom`](super::AttributeOrder::Custom).
+pub type AttributeComparator = Rc<dyn Fn(&ExpandedName, &ExpandedName) -> Ordering>;
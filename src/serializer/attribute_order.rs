@@ -0,0 +1,71 @@
+//! Attribute ordering for serialization.
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::rc::Rc;
+
+use super::AttributeComparator;
+use crate::attributes::ExpandedName;
+
+/// The order in which the attributes of an element are serialized.
+#[derive(Clone, Default)]
+pub enum AttributeOrder {
+    /// Insertion order, which for parsed documents is source order.
+    #[default]
+    Insertion,
+
+    /// Sorted by namespace URL, then by local name.
+    ///
+    /// Attributes without a namespace come first, as in Canonical XML.
+    Name,
+
+    /// Sorted with a user comparator.
+    ///
+    /// The sort is stable, so attributes that compare equal keep their
+    /// insertion order.
+    Custom(AttributeComparator),
+}
+
+/// Methods for AttributeOrder.
+///
+/// Provides construction of custom orderings.
+impl AttributeOrder {
+    /// Creates an ordering from a comparator of attribute names.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::AttributeOrder;
+    ///
+    /// // Sort by local name, longest first.
+    /// let order = AttributeOrder::custom(|a, b| b.local.len().cmp(&a.local.len()));
+    /// ```
+    pub fn custom<F>(compare: F) -> Self
+    where
+        F: Fn(&ExpandedName, &ExpandedName) -> Ordering + 'static,
+    {
+        AttributeOrder::Custom(Rc::new(compare))
+    }
+
+    /// Sorts attribute entries in place according to this ordering.
+    pub(crate) fn sort<T>(&self, entries: &mut [(&ExpandedName, T)]) {
+        match self {
+            AttributeOrder::Insertion => {}
+            AttributeOrder::Name => entries.sort_by(|a, b| a.0.cmp(b.0)),
+            AttributeOrder::Custom(compare) => entries.sort_by(|a, b| compare(a.0, b.0)),
+        }
+    }
+}
+
+/// Implements Debug for AttributeOrder.
+///
+/// Custom comparators cannot be formatted, so only the variant name is shown.
+impl fmt::Debug for AttributeOrder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AttributeOrder::Insertion => f.write_str("Insertion"),
+            AttributeOrder::Name => f.write_str("Name"),
+            AttributeOrder::Custom(_) => f.write_str("Custom(..)"),
+        }
+    }
+}
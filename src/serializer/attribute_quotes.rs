@@ -0,0 +1,17 @@
+//! Attribute value quoting style for serialization.
+
+/// How attribute values are quoted by the serializer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AttributeQuotes {
+    /// Double quotes, escaping `"` as `&quot;`, such as `title="a"`.
+    #[default]
+    Double,
+
+    /// Single quotes, escaping `'` as `&#39;`, such as `title='a'`.
+    Single,
+
+    /// No quotes where the value allows it, such as `title=a`, and double
+    /// quotes for empty values and values containing whitespace or any of
+    /// `"`, `'`, `=`, `<`, `>` and `` ` ``.
+    Minimal,
+}
@@ -0,0 +1,11 @@
+//! Canonical XML serialization options.
+
+/// Options for Canonical XML serialization.
+///
+/// **Note:** This type requires the `c14n` feature to be enabled.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct C14nOptions {
+    /// Include comments in the output, as in the "with comments" variant of
+    /// the canonicalization algorithm. Default: false.
+    pub with_comments: bool,
+}
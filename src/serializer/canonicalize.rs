@@ -0,0 +1,334 @@
+//! Exclusive Canonical XML serialization of nodes.
+
+use std::io::{self, Write};
+use unicode_normalization::UnicodeNormalization;
+
+use super::C14nOptions;
+use crate::tree::{NodeData, NodeRef};
+
+/// The namespace URL of namespace declaration attributes.
+const XMLNS_NAMESPACE: &str = "http://www.w3.org/2000/xmlns/";
+
+/// The namespace URL bound to the reserved `xml` prefix.
+const XML_NAMESPACE: &str = "http://www.w3.org/XML/1998/namespace";
+
+/// Writes nodes in Exclusive XML Canonicalization form.
+struct Canonicalizer<'a, W: Write> {
+    /// The output stream.
+    writer: &'a mut W,
+    /// The canonicalization options.
+    options: &'a C14nOptions,
+    /// Namespace declarations rendered by output ancestors, as (prefix, URL)
+    /// pairs; the default namespace has an empty prefix.
+    rendered: Vec<(String, String)>,
+}
+
+/// Methods for Canonicalizer.
+///
+/// Provides the recursive node writer and escaping.
+impl<W: Write> Canonicalizer<'_, W> {
+    /// Returns the namespace URL currently rendered for a prefix.
+    fn rendered_namespace(&self, prefix: &str) -> &str {
+        self.rendered
+            .iter()
+            .rev()
+            .find(|(p, _)| p == prefix)
+            .map_or("", |(_, url)| url)
+    }
+
+    /// Writes a string in NFC, escaping characters as in text or attribute values.
+    fn write_escaped(&mut self, text: &str, attr_mode: bool) -> io::Result<()> {
+        for c in text.nfc() {
+            match c {
+                '&' => self.writer.write_all(b"&amp;"),
+                '<' => self.writer.write_all(b"&lt;"),
+                '>' if !attr_mode => self.writer.write_all(b"&gt;"),
+                '"' if attr_mode => self.writer.write_all(b"&quot;"),
+                '\t' if attr_mode => self.writer.write_all(b"&#x9;"),
+                '\n' if attr_mode => self.writer.write_all(b"&#xA;"),
+                '\r' => self.writer.write_all(b"&#xD;"),
+                c => self.writer.write_all(c.encode_utf8(&mut [0; 4]).as_bytes()),
+            }?;
+        }
+        Ok(())
+    }
+
+    /// Writes a string in NFC without escaping.
+    fn write_normalized(&mut self, text: &str) -> io::Result<()> {
+        self.writer
+            .write_all(text.nfc().collect::<String>().as_bytes())
+    }
+
+    /// Writes a node and its descendants.
+    fn node(&mut self, node: &NodeRef) -> io::Result<()> {
+        match node.data() {
+            NodeData::Element(_) => self.element(node),
+            NodeData::Text(text) => self.write_escaped(&text.borrow(), false),
+            NodeData::Comment(text) => {
+                if self.options.with_comments {
+                    self.writer.write_all(b"<!--")?;
+                    self.write_normalized(&text.borrow())?;
+                    self.writer.write_all(b"-->")?;
+                }
+                Ok(())
+            }
+            NodeData::ProcessingInstruction(contents) => {
+                let contents = contents.borrow();
+                self.writer.write_all(b"<?")?;
+                self.write_normalized(&contents.0)?;
+                if !contents.1.is_empty() {
+                    self.writer.write_all(b" ")?;
+                    self.write_normalized(&contents.1)?;
+                }
+                self.writer.write_all(b"?>")
+            }
+            NodeData::Doctype(_) => Ok(()),
+            NodeData::DocumentFragment => {
+                for child in node.children() {
+                    self.node(&child)?;
+                }
+                Ok(())
+            }
+            NodeData::Document(_) => {
+                // Nodes outside the document element are separated from it by
+                // line feeds; doctypes are omitted.
+                let mut before_root = true;
+                for child in node.children() {
+                    match child.data() {
+                        NodeData::Element(_) => {
+                            before_root = false;
+                            self.node(&child)?;
+                        }
+                        NodeData::Comment(_) if !self.options.with_comments => {}
+                        NodeData::Comment(_) | NodeData::ProcessingInstruction(_) => {
+                            if !before_root {
+                                self.writer.write_all(b"\n")?;
+                            }
+                            self.node(&child)?;
+                            if before_root {
+                                self.writer.write_all(b"\n")?;
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// Writes an element, its namespace declarations and attributes, and its contents.
+    fn element(&mut self, node: &NodeRef) -> io::Result<()> {
+        let Some(element) = node.as_element() else {
+            return Ok(());
+        };
+        let qualified_name = |prefix: Option<&str>, local: &str| match prefix {
+            Some(prefix) => format!("{prefix}:{local}"),
+            None => local.to_string(),
+        };
+
+        // Declare only the namespaces visibly utilized by this element and its
+        // attributes that output ancestors have not already declared.
+        let mut declarations: Vec<(String, String)> = Vec::new();
+        let element_prefix = element.name.prefix.as_deref().unwrap_or("");
+        let element_namespace: &str = &element.name.ns;
+        if self.rendered_namespace(element_prefix) != element_namespace
+            && (element_prefix.is_empty() || !element_namespace.is_empty())
+        {
+            declarations.push((element_prefix.to_string(), element_namespace.to_string()));
+        }
+
+        let attributes = element.attributes.borrow();
+        let mut attrs = Vec::new();
+        for (name, attr) in &attributes.map {
+            let namespace: &str = &name.ns;
+            if namespace == XMLNS_NAMESPACE {
+                continue;
+            }
+            let prefix = if namespace == XML_NAMESPACE {
+                Some("xml")
+            } else {
+                attr.prefix.as_deref().filter(|_| !namespace.is_empty())
+            };
+            if let Some(prefix) = prefix {
+                if prefix != "xml"
+                    && self.rendered_namespace(prefix) != namespace
+                    && !declarations.iter().any(|(p, _)| p == prefix)
+                {
+                    declarations.push((prefix.to_string(), namespace.to_string()));
+                }
+            }
+            attrs.push((name, qualified_name(prefix, &name.local), &attr.value));
+        }
+        declarations.sort();
+        attrs.sort_by(|a, b| a.0.cmp(b.0));
+
+        let name = qualified_name(element.name.prefix.as_deref(), &element.name.local);
+        self.writer.write_all(b"<")?;
+        self.writer.write_all(name.as_bytes())?;
+        for (prefix, url) in &declarations {
+            if prefix.is_empty() {
+                self.writer.write_all(b" xmlns=\"")?;
+            } else {
+                write!(self.writer, " xmlns:{prefix}=\"")?;
+            }
+            self.write_escaped(url, true)?;
+            self.writer.write_all(b"\"")?;
+        }
+        for (_, qualified, value) in &attrs {
+            write!(self.writer, " {qualified}=\"")?;
+            self.write_escaped(value, true)?;
+            self.writer.write_all(b"\"")?;
+        }
+        self.writer.write_all(b">")?;
+        drop(attrs);
+        drop(attributes);
+
+        let scope = self.rendered.len();
+        self.rendered.extend(declarations);
+        let children = match element.template_contents.as_ref() {
+            Some(template_root) => template_root.children(),
+            None => node.children(),
+        };
+        for child in children {
+            self.node(&child)?;
+        }
+        self.rendered.truncate(scope);
+
+        self.writer.write_all(b"</")?;
+        self.writer.write_all(name.as_bytes())?;
+        self.writer.write_all(b">")
+    }
+}
+
+/// Methods for Canonical XML serialization.
+///
+/// Provides Exclusive XML Canonicalization of nodes for hashing and signing.
+impl NodeRef {
+    /// Serialize this node and its descendants as Exclusive Canonical XML.
+    ///
+    /// The output follows [Exclusive XML Canonicalization 1.0]: attributes are
+    /// sorted by namespace URL and local name, namespace declarations are
+    /// emitted sorted by prefix on the outermost element that uses them,
+    /// empty elements are written as start and end tag pairs, whitespace
+    /// inside tags is normalized, and doctypes are omitted. Text is written
+    /// as UTF-8 in Unicode Normalization Form C.
+    ///
+    /// Namespaces come from element and attribute names, so documents whose
+    /// prefixes were resolved with the `namespaces` feature canonicalize with
+    /// their declared namespaces. Existing `xmlns` attributes are not copied.
+    ///
+    /// **Note:** This method requires the `c14n` feature to be enabled.
+    ///
+    /// [Exclusive XML Canonicalization 1.0]: https://www.w3.org/TR/xml-exc-c14n/
+    ///
+    /// # Errors
+    ///
+    /// Returns an `io::Error` if writing to the stream fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::parse_html;
+    /// use brik::traits::*;
+    /// use brik::C14nOptions;
+    ///
+    /// let doc = parse_html().one("<p id=b class=a>x<br></p>");
+    /// let p = doc.select_first("p").unwrap();
+    ///
+    /// let mut bytes = Vec::new();
+    /// p.as_node().serialize_c14n(&mut bytes, &C14nOptions::default()).unwrap();
+    /// assert_eq!(
+    ///     String::from_utf8(bytes).unwrap(),
+    ///     r#"<p xmlns="http://www.w3.org/1999/xhtml" class="a" id="b">x<br></br></p>"#
+    /// );
+    /// ```
+    pub fn serialize_c14n<W: Write>(
+        &self,
+        writer: &mut W,
+        options: &C14nOptions,
+    ) -> io::Result<()> {
+        Canonicalizer {
+            writer,
+            options,
+            rendered: Vec::new(),
+        }
+        .node(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::parse_html;
+    use crate::serializer::C14nOptions;
+    use crate::traits::*;
+    use crate::NodeRef;
+
+    /// Serializes a node as canonical XML into a string.
+    fn c14n(node: &NodeRef, with_comments: bool) -> String {
+        let mut buffer = Vec::new();
+        node.serialize_c14n(&mut buffer, &C14nOptions { with_comments })
+            .unwrap();
+        String::from_utf8(buffer).unwrap()
+    }
+
+    /// Tests canonicalization of a full document.
+    ///
+    /// Verifies that the doctype is dropped, the default namespace is
+    /// declared once on the root, and comments are omitted by default.
+    #[test]
+    fn document() {
+        let doc = parse_html().one("<!DOCTYPE html><title>T</title><!-- c --><p>a</p>");
+        assert_eq!(
+            c14n(&doc, false),
+            "<html xmlns=\"http://www.w3.org/1999/xhtml\"><head><title>T</title></head>\
+             <body><p>a</p></body></html>"
+        );
+    }
+
+    /// Tests canonicalization with comments.
+    ///
+    /// Verifies that comments inside the root are kept and that comments
+    /// outside it are separated by line feeds.
+    #[test]
+    fn with_comments() {
+        let doc = parse_html().one("<!-- a --><p><!-- b --></p>");
+        assert_eq!(
+            c14n(&doc, true),
+            "<!-- a -->\n<html xmlns=\"http://www.w3.org/1999/xhtml\"><head></head>\
+             <body><p><!-- b --></p></body></html>"
+        );
+    }
+
+    /// Tests exclusive namespace handling for foreign content.
+    ///
+    /// Verifies that namespaces are redeclared where they change and that a
+    /// prefixed namespace is declared only on the element that uses it.
+    #[test]
+    fn namespaces() {
+        let doc = parse_html().one("<div><svg><use xlink:href='#i' x='1'/></svg></div>");
+        let div = doc.select_first("div").unwrap();
+        assert_eq!(
+            c14n(div.as_node(), false),
+            "<div xmlns=\"http://www.w3.org/1999/xhtml\">\
+             <svg xmlns=\"http://www.w3.org/2000/svg\">\
+             <use xmlns:xlink=\"http://www.w3.org/1999/xlink\" x=\"1\" xlink:href=\"#i\"></use>\
+             </svg></div>"
+        );
+    }
+
+    /// Tests escaping and Unicode normalization.
+    ///
+    /// Edge case: decomposed characters are composed to NFC, and whitespace
+    /// characters in attribute values are written as character references.
+    #[test]
+    fn escaping_and_nfc() {
+        let doc = parse_html().one("<p title='a\tb\n\"c\"'>e\u{301} &lt;&amp;&gt;</p>");
+        let p = doc.select_first("p").unwrap();
+        assert_eq!(
+            c14n(p.as_node(), false),
+            "<p xmlns=\"http://www.w3.org/1999/xhtml\" title=\"a&#x9;b&#xA;&quot;c&quot;\">\
+             \u{e9} &lt;&amp;&gt;</p>"
+        );
+    }
+}
@@ -0,0 +1,12 @@
+//! Character reference style for serialization.
+
+/// How escaped characters are written by the serializer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EntityStyle {
+    /// Named character references, such as `&amp;` and `&nbsp;`.
+    #[default]
+    Named,
+
+    /// Decimal numeric character references, such as `&#38;` and `&#160;`.
+    Numeric,
+}
@@ -0,0 +1,17 @@
+//! Serialization filter actions.
+
+use crate::tree::NodeRef;
+
+/// What the serializer does with a node, as decided by a
+/// [`NodeFilter`](super::NodeFilter).
+#[derive(Debug, Clone, PartialEq)]
+pub enum FilterAction {
+    /// Write the node as usual.
+    Keep,
+    /// Leave the node and its descendants out.
+    Skip,
+    /// Write the given node in place of this node and its descendants.
+    ///
+    /// The replacement is written as is, without being filtered.
+    Replace(NodeRef),
+}
@@ -0,0 +1,298 @@
+//! HTML serializer with configurable escaping.
+
+use html5ever::serialize::{AttrRef, Serializer};
+use html5ever::{LocalName, QualName};
+use std::borrow::Cow;
+use std::io::{self, Write};
+
+use super::{AttributeQuotes, EntityStyle, SerializeOptions};
+
+/// Per-element state tracked while serializing.
+struct ElemInfo {
+    /// The local name, if the element is in the HTML namespace.
+    html_name: Option<LocalName>,
+    /// Whether the element is void, so its children are not written.
+    ignore_children: bool,
+}
+
+/// An HTML serializer honoring [`SerializeOptions`].
+///
+/// This follows html5ever's `HtmlSerializer`, which does not allow escaping
+/// to be customized, and adds the escaping options.
+pub(crate) struct HtmlSerializer<'a, W: Write> {
+    /// The output stream.
+    writer: W,
+    /// The serialization options.
+    options: Cow<'a, SerializeOptions>,
+    /// The stack of open elements, with a root entry for the parent scope.
+    stack: Vec<ElemInfo>,
+}
+
+/// Methods for HtmlSerializer.
+///
+/// Provides construction and escaping.
+impl<'a, W: Write> HtmlSerializer<'a, W> {
+    /// Creates a serializer writing to `writer`.
+    pub(crate) fn new(writer: W, options: &'a SerializeOptions) -> Self {
+        Self::with_options(writer, Cow::Borrowed(options))
+    }
+
+    /// Creates a serializer writing to `writer` that may own its options.
+    pub(crate) fn with_options(writer: W, options: Cow<'a, SerializeOptions>) -> Self {
+        HtmlSerializer {
+            writer,
+            options,
+            stack: vec![ElemInfo {
+                html_name: None,
+                ignore_children: false,
+            }],
+        }
+    }
+
+    /// Returns the output stream, for writing markup that is already
+    /// serialized.
+    pub(crate) fn writer_mut(&mut self) -> &mut W {
+        &mut self.writer
+    }
+
+    /// Consumes the serializer, returning the output stream.
+    pub(crate) fn into_inner(self) -> W {
+        self.writer
+    }
+
+    /// Returns the innermost open element, if any.
+    fn parent(&self) -> Option<&ElemInfo> {
+        self.stack.last()
+    }
+
+    /// Writes a start tag from markup that is already serialized, such as
+    /// the tag an element was parsed from.
+    pub(crate) fn start_elem_from_source(
+        &mut self,
+        name: &QualName,
+        markup: &str,
+    ) -> io::Result<()> {
+        if self.skip_elem(name) {
+            return Ok(());
+        }
+        self.writer.write_all(markup.as_bytes())?;
+        self.push_elem(name);
+        Ok(())
+    }
+
+    /// Writes an end tag from markup that is already serialized.
+    pub(crate) fn end_elem_from_source(&mut self, markup: &str) -> io::Result<()> {
+        let ignore_children = self.stack.pop().is_some_and(|info| info.ignore_children);
+        if ignore_children {
+            return Ok(());
+        }
+        self.writer.write_all(markup.as_bytes())
+    }
+
+    /// Opens an element without writing it if its parent's children are
+    /// not written, returning whether it was skipped.
+    fn skip_elem(&mut self, name: &QualName) -> bool {
+        if !self.parent().is_some_and(|parent| parent.ignore_children) {
+            return false;
+        }
+        self.stack.push(ElemInfo {
+            html_name: html_name(name),
+            ignore_children: true,
+        });
+        true
+    }
+
+    /// Opens an element whose start tag was written.
+    fn push_elem(&mut self, name: &QualName) {
+        let ignore_children = name.ns == ns!(html)
+            && matches!(
+                name.local,
+                local_name!("area")
+                    | local_name!("base")
+                    | local_name!("basefont")
+                    | local_name!("bgsound")
+                    | local_name!("br")
+                    | local_name!("col")
+                    | local_name!("embed")
+                    | local_name!("frame")
+                    | local_name!("hr")
+                    | local_name!("img")
+                    | local_name!("input")
+                    | local_name!("keygen")
+                    | local_name!("link")
+                    | local_name!("meta")
+                    | local_name!("param")
+                    | local_name!("source")
+                    | local_name!("track")
+                    | local_name!("wbr")
+            );
+
+        self.stack.push(ElemInfo {
+            html_name: html_name(name),
+            ignore_children,
+        });
+    }
+
+    /// Writes a character reference in the configured style.
+    fn write_reference(&mut self, named: &str, c: char) -> io::Result<()> {
+        match self.options.entity_style {
+            EntityStyle::Named => write!(self.writer, "&{named};"),
+            EntityStyle::Numeric => write!(self.writer, "&#{};", u32::from(c)),
+        }
+    }
+
+    /// Writes text or an attribute value, escaping as configured.
+    pub(crate) fn write_escaped(&mut self, text: &str, attr_mode: bool) -> io::Result<()> {
+        self.write_escaped_in(text, attr_mode, '"')
+    }
+
+    /// Writes the value of an attribute after its name, quoted and
+    /// collapsed as configured.
+    pub(crate) fn write_attribute_value(&mut self, value: &str) -> io::Result<()> {
+        if value.is_empty() && self.options.collapse_boolean_attributes {
+            return Ok(());
+        }
+        let quote = match self.options.attribute_quotes {
+            AttributeQuotes::Double => Some('"'),
+            AttributeQuotes::Single => Some('\''),
+            AttributeQuotes::Minimal => {
+                let quoted = value.is_empty()
+                    || value.contains(|c: char| {
+                        c.is_ascii_whitespace() || matches!(c, '"' | '\'' | '=' | '<' | '>' | '`')
+                    });
+                quoted.then_some('"')
+            }
+        };
+        let delimiter: &[u8] = match quote {
+            Some('\'') => b"'",
+            Some(_) => b"\"",
+            None => b"",
+        };
+        self.writer.write_all(b"=")?;
+        self.writer.write_all(delimiter)?;
+        self.write_escaped_in(value, true, quote.unwrap_or('"'))?;
+        self.writer.write_all(delimiter)
+    }
+
+    /// Writes text or an attribute value delimited by `quote`, escaping as
+    /// configured.
+    fn write_escaped_in(&mut self, text: &str, attr_mode: bool, quote: char) -> io::Result<()> {
+        for c in text.chars() {
+            match c {
+                '&' => self.write_reference("amp", c),
+                '\u{00A0}' => self.write_reference("nbsp", c),
+                '"' if attr_mode && quote == '"' => self.write_reference("quot", c),
+                '<' if !attr_mode => self.write_reference("lt", c),
+                '>' if !attr_mode => self.write_reference("gt", c),
+                '\'' if self.options.escape_apostrophe || (attr_mode && quote == '\'') => {
+                    self.writer.write_all(b"&#39;")
+                }
+                c if !c.is_ascii() && self.options.escape_non_ascii => {
+                    write!(self.writer, "&#x{:X};", u32::from(c))
+                }
+                c => self.writer.write_all(c.encode_utf8(&mut [0; 4]).as_bytes()),
+            }?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns the local name of an element in the HTML namespace.
+fn html_name(name: &QualName) -> Option<LocalName> {
+    match name.ns {
+        ns!(html) => Some(name.local.clone()),
+        _ => None,
+    }
+}
+
+/// Implements Serializer for HtmlSerializer.
+///
+/// Writes HTML syntax as html5ever's serializer does, with custom escaping
+/// of text and attribute values.
+impl<W: Write> Serializer for HtmlSerializer<'_, W> {
+    fn start_elem<'b, AttrIter>(&mut self, name: QualName, attrs: AttrIter) -> io::Result<()>
+    where
+        AttrIter: Iterator<Item = AttrRef<'b>>,
+    {
+        if self.skip_elem(&name) {
+            return Ok(());
+        }
+
+        self.writer.write_all(b"<")?;
+        self.writer.write_all(name.local.as_bytes())?;
+        for (name, value) in attrs {
+            self.writer.write_all(b" ")?;
+
+            match name.ns {
+                ns!() => (),
+                ns!(xml) => self.writer.write_all(b"xml:")?,
+                ns!(xmlns) => {
+                    if name.local != local_name!("xmlns") {
+                        self.writer.write_all(b"xmlns:")?;
+                    }
+                }
+                ns!(xlink) => self.writer.write_all(b"xlink:")?,
+                _ => self.writer.write_all(b"unknown_namespace:")?,
+            }
+
+            self.writer.write_all(name.local.as_bytes())?;
+            self.write_attribute_value(value)?;
+        }
+        self.writer.write_all(b">")?;
+        self.push_elem(&name);
+        Ok(())
+    }
+
+    fn end_elem(&mut self, name: QualName) -> io::Result<()> {
+        let ignore_children = self.stack.pop().is_some_and(|info| info.ignore_children);
+        if ignore_children {
+            return Ok(());
+        }
+
+        self.writer.write_all(b"</")?;
+        self.writer.write_all(name.local.as_bytes())?;
+        self.writer.write_all(b">")
+    }
+
+    fn write_text(&mut self, text: &str) -> io::Result<()> {
+        // Raw text is not decoded by parsers, so it must be written as is.
+        // Scripting is assumed enabled, so <noscript> is raw text too.
+        let escape = !matches!(
+            self.parent().and_then(|parent| parent.html_name.as_ref()),
+            Some(&local_name!("style"))
+                | Some(&local_name!("script"))
+                | Some(&local_name!("xmp"))
+                | Some(&local_name!("iframe"))
+                | Some(&local_name!("noembed"))
+                | Some(&local_name!("noframes"))
+                | Some(&local_name!("plaintext"))
+                | Some(&local_name!("noscript"))
+        );
+
+        if escape {
+            self.write_escaped(text, false)
+        } else {
+            self.writer.write_all(text.as_bytes())
+        }
+    }
+
+    fn write_comment(&mut self, text: &str) -> io::Result<()> {
+        self.writer.write_all(b"<!--")?;
+        self.writer.write_all(text.as_bytes())?;
+        self.writer.write_all(b"-->")
+    }
+
+    fn write_doctype(&mut self, name: &str) -> io::Result<()> {
+        self.writer.write_all(b"<!DOCTYPE ")?;
+        self.writer.write_all(name.as_bytes())?;
+        self.writer.write_all(b">")
+    }
+
+    fn write_processing_instruction(&mut self, target: &str, data: &str) -> io::Result<()> {
+        self.writer.write_all(b"<?")?;
+        self.writer.write_all(target.as_bytes())?;
+        self.writer.write_all(b" ")?;
+        self.writer.write_all(data.as_bytes())?;
+        self.writer.write_all(b">")
+    }
+}
@@ -0,0 +1,38 @@
+//! HTML serialization from the tree structure.
+//!
+//! This module implements html5ever's `Serialize` for nodes and provides
+//! options for controlling the serialized output.
+
+pub mod attribute_comparator;
+pub mod attribute_order;
+pub mod attribute_quotes;
+#[cfg(feature = "c14n")]
+pub mod c14n_options;
+#[cfg(feature = "c14n")]
+pub mod canonicalize;
+pub mod entity_style;
+pub mod filter_action;
+pub(crate) mod html_serializer;
+pub mod node_filter;
+pub mod node_filter_fn;
+pub mod node_ref_serialize;
+#[cfg(feature = "encoding")]
+mod node_ref_serialize_encoded;
+mod node_ref_serialize_matching;
+mod node_ref_serialize_source;
+#[cfg(feature = "quick-xml")]
+mod node_ref_xml_events;
+pub mod serialize_cache;
+pub mod serialize_options;
+
+pub use attribute_comparator::AttributeComparator;
+pub use attribute_order::AttributeOrder;
+pub use attribute_quotes::AttributeQuotes;
+#[cfg(feature = "c14n")]
+pub use c14n_options::C14nOptions;
+pub use entity_style::EntityStyle;
+pub use filter_action::FilterAction;
+pub use node_filter::NodeFilter;
+pub use node_filter_fn::NodeFilterFn;
+pub use serialize_cache::SerializeCache;
+pub use serialize_options::SerializeOptions;
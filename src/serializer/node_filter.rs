@@ -0,0 +1,113 @@
+//! Node filtering for serialization.
+
+use std::fmt;
+use std::rc::Rc;
+
+use super::{FilterAction, NodeFilterFn};
+use crate::select::Selectors;
+use crate::tree::NodeRef;
+
+/// Which nodes the serializer writes, leaves out or replaces.
+///
+/// Filtering happens while writing, so the tree itself is not changed and
+/// several variants of a document can be written from one tree.
+///
+/// # Examples
+///
+/// ```
+/// use brik::parse_html;
+/// use brik::traits::*;
+/// use brik::{NodeFilter, NodeRef, SerializeOptions, Selectors};
+///
+/// let doc = parse_html().one("<p>Public <span class=private>secret</span><!--note--></p>");
+/// let options = SerializeOptions {
+///     node_filter: NodeFilter::replace_matching(
+///         Selectors::compile(".private").unwrap(),
+///         NodeRef::new_text("[redacted]"),
+///     ),
+///     ..Default::default()
+/// };
+///
+/// let mut bytes = Vec::new();
+/// let p = doc.select_first("p").unwrap();
+/// p.as_node().serialize_with_options(&mut bytes, &options).unwrap();
+/// assert_eq!(String::from_utf8(bytes).unwrap(), "<p>Public [redacted]<!--note--></p>");
+/// ```
+#[derive(Clone, Default)]
+pub enum NodeFilter {
+    /// Every node is written.
+    #[default]
+    KeepAll,
+
+    /// Each node is passed to a user function deciding what to do with it.
+    ///
+    /// Descendants of skipped or replaced nodes are not passed to it.
+    Custom(NodeFilterFn),
+}
+
+/// Methods for NodeFilter.
+///
+/// Provides construction of custom and selector-based filters.
+impl NodeFilter {
+    /// Creates a filter from a function deciding what to do with each node.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::{FilterAction, NodeFilter};
+    ///
+    /// // Leave out comments.
+    /// let filter = NodeFilter::custom(|node| {
+    ///     if node.as_comment().is_some() {
+    ///         FilterAction::Skip
+    ///     } else {
+    ///         FilterAction::Keep
+    ///     }
+    /// });
+    /// ```
+    pub fn custom<F>(filter: F) -> Self
+    where
+        F: Fn(&NodeRef) -> FilterAction + 'static,
+    {
+        NodeFilter::Custom(Rc::new(filter))
+    }
+
+    /// Creates a filter leaving out the elements matching `selectors`.
+    pub fn skip_matching(selectors: Selectors) -> Self {
+        NodeFilter::custom(move |node| match node.clone().into_element_ref() {
+            Some(element) if selectors.matches(&element) => FilterAction::Skip,
+            _ => FilterAction::Keep,
+        })
+    }
+
+    /// Creates a filter writing `placeholder` in place of the elements
+    /// matching `selectors`.
+    pub fn replace_matching(selectors: Selectors, placeholder: NodeRef) -> Self {
+        NodeFilter::custom(move |node| match node.clone().into_element_ref() {
+            Some(element) if selectors.matches(&element) => {
+                FilterAction::Replace(placeholder.clone())
+            }
+            _ => FilterAction::Keep,
+        })
+    }
+
+    /// Returns what to do with a node.
+    pub(crate) fn action(&self, node: &NodeRef) -> FilterAction {
+        match self {
+            NodeFilter::KeepAll => FilterAction::Keep,
+            NodeFilter::Custom(filter) => filter(node),
+        }
+    }
+}
+
+/// Implements Debug for NodeFilter.
+///
+/// Custom functions cannot be formatted, so only the variant name is shown.
+impl fmt::Debug for NodeFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NodeFilter::KeepAll => f.write_str("KeepAll"),
+            NodeFilter::Custom(_) => f.write_str("Custom(..)"),
+        }
+    }
+}
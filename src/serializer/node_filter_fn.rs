@@ -0,0 +1,9 @@
+//! Node filter function type.
+
+use std::rc::Rc;
+
+use super::FilterAction;
+use crate::tree::NodeRef;
+
+/// A shared node filter function, used by [`NodeFilter::Custom`](super::NodeFilter::Custom).
+pub type NodeFilterFn = Rc<dyn Fn(&NodeRef) -> FilterAction>;
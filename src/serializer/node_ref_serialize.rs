@@ -0,0 +1,525 @@
+//! HTML serialization of nodes.
+
+use super::html_serializer::HtmlSerializer;
+use super::{FilterAction, NodeFilter, SerializeOptions};
+use crate::node_data_ref::NodeDataRef;
+use crate::tree::{ElementData, NodeData, NodeRef};
+use html5ever::serialize::TraversalScope::*;
+use html5ever::serialize::{serialize, Serialize, SerializeOpts, Serializer, TraversalScope};
+use html5ever::{LocalName, QualName};
+use std::fmt;
+use std::fs::File;
+use std::io;
+use std::io::Write;
+use std::path::Path;
+
+/// Implements Serialize for NodeRef.
+///
+/// Enables HTML serialization of DOM nodes using html5ever's serialization
+/// infrastructure. Handles all node types including elements, text, comments,
+/// doctypes, processing instructions, documents, and document fragments.
+impl Serialize for NodeRef {
+    fn serialize<S: Serializer>(
+        &self,
+        serializer: &mut S,
+        traversal_scope: TraversalScope,
+    ) -> io::Result<()> {
+        serialize_node(
+            self,
+            serializer,
+            traversal_scope,
+            &SerializeOptions::default(),
+        )
+    }
+}
+
+/// Writes the start tag of an element with the given options.
+pub(super) fn start_element<S: Serializer>(
+    element: &ElementData,
+    serializer: &mut S,
+    options: &SerializeOptions,
+) -> io::Result<()> {
+    let attrs = element.attributes.borrow();
+    let mut attrs = attrs.map.iter().collect::<Vec<_>>();
+    options.attribute_order.sort(&mut attrs);
+
+    // Unfortunately we need to allocate something to hold these &'a QualName
+    let attrs = attrs
+        .into_iter()
+        .map(|(name, attr)| {
+            let local = match attr.source_name.as_deref() {
+                Some(source) if options.source_attribute_names => {
+                    let local = source.rsplit(':').next().unwrap_or(source);
+                    LocalName::from(local)
+                }
+                _ => name.local.clone(),
+            };
+            (
+                QualName::new(attr.prefix.clone(), name.ns.clone(), local),
+                &attr.value,
+            )
+        })
+        .collect::<Vec<_>>();
+
+    serializer.start_elem(
+        element.name.clone(),
+        attrs.iter().map(|&(ref name, value)| (name, &**value)),
+    )
+}
+
+/// Serializes a node and its descendants with the given options.
+pub(super) fn serialize_node<S: Serializer>(
+    node: &NodeRef,
+    serializer: &mut S,
+    traversal_scope: TraversalScope,
+    options: &SerializeOptions,
+) -> io::Result<()> {
+    match options.node_filter.action(node) {
+        FilterAction::Keep => {}
+        FilterAction::Skip => return Ok(()),
+        FilterAction::Replace(replacement) => {
+            let unfiltered = SerializeOptions {
+                node_filter: NodeFilter::KeepAll,
+                ..options.clone()
+            };
+            return serialize_node(&replacement, serializer, IncludeNode, &unfiltered);
+        }
+    }
+    match (traversal_scope, node.data()) {
+        (ref scope, NodeData::Element(element)) => {
+            if *scope == IncludeNode {
+                start_element(element, serializer, options)?
+            }
+
+            let children = match element.template_contents.as_ref() {
+                Some(template_root) => template_root.children(),
+                None => node.children(),
+            };
+
+            for child in children {
+                serialize_node(&child, serializer, IncludeNode, options)?
+            }
+
+            if *scope == IncludeNode {
+                serializer.end_elem(element.name.clone())?
+            }
+            Ok(())
+        }
+
+        (_, &NodeData::DocumentFragment) | (_, &NodeData::Document(_)) => {
+            for child in node.children() {
+                serialize_node(&child, serializer, IncludeNode, options)?
+            }
+            Ok(())
+        }
+
+        (ChildrenOnly(_), _) => Ok(()),
+
+        (IncludeNode, NodeData::Doctype(doctype)) => serializer.write_doctype(&doctype.name),
+        (IncludeNode, NodeData::Text(text)) => serializer.write_text(&text.borrow()),
+        (IncludeNode, NodeData::Comment(text)) => serializer.write_comment(&text.borrow()),
+        (IncludeNode, NodeData::ProcessingInstruction(contents)) => {
+            let contents = contents.borrow();
+            serializer.write_processing_instruction(&contents.0, &contents.1)
+        }
+    }
+}
+
+/// Implements Display for NodeRef.
+///
+/// Formats the node and its descendants as an HTML string. Uses the
+/// Serialize implementation to generate the HTML output.
+impl fmt::Display for NodeRef {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // Call the html serializer for the node (sub)tree.
+        let mut bytes = Vec::new();
+        self.serialize(&mut bytes).or(Err(fmt::Error))?;
+        let html = String::from_utf8(bytes).or(Err(fmt::Error))?;
+        f.write_str(&html)
+    }
+}
+
+/// Implements Display for element references.
+///
+/// Formats the element's outer HTML, as the Display implementation of its
+/// NodeRef does, so matches can be used directly in `format!` and logs.
+impl fmt::Display for NodeDataRef<ElementData> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self.as_node(), f)
+    }
+}
+
+/// Methods for HTML serialization.
+///
+/// Provides convenient methods for serializing DOM nodes to HTML strings,
+/// byte streams, and files.
+impl NodeRef {
+    /// Serialize this node and its descendants in HTML syntax to the given stream.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `io::Error` if writing to the stream fails.
+    #[inline]
+    pub fn serialize<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        serialize(
+            writer,
+            self,
+            SerializeOpts {
+                traversal_scope: IncludeNode,
+                ..Default::default()
+            },
+        )
+    }
+
+    /// Serialize this node and its descendants in HTML syntax with custom options.
+    ///
+    /// See [`SerializeOptions`] for the available options.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `io::Error` if writing to the stream fails.
+    pub fn serialize_with_options<W: Write>(
+        &self,
+        writer: &mut W,
+        options: &SerializeOptions,
+    ) -> io::Result<()> {
+        let mut serializer = HtmlSerializer::new(writer, options);
+        serialize_node(self, &mut serializer, IncludeNode, options)
+    }
+
+    /// Serialize this node and its descendants in HTML syntax to a new file at the given path.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `io::Error` if the file cannot be created or if writing fails.
+    #[inline]
+    pub fn serialize_to_file<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let mut file = File::create(&path)?;
+        self.serialize(&mut file)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::parse_html;
+    use crate::serializer::{
+        AttributeOrder, AttributeQuotes, EntityStyle, FilterAction, NodeFilter, SerializeOptions,
+    };
+    use crate::traits::*;
+    use tempfile::TempDir;
+
+    /// Tests writing attribute names as spelled in the source.
+    ///
+    /// Verifies that recorded names are written only when requested, and
+    /// that attributes without a recorded name keep their folded name.
+    #[test]
+    fn serialize_source_attribute_names() {
+        let opts = crate::ParseOpts {
+            preserve_attribute_names: true,
+            ..Default::default()
+        };
+        let doc = crate::parse_html_str_with_options(
+            opts,
+            r#"<div onClick="go()" ID=a><svg viewBox="0 0 1 1"></svg></div>"#,
+        );
+        let div = doc.select_first("div").unwrap();
+        div.attributes
+            .borrow_mut()
+            .insert("data-new", "x".to_string());
+
+        let serialize = |options: &SerializeOptions| {
+            let mut bytes = Vec::new();
+            div.as_node()
+                .serialize_with_options(&mut bytes, options)
+                .unwrap();
+            String::from_utf8(bytes).unwrap()
+        };
+        assert_eq!(
+            serialize(&SerializeOptions::default()),
+            r#"<div onclick="go()" id="a" data-new="x"><svg viewBox="0 0 1 1"></svg></div>"#
+        );
+        let options = SerializeOptions {
+            source_attribute_names: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            serialize(&options),
+            r#"<div onClick="go()" ID="a" data-new="x"><svg viewBox="0 0 1 1"></svg></div>"#
+        );
+    }
+
+    /// Tests serializing to a file and reading it back.
+    ///
+    /// Verifies that serialize_to_file() correctly writes HTML to disk
+    /// and that the resulting file can be parsed to produce an equivalent
+    /// DOM structure.
+    #[test]
+    fn serialize_and_read_file() {
+        let tempdir = TempDir::new().unwrap();
+        let mut path = tempdir.path().to_path_buf();
+        path.push("temp.html");
+
+        let html =
+            r"<!DOCTYPE html><html><head><title>Title</title></head><body>Body</body></html>";
+        let document = parse_html().one(html);
+        let _ = document.serialize_to_file(path.clone());
+
+        let document2 = parse_html().from_utf8().from_file(&path).unwrap();
+        assert_eq!(document.to_string(), document2.to_string());
+    }
+
+    /// Tests Display trait for NodeRef.
+    ///
+    /// Verifies that to_string() produces correct HTML output for a
+    /// subtree, properly serializing element tags and attributes.
+    #[test]
+    fn to_string() {
+        let html = r"<!DOCTYPE html>
+<html>
+    <head>
+        <title>Test case</title>
+    </head>
+    <body>
+        <p class=foo>Foo
+    </body>
+</html>";
+
+        let document = parse_html().one(html);
+        assert_eq!(
+            document
+                .inclusive_descendants()
+                .nth(11)
+                .unwrap()
+                .to_string(),
+            "<p class=\"foo\">Foo\n    \n</p>"
+        );
+    }
+
+    /// Tests Display for element references.
+    ///
+    /// Verifies that a selected element formats as its outer HTML.
+    #[test]
+    fn element_to_string() {
+        let document = parse_html().one(r#"<ul><li class="a">One</li></ul>"#);
+        let li = document.select_first("li").unwrap();
+        assert_eq!(format!("match: {}", li), r#"match: <li class="a">One</li>"#);
+    }
+
+    /// Tests serialization of HTML comments.
+    ///
+    /// Verifies that Comment nodes are properly serialized using the
+    /// standard HTML comment syntax.
+    #[test]
+    fn serialize_comment() {
+        let html = r"<div><!-- This is a comment --></div>";
+        let document = parse_html().one(html);
+        let output = document.to_string();
+        assert!(output.contains("<!-- This is a comment -->"));
+    }
+
+    /// Tests serialization preserves multiple node types.
+    ///
+    /// Verifies that documents with mixed content (text, elements, comments)
+    /// are properly serialized.
+    #[test]
+    fn serialize_mixed_content() {
+        let html = r"<div>Text<!-- comment --><span>more</span></div>";
+        let document = parse_html().one(html);
+        let output = document.to_string();
+        assert!(output.contains("Text"));
+        assert!(output.contains("<!-- comment -->"));
+        assert!(output.contains("<span>"));
+    }
+
+    /// Tests direct serialization to a writer.
+    ///
+    /// Verifies that serialize() method correctly writes HTML to an arbitrary
+    /// writer, not just via Display or file operations.
+    #[test]
+    fn serialize_to_writer() {
+        let html = r"<p>Hello</p>";
+        let document = parse_html().one(html);
+        let p = document.select_first("p").unwrap();
+
+        let mut buffer = Vec::new();
+        p.as_node().serialize(&mut buffer).unwrap();
+        let output = String::from_utf8(buffer).unwrap();
+
+        assert_eq!(output, "<p>Hello</p>");
+    }
+
+    /// Serializes a node with options into a string.
+    fn to_string_with(node: &crate::NodeRef, options: &SerializeOptions) -> String {
+        let mut buffer = Vec::new();
+        node.serialize_with_options(&mut buffer, options).unwrap();
+        String::from_utf8(buffer).unwrap()
+    }
+
+    /// Tests attribute ordering options.
+    ///
+    /// Verifies insertion order by default, name order, and a custom
+    /// comparator, including on nested elements.
+    #[test]
+    fn serialize_attribute_order() {
+        let document = parse_html().one(r#"<div z="1" a="2"><p id="x" class="y">T</p></div>"#);
+        let div = document.select_first("div").unwrap();
+
+        let insertion = SerializeOptions::default();
+        assert_eq!(
+            to_string_with(div.as_node(), &insertion),
+            r#"<div z="1" a="2"><p id="x" class="y">T</p></div>"#
+        );
+
+        let by_name = SerializeOptions {
+            attribute_order: AttributeOrder::Name,
+            ..Default::default()
+        };
+        assert_eq!(
+            to_string_with(div.as_node(), &by_name),
+            r#"<div a="2" z="1"><p class="y" id="x">T</p></div>"#
+        );
+
+        let reversed = SerializeOptions {
+            attribute_order: AttributeOrder::custom(|a, b| b.local.cmp(&a.local)),
+            ..Default::default()
+        };
+        assert_eq!(
+            to_string_with(div.as_node(), &reversed),
+            r#"<div z="1" a="2"><p id="x" class="y">T</p></div>"#
+        );
+    }
+
+    /// Tests that name ordering is stable across manipulation.
+    ///
+    /// Verifies that documents with the same attributes inserted in different
+    /// orders serialize to identical bytes.
+    #[test]
+    fn serialize_attribute_order_stable() {
+        let first = parse_html().one(r#"<p b="2" a="1">T</p>"#);
+        let second = parse_html().one(r#"<p a="1">T</p>"#);
+        second
+            .select_first("p")
+            .unwrap()
+            .attributes
+            .borrow_mut()
+            .insert("b", "2".to_string());
+
+        let options = SerializeOptions {
+            attribute_order: AttributeOrder::Name,
+            ..Default::default()
+        };
+        assert_eq!(
+            to_string_with(&first, &options),
+            to_string_with(&second, &options)
+        );
+    }
+
+    /// Tests that default options match the standard serializer.
+    ///
+    /// Verifies that serialize_with_options() with defaults produces the same
+    /// bytes as Display, including void and raw text elements.
+    #[test]
+    fn serialize_with_default_options() {
+        let html = "<!DOCTYPE html><p a=\"&quot;\">x &amp; y&nbsp;&lt;\u{e9}<br>\
+                    <script>if (a < b) {}</script><!-- c --></p><svg><rect/></svg>";
+        let document = parse_html().one(html);
+        assert_eq!(
+            to_string_with(&document, &SerializeOptions::default()),
+            document.to_string()
+        );
+    }
+
+    /// Tests the escaping options.
+    ///
+    /// Verifies numeric entities, non-ASCII escaping, and apostrophe escaping
+    /// in text and attribute values, while raw text is left untouched.
+    #[test]
+    fn serialize_escaping() {
+        let document = parse_html()
+            .one("<p title=\"caf\u{e9} 'x' &amp;\">It's &lt;\u{1F600}&nbsp;</p><style>p{content:'\u{e9}'}</style>");
+        let body = document.select_first("body").unwrap();
+
+        let numeric = SerializeOptions {
+            entity_style: EntityStyle::Numeric,
+            ..Default::default()
+        };
+        assert_eq!(
+            to_string_with(body.as_node(), &numeric),
+            "<body><p title=\"caf\u{e9} 'x' &#38;\">It's &#60;\u{1F600}&#160;</p>\
+             <style>p{content:'\u{e9}'}</style></body>"
+        );
+
+        let ascii = SerializeOptions {
+            escape_non_ascii: true,
+            escape_apostrophe: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            to_string_with(body.as_node(), &ascii),
+            "<body><p title=\"caf&#xE9; &#39;x&#39; &amp;\">It&#39;s &lt;&#x1F600;&nbsp;</p>\
+             <style>p{content:'\u{e9}'}</style></body>"
+        );
+    }
+
+    /// Tests the attribute quoting options.
+    ///
+    /// Verifies single and minimal quoting, with values that need quotes
+    /// or escaping, and collapsing of empty values.
+    #[test]
+    fn serialize_attribute_quotes() {
+        let document = parse_html()
+            .one(r#"<input id=name title="it's &quot;x&quot;" class="a b" disabled="">"#);
+        let input = document.select_first("input").unwrap();
+
+        let single = SerializeOptions {
+            attribute_quotes: AttributeQuotes::Single,
+            ..Default::default()
+        };
+        assert_eq!(
+            to_string_with(input.as_node(), &single),
+            r#"<input id='name' title='it&#39;s "x"' class='a b' disabled=''>"#
+        );
+
+        let minimal = SerializeOptions {
+            attribute_quotes: AttributeQuotes::Minimal,
+            collapse_boolean_attributes: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            to_string_with(input.as_node(), &minimal),
+            r#"<input id=name title="it's &quot;x&quot;" class="a b" disabled>"#
+        );
+    }
+
+    /// Tests filtering nodes while serializing.
+    ///
+    /// Verifies that skipped nodes are left out with their descendants,
+    /// that template contents are filtered, and that the tree is unchanged.
+    #[test]
+    fn serialize_node_filter() {
+        let document =
+            parse_html().one("<p>a<!--c--><script>s()</script><template><!--t-->b</template></p>");
+        let p = document.select_first("p").unwrap();
+        let options = SerializeOptions {
+            node_filter: NodeFilter::custom(|node| {
+                let script = node
+                    .as_element()
+                    .is_some_and(|element| &*element.name.local == "script");
+                if script || node.as_comment().is_some() {
+                    FilterAction::Skip
+                } else {
+                    FilterAction::Keep
+                }
+            }),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            to_string_with(p.as_node(), &options),
+            "<p>a<template>b</template></p>"
+        );
+        assert_eq!(p.as_node().children().count(), 4);
+    }
+}
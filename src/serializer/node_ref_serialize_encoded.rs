@@ -0,0 +1,134 @@
+//! Serialization to legacy character encodings.
+
+use crate::iter::NodeIterator;
+use crate::tree::NodeRef;
+use encoding_rs::Encoding;
+use html5ever::QualName;
+
+/// Encoded serialization methods for NodeRef.
+///
+/// Provides serialization to character encodings other than UTF-8.
+impl NodeRef {
+    /// Serializes this node to bytes in the encoding named by `label`,
+    /// such as `"shift_jis"` or `"windows-1252"`.
+    ///
+    /// Characters the encoding cannot represent are written as numeric
+    /// character references like `&#8364;`. These are not decoded inside
+    /// `<script>` and `<style>`, so keep those ASCII. Labels are those of
+    /// the WHATWG Encoding Standard; UTF-16 labels produce UTF-8, as
+    /// browsers do when serializing.
+    ///
+    /// Before serializing, the `charset` of every `<meta charset>` and
+    /// `<meta http-equiv="content-type">` in this subtree is changed to the
+    /// encoding's name. If there is none and this node has a
+    /// [`head()`](NodeRef::head), a `<meta charset>` is inserted at its
+    /// start. This modifies the tree.
+    ///
+    /// Returns `None` if `label` is not a known encoding.
+    ///
+    /// **Note:** This method requires the `encoding` feature to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::parse_html;
+    /// use brik::traits::*;
+    ///
+    /// let doc = parse_html().one("<meta charset=utf-8><p>Caf\u{e9} \u{20ac}5 \u{1f600}");
+    /// let bytes = doc.serialize_encoded("latin1").unwrap();
+    /// assert_eq!(
+    ///     bytes,
+    ///     b"<html><head><meta charset=\"windows-1252\"></head>\
+    ///       <body><p>Caf\xe9 \x805 &#128512;</p></body></html>"
+    /// );
+    /// ```
+    pub fn serialize_encoded(&self, label: &str) -> Option<Vec<u8>> {
+        let encoding = Encoding::for_label(label.as_bytes())?.output_encoding();
+        self.set_meta_charset(encoding.name());
+        let html = self.to_string();
+        let (bytes, _, _) = encoding.encode(&html);
+        Some(bytes.into_owned())
+    }
+
+    /// Sets the charset declared by `<meta>` elements in this subtree,
+    /// inserting a `<meta charset>` at the start of the head if there is no
+    /// declaration.
+    fn set_meta_charset(&self, name: &str) {
+        let mut declared = false;
+        for meta in self.inclusive_descendants().elements() {
+            if meta.name.ns != ns!(html) || meta.name.local != local_name!("meta") {
+                continue;
+            }
+            let mut attributes = meta.attributes.borrow_mut();
+            if attributes.contains(local_name!("charset")) {
+                attributes.insert(local_name!("charset"), name);
+            } else if attributes
+                .get_trimmed(local_name!("http-equiv"))
+                .is_some_and(|value| value.eq_ignore_ascii_case("content-type"))
+            {
+                attributes.insert(local_name!("content"), format!("text/html; charset={name}"));
+            } else {
+                continue;
+            }
+            meta.as_node().mark_dirty();
+            declared = true;
+        }
+        if declared {
+            return;
+        }
+        if let Some(head) = self.head() {
+            let meta =
+                NodeRef::new_element(QualName::new(None, ns!(html), local_name!("meta")), None);
+            if let Some(element) = meta.as_element() {
+                element
+                    .attributes
+                    .borrow_mut()
+                    .insert(local_name!("charset"), name);
+            }
+            head.as_node().prepend(meta);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::html5ever::tendril::TendrilSink;
+    use crate::parse_html;
+
+    /// Tests serializing to Shift_JIS.
+    ///
+    /// Verifies that a `<meta charset>` is added when missing, that
+    /// Japanese text is encoded natively, and that characters outside the
+    /// encoding become numeric character references, in attributes too.
+    #[test]
+    fn serialize_encoded_shift_jis() {
+        let doc = parse_html().one("<p title=\"\u{e9}\">\u{65e5}\u{672c}</p>");
+
+        let bytes = doc.serialize_encoded("Shift_JIS").unwrap();
+
+        assert_eq!(
+            bytes,
+            b"<html><head><meta charset=\"Shift_JIS\"></head><body>\
+              <p title=\"&#233;\">\x93\xfa\x96\x7b</p></body></html>"
+        );
+        assert_eq!(doc.serialize_encoded("no-such-encoding"), None);
+    }
+
+    /// Tests updating an `http-equiv` charset declaration.
+    ///
+    /// Edge case: an existing `content-type` declaration is rewritten
+    /// instead of adding a `<meta charset>`.
+    #[test]
+    fn serialize_encoded_updates_http_equiv() {
+        let doc =
+            parse_html().one("<meta http-equiv=Content-Type content=\"text/html; charset=utf-8\">");
+
+        doc.serialize_encoded("windows-1252").unwrap();
+
+        assert_eq!(
+            doc.head().unwrap().as_node().to_string(),
+            "<head><meta http-equiv=\"Content-Type\" \
+             content=\"text/html; charset=windows-1252\"></head>"
+        );
+    }
+}
@@ -0,0 +1,78 @@
+//! Serialization of the elements matching a selector.
+
+use crate::iter::NodeIterator;
+use crate::select::Selectors;
+use crate::tree::NodeRef;
+
+/// Selector-scoped serialization methods for NodeRef.
+///
+/// Provides the outer HTML of each element matching a selector.
+impl NodeRef {
+    /// Returns the outer HTML of each element in this subtree matching
+    /// `selectors`, in document order.
+    ///
+    /// The compiled selectors are reused for a single traversal. A match
+    /// inside another match is serialized both on its own and as part of
+    /// the outer one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::parse_html;
+    /// use brik::traits::*;
+    /// use brik::Selectors;
+    ///
+    /// let doc = parse_html().one("<p class=x>One</p><p>Two</p><div class=x>Three</div>");
+    /// let selectors = Selectors::compile(".x").unwrap();
+    /// assert_eq!(
+    ///     doc.serialize_matching(&selectors),
+    ///     [r#"<p class="x">One</p>"#, r#"<div class="x">Three</div>"#]
+    /// );
+    /// ```
+    pub fn serialize_matching(&self, selectors: &Selectors) -> Vec<String> {
+        self.serialize_matching_iter(selectors).collect()
+    }
+
+    /// Returns an iterator of the outer HTML of each element in this
+    /// subtree matching `selectors`, in document order.
+    ///
+    /// Each element is serialized as the iterator reaches it, so the
+    /// results can be forwarded one at a time. See
+    /// [`serialize_matching()`](NodeRef::serialize_matching).
+    pub fn serialize_matching_iter<'a>(
+        &self,
+        selectors: &'a Selectors,
+    ) -> impl Iterator<Item = String> + 'a {
+        selectors
+            .filter(self.inclusive_descendants().elements())
+            .map(|element| element.as_node().to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::html5ever::tendril::TendrilSink;
+    use crate::parse_html;
+    use crate::Selectors;
+
+    /// Tests serializing nested matches.
+    ///
+    /// Edge case: a match inside another match is yielded separately after
+    /// the outer one, and the subtree root itself can match.
+    #[test]
+    fn serialize_matching_nested() {
+        let doc = parse_html().one("<ul><li>a<ul><li>b</li></ul></li></ul>");
+        let list = doc.select_first("ul").unwrap();
+        let selectors = Selectors::compile("ul, li li").unwrap();
+
+        let mut matches = list.as_node().serialize_matching_iter(&selectors);
+
+        assert_eq!(
+            matches.next().as_deref(),
+            Some("<ul><li>a<ul><li>b</li></ul></li></ul>")
+        );
+        assert_eq!(matches.next().as_deref(), Some("<ul><li>b</li></ul>"));
+        assert_eq!(matches.next().as_deref(), Some("<li>b</li>"));
+        assert_eq!(matches.next(), None);
+    }
+}
@@ -0,0 +1,185 @@
+//! Minimal-touch serialization reusing parsed source markup.
+
+use super::html_serializer::HtmlSerializer;
+use super::node_ref_serialize::{serialize_node, start_element};
+use super::{FilterAction, SerializeOptions};
+use crate::tree::{NodeData, NodeRef};
+use html5ever::serialize::Serializer;
+use html5ever::serialize::TraversalScope::IncludeNode;
+use std::io::{self, Write};
+
+/// Source-preserving serialization methods for NodeRef.
+///
+/// Provides output that keeps the original markup of unchanged nodes.
+impl NodeRef {
+    /// Serialize this node and its descendants in HTML syntax, writing the
+    /// start tags and text of unchanged nodes exactly as they were in the
+    /// source.
+    ///
+    /// Nodes parsed with
+    /// [`ParseOpts::preserve_source`](crate::ParseOpts::preserve_source)
+    /// keep their source markup. An element whose name and attributes are
+    /// unchanged has its start and end tags written as they were, with
+    /// their original case, quoting and spacing, and an unchanged text node
+    /// has its text written with its original character references.
+    /// Everything else, including omitted end tags, comments, doctypes and
+    /// new or modified nodes, is serialized with `options`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `io::Error` if writing to the stream fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::{parse_html_str_with_options, ParseOpts, SerializeOptions};
+    /// use brik::traits::*;
+    ///
+    /// let opts = ParseOpts {
+    ///     preserve_source: true,
+    ///     ..Default::default()
+    /// };
+    /// let doc = parse_html_str_with_options(
+    ///     opts,
+    ///     "<UL class=list><LI data-id=1>caf&eacute;</LI><LI data-id=2>tea</LI></UL>",
+    /// );
+    /// let item = doc.select("li").unwrap().nth(1).unwrap();
+    /// item.attributes_mut().insert("class", "new".to_string());
+    ///
+    /// let ul = doc.select_first("ul").unwrap();
+    /// let mut output = Vec::new();
+    /// ul.as_node()
+    ///     .serialize_preserving_source(&mut output, &SerializeOptions::default())
+    ///     .unwrap();
+    /// assert_eq!(
+    ///     String::from_utf8(output).unwrap(),
+    ///     r#"<UL class=list><LI data-id=1>caf&eacute;</LI><li data-id="2" class="new">tea</li></UL>"#
+    /// );
+    /// ```
+    pub fn serialize_preserving_source<W: Write>(
+        &self,
+        writer: &mut W,
+        options: &SerializeOptions,
+    ) -> io::Result<()> {
+        let mut serializer = HtmlSerializer::new(writer, options);
+        write_node(self, &mut serializer, options)
+    }
+}
+
+/// Writes a node and its descendants, reusing the source markup of
+/// unchanged nodes.
+fn write_node<W: Write>(
+    node: &NodeRef,
+    serializer: &mut HtmlSerializer<'_, W>,
+    options: &SerializeOptions,
+) -> io::Result<()> {
+    let source = node.source().filter(|source| source.is_unchanged(node));
+    let keep = matches!(options.node_filter.action(node), FilterAction::Keep);
+    match node.data() {
+        NodeData::Element(element) if keep => {
+            match &source {
+                Some(source) => {
+                    serializer.start_elem_from_source(&element.name, source.markup())?
+                }
+                None => start_element(element, serializer, options)?,
+            }
+            let children = match element.template_contents.as_ref() {
+                Some(contents) => contents.children(),
+                None => node.children(),
+            };
+            for child in children {
+                write_node(&child, serializer, options)?;
+            }
+            match source.as_ref().and_then(|source| source.end_markup()) {
+                Some(markup) => serializer.end_elem_from_source(markup),
+                None => serializer.end_elem(element.name.clone()),
+            }
+        }
+        NodeData::Text(_) if keep && source.is_some() => {
+            let source = source.as_ref().map_or("", |source| source.markup());
+            serializer.writer_mut().write_all(source.as_bytes())
+        }
+        NodeData::Document(_) | NodeData::DocumentFragment if keep => {
+            for child in node.children() {
+                write_node(&child, serializer, options)?;
+            }
+            Ok(())
+        }
+        _ => serialize_node(node, serializer, IncludeNode, options),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::html5ever::tendril::TendrilSink;
+    use crate::{parse_html, parse_html_str_with_options, NodeRef, ParseOpts, SerializeOptions};
+
+    /// Tests that only modified regions are regenerated.
+    ///
+    /// Verifies that an untouched document keeps its start tags and text,
+    /// and that edited text, new nodes and changed attributes are
+    /// serialized normally while their neighbours keep their source.
+    #[test]
+    fn regenerates_only_changes() {
+        let html = "<!DOCTYPE html><html><head><title>A &amp; B</title></head>\
+                    <body><P CLASS='intro'>Hello&nbsp;world</P><p id=x>Bye</p></body></html>";
+        let opts = ParseOpts {
+            preserve_source: true,
+            ..Default::default()
+        };
+        let doc = parse_html_str_with_options(opts, html);
+        let serialize = || {
+            let mut output = Vec::new();
+            doc.serialize_preserving_source(&mut output, &SerializeOptions::default())
+                .unwrap();
+            String::from_utf8(output).unwrap()
+        };
+        assert_eq!(serialize(), html);
+
+        let last = doc.select("p").unwrap().nth(1).unwrap();
+        last.as_node().first_child().unwrap().detach();
+        last.as_node().append(NodeRef::new_text("See you"));
+        let title = doc.select_first("title").unwrap();
+        title.attributes_mut().insert("lang", "en".to_string());
+        assert_eq!(
+            serialize(),
+            "<!DOCTYPE html><html><head><title lang=\"en\">A &amp; B</title></head>\
+             <body><P CLASS='intro'>Hello&nbsp;world</P><p id=x>See you</p></body></html>"
+        );
+    }
+
+    /// Tests source preservation across foster parenting.
+    ///
+    /// Verifies that an element the tree builder moves out of a table does
+    /// not take the start tag of an earlier element with the same tag and
+    /// attribute names but different values.
+    #[test]
+    fn foster_parented_elements_keep_their_attributes() {
+        let html = "<table><tr><td><p class=one>a</p></td></tr><p class=two>b</p></table>";
+        let opts = ParseOpts {
+            preserve_source: true,
+            ..Default::default()
+        };
+        let doc = parse_html_str_with_options(opts, html);
+        let mut output = Vec::new();
+        doc.serialize_preserving_source(&mut output, &SerializeOptions::default())
+            .unwrap();
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.contains("<p class=two>b</p><table>"), "{}", output);
+        assert!(output.contains("<p class=one>a</p>"), "{}", output);
+
+        let reparsed = parse_html().one(output);
+        let classes: Vec<String> = reparsed
+            .select("p")
+            .unwrap()
+            .map(|p| {
+                format!(
+                    "{}:{}",
+                    p.attributes.borrow().get("class").unwrap(),
+                    p.text_contents()
+                )
+            })
+            .collect();
+        assert_eq!(classes, ["two:b", "one:a"]);
+    }
+}
@@ -0,0 +1,268 @@
+//! Export of node trees as `quick-xml` events.
+
+use crate::tree::{NodeData, NodeRef};
+use quick_xml::events::{BytesCData, BytesEnd, BytesPI, BytesStart, BytesText, Event};
+use quick_xml::Writer;
+use std::io::{self, Write};
+
+/// Produces `quick-xml` events for nodes, declaring namespaces as needed.
+struct XmlEvents<'a> {
+    /// Receives each event.
+    emit: &'a mut dyn FnMut(Event<'_>) -> io::Result<()>,
+    /// Namespace declarations in scope, as (prefix, URL) pairs; the default
+    /// namespace has an empty prefix.
+    declared: Vec<(String, String)>,
+}
+
+/// Methods for XmlEvents.
+///
+/// Provides the recursive node walker.
+impl XmlEvents<'_> {
+    /// Returns the namespace URL in scope for a prefix.
+    fn declared_namespace(&self, prefix: &str) -> &str {
+        self.declared
+            .iter()
+            .rev()
+            .find(|(p, _)| p == prefix)
+            .map_or("", |(_, url)| url)
+    }
+
+    /// Emits the events of a node and its descendants.
+    fn node(&mut self, node: &NodeRef) -> io::Result<()> {
+        match node.data() {
+            NodeData::Document(_) | NodeData::DocumentFragment => {
+                for child in node.children() {
+                    self.node(&child)?;
+                }
+                Ok(())
+            }
+            NodeData::Element(_) => self.element(node),
+            NodeData::Text(text) => {
+                let text = text.borrow();
+                // A CDATA section cannot contain its own end marker.
+                if node.is_cdata_section() && !text.contains("]]>") {
+                    (self.emit)(Event::CData(BytesCData::new(&**text)))
+                } else {
+                    (self.emit)(Event::Text(BytesText::new(&text)))
+                }
+            }
+            NodeData::Comment(text) => {
+                (self.emit)(Event::Comment(BytesText::from_escaped(&**text.borrow())))
+            }
+            NodeData::ProcessingInstruction(value) => {
+                let value = value.borrow();
+                let content = if value.1.is_empty() {
+                    value.0.to_string()
+                } else {
+                    format!("{} {}", value.0, value.1)
+                };
+                (self.emit)(Event::PI(BytesPI::new(content)))
+            }
+            NodeData::Doctype(doctype) => {
+                let mut content = doctype.name.to_string();
+                if !doctype.public_id.is_empty() {
+                    content.push_str(&format!(" PUBLIC \"{}\"", doctype.public_id));
+                    if !doctype.system_id.is_empty() {
+                        content.push_str(&format!(" \"{}\"", doctype.system_id));
+                    }
+                } else if !doctype.system_id.is_empty() {
+                    content.push_str(&format!(" SYSTEM \"{}\"", doctype.system_id));
+                }
+                (self.emit)(Event::DocType(BytesText::from_escaped(content)))
+            }
+        }
+    }
+
+    /// Emits the events of an element, its namespace declarations and its
+    /// children, or its template contents.
+    fn element(&mut self, node: &NodeRef) -> io::Result<()> {
+        let Some(element) = node.as_element() else {
+            return Ok(());
+        };
+        let qualified_name = |prefix: Option<&str>, local: &str| match prefix {
+            Some(prefix) => format!("{prefix}:{local}"),
+            None => local.to_string(),
+        };
+
+        let mut declarations: Vec<(String, String)> = Vec::new();
+        let element_prefix = element.name.prefix.as_deref().unwrap_or("");
+        let element_namespace: &str = &element.name.ns;
+        if self.declared_namespace(element_prefix) != element_namespace
+            && (element_prefix.is_empty() || !element_namespace.is_empty())
+        {
+            declarations.push((element_prefix.to_string(), element_namespace.to_string()));
+        }
+
+        let attributes = element.attributes.borrow();
+        let mut attrs = Vec::new();
+        for (name, attr) in &attributes.map {
+            if name.ns == ns!(xmlns) {
+                continue;
+            }
+            let prefix = if name.ns == ns!(xml) {
+                Some("xml")
+            } else {
+                attr.prefix.as_deref().filter(|_| !name.ns.is_empty())
+            };
+            if let Some(prefix) = prefix {
+                if prefix != "xml"
+                    && self.declared_namespace(prefix) != &*name.ns
+                    && !declarations.iter().any(|(p, _)| p == prefix)
+                {
+                    declarations.push((prefix.to_string(), name.ns.to_string()));
+                }
+            }
+            attrs.push((qualified_name(prefix, &name.local), &attr.value));
+        }
+
+        let name = qualified_name(element.name.prefix.as_deref(), &element.name.local);
+        let mut start = BytesStart::new(name.as_str());
+        for (prefix, url) in &declarations {
+            let key = if prefix.is_empty() {
+                "xmlns".to_string()
+            } else {
+                format!("xmlns:{prefix}")
+            };
+            start.push_attribute((key.as_str(), url.as_str()));
+        }
+        for (qualified, value) in &attrs {
+            start.push_attribute((qualified.as_str(), &***value));
+        }
+        drop(attrs);
+        drop(attributes);
+
+        let parent = match element.template_contents.as_ref() {
+            Some(contents) => contents.clone(),
+            None => node.clone(),
+        };
+        if parent.first_child().is_none() {
+            return (self.emit)(Event::Empty(start));
+        }
+        (self.emit)(Event::Start(start))?;
+        let scope = self.declared.len();
+        self.declared.extend(declarations);
+        for child in parent.children() {
+            self.node(&child)?;
+        }
+        self.declared.truncate(scope);
+        (self.emit)(Event::End(BytesEnd::new(name)))
+    }
+}
+
+/// Methods for `quick-xml` event export.
+///
+/// Provides handoff of node trees to `quick-xml` writers and pipelines.
+impl NodeRef {
+    /// Returns the `quick-xml` events of this node and its descendants.
+    ///
+    /// Elements without children are [`Event::Empty`]. Each element declares
+    /// the namespaces it and its attributes use that are not declared by an
+    /// ancestor, so the events form namespace-well-formed XML; existing
+    /// `xmlns` attributes are replaced by these declarations. Template
+    /// contents are written as the template's children. Text is escaped;
+    /// comments are passed through unchanged.
+    ///
+    /// Run the tree through `ns::apply_xmlns()`, from the `namespaces`
+    /// feature, first if it was parsed as HTML from XML with prefixed names.
+    ///
+    /// **Note:** This method requires the `quick-xml` feature to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::parse_html;
+    /// use brik::traits::*;
+    /// use quick_xml::events::Event;
+    ///
+    /// let doc = parse_html().one("<p>a &amp; b<br></p>");
+    /// let p = doc.select_first("p").unwrap();
+    /// let events = p.as_node().xml_events();
+    ///
+    /// assert!(matches!(&events[0], Event::Start(start) if start.name().as_ref() == b"p"));
+    /// assert!(matches!(&events[2], Event::Empty(_)));
+    /// assert_eq!(events.len(), 4);
+    /// ```
+    pub fn xml_events(&self) -> Vec<Event<'static>> {
+        let mut events = Vec::new();
+        let mut emit = |event: Event<'_>| {
+            events.push(event.into_owned());
+            Ok(())
+        };
+        let _ = XmlEvents {
+            emit: &mut emit,
+            declared: Vec::new(),
+        }
+        .node(self);
+        events
+    }
+
+    /// Writes the `quick-xml` events of this node and its descendants to
+    /// `writer`.
+    ///
+    /// The events are those of [`xml_events()`](NodeRef::xml_events),
+    /// written as they are produced.
+    ///
+    /// **Note:** This method requires the `quick-xml` feature to be enabled.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from writing to the underlying writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::parse_html;
+    /// use brik::traits::*;
+    /// use quick_xml::Writer;
+    ///
+    /// let doc = parse_html().one("<svg><a href=#x>1 &lt; 2</a></svg>");
+    /// let svg = doc.select_first("svg").unwrap();
+    /// let mut writer = Writer::new(Vec::new());
+    /// svg.as_node().write_xml_events(&mut writer).unwrap();
+    ///
+    /// assert_eq!(
+    ///     String::from_utf8(writer.into_inner()).unwrap(),
+    ///     r##"<svg xmlns="http://www.w3.org/2000/svg"><a href="#x">1 &lt; 2</a></svg>"##
+    /// );
+    /// ```
+    pub fn write_xml_events<W: Write>(&self, writer: &mut Writer<W>) -> io::Result<()> {
+        let mut emit = |event: Event<'_>| writer.write_event(event);
+        XmlEvents {
+            emit: &mut emit,
+            declared: Vec::new(),
+        }
+        .node(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::html5ever::tendril::TendrilSink;
+    use crate::parse_html;
+    use quick_xml::Writer;
+
+    /// Tests writing a document with nested namespaces.
+    ///
+    /// Verifies that the doctype is kept, that the XHTML and SVG default
+    /// namespaces are declared where they change, that the `xlink` prefix
+    /// is declared on the element using it, and that template contents are
+    /// written as children.
+    #[test]
+    fn write_xml_events_namespaces() {
+        let doc = parse_html().one(
+            "<!DOCTYPE html><title>T</title><svg><use xlink:href=#i /></svg>\
+             <template><b>t</b></template>",
+        );
+        let mut writer = Writer::new(Vec::new());
+
+        doc.write_xml_events(&mut writer).unwrap();
+
+        assert_eq!(
+            String::from_utf8(writer.into_inner()).unwrap(),
+            "<!DOCTYPE html><html xmlns=\"http://www.w3.org/1999/xhtml\"><head><title>T</title>\
+             </head><body><svg xmlns=\"http://www.w3.org/2000/svg\">\
+             <use xmlns:xlink=\"http://www.w3.org/1999/xlink\" xlink:href=\"#i\"/></svg>\
+             <template><b>t</b></template></body></html>"
+        );
+    }
+}
@@ -0,0 +1,501 @@
+//! Incremental re-serialization of unchanged subtrees.
+
+use super::html_serializer::HtmlSerializer;
+use super::node_ref_serialize::{serialize_node, start_element};
+use super::{FilterAction, SerializeOptions};
+use crate::tree::{Node, NodeData, NodeRef};
+use html5ever::serialize::Serializer;
+use html5ever::serialize::TraversalScope::IncludeNode;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::io;
+use std::mem;
+use std::rc::Weak;
+
+/// A node identity that does not keep the node alive.
+///
+/// Holding the weak reference keeps the allocation, so the address of a
+/// dropped node cannot be reused by a new node while it is a key.
+#[derive(Debug, Clone)]
+struct NodeKey(Weak<Node>);
+
+/// Implements Eq for NodeKey using pointer equality.
+impl Eq for NodeKey {}
+
+/// Implements PartialEq for NodeKey using pointer equality.
+impl PartialEq for NodeKey {
+    #[inline]
+    fn eq(&self, other: &NodeKey) -> bool {
+        Weak::ptr_eq(&self.0, &other.0)
+    }
+}
+
+/// Implements Hash for NodeKey using pointer identity.
+impl Hash for NodeKey {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.as_ptr().hash(state);
+    }
+}
+
+/// Where a node was written in the previous output.
+#[derive(Debug, Clone)]
+struct Span {
+    /// The parent the node was written under.
+    parent: Option<Weak<Node>>,
+    /// The offset from the start of the parent's output.
+    offset: usize,
+    /// The length of the node's output.
+    len: usize,
+}
+
+/// Methods for Span.
+///
+/// Provides checking that a span still applies to a node.
+impl Span {
+    /// Returns whether `node` is still under the parent it was written
+    /// under, so the offset is relative to the right output.
+    fn has_parent(&self, node: &NodeRef) -> bool {
+        match (&self.parent, node.parent()) {
+            (Some(old), Some(new)) => Weak::ptr_eq(old, &new.downgrade()),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+}
+
+/// A serializer that reuses its previous output for unchanged subtrees.
+///
+/// Each call to [`SerializeCache::serialize()`] writes the tree again, but
+/// copies the bytes written last time for every element that is not
+/// [dirty](crate::Node::is_dirty), so changing one attribute in a large
+/// document only re-serializes the path from that element to the root.
+///
+/// Tree operations such as [`NodeRef::append()`] and
+/// [`NodeDataRef::attributes_mut()`](crate::NodeDataRef::attributes_mut)
+/// mark nodes dirty. Changes made in place through node data, such as
+/// `attributes.borrow_mut()` or `as_text().unwrap().borrow_mut()`, are not
+/// tracked, and the cache returns stale output for them unless they are
+/// followed by [`Node::mark_dirty()`](crate::Node::mark_dirty).
+///
+/// Serializing marks nodes clean, so a tree should be serialized
+/// incrementally by one cache at a time.
+///
+/// # Examples
+///
+/// ```
+/// use brik::parse_html;
+/// use brik::SerializeCache;
+/// use brik::traits::*;
+///
+/// let doc = parse_html().one("<p>One</p><p>Two</p>");
+/// let mut cache = SerializeCache::default();
+/// let first = cache.serialize(&doc).unwrap().to_vec();
+/// assert_eq!(first, doc.to_string().as_bytes());
+///
+/// let p = doc.select_first("p").unwrap();
+/// p.attributes_mut().insert("class", "lead");
+/// let second = cache.serialize(&doc).unwrap();
+/// assert_eq!(second, doc.to_string().as_bytes());
+/// ```
+#[derive(Debug, Default)]
+pub struct SerializeCache {
+    /// The serialization options.
+    options: SerializeOptions,
+    /// The root of the tree last serialized.
+    root: Option<NodeRef>,
+    /// The previous output.
+    output: Vec<u8>,
+    /// Where each node was written in the previous output.
+    spans: HashMap<NodeKey, Span>,
+}
+
+/// Methods for SerializeCache.
+///
+/// Provides construction, serialization and invalidation.
+impl SerializeCache {
+    /// Creates an empty cache serializing with the given options.
+    ///
+    /// Node filters must give the same result for unchanged nodes on every
+    /// call, or the reused output may be stale.
+    pub fn new(options: SerializeOptions) -> Self {
+        SerializeCache {
+            options,
+            ..SerializeCache::default()
+        }
+    }
+
+    /// Serializes `root` and its descendants in HTML syntax, reusing the
+    /// previous output for unchanged subtrees.
+    ///
+    /// Serializing a different root than last time starts from scratch.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `io::Error` if serialization fails, after which the cache
+    /// is cleared.
+    pub fn serialize(&mut self, root: &NodeRef) -> io::Result<&[u8]> {
+        if self.root.as_ref() != Some(root) {
+            self.clear();
+            self.root = Some(root.clone());
+        }
+        let previous = mem::take(&mut self.output);
+        let old_spans = mem::take(&mut self.spans);
+        let mut writer = CacheWriter {
+            previous: &previous,
+            old_spans: &old_spans,
+            spans: &mut self.spans,
+            options: &self.options,
+        };
+        let mut serializer =
+            HtmlSerializer::new(Vec::with_capacity(previous.len()), writer.options);
+        let parent_start = (!previous.is_empty()).then_some(0);
+        match writer.write_node(&mut serializer, root, parent_start, 0) {
+            Ok(()) => {
+                self.output = serializer.into_inner();
+                Ok(&self.output)
+            }
+            Err(error) => {
+                self.clear();
+                Err(error)
+            }
+        }
+    }
+
+    /// Discards the previous output, so the next call writes every node.
+    pub fn clear(&mut self) {
+        self.root = None;
+        self.output.clear();
+        self.spans.clear();
+    }
+}
+
+/// The state of one incremental serialization.
+struct CacheWriter<'a> {
+    /// The previous output.
+    previous: &'a [u8],
+    /// Where each node was written in the previous output.
+    old_spans: &'a HashMap<NodeKey, Span>,
+    /// Where each node was written in the new output.
+    spans: &'a mut HashMap<NodeKey, Span>,
+    /// The serialization options.
+    options: &'a SerializeOptions,
+}
+
+/// Methods for CacheWriter.
+///
+/// Provides writing nodes and reusing unchanged output.
+impl CacheWriter<'_> {
+    /// Writes `node`, given where its parent started in the previous and
+    /// the new output.
+    fn write_node(
+        &mut self,
+        serializer: &mut HtmlSerializer<'_, Vec<u8>>,
+        node: &NodeRef,
+        old_parent_start: Option<usize>,
+        new_parent_start: usize,
+    ) -> io::Result<()> {
+        let key = NodeKey(node.downgrade());
+        let start = serializer.writer_mut().len();
+        let span = self
+            .old_spans
+            .get(&key)
+            .filter(|span| span.has_parent(node))
+            .map(|span| (span.offset, span.len));
+        let old_start = old_parent_start
+            .zip(span)
+            .map(|(parent, (offset, _))| parent + offset);
+
+        if !node.is_dirty() {
+            let old = old_start
+                .zip(span)
+                .and_then(|(old_start, (_, len))| self.previous.get(old_start..old_start + len));
+            if let Some(old) = old {
+                serializer.writer_mut().extend_from_slice(old);
+                self.record(key, node, start, new_parent_start, serializer);
+                return Ok(());
+            }
+        }
+
+        let keep = matches!(self.options.node_filter.action(node), FilterAction::Keep);
+        let clean = match node.data() {
+            NodeData::Element(element) if keep => {
+                start_element(element, serializer, self.options)?;
+                let children = match element.template_contents.as_ref() {
+                    Some(contents) => {
+                        self.write_node(serializer, contents, old_start, start)?;
+                        false
+                    }
+                    None => self.write_children(serializer, node, old_start, start)?,
+                };
+                serializer.end_elem(element.name.clone())?;
+                children
+            }
+            NodeData::Document(_) | NodeData::DocumentFragment if keep => {
+                self.write_children(serializer, node, old_start, start)?
+            }
+            _ => {
+                serialize_node(node, serializer, IncludeNode, self.options)?;
+                keep
+            }
+        };
+        if clean {
+            node.mark_clean();
+        }
+        self.record(key, node, start, new_parent_start, serializer);
+        Ok(())
+    }
+
+    /// Writes the children of `node`, returning whether they are all clean.
+    fn write_children(
+        &mut self,
+        serializer: &mut HtmlSerializer<'_, Vec<u8>>,
+        node: &NodeRef,
+        old_start: Option<usize>,
+        new_start: usize,
+    ) -> io::Result<bool> {
+        let mut clean = true;
+        for child in node.children() {
+            self.write_node(serializer, &child, old_start, new_start)?;
+            clean &= !child.is_dirty();
+        }
+        Ok(clean)
+    }
+
+    /// Records where a node was written in the new output.
+    fn record(
+        &mut self,
+        key: NodeKey,
+        node: &NodeRef,
+        start: usize,
+        new_parent_start: usize,
+        serializer: &mut HtmlSerializer<'_, Vec<u8>>,
+    ) {
+        let span = Span {
+            parent: node.parent().map(|parent| parent.downgrade()),
+            offset: start - new_parent_start,
+            len: serializer.writer_mut().len() - start,
+        };
+        self.spans.insert(key, span);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::html5ever::tendril::TendrilSink;
+    use crate::parse_html;
+
+    /// Tests that incremental output matches a full serialization.
+    ///
+    /// Verifies that attribute changes, moves, removals, in-place text
+    /// changes and template contents are all reflected after the first
+    /// call.
+    #[test]
+    fn matches_full_serialization() {
+        let doc = parse_html().one(
+            "<ul><li>1</li><li>2</li><li>3</li></ul><div><b>x</b></div>\
+             <template><i>t</i></template>",
+        );
+        let mut cache = SerializeCache::default();
+        let check = |cache: &mut SerializeCache| {
+            let output = cache.serialize(&doc).unwrap().to_vec();
+            assert_eq!(String::from_utf8(output).unwrap(), doc.to_string());
+        };
+        check(&mut cache);
+        assert!(!doc.select_first("ul").unwrap().as_node().is_dirty());
+
+        let items: Vec<_> = doc.select("li").unwrap().collect();
+        items[1].attributes_mut().insert("class", "two");
+        check(&mut cache);
+
+        let b = doc.select_first("b").unwrap();
+        items[0].as_node().append(b.as_node().clone());
+        items[2].as_node().detach();
+        check(&mut cache);
+
+        let text = items[1].as_node().first_child().unwrap();
+        *text.as_text().unwrap().borrow_mut() = "two".into();
+        text.mark_dirty();
+        check(&mut cache);
+
+        let template = doc.select_first("template").unwrap();
+        let contents = template.template_contents.as_ref().unwrap();
+        contents.append(NodeRef::new_text("more"));
+        check(&mut cache);
+        assert!(template.as_node().is_dirty());
+    }
+
+    /// Tests moving a subtree whose descendants are clean.
+    ///
+    /// Verifies that only the moved root is marked dirty and that its
+    /// descendants are rewritten under the new parent rather than copied
+    /// from offsets relative to the old one.
+    #[test]
+    fn moved_subtree_with_clean_descendants() {
+        let doc = parse_html().one(
+            "<div id=a><p>one</p><section><em>deep <b>text</b></em></section></div>\
+             <div id=b><i>two</i></div>",
+        );
+        let mut cache = SerializeCache::default();
+        let check = |cache: &mut SerializeCache| {
+            let output = cache.serialize(&doc).unwrap().to_vec();
+            assert_eq!(String::from_utf8(output).unwrap(), doc.to_string());
+        };
+        check(&mut cache);
+
+        let section = doc.select_first("section").unwrap();
+        let b = doc.select_first("#b").unwrap();
+        b.as_node().prepend(section.as_node().clone());
+        assert!(section.as_node().is_dirty());
+        assert!(!doc.select_first("em").unwrap().as_node().is_dirty());
+        check(&mut cache);
+
+        let p = doc.select_first("p").unwrap();
+        section.as_node().append(p.as_node().clone());
+        check(&mut cache);
+    }
+
+    /// Serializes `doc` through `cache` once, applies `change`, and checks
+    /// that the second serialization matches a full one.
+    fn assert_fresh_after<F: FnOnce()>(doc: &NodeRef, change: F) {
+        let mut cache = SerializeCache::default();
+        cache.serialize(doc).unwrap();
+        let before = doc.to_string();
+        change();
+        let after = doc.to_string();
+        assert_ne!(before, after);
+        let output = cache.serialize(doc).unwrap().to_vec();
+        assert_eq!(String::from_utf8(output).unwrap(), after);
+    }
+
+    /// Tests form control helpers with a cache.
+    ///
+    /// Verifies that checking and unchecking inputs, including the radio
+    /// buttons unchecked in the same group, and selecting options and
+    /// setting values are not served stale.
+    #[test]
+    fn fresh_after_form_controls() {
+        let doc = parse_html().one(
+            "<input type=checkbox><input type=radio name=r checked><input type=radio name=r>\
+             <select><option selected>a</option><option>b</option></select><input name=q>",
+        );
+        let input = |selector: &str| doc.select_first(selector).unwrap().as_node().clone();
+        assert_fresh_after(&doc, || {
+            input("[type=checkbox]").set_checked(true);
+        });
+        assert_fresh_after(&doc, || {
+            input("[type=checkbox]").set_checked(false);
+        });
+        assert_fresh_after(&doc, || {
+            input("[type=radio]:not([checked])").set_checked(true);
+        });
+        assert_fresh_after(&doc, || {
+            input("select").select_option("b");
+        });
+        assert_fresh_after(&doc, || {
+            input("[name=q]").set_input_value("x");
+        });
+    }
+
+    /// Tests sanitizing helpers with a cache.
+    ///
+    /// Verifies that stripped handlers and added nonces are not served
+    /// stale.
+    #[test]
+    fn fresh_after_sanitizing() {
+        let doc = parse_html().one("<a href=/ onclick=go()>x</a><script>s()</script>");
+        assert_fresh_after(&doc, || {
+            doc.add_csp_nonce("n");
+        });
+        assert_fresh_after(&doc, || {
+            doc.strip_active_content();
+        });
+    }
+
+    /// Tests attribute accessors with a cache.
+    ///
+    /// Verifies that ARIA setters, `srcset` replacement and head metadata
+    /// updates are not served stale.
+    #[test]
+    fn fresh_after_attribute_accessors() {
+        let doc = parse_html().one(
+            "<head><meta name=description content=a><link rel=canonical href=/a></head>\
+             <div role=button></div><img src=a.png>",
+        );
+        let div = doc.select_first("div").unwrap();
+        assert_fresh_after(&doc, || div.aria().set_label(Some("Go")));
+        assert_fresh_after(&doc, || div.aria().set_label(None));
+
+        let image = crate::html::ImageRef::new(doc.select_first("img").unwrap()).unwrap();
+        assert_fresh_after(&doc, || {
+            image.set_srcset(&crate::html::Srcset::parse("b.png 2x"));
+        });
+        assert_fresh_after(&doc, || doc.set_meta("description", "b"));
+        assert_fresh_after(&doc, || doc.set_canonical("/b"));
+    }
+
+    /// Tests CSS inlining with a cache.
+    ///
+    /// Verifies that style attributes written in place are not served
+    /// stale.
+    #[test]
+    fn fresh_after_inline_css() {
+        let doc = parse_html().one("<style>p { color: red }</style><p style='margin: 0'>x</p>");
+        assert_fresh_after(&doc, || crate::css::inline_css(&doc));
+    }
+
+    /// Tests range edits with a cache.
+    ///
+    /// Verifies that text shortened by deleting range contents is not
+    /// served stale.
+    #[test]
+    fn fresh_after_range_edits() {
+        use crate::range::{BoundaryPoint, Range};
+
+        let doc = parse_html().one("<p>Hello world</p>");
+        let text = doc
+            .select_first("p")
+            .unwrap()
+            .as_node()
+            .first_child()
+            .unwrap();
+        assert_fresh_after(&doc, || {
+            let mut range = Range::new(
+                BoundaryPoint::new(text.clone(), 5),
+                BoundaryPoint::new(text.clone(), 11),
+            )
+            .unwrap();
+            range.delete_contents().unwrap();
+        });
+    }
+
+    /// Tests template rendering with a cache.
+    ///
+    /// Verifies that directives removed from kept elements are not served
+    /// stale.
+    #[cfg(feature = "namespaces")]
+    #[test]
+    fn fresh_after_template_render() {
+        use std::collections::HashMap;
+
+        let engine = crate::template::TemplateEngine::new("t", "urn:example:template");
+        let doc = engine.parse(r#"<p t:if="show">Shown</p>"#);
+        let mut data = HashMap::new();
+        data.insert("show".to_string(), "true".to_string());
+        assert_fresh_after(&doc, || engine.render(&doc, &data).unwrap());
+    }
+
+    /// Tests encoded serialization with a cache.
+    ///
+    /// Verifies that the charset declaration rewritten in place is not
+    /// served stale.
+    #[cfg(feature = "encoding")]
+    #[test]
+    fn fresh_after_serialize_encoded() {
+        let doc = parse_html().one("<meta charset=utf-8><p>x</p>");
+        assert_fresh_after(&doc, || {
+            doc.serialize_encoded("latin1").unwrap();
+        });
+    }
+}
@@ -0,0 +1,63 @@
+//! HTML serializer configuration options.
+
+use super::{AttributeOrder, AttributeQuotes, EntityStyle, NodeFilter};
+
+/// Options for the HTML serializer.
+///
+/// # Examples
+///
+/// ```
+/// use brik::parse_html;
+/// use brik::traits::*;
+/// use brik::{AttributeOrder, SerializeOptions};
+///
+/// let doc = parse_html().one("<p id='a' class='b'>Text</p>");
+/// let p = doc.select_first("p").unwrap();
+///
+/// let options = SerializeOptions {
+///     attribute_order: AttributeOrder::Name,
+///     ..Default::default()
+/// };
+/// let mut bytes = Vec::new();
+/// p.as_node().serialize_with_options(&mut bytes, &options).unwrap();
+/// assert_eq!(String::from_utf8(bytes).unwrap(), r#"<p class="b" id="a">Text</p>"#);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct SerializeOptions {
+    /// The order in which element attributes are written.
+    pub attribute_order: AttributeOrder,
+
+    /// How attribute values are quoted.
+    pub attribute_quotes: AttributeQuotes,
+
+    /// Write attributes with empty values by name alone, such as
+    /// `disabled` instead of `disabled=""`.
+    pub collapse_boolean_attributes: bool,
+
+    /// Whether `&`, `<`, `>`, `"` and non-breaking spaces are escaped with
+    /// named or numeric character references.
+    pub entity_style: EntityStyle,
+
+    /// Escape all non-ASCII characters in text and attribute values as
+    /// hexadecimal numeric character references, such as `&#xE9;`.
+    ///
+    /// Comments, doctypes, processing instructions and raw text elements such
+    /// as `<script>` cannot contain character references and are written as is.
+    pub escape_non_ascii: bool,
+
+    /// Escape `'` as `&#39;` in text and attribute values, so the output can be
+    /// embedded in single-quoted contexts.
+    pub escape_apostrophe: bool,
+
+    /// Write attribute names as they were spelled in the source, where
+    /// recorded in [`Attribute::source_name`](crate::Attribute::source_name),
+    /// instead of in lowercase.
+    pub source_attribute_names: bool,
+
+    /// Which nodes are written, left out or replaced.
+    ///
+    /// Filters apply to [`serialize_with_options()`](crate::NodeRef::serialize_with_options)
+    /// but not to the streaming [`Rewriter`](crate::stream::Rewriter), which
+    /// has no tree to filter.
+    pub node_filter: NodeFilter,
+}
@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+use super::NameSnapshot;
+
+/// A snapshot of an element attribute.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AttributeSnapshot {
+    /// The attribute name.
+    pub name: NameSnapshot,
+    /// The attribute value.
+    pub value: String,
+}
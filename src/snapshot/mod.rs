@@ -0,0 +1,25 @@
+//! Serde-compatible snapshots of node trees.
+//!
+//! A [`NodeSnapshot`] is an owned, plain-data copy of a subtree that implements
+//! `Serialize` and `Deserialize`, so trees can be cached, sent between processes,
+//! or stored as structured test fixtures and rebuilt exactly, without reparsing.
+//!
+//! With the `json` feature, `NodeRef::to_json()` and `NodeRef::from_json()`
+//! use this representation as a stable JSON format for exchanging trees with
+//! other tooling.
+//!
+//! **Note:** This module requires the `serde` feature to be enabled.
+
+/// Attribute snapshot.
+mod attribute_snapshot;
+/// Qualified name snapshot.
+mod name_snapshot;
+/// JSON methods for NodeRef.
+#[cfg(feature = "json")]
+mod node_ref_json;
+/// Node snapshot.
+mod node_snapshot;
+
+pub use attribute_snapshot::AttributeSnapshot;
+pub use name_snapshot::NameSnapshot;
+pub use node_snapshot::NodeSnapshot;
@@ -0,0 +1,41 @@
+use html5ever::{LocalName, Namespace, Prefix, QualName};
+use serde::{Deserialize, Serialize};
+
+/// A snapshot of a qualified element or attribute name.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NameSnapshot {
+    /// The namespace prefix, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prefix: Option<String>,
+    /// The namespace URL, empty for no namespace.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub ns: String,
+    /// The local name.
+    pub local: String,
+}
+
+/// Implements From<&QualName> for NameSnapshot.
+///
+/// Copies the name components into owned strings.
+impl From<&QualName> for NameSnapshot {
+    fn from(name: &QualName) -> NameSnapshot {
+        NameSnapshot {
+            prefix: name.prefix.as_ref().map(|prefix| prefix.to_string()),
+            ns: name.ns.to_string(),
+            local: name.local.to_string(),
+        }
+    }
+}
+
+/// Implements From<&NameSnapshot> for QualName.
+///
+/// Interns the name components.
+impl From<&NameSnapshot> for QualName {
+    fn from(name: &NameSnapshot) -> QualName {
+        QualName::new(
+            name.prefix.as_deref().map(Prefix::from),
+            Namespace::from(&*name.ns),
+            LocalName::from(&*name.local),
+        )
+    }
+}
@@ -0,0 +1,92 @@
+//! JSON import and export of node trees.
+
+use super::NodeSnapshot;
+use crate::tree::NodeRef;
+
+/// JSON methods for NodeRef.
+///
+/// Provides conversion of node trees to and from the snapshot JSON format.
+impl NodeRef {
+    /// Serializes this node and its descendants to JSON.
+    ///
+    /// The output uses the [`NodeSnapshot`] representation: each node is an
+    /// object with a `"type"` tag, elements carry a `"name"` of
+    /// `{"prefix", "ns", "local"}` along with `"attributes"` and `"children"`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::parse_html;
+    /// use brik::traits::*;
+    ///
+    /// let doc = parse_html().one("<p>Hi</p>");
+    /// let p = doc.select_first("p").unwrap();
+    /// assert_eq!(
+    ///     p.as_node().to_json(),
+    ///     r#"{"type":"element","name":{"ns":"http://www.w3.org/1999/xhtml","local":"p"},"children":[{"type":"text","text":"Hi"}]}"#
+    /// );
+    /// ```
+    pub fn to_json(&self) -> String {
+        // Snapshots contain only strings, vectors and maps with string keys,
+        // which always serialize successfully.
+        serde_json::to_string(&NodeSnapshot::from(self)).unwrap_or_default()
+    }
+
+    /// Serializes this node and its descendants to indented JSON.
+    ///
+    /// This produces the same representation as [`NodeRef::to_json()`].
+    pub fn to_json_pretty(&self) -> String {
+        serde_json::to_string_pretty(&NodeSnapshot::from(self)).unwrap_or_default()
+    }
+
+    /// Builds a new node tree from JSON produced by [`NodeRef::to_json()`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input is not valid JSON or does not match the
+    /// [`NodeSnapshot`] representation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::NodeRef;
+    ///
+    /// let json = r#"{"type":"element","name":{"ns":"http://www.w3.org/1999/xhtml","local":"br"}}"#;
+    /// let node = NodeRef::from_json(json).unwrap();
+    /// assert_eq!(node.to_string(), "<br>");
+    /// ```
+    pub fn from_json(json: &str) -> Result<NodeRef, serde_json::Error> {
+        serde_json::from_str::<NodeSnapshot>(json).map(|snapshot| snapshot.to_node())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::html5ever::tendril::TendrilSink;
+    use crate::parse_html;
+    use crate::tree::NodeRef;
+
+    /// Tests a to_json()/from_json() round trip.
+    ///
+    /// Verifies that the rebuilt tree serializes to the same HTML and that
+    /// pretty and compact output describe the same tree.
+    #[test]
+    fn round_trip() {
+        let doc = parse_html().one("<!DOCTYPE html><div class=a>x<!--y--></div>");
+
+        let rebuilt = NodeRef::from_json(&doc.to_json()).unwrap();
+        assert_eq!(rebuilt.to_string(), doc.to_string());
+
+        let pretty = NodeRef::from_json(&doc.to_json_pretty()).unwrap();
+        assert_eq!(pretty.to_json(), doc.to_json());
+    }
+
+    /// Tests from_json() with malformed input.
+    ///
+    /// Edge case: invalid JSON and unknown node types are reported as errors.
+    #[test]
+    fn invalid_input() {
+        assert!(NodeRef::from_json("{").is_err());
+        assert!(NodeRef::from_json(r#"{"type":"widget"}"#).is_err());
+    }
+}
@@ -0,0 +1,268 @@
+use html5ever::tree_builder::QuirksMode;
+use html5ever::QualName;
+use serde::{Deserialize, Serialize};
+
+use super::{AttributeSnapshot, NameSnapshot};
+use crate::attributes::{Attribute, ExpandedName};
+use crate::tree::{NodeData, NodeRef};
+
+/// A snapshot of a node and its descendants.
+///
+/// # Examples
+///
+/// ```
+/// use brik::parse_html;
+/// use brik::snapshot::NodeSnapshot;
+/// use brik::traits::*;
+///
+/// let doc = parse_html().one("<p class='a'>Hello</p>");
+/// let snapshot = NodeSnapshot::from(&doc);
+///
+/// // Round-trip through any serde format, then rebuild the tree.
+/// let rebuilt = snapshot.clone().to_node();
+/// assert_eq!(rebuilt.to_string(), doc.to_string());
+/// assert_eq!(NodeSnapshot::from(&rebuilt), snapshot);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum NodeSnapshot {
+    /// A document node.
+    Document {
+        /// The quirks mode: `"no-quirks"`, `"limited-quirks"` or `"quirks"`.
+        quirks_mode: String,
+        /// The child nodes.
+        children: Vec<NodeSnapshot>,
+    },
+    /// A document fragment node.
+    DocumentFragment {
+        /// The child nodes.
+        children: Vec<NodeSnapshot>,
+    },
+    /// A doctype node.
+    Doctype {
+        /// The doctype name.
+        name: String,
+        /// The public identifier.
+        public_id: String,
+        /// The system identifier.
+        system_id: String,
+    },
+    /// An element node.
+    Element {
+        /// The element name.
+        name: NameSnapshot,
+        /// The attributes, in order.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        attributes: Vec<AttributeSnapshot>,
+        /// The child nodes.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        children: Vec<NodeSnapshot>,
+        /// The contents of a `<template>` element.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        template_contents: Option<Vec<NodeSnapshot>>,
+    },
+    /// A text node.
+    Text {
+        /// The text.
+        text: String,
+    },
+    /// A comment node.
+    Comment {
+        /// The comment text.
+        text: String,
+    },
+    /// A processing instruction node.
+    ProcessingInstruction {
+        /// The target.
+        target: String,
+        /// The data.
+        data: String,
+    },
+}
+
+/// Methods for NodeSnapshot.
+///
+/// Provides rebuilding node trees from snapshots.
+impl NodeSnapshot {
+    /// Rebuilds a new node tree from this snapshot.
+    ///
+    /// An unknown document quirks mode is treated as `"no-quirks"`.
+    pub fn to_node(&self) -> NodeRef {
+        let append_all = |parent: &NodeRef, children: &[NodeSnapshot]| {
+            for child in children {
+                parent.append(child.to_node());
+            }
+        };
+        match self {
+            NodeSnapshot::Document {
+                quirks_mode,
+                children,
+            } => {
+                let node = NodeRef::new_document();
+                if let Some(document) = node.as_document() {
+                    document._quirks_mode.set(match &**quirks_mode {
+                        "quirks" => QuirksMode::Quirks,
+                        "limited-quirks" => QuirksMode::LimitedQuirks,
+                        _ => QuirksMode::NoQuirks,
+                    });
+                }
+                append_all(&node, children);
+                node
+            }
+            NodeSnapshot::DocumentFragment { children } => {
+                let node = NodeRef::new(NodeData::DocumentFragment);
+                append_all(&node, children);
+                node
+            }
+            NodeSnapshot::Doctype {
+                name,
+                public_id,
+                system_id,
+            } => NodeRef::new_doctype(name, public_id, system_id),
+            NodeSnapshot::Element {
+                name,
+                attributes,
+                children,
+                template_contents,
+            } => {
+                let attributes = attributes.iter().map(|attribute| {
+                    let name = QualName::from(&attribute.name);
+                    (
+                        ExpandedName::new(name.ns, name.local),
+                        Attribute {
+                            prefix: name.prefix,
+                            value: attribute.value.as_str().into(),
+                            source_name: None,
+                        },
+                    )
+                });
+                let node = NodeRef::new_element(QualName::from(name), attributes);
+                append_all(&node, children);
+                if let (Some(contents), Some(snapshots)) = (
+                    node.as_element()
+                        .and_then(|element| element.template_contents.as_ref()),
+                    template_contents,
+                ) {
+                    append_all(contents, snapshots);
+                }
+                node
+            }
+            NodeSnapshot::Text { text } => NodeRef::new_text(text.as_str()),
+            NodeSnapshot::Comment { text } => NodeRef::new_comment(text.as_str()),
+            NodeSnapshot::ProcessingInstruction { target, data } => {
+                NodeRef::new_processing_instruction(target, data)
+            }
+        }
+    }
+}
+
+/// Implements From<&NodeRef> for NodeSnapshot.
+///
+/// Copies the node and its descendants, including template contents.
+impl From<&NodeRef> for NodeSnapshot {
+    fn from(node: &NodeRef) -> NodeSnapshot {
+        let children = |node: &NodeRef| node.children().map(|child| (&child).into()).collect();
+        match node.data() {
+            NodeData::Document(document) => NodeSnapshot::Document {
+                quirks_mode: match document.quirks_mode() {
+                    QuirksMode::Quirks => "quirks",
+                    QuirksMode::LimitedQuirks => "limited-quirks",
+                    QuirksMode::NoQuirks => "no-quirks",
+                }
+                .to_string(),
+                children: children(node),
+            },
+            NodeData::DocumentFragment => NodeSnapshot::DocumentFragment {
+                children: children(node),
+            },
+            NodeData::Doctype(doctype) => NodeSnapshot::Doctype {
+                name: doctype.name.clone(),
+                public_id: doctype.public_id.clone(),
+                system_id: doctype.system_id.clone(),
+            },
+            NodeData::Element(element) => NodeSnapshot::Element {
+                name: NameSnapshot::from(&element.name),
+                attributes: element
+                    .attributes
+                    .borrow()
+                    .map
+                    .iter()
+                    .map(|(name, attribute)| AttributeSnapshot {
+                        name: NameSnapshot {
+                            prefix: attribute.prefix.as_ref().map(|prefix| prefix.to_string()),
+                            ns: name.ns.to_string(),
+                            local: name.local.to_string(),
+                        },
+                        value: attribute.value.to_string(),
+                    })
+                    .collect(),
+                children: children(node),
+                template_contents: element.template_contents.as_ref().map(children),
+            },
+            NodeData::Text(text) => NodeSnapshot::Text {
+                text: text.borrow().to_string(),
+            },
+            NodeData::Comment(text) => NodeSnapshot::Comment {
+                text: text.borrow().to_string(),
+            },
+            NodeData::ProcessingInstruction(contents) => {
+                let contents = contents.borrow();
+                NodeSnapshot::ProcessingInstruction {
+                    target: contents.0.clone(),
+                    data: contents.1.clone(),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::html5ever::tendril::TendrilSink;
+    use crate::parse_html;
+
+    /// Tests a JSON round trip of a full document.
+    ///
+    /// Verifies that the rebuilt tree serializes to the same HTML, keeps the
+    /// quirks mode, and restores template contents and foreign attributes.
+    #[test]
+    fn json_round_trip() {
+        let html = "<html><p id=a>x<!--c--></p><template><b>t</b></template>\
+                    <svg><use xlink:href='#i'/></svg></html>";
+        let doc = parse_html().one(html);
+
+        let json = serde_json::to_string(&NodeSnapshot::from(&doc)).unwrap();
+        let snapshot: NodeSnapshot = serde_json::from_str(&json).unwrap();
+        let rebuilt = snapshot.to_node();
+
+        assert_eq!(rebuilt.to_string(), doc.to_string());
+        assert_eq!(
+            rebuilt.as_document().unwrap().quirks_mode(),
+            QuirksMode::Quirks
+        );
+        let template = rebuilt.select_first("template").unwrap();
+        let contents = template.template_contents.as_ref().unwrap();
+        assert_eq!(contents.children().count(), 1);
+        let use_element = rebuilt.select_first("use").unwrap();
+        let attributes = use_element.attributes.borrow();
+        let (name, attribute) = attributes.map.get_index(0).unwrap();
+        assert_eq!(name.ns, ns!(xlink));
+        assert_eq!(attribute.prefix.as_deref(), Some("xlink"));
+    }
+
+    /// Tests the JSON shape of a snapshot.
+    ///
+    /// Verifies the tagged representation and that empty optional fields
+    /// are omitted.
+    #[test]
+    fn json_shape() {
+        let doc = parse_html().one("<br>");
+        let br = doc.select_first("br").unwrap();
+        let json = serde_json::to_string(&NodeSnapshot::from(br.as_node())).unwrap();
+        assert_eq!(
+            json,
+            r#"{"type":"element","name":{"ns":"http://www.w3.org/1999/xhtml","local":"br"}}"#
+        );
+    }
+}
@@ -0,0 +1,205 @@
+//! Incremental tokenization of HTML into events.
+
+use super::HtmlEvent;
+use html5ever::tendril::StrTendril;
+use html5ever::tokenizer::states::RawKind;
+use html5ever::tokenizer::{
+    BufferQueue, TagKind, Token, TokenSink, TokenSinkResult, Tokenizer, TokenizerOpts,
+};
+use std::cell::{Cell, RefCell};
+
+/// Streams HTML as [`HtmlEvent`]s without building a tree.
+///
+/// Input may be fed in chunks of any size; events are passed to the handler
+/// as soon as they are complete, so memory use does not grow with the input.
+/// The contents of elements like `<script>`, `<style>` and `<textarea>` are
+/// reported as text, as the tree builder would treat them.
+///
+/// # Examples
+///
+/// ```
+/// use brik::stream::{EventStream, HtmlEvent};
+///
+/// let mut links = Vec::new();
+/// let mut stream = EventStream::new(|event| {
+///     if let HtmlEvent::StartTag { name, attributes, .. } = event {
+///         for (attribute, value) in attributes {
+///             if &*name == "a" && &*attribute == "href" {
+///                 links.push(value.to_string());
+///             }
+///         }
+///     }
+/// });
+/// stream.feed("<p><a href=/one>One</a> <a hr");
+/// stream.feed("ef=/two>Two</a>");
+/// stream.finish();
+/// assert_eq!(links, ["/one", "/two"]);
+/// ```
+pub struct EventStream<F: FnMut(HtmlEvent)> {
+    /// The tokenizer, which owns the event sink.
+    tokenizer: Tokenizer<EventSink<F>>,
+    /// Input that has been fed but not yet tokenized.
+    input: BufferQueue,
+}
+
+/// Streaming methods for EventStream.
+///
+/// Provides feeding of input chunks and completion.
+impl<F: FnMut(HtmlEvent)> EventStream<F> {
+    /// Creates a stream that passes each event to `handler`.
+    pub fn new(handler: F) -> EventStream<F> {
+        EventStream {
+            tokenizer: Tokenizer::new(
+                EventSink {
+                    handler: RefCell::new(handler),
+                    foreign_depth: Cell::new(0),
+                },
+                TokenizerOpts::default(),
+            ),
+            input: BufferQueue::default(),
+        }
+    }
+
+    /// Tokenizes a chunk of input.
+    ///
+    /// Markup split across chunks is reported once it is complete.
+    pub fn feed(&mut self, chunk: &str) {
+        self.input.push_back(StrTendril::from_slice(chunk));
+        let _ = self.tokenizer.feed(&self.input);
+    }
+
+    /// Ends the input, reporting any markup still pending.
+    pub fn finish(self) {
+        let _ = self.tokenizer.feed(&self.input);
+        self.tokenizer.end();
+    }
+}
+
+/// Receives tokens from the tokenizer and passes them on as events.
+struct EventSink<F> {
+    /// The caller's event handler.
+    handler: RefCell<F>,
+    /// Number of open `svg` and `math` elements, whose descendants do not
+    /// switch the tokenizer into raw text modes.
+    foreign_depth: Cell<usize>,
+}
+
+/// Implements TokenSink for EventSink.
+///
+/// Converts tokens into events and switches the tokenizer into raw text
+/// modes after the start tags that require them.
+impl<F: FnMut(HtmlEvent)> TokenSink for EventSink<F> {
+    type Handle = ();
+
+    fn process_token(&self, token: Token, _line_number: u64) -> TokenSinkResult<()> {
+        let mut result = TokenSinkResult::Continue;
+        let event = match token {
+            Token::DoctypeToken(doctype) => HtmlEvent::Doctype {
+                name: doctype.name,
+                public_id: doctype.public_id,
+                system_id: doctype.system_id,
+            },
+            Token::TagToken(tag) => {
+                let foreign = matches!(&*tag.name, "svg" | "math");
+                match tag.kind {
+                    TagKind::StartTag => {
+                        if foreign && !tag.self_closing {
+                            self.foreign_depth.set(self.foreign_depth.get() + 1);
+                        } else if self.foreign_depth.get() == 0 {
+                            result = raw_text_result(&tag.name);
+                        }
+                        HtmlEvent::StartTag {
+                            name: tag.name,
+                            attributes: tag
+                                .attrs
+                                .into_iter()
+                                .map(|attribute| (attribute.name.local, attribute.value))
+                                .collect(),
+                            self_closing: tag.self_closing,
+                        }
+                    }
+                    TagKind::EndTag => {
+                        if foreign {
+                            self.foreign_depth
+                                .set(self.foreign_depth.get().saturating_sub(1));
+                        }
+                        HtmlEvent::EndTag { name: tag.name }
+                    }
+                }
+            }
+            Token::CommentToken(text) => HtmlEvent::Comment(text),
+            Token::CharacterTokens(text) => HtmlEvent::Text(text),
+            Token::NullCharacterToken => HtmlEvent::Text(StrTendril::from_char('\0')),
+            Token::EOFToken | Token::ParseError(_) => return result,
+        };
+        (self.handler.borrow_mut())(event);
+        result
+    }
+
+    fn adjusted_current_node_present_but_not_in_html_namespace(&self) -> bool {
+        self.foreign_depth.get() > 0
+    }
+}
+
+/// Returns the tokenizer state that follows a start tag in HTML content.
+fn raw_text_result(name: &str) -> TokenSinkResult<()> {
+    match name {
+        "title" | "textarea" => TokenSinkResult::RawData(RawKind::Rcdata),
+        "style" | "xmp" | "iframe" | "noembed" | "noframes" | "noscript" => {
+            TokenSinkResult::RawData(RawKind::Rawtext)
+        }
+        "script" => TokenSinkResult::RawData(RawKind::ScriptData),
+        "plaintext" => TokenSinkResult::Plaintext,
+        _ => TokenSinkResult::Continue,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EventStream;
+    use crate::stream::HtmlEvent;
+
+    /// Tests the events for markup with raw text and foreign content.
+    ///
+    /// Verifies that script contents are reported as text rather than tags,
+    /// that CDATA inside SVG becomes text, and that chunk boundaries inside
+    /// a tag do not change the events.
+    #[test]
+    fn events_follow_markup() {
+        let html = "<!DOCTYPE html><p class=a>x&amp;y<!--c--></p><script>a<b</script>\
+                    <svg><title><![CDATA[t]]></title></svg><br/>";
+        let mut events = Vec::new();
+        let mut stream = EventStream::new(|event| events.push(event));
+        for chunk in html.as_bytes().chunks(5) {
+            stream.feed(std::str::from_utf8(chunk).unwrap());
+        }
+        stream.finish();
+
+        let summary: Vec<String> = events
+            .iter()
+            .map(|event| match event {
+                HtmlEvent::Doctype { name, .. } => format!("!{}", name.as_deref().unwrap_or("")),
+                HtmlEvent::StartTag {
+                    name,
+                    attributes,
+                    self_closing,
+                } => format!(
+                    "<{}{}{}>",
+                    name,
+                    attributes
+                        .iter()
+                        .map(|(n, v)| format!(" {}={}", n, v))
+                        .collect::<String>(),
+                    if *self_closing { "/" } else { "" }
+                ),
+                HtmlEvent::EndTag { name } => format!("</{}>", name),
+                HtmlEvent::Text(text) => text.to_string(),
+                HtmlEvent::Comment(text) => format!("#{}", text),
+            })
+            .collect();
+        assert_eq!(
+            summary.concat(),
+            "!html<p class=a>x&y#c</p><script>a<b</script><svg><title>t</title></svg><br/>"
+        );
+    }
+}
@@ -0,0 +1,41 @@
+//! Events produced while streaming HTML.
+
+use html5ever::tendril::StrTendril;
+use html5ever::LocalName;
+
+/// A piece of markup reported by an [`EventStream`](super::EventStream).
+///
+/// Events follow the markup as written: tags the tree builder would imply,
+/// close or move are not synthesized, and end tags may be unmatched.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HtmlEvent {
+    /// A doctype declaration.
+    Doctype {
+        /// The doctype name, if any.
+        name: Option<StrTendril>,
+        /// The public identifier, if any.
+        public_id: Option<StrTendril>,
+        /// The system identifier, if any.
+        system_id: Option<StrTendril>,
+    },
+    /// A start tag.
+    StartTag {
+        /// The lowercased tag name.
+        name: LocalName,
+        /// The attributes in source order, with duplicates removed.
+        attributes: Vec<(LocalName, StrTendril)>,
+        /// Whether the tag was written as self-closing, like `<br/>`.
+        self_closing: bool,
+    },
+    /// An end tag.
+    EndTag {
+        /// The lowercased tag name.
+        name: LocalName,
+    },
+    /// A run of text, with character references decoded.
+    ///
+    /// Adjacent text may be split across several events.
+    Text(StrTendril),
+    /// A comment.
+    Comment(StrTendril),
+}
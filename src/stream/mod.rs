@@ -0,0 +1,19 @@
+/// Incremental tokenization into events.
+pub mod event_stream;
+/// Events produced while streaming.
+pub mod html_event;
+/// Elements handed to rewriter handlers.
+pub mod rewrite_element;
+/// Selector-driven rewriting of streamed HTML.
+pub mod rewriter;
+/// Open elements tracked while rewriting.
+mod stream_element;
+/// One-shot streaming of a complete string.
+pub mod stream_events;
+
+pub use event_stream::EventStream;
+pub use html_event::HtmlEvent;
+pub use rewrite_element::RewriteElement;
+pub use rewriter::Rewriter;
+pub(crate) use stream_element::{OpenElement, StreamElement};
+pub use stream_events::stream_events;
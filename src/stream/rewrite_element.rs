@@ -0,0 +1,82 @@
+//! Elements handed to rewriter handlers.
+
+use html5ever::tendril::StrTendril;
+use html5ever::LocalName;
+
+/// An element matched by a [`Rewriter`](super::Rewriter) handler.
+///
+/// Changes made by the handler apply to the element's start tag and, for
+/// [`set_inner_content()`](RewriteElement::set_inner_content) and
+/// [`remove()`](RewriteElement::remove), to everything up to its end.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RewriteElement {
+    /// The lowercased tag name.
+    pub(super) name: LocalName,
+    /// The attributes in source order.
+    pub(super) attributes: Vec<(LocalName, StrTendril)>,
+    /// Markup replacing the element's contents, if set.
+    pub(super) inner_content: Option<String>,
+    /// Whether the element is left out of the output.
+    pub(super) removed: bool,
+}
+
+/// Methods for RewriteElement.
+///
+/// Provides inspection and modification of a streamed element.
+impl RewriteElement {
+    /// Returns the lowercased tag name.
+    #[inline]
+    pub fn name(&self) -> &LocalName {
+        &self.name
+    }
+
+    /// Returns the value of an attribute, if present.
+    pub fn attr(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(attribute, _)| &**attribute == name)
+            .map(|(_, value)| &**value)
+    }
+
+    /// Sets an attribute, replacing its value if it is already present.
+    pub fn set_attr(&mut self, name: &str, value: &str) {
+        match self
+            .attributes
+            .iter_mut()
+            .find(|(attribute, _)| &**attribute == name)
+        {
+            Some((_, existing)) => *existing = StrTendril::from_slice(value),
+            None => self
+                .attributes
+                .push((LocalName::from(name), StrTendril::from_slice(value))),
+        }
+    }
+
+    /// Removes an attribute, returning its value if it was present.
+    pub fn remove_attr(&mut self, name: &str) -> Option<StrTendril> {
+        let index = self
+            .attributes
+            .iter()
+            .position(|(attribute, _)| &**attribute == name)?;
+        Some(self.attributes.remove(index).1)
+    }
+
+    /// Replaces the element's contents with `html`, which is written as is.
+    ///
+    /// Has no effect on void elements such as `<img>`.
+    pub fn set_inner_content(&mut self, html: &str) {
+        self.inner_content = Some(html.to_string());
+    }
+
+    /// Leaves the element and its contents out of the output.
+    #[inline]
+    pub fn remove(&mut self) {
+        self.removed = true;
+    }
+
+    /// Returns whether the element has been removed.
+    #[inline]
+    pub fn is_removed(&self) -> bool {
+        self.removed
+    }
+}
@@ -0,0 +1,566 @@
+//! Selector-driven rewriting of streamed HTML.
+
+use super::{EventStream, HtmlEvent, OpenElement, RewriteElement, StreamElement};
+use crate::select::{SelectorParseError, Selectors};
+use crate::serializer::html_serializer::HtmlSerializer;
+use crate::serializer::SerializeOptions;
+use html5ever::serialize::Serializer;
+use html5ever::tendril::StrTendril;
+use html5ever::{local_name, ns, LocalName, QualName};
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::io::{self, Write};
+use std::rc::Rc;
+
+/// A handler registered for the elements matching a selector.
+type ElementHandler<'h> = (Selectors, Box<dyn FnMut(&mut RewriteElement) + 'h>);
+
+/// Rewrites HTML as it streams, without building a tree.
+///
+/// Handlers registered with [`on()`](Rewriter::on) are called for each
+/// element matching their selector, as soon as its start tag is read, and
+/// may change its attributes, replace its contents or remove it. Everything
+/// else is written through unchanged, so memory use does not grow with the
+/// input.
+///
+/// Only an element's ancestors are known when its start tag is read, so
+/// selectors are matched against them alone: sibling combinators,
+/// positional pseudo-classes and `:empty` never match. Markup is
+/// re-serialized from its tokens, so quoting and character references may
+/// differ from the input.
+///
+/// # Examples
+///
+/// ```
+/// use brik::stream::Rewriter;
+///
+/// let mut rewriter = Rewriter::new(Vec::new())
+///     .on("a[href^='http:']", |element| {
+///         let href = element.attr("href").unwrap().replacen("http:", "https:", 1);
+///         element.set_attr("href", &href);
+///     })
+///     .unwrap()
+///     .on("script", |element| element.remove())
+///     .unwrap();
+/// rewriter.feed("<p><a href='http://example.com'>Hi</a><scr").unwrap();
+/// rewriter.feed("ipt>track()</script></p>").unwrap();
+/// let output = rewriter.finish().unwrap();
+/// assert_eq!(
+///     String::from_utf8(output).unwrap(),
+///     r#"<p><a href="https://example.com">Hi</a></p>"#
+/// );
+/// ```
+pub struct Rewriter<'h, W: Write + 'h> {
+    /// The tokenizer, whose handler updates the shared state.
+    stream: EventStream<Box<dyn FnMut(HtmlEvent) + 'h>>,
+    /// State shared with the tokenizer's handler.
+    state: Rc<RefCell<RewriterState<'h, W>>>,
+}
+
+/// Methods for Rewriter.
+///
+/// Provides construction, handler registration, feeding and completion.
+impl<'h, W: Write + 'h> Rewriter<'h, W> {
+    /// Creates a rewriter writing to `writer` with default escaping.
+    pub fn new(writer: W) -> Rewriter<'h, W> {
+        Rewriter::with_options(writer, SerializeOptions::default())
+    }
+
+    /// Creates a rewriter writing to `writer`, escaping text and attribute
+    /// values as `options` specify.
+    pub fn with_options(writer: W, options: SerializeOptions) -> Rewriter<'h, W> {
+        let state = Rc::new(RefCell::new(RewriterState {
+            serializer: HtmlSerializer::with_options(writer, Cow::Owned(options)),
+            handlers: Vec::new(),
+            stack: Vec::new(),
+            suppressing: 0,
+            error: None,
+        }));
+        let shared = Rc::clone(&state);
+        let handler: Box<dyn FnMut(HtmlEvent) + 'h> =
+            Box::new(move |event| shared.borrow_mut().process(event));
+        Rewriter {
+            stream: EventStream::new(handler),
+            state,
+        }
+    }
+
+    /// Registers `handler` for the elements matching `selector`.
+    ///
+    /// Handlers are called in registration order. Elements inside removed
+    /// elements or replaced contents are not matched.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`SelectorParseError`] if `selector` is invalid.
+    pub fn on<F>(self, selector: &str, handler: F) -> Result<Self, SelectorParseError>
+    where
+        F: FnMut(&mut RewriteElement) + 'h,
+    {
+        let selectors = Selectors::parse(selector)?;
+        self.state
+            .borrow_mut()
+            .handlers
+            .push((selectors, Box::new(handler)));
+        Ok(self)
+    }
+
+    /// Rewrites a chunk of input.
+    ///
+    /// Markup split across chunks is written once it is complete.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error from the writer.
+    pub fn feed(&mut self, chunk: &str) -> io::Result<()> {
+        self.stream.feed(chunk);
+        self.state.borrow_mut().take_error()
+    }
+
+    /// Ends the input, writing any markup still pending, and returns the
+    /// writer.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error from the writer.
+    pub fn finish(self) -> io::Result<W> {
+        self.stream.finish();
+        let state = match Rc::try_unwrap(self.state) {
+            Ok(state) => state.into_inner(),
+            Err(_) => unreachable!("the tokenizer's handler has been dropped"),
+        };
+        match state.error {
+            Some(error) => Err(error),
+            None => Ok(state.serializer.into_inner()),
+        }
+    }
+}
+
+/// The state of a rewriter, updated for each event.
+struct RewriterState<'h, W: Write> {
+    /// Writes the output.
+    serializer: HtmlSerializer<'static, W>,
+    /// The registered handlers, in registration order.
+    handlers: Vec<ElementHandler<'h>>,
+    /// The open elements, outermost first.
+    stack: Vec<OpenElement>,
+    /// How many open elements are removed or have replaced contents.
+    suppressing: usize,
+    /// The first error from the writer, not yet reported.
+    error: Option<io::Error>,
+}
+
+/// Event processing methods for RewriterState.
+///
+/// Provides element tracking, handler dispatch and output.
+impl<W: Write> RewriterState<'_, W> {
+    /// Processes an event, recording the first write error.
+    fn process(&mut self, event: HtmlEvent) {
+        if self.error.is_some() {
+            return;
+        }
+        let result = match event {
+            HtmlEvent::Doctype {
+                name,
+                public_id,
+                system_id,
+            } => {
+                if self.skipping() {
+                    Ok(())
+                } else {
+                    write_doctype(
+                        self.serializer.writer_mut(),
+                        name.as_deref(),
+                        public_id.as_deref(),
+                        system_id.as_deref(),
+                    )
+                }
+            }
+            HtmlEvent::StartTag {
+                name,
+                attributes,
+                self_closing,
+            } => self.start_tag(name, attributes, self_closing),
+            HtmlEvent::EndTag { name } => self.end_tag(name),
+            HtmlEvent::Text(text) => self.text(&text),
+            HtmlEvent::Comment(text) => {
+                if self.skipping() {
+                    Ok(())
+                } else {
+                    self.serializer.write_comment(&text)
+                }
+            }
+        };
+        if let Err(error) = result {
+            self.error = Some(error);
+        }
+    }
+
+    /// Returns and clears the pending write error, if any.
+    fn take_error(&mut self) -> io::Result<()> {
+        match self.error.take() {
+            Some(error) => Err(error),
+            None => Ok(()),
+        }
+    }
+
+    /// Returns whether output is suppressed by a removed element or
+    /// replaced contents.
+    fn skipping(&self) -> bool {
+        self.suppressing > 0
+    }
+
+    /// Closes the innermost open element, returning it.
+    fn pop(&mut self) -> Option<OpenElement> {
+        let open = self.stack.pop()?;
+        if open.removed || open.skip_content {
+            self.suppressing -= 1;
+        }
+        Some(open)
+    }
+
+    /// Opens an element, runs the matching handlers and writes its start tag.
+    fn start_tag(
+        &mut self,
+        local: LocalName,
+        attributes: Vec<(LocalName, StrTendril)>,
+        self_closing: bool,
+    ) -> io::Result<()> {
+        while self
+            .stack
+            .last()
+            .is_some_and(|open| closes_implicitly(&open.name, &local))
+        {
+            self.pop();
+        }
+        let name = QualName::new(None, self.child_namespace(&local), local);
+        let void = if name.ns == ns!(html) {
+            is_void(&name.local)
+        } else {
+            self_closing
+        };
+        let skipping = self.skipping();
+        self.stack.push(OpenElement {
+            name,
+            attributes,
+            removed: false,
+            skip_content: false,
+        });
+        if skipping {
+            if void {
+                self.pop();
+            }
+            return Ok(());
+        }
+
+        let mut inner_content = None;
+        let matched: Vec<usize> = {
+            let element = StreamElement {
+                stack: &self.stack,
+                index: self.stack.len() - 1,
+            };
+            (0..self.handlers.len())
+                .filter(|&index| {
+                    self.handlers[index]
+                        .0
+                         .0
+                        .iter()
+                        .any(|selector| selector.matches_in_scope(&element, None))
+                })
+                .collect()
+        };
+        if !matched.is_empty() {
+            let open = self.stack.last_mut().expect("element was pushed");
+            let mut element = RewriteElement {
+                name: open.name.local.clone(),
+                attributes: std::mem::take(&mut open.attributes),
+                inner_content: None,
+                removed: false,
+            };
+            for index in matched {
+                (self.handlers[index].1)(&mut element);
+            }
+            open.attributes = element.attributes;
+            open.removed = element.removed;
+            if !void {
+                open.skip_content = element.inner_content.is_some();
+                inner_content = element.inner_content;
+            }
+            if open.removed || open.skip_content {
+                self.suppressing += 1;
+            }
+        }
+
+        let open = self.stack.last().expect("element was pushed");
+        if !open.removed {
+            let writer = self.serializer.writer_mut();
+            writer.write_all(b"<")?;
+            writer.write_all(open.name.local.as_bytes())?;
+            for (attribute, value) in &open.attributes {
+                let writer = self.serializer.writer_mut();
+                writer.write_all(b" ")?;
+                writer.write_all(attribute.as_bytes())?;
+                self.serializer.write_attribute_value(value)?;
+            }
+            let end: &[u8] = if void && open.name.ns != ns!(html) {
+                b"/>"
+            } else {
+                b">"
+            };
+            self.serializer.writer_mut().write_all(end)?;
+            if let Some(html) = inner_content {
+                self.serializer.writer_mut().write_all(html.as_bytes())?;
+            }
+        }
+        if void {
+            self.pop();
+        }
+        Ok(())
+    }
+
+    /// Closes the innermost open element with the given name, along with
+    /// the elements it contains, and writes the end tag.
+    ///
+    /// End tags without a matching open element are written as they are.
+    fn end_tag(&mut self, local: LocalName) -> io::Result<()> {
+        let mut removed = false;
+        if let Some(index) = self.stack.iter().rposition(|open| open.name.local == local) {
+            while self.stack.len() > index {
+                removed = self.pop().is_some_and(|open| open.removed);
+            }
+        }
+        if removed || self.skipping() {
+            return Ok(());
+        }
+        let writer = self.serializer.writer_mut();
+        writer.write_all(b"</")?;
+        writer.write_all(local.as_bytes())?;
+        writer.write_all(b">")
+    }
+
+    /// Writes text, escaping it unless it is the contents of a raw text
+    /// element.
+    fn text(&mut self, text: &str) -> io::Result<()> {
+        if self.skipping() {
+            return Ok(());
+        }
+        let raw = self.stack.last().is_some_and(|open| {
+            open.name.ns == ns!(html)
+                && matches!(
+                    open.name.local,
+                    local_name!("style")
+                        | local_name!("script")
+                        | local_name!("xmp")
+                        | local_name!("iframe")
+                        | local_name!("noembed")
+                        | local_name!("noframes")
+                        | local_name!("plaintext")
+                        | local_name!("noscript")
+                )
+        });
+        if raw {
+            self.serializer.writer_mut().write_all(text.as_bytes())
+        } else {
+            self.serializer.write_escaped(text, false)
+        }
+    }
+
+    /// Returns the namespace of a new child of the innermost open element.
+    ///
+    /// `svg` and `math` start their namespaces, which their descendants
+    /// inherit, except inside `foreignObject`.
+    fn child_namespace(&self, local: &LocalName) -> html5ever::Namespace {
+        match self.stack.last() {
+            Some(open)
+                if open.name.ns != ns!(html) && open.name.local != local_name!("foreignObject") =>
+            {
+                open.name.ns.clone()
+            }
+            _ => match *local {
+                local_name!("svg") => ns!(svg),
+                local_name!("math") => ns!(mathml),
+                _ => ns!(html),
+            },
+        }
+    }
+}
+
+/// Writes a doctype with its public and system identifiers, which decide
+/// whether the document is rendered in quirks mode.
+fn write_doctype<W: Write>(
+    writer: &mut W,
+    name: Option<&str>,
+    public_id: Option<&str>,
+    system_id: Option<&str>,
+) -> io::Result<()> {
+    writer.write_all(b"<!DOCTYPE")?;
+    if let Some(name) = name {
+        write!(writer, " {}", name)?;
+    }
+    if let Some(public_id) = public_id {
+        write!(writer, " PUBLIC {}", quote_id(public_id))?;
+    }
+    if let Some(system_id) = system_id {
+        let keyword = if public_id.is_some() { "" } else { " SYSTEM" };
+        write!(writer, "{} {}", keyword, quote_id(system_id))?;
+    }
+    writer.write_all(b">")
+}
+
+/// Quotes a doctype identifier, with single quotes if it contains a double
+/// quote.
+fn quote_id(id: &str) -> String {
+    if id.contains('"') {
+        format!("'{}'", id)
+    } else {
+        format!("\"{}\"", id)
+    }
+}
+
+/// Returns whether an HTML element is void, having no contents or end tag.
+fn is_void(local: &LocalName) -> bool {
+    matches!(
+        *local,
+        local_name!("area")
+            | local_name!("base")
+            | local_name!("br")
+            | local_name!("col")
+            | local_name!("embed")
+            | local_name!("hr")
+            | local_name!("img")
+            | local_name!("input")
+            | local_name!("link")
+            | local_name!("meta")
+            | local_name!("source")
+            | local_name!("track")
+            | local_name!("wbr")
+    )
+}
+
+/// Returns whether a start tag implicitly closes the open element, as for
+/// `<li>` following an unclosed `<li>`.
+fn closes_implicitly(open: &QualName, start: &LocalName) -> bool {
+    if open.ns != ns!(html) {
+        return false;
+    }
+    match open.local {
+        local_name!("p") => matches!(
+            *start,
+            local_name!("address")
+                | local_name!("article")
+                | local_name!("aside")
+                | local_name!("blockquote")
+                | local_name!("details")
+                | local_name!("div")
+                | local_name!("dl")
+                | local_name!("fieldset")
+                | local_name!("figcaption")
+                | local_name!("figure")
+                | local_name!("footer")
+                | local_name!("form")
+                | local_name!("h1")
+                | local_name!("h2")
+                | local_name!("h3")
+                | local_name!("h4")
+                | local_name!("h5")
+                | local_name!("h6")
+                | local_name!("header")
+                | local_name!("hr")
+                | local_name!("main")
+                | local_name!("nav")
+                | local_name!("ol")
+                | local_name!("p")
+                | local_name!("pre")
+                | local_name!("section")
+                | local_name!("table")
+                | local_name!("ul")
+        ),
+        local_name!("li") => *start == local_name!("li"),
+        local_name!("dt") | local_name!("dd") => {
+            matches!(*start, local_name!("dt") | local_name!("dd"))
+        }
+        local_name!("option") => {
+            matches!(*start, local_name!("option") | local_name!("optgroup"))
+        }
+        local_name!("optgroup") => *start == local_name!("optgroup"),
+        local_name!("tr") => *start == local_name!("tr"),
+        local_name!("td") | local_name!("th") => matches!(
+            *start,
+            local_name!("td") | local_name!("th") | local_name!("tr")
+        ),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Rewriter;
+
+    /// Tests rewriting a document fed in small chunks.
+    ///
+    /// Verifies that attributes are changed, that removed elements and
+    /// replaced contents are left out along with the elements inside them,
+    /// and that child combinators follow implicitly closed paragraphs.
+    #[test]
+    fn rewrite_chunked() {
+        let html = "<div><p>one<p>two <a href=/x>x</a></p><script>a<b</script>\
+                    <span class=ad><p>ad</p></span></div><p>three";
+        let mut rewriter = Rewriter::new(Vec::new())
+            .on("a[href]", |element| element.set_attr("rel", "nofollow"))
+            .unwrap()
+            .on("script", |element| element.remove())
+            .unwrap()
+            .on(".ad", |element| element.set_inner_content("<i>gone</i>"))
+            .unwrap()
+            .on("div > p", |element| {
+                element.set_attr("class", "child");
+            })
+            .unwrap();
+        for chunk in html.as_bytes().chunks(3) {
+            rewriter.feed(std::str::from_utf8(chunk).unwrap()).unwrap();
+        }
+        let output = String::from_utf8(rewriter.finish().unwrap()).unwrap();
+
+        assert_eq!(
+            output,
+            "<div><p class=\"child\">one<p class=\"child\">two \
+             <a href=\"/x\" rel=\"nofollow\">x</a></p>\
+             <span class=\"ad\"><i>gone</i></span></div><p>three"
+        );
+    }
+
+    /// Tests an invalid selector.
+    ///
+    /// Edge case: registering a handler reports the selector's parse error
+    /// instead of panicking.
+    #[test]
+    fn rewrite_invalid_selector() {
+        assert!(Rewriter::new(Vec::new()).on("p:hovr", |_| {}).is_err());
+    }
+
+    /// Tests that doctypes are written with their identifiers.
+    ///
+    /// Verifies that a legacy doctype keeps the public and system
+    /// identifiers that select quirks or limited-quirks mode.
+    #[test]
+    fn rewrite_keeps_doctype_identifiers() {
+        let rewrite = |html: &str| {
+            let mut rewriter = Rewriter::new(Vec::new());
+            rewriter.feed(html).unwrap();
+            String::from_utf8(rewriter.finish().unwrap()).unwrap()
+        };
+        assert_eq!(
+            rewrite(r#"<!DOCTYPE HTML PUBLIC "-//W3C//DTD HTML 4.01 Transitional//EN"><p>"#),
+            r#"<!DOCTYPE html PUBLIC "-//W3C//DTD HTML 4.01 Transitional//EN"><p>"#
+        );
+        assert_eq!(
+            rewrite(r#"<!doctype html public '-//W3C//DTD XHTML 1.0//EN' "x.dtd">"#),
+            r#"<!DOCTYPE html PUBLIC "-//W3C//DTD XHTML 1.0//EN" "x.dtd">"#
+        );
+        assert_eq!(
+            rewrite("<!DOCTYPE html SYSTEM 'about:\"legacy\"'>"),
+            r#"<!DOCTYPE html SYSTEM 'about:"legacy"'>"#
+        );
+        assert_eq!(rewrite("<!DOCTYPE html>"), "<!DOCTYPE html>");
+    }
+}
@@ -0,0 +1,59 @@
+//! Open elements tracked while rewriting a stream.
+
+use html5ever::tendril::StrTendril;
+use html5ever::{LocalName, QualName};
+
+/// An element whose start tag has been seen but whose end has not.
+#[derive(Debug)]
+pub(crate) struct OpenElement {
+    /// The element name, with the namespace inferred from its ancestors.
+    pub(crate) name: QualName,
+    /// The attributes in source order.
+    pub(crate) attributes: Vec<(LocalName, StrTendril)>,
+    /// Whether the element and its contents are left out of the output.
+    pub(crate) removed: bool,
+    /// Whether the element's original contents are left out of the output.
+    pub(crate) skip_content: bool,
+}
+
+/// An element on the open element stack, for selector matching.
+///
+/// Only ancestors are known while streaming, so siblings and children are
+/// never reported.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct StreamElement<'a> {
+    /// The open element stack, outermost first.
+    pub(crate) stack: &'a [OpenElement],
+    /// The position of this element in the stack.
+    pub(crate) index: usize,
+}
+
+/// Accessor methods for StreamElement.
+///
+/// Provides the element's data and its parent.
+impl<'a> StreamElement<'a> {
+    /// Returns the open element this refers to.
+    #[inline]
+    pub(crate) fn open(&self) -> &'a OpenElement {
+        &self.stack[self.index]
+    }
+
+    /// Returns the enclosing element, if any.
+    #[inline]
+    pub(crate) fn parent(&self) -> Option<StreamElement<'a>> {
+        self.index.checked_sub(1).map(|index| StreamElement {
+            stack: self.stack,
+            index,
+        })
+    }
+
+    /// Returns the value of an attribute, if present.
+    #[inline]
+    pub(crate) fn attr(&self, name: &str) -> Option<&'a str> {
+        self.open()
+            .attributes
+            .iter()
+            .find(|(attribute, _)| &**attribute == name)
+            .map(|(_, value)| &**value)
+    }
+}
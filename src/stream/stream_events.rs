@@ -0,0 +1,26 @@
+//! One-shot streaming of HTML events.
+
+use super::{EventStream, HtmlEvent};
+
+/// Streams the events of a complete HTML string to `handler`.
+///
+/// A shorthand for feeding the whole string to an [`EventStream`].
+///
+/// # Examples
+///
+/// ```
+/// use brik::stream::{stream_events, HtmlEvent};
+///
+/// let mut text = String::new();
+/// stream_events("<p>Hello <b>World</b></p>", |event| {
+///     if let HtmlEvent::Text(t) = event {
+///         text.push_str(&t);
+///     }
+/// });
+/// assert_eq!(text, "Hello World");
+/// ```
+pub fn stream_events<F: FnMut(HtmlEvent)>(html: &str, handler: F) {
+    let mut stream = EventStream::new(handler);
+    stream.feed(html);
+    stream.finish();
+}
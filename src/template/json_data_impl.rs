@@ -0,0 +1,95 @@
+use super::TemplateData;
+use serde_json::Value;
+
+/// Implements TemplateData for JSON values.
+///
+/// Provides lookup of dotted paths with list indexes, like `items[2].title`,
+/// through objects and arrays. Strings, numbers and booleans have text;
+/// arrays are lists.
+impl TemplateData for Value {
+    fn text(&self, path: &str) -> Option<String> {
+        match lookup(self, path)? {
+            Value::String(text) => Some(text.clone()),
+            Value::Number(number) => Some(number.to_string()),
+            Value::Bool(value) => Some(value.to_string()),
+            Value::Null | Value::Array(_) | Value::Object(_) => None,
+        }
+    }
+
+    fn len(&self, path: &str) -> Option<usize> {
+        lookup(self, path)?.as_array().map(Vec::len)
+    }
+
+    /// Returns whether the value at `path` counts as true.
+    ///
+    /// Missing values, `null`, `false`, zero, empty strings and empty
+    /// arrays and objects are false; everything else is true.
+    fn truthy(&self, path: &str) -> bool {
+        match lookup(self, path) {
+            None | Some(Value::Null) => false,
+            Some(Value::Bool(value)) => *value,
+            Some(Value::Number(number)) => number.as_f64() != Some(0.0),
+            Some(Value::String(text)) => !text.is_empty(),
+            Some(Value::Array(items)) => !items.is_empty(),
+            Some(Value::Object(fields)) => !fields.is_empty(),
+        }
+    }
+}
+
+/// Returns the value at a dotted path with list indexes, if present.
+fn lookup<'a>(value: &'a Value, path: &str) -> Option<&'a Value> {
+    let mut current = value;
+    for segment in path.split('.') {
+        let (name, mut indexes) = match segment.find('[') {
+            Some(start) => segment.split_at(start),
+            None => (segment, ""),
+        };
+        if !name.is_empty() {
+            current = current.get(name)?;
+        }
+        while let Some(rest) = indexes.strip_prefix('[') {
+            let end = rest.find(']')?;
+            current = current.get(rest[..end].trim().parse::<usize>().ok()?)?;
+            indexes = &rest[end + 1..];
+        }
+        if !indexes.is_empty() {
+            return None;
+        }
+    }
+    Some(current)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::template::{TemplateData, TemplateEngine};
+    use serde_json::json;
+
+    /// Tests rendering a template against a JSON value.
+    ///
+    /// Verifies that dotted paths and indexes resolve through objects and
+    /// arrays, that arrays drive `for` directives, and that JSON truthiness
+    /// applies to `if` directives.
+    #[test]
+    fn render_json() {
+        let data = json!({
+            "user": { "name": "Ann", "admin": false },
+            "items": [{ "title": "One", "count": 0 }, { "title": "Two", "count": 2 }],
+        });
+        assert_eq!(data.text("items[1].title").as_deref(), Some("Two"));
+        assert_eq!(data.text("items[2].title"), None);
+        assert_eq!(data.len("items"), Some(2));
+
+        let engine = TemplateEngine::new("t", "urn:t");
+        let doc = engine.parse(
+            r#"<h1>{{user.name}}</h1><b t:if="user.admin">admin</b>
+               <li t:for="item in items">{{item.title}}<i t:if="item.count">{{ item.count }}</i></li>"#,
+        );
+        engine.render(&doc, &data).unwrap();
+
+        let body = doc.select_first("body").unwrap();
+        assert_eq!(
+            body.as_node().to_string(),
+            "<body><h1>Ann</h1>\n               <li>One</li><li>Two<i>2</i></li></body>"
+        );
+    }
+}
@@ -0,0 +1,25 @@
+//! Server-side HTML templating with directives.
+//!
+//! Templates are ordinary HTML with directive attributes and elements in a
+//! template namespace, such as `tmpl:if`, `tmpl:for` and `tmpl:include`. A
+//! [`TemplateEngine`] expands them in place against caller data implementing
+//! [`TemplateData`], leaving plain HTML.
+//!
+//! With the `json` feature, a `serde_json::Value` can be used as template
+//! data, with paths like `user.name` and `items[2].title`.
+//!
+//! **Note:** This module requires the `namespaces` feature to be enabled.
+
+/// JSON values as template data.
+#[cfg(feature = "json")]
+mod json_data_impl;
+/// Data that directives are evaluated against.
+mod template_data;
+/// Directive expansion.
+mod template_engine;
+/// Errors from rendering templates.
+mod template_error;
+
+pub use template_data::TemplateData;
+pub use template_engine::TemplateEngine;
+pub use template_error::TemplateError;
@@ -0,0 +1,55 @@
+use std::collections::HashMap;
+
+/// Data that template directives are evaluated against.
+///
+/// Values are looked up by path: names separated by dots, with list items
+/// selected by index, like `user.name` or `items[2].title`. Only
+/// [`text()`](TemplateData::text) and [`len()`](TemplateData::len) must be
+/// implemented.
+pub trait TemplateData {
+    /// Returns the value at `path` as text, if present.
+    fn text(&self, path: &str) -> Option<String>;
+
+    /// Returns the number of items in the list at `path`, if it is a list.
+    fn len(&self, path: &str) -> Option<usize>;
+
+    /// Returns whether the value at `path` counts as true for `if`
+    /// directives.
+    ///
+    /// By default, lists are true when they are not empty, and other values
+    /// when they are present, not empty and not `false`.
+    fn truthy(&self, path: &str) -> bool {
+        match self.len(path) {
+            Some(len) => len > 0,
+            None => self
+                .text(path)
+                .is_some_and(|text| !text.is_empty() && text != "false"),
+        }
+    }
+}
+
+/// Implements TemplateData for string maps.
+///
+/// Provides lookup of flat data keyed by full paths. Lists are the runs of
+/// keys `path[0]`, `path[1]` and so on, or of keys starting with them.
+impl TemplateData for HashMap<String, String> {
+    fn text(&self, path: &str) -> Option<String> {
+        self.get(path).cloned()
+    }
+
+    fn len(&self, path: &str) -> Option<usize> {
+        let mut len = 0;
+        loop {
+            let item = format!("{}[{}]", path, len);
+            let present = self.keys().any(|key| {
+                key.strip_prefix(&item)
+                    .is_some_and(|rest| rest.is_empty() || rest.starts_with(['.', '[']))
+            });
+            if !present {
+                break;
+            }
+            len += 1;
+        }
+        (len > 0).then_some(len)
+    }
+}
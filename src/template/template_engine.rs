@@ -0,0 +1,422 @@
+use super::{TemplateData, TemplateError};
+use crate::ns::{apply_xmlns_opts, NsOptions};
+use crate::tree::{NodeData, NodeRef};
+use crate::{parse_html, traits::*};
+use html5ever::{LocalName, Namespace};
+use std::collections::HashMap;
+use std::rc::Rc;
+
+/// Expands directives in a template namespace against caller data.
+///
+/// Directives are attributes and elements in the engine's namespace, written
+/// with its prefix:
+///
+/// - `prefix:if="path"` keeps its element only when the value at `path` is
+///   [truthy](TemplateData::truthy); `prefix:if="!path"` negates the test.
+/// - `prefix:for="item in path"` repeats its element for each item of the
+///   list at `path`. Inside the copies, paths starting with `item` refer to
+///   the current item. A `for` is expanded before an `if` on the same element.
+/// - `<prefix:include name="partial"></prefix:include>` is replaced by the
+///   partial added under that name, whose directives are expanded in turn.
+///
+/// `{{path}}` placeholders in text and attribute values are replaced as by
+/// [`NodeRef::interpolate()`], seeing the items bound by enclosing `for`
+/// directives; missing values are replaced by nothing.
+///
+/// The prefix is mapped to the namespace by the engine, so templates do not
+/// need to declare it.
+///
+/// # Examples
+///
+/// ```
+/// use brik::template::TemplateEngine;
+/// use std::collections::HashMap;
+///
+/// let mut engine = TemplateEngine::new("t", "urn:example:template");
+/// engine.add_partial("item", r#"<li t:if="row.done">Done</li>"#);
+/// let doc = engine.parse(
+///     r#"<ul><t:include name="item" t:for="row in rows"></t:include></ul>
+///        <p t:if="!rows">Nothing to do</p>"#,
+/// );
+///
+/// let data: HashMap<String, String> = [("rows[0].done", "true"), ("rows[1].done", "")]
+///     .into_iter()
+///     .map(|(k, v)| (k.to_string(), v.to_string()))
+///     .collect();
+/// engine.render(&doc, &data).unwrap();
+///
+/// assert_eq!(
+///     doc.to_string(),
+///     "<html><head></head><body><ul><li>Done</li></ul>\n       </body></html>"
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct TemplateEngine {
+    /// The prefix directives are written with.
+    prefix: String,
+    /// The directive namespace.
+    namespace: Namespace,
+    /// Partials by name, as document fragments.
+    partials: HashMap<String, NodeRef>,
+}
+
+/// A name bound by a `for` directive, in a chain of enclosing bindings.
+struct Binding {
+    /// The name the item is bound to.
+    alias: String,
+    /// The full path of the item.
+    path: String,
+    /// The enclosing binding, if any.
+    parent: Scope,
+}
+
+/// The innermost `for` binding in effect, if any.
+type Scope = Option<Rc<Binding>>;
+
+/// A partial being included, in a chain of enclosing includes.
+struct Include {
+    /// The partial's name.
+    name: String,
+    /// The enclosing include, if any.
+    parent: Option<Rc<Include>>,
+}
+
+/// Methods for TemplateEngine.
+///
+/// Provides construction, template and partial parsing, and rendering.
+impl TemplateEngine {
+    /// Creates an engine for directives written with `prefix` in
+    /// `namespace`.
+    pub fn new<N: Into<Namespace>>(prefix: &str, namespace: N) -> TemplateEngine {
+        TemplateEngine {
+            prefix: prefix.to_string(),
+            namespace: namespace.into(),
+            partials: HashMap::new(),
+        }
+    }
+
+    /// Parses a template document, putting prefixed names in the directive
+    /// namespace.
+    pub fn parse(&self, html: &str) -> NodeRef {
+        let document = parse_html().one(html);
+        let options = NsOptions {
+            namespaces: HashMap::from([(self.prefix.clone(), self.namespace.clone())]),
+            strict: false,
+            ..NsOptions::default()
+        };
+        match apply_xmlns_opts(&document, &options) {
+            Ok(document) => document,
+            Err(_) => unreachable!("lenient namespace processing does not fail"),
+        }
+    }
+
+    /// Adds a partial for `include` directives, parsed from `html` as the
+    /// contents of a `<body>`.
+    ///
+    /// A partial added under an existing name replaces it.
+    pub fn add_partial(&mut self, name: &str, html: &str) -> &mut Self {
+        let fragment = NodeRef::new(NodeData::DocumentFragment);
+        if let Ok(body) = self.parse(html).select_first("body") {
+            for child in body.as_node().children().collect::<Vec<_>>() {
+                fragment.append(child);
+            }
+        }
+        self.partials.insert(name.to_string(), fragment);
+        self
+    }
+
+    /// Expands the directives in this subtree in place against `data`.
+    ///
+    /// Directive attributes and elements are removed as they are expanded,
+    /// and declarations of the directive namespace are removed with
+    /// [`Attributes::remove_xmlns_for()`](crate::Attributes::remove_xmlns_for).
+    /// Template contents are expanded too.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`TemplateError`] if a directive is unknown or invalid, or
+    /// an include names a missing partial or one already being included.
+    /// The tree is left partly expanded.
+    pub fn render<D>(&self, root: &NodeRef, data: &D) -> Result<(), TemplateError>
+    where
+        D: TemplateData + ?Sized,
+    {
+        let mut pending: Vec<(NodeRef, Scope, Option<Rc<Include>>)> =
+            root.children().map(|child| (child, None, None)).collect();
+        pending.reverse();
+        while let Some((node, scope, include)) = pending.pop() {
+            let element = match node.clone().into_element_ref() {
+                Some(element) => element,
+                None => {
+                    // Text merged into a preceding node has been detached.
+                    if node.as_text().is_some() && node.parent().is_some() {
+                        self.interpolate(&node, &scope, data);
+                    }
+                    continue;
+                }
+            };
+
+            if element.name.ns == self.namespace {
+                if &*element.name.local != "include" {
+                    return Err(TemplateError::UnknownDirective(
+                        element.name.local.to_string(),
+                    ));
+                }
+                if let Some(binding) = self.take_for(&node)? {
+                    self.repeat(&node, &binding, &scope, &include, data, &mut pending);
+                    continue;
+                }
+                if !self.take_if(&node, &scope, data) {
+                    node.detach();
+                    continue;
+                }
+                let name = element
+                    .attributes
+                    .borrow()
+                    .get(local_name!("name"))
+                    .unwrap_or("")
+                    .to_string();
+                let partial = self
+                    .partials
+                    .get(&name)
+                    .ok_or_else(|| TemplateError::MissingPartial(name.clone()))?;
+                let mut outer = include.as_deref();
+                while let Some(enclosing) = outer {
+                    if enclosing.name == name {
+                        return Err(TemplateError::IncludeCycle(name));
+                    }
+                    outer = enclosing.parent.as_deref();
+                }
+                let include = Some(Rc::new(Include {
+                    name,
+                    parent: include,
+                }));
+                let copies: Vec<NodeRef> = partial
+                    .children()
+                    .map(|child| child.clone_with(true, &mut str::to_string))
+                    .collect();
+                for copy in &copies {
+                    node.insert_before(copy.clone());
+                }
+                node.detach();
+                for copy in copies.into_iter().rev() {
+                    pending.push((copy, scope.clone(), include.clone()));
+                }
+                continue;
+            }
+
+            if let Some(binding) = self.take_for(&node)? {
+                self.repeat(&node, &binding, &scope, &include, data, &mut pending);
+                continue;
+            }
+            if !self.take_if(&node, &scope, data) {
+                node.detach();
+                continue;
+            }
+            {
+                let mut attributes = element.attributes.borrow_mut();
+                if let Some(name) = attributes.map.keys().find(|name| name.ns == self.namespace) {
+                    return Err(TemplateError::UnknownDirective(name.local.to_string()));
+                }
+                let count = attributes.map.len();
+                attributes.remove_xmlns_for(self.namespace.as_ref());
+                if attributes.map.len() != count {
+                    node.mark_dirty();
+                }
+            }
+            self.interpolate(&node, &scope, data);
+            let children = node.children().chain(
+                element
+                    .template_contents
+                    .iter()
+                    .flat_map(|contents| contents.children()),
+            );
+            let children: Vec<NodeRef> = children.collect();
+            for child in children.into_iter().rev() {
+                pending.push((child, scope.clone(), include.clone()));
+            }
+        }
+        Ok(())
+    }
+
+    /// Removes and parses the `for` directive of an element, returning the
+    /// bound name and the list path.
+    fn take_for(&self, node: &NodeRef) -> Result<Option<(String, String)>, TemplateError> {
+        let value = match self.take_directive(node, "for") {
+            Some(value) => value,
+            None => return Ok(None),
+        };
+        let mut parts = value.split_whitespace();
+        match (parts.next(), parts.next(), parts.next(), parts.next()) {
+            (Some(alias), Some("in"), Some(path), None) => {
+                Ok(Some((alias.to_string(), path.to_string())))
+            }
+            _ => Err(TemplateError::InvalidDirective {
+                directive: "for".to_string(),
+                value,
+            }),
+        }
+    }
+
+    /// Removes and evaluates the `if` directive of an element, returning
+    /// whether to keep it.
+    fn take_if<D>(&self, node: &NodeRef, scope: &Scope, data: &D) -> bool
+    where
+        D: TemplateData + ?Sized,
+    {
+        match self.take_directive(node, "if") {
+            Some(value) => {
+                let value = value.trim();
+                match value.strip_prefix('!') {
+                    Some(path) => !data.truthy(&resolve(path.trim(), scope)),
+                    None => data.truthy(&resolve(value, scope)),
+                }
+            }
+            None => true,
+        }
+    }
+
+    /// Replaces placeholders in a text node or an element's attributes, with
+    /// missing values replaced by nothing.
+    fn interpolate<D>(&self, node: &NodeRef, scope: &Scope, data: &D)
+    where
+        D: TemplateData + ?Sized,
+    {
+        let mut resolver = |expr: &str| Some(data.text(&resolve(expr, scope)).unwrap_or_default());
+        node.interpolate_node(&mut resolver, &mut Vec::new());
+    }
+
+    /// Removes a directive attribute from an element, returning its value.
+    fn take_directive(&self, node: &NodeRef, directive: &str) -> Option<String> {
+        let element = node.as_element()?;
+        let attribute = element
+            .attributes
+            .borrow_mut()
+            .remove_ns(&self.namespace, LocalName::from(directive))?;
+        node.mark_dirty();
+        Some(attribute.value.to_string())
+    }
+
+    /// Replaces an element with a copy for each item of a list, queueing
+    /// the copies with the item bound.
+    fn repeat<D>(
+        &self,
+        node: &NodeRef,
+        (alias, path): &(String, String),
+        scope: &Scope,
+        include: &Option<Rc<Include>>,
+        data: &D,
+        pending: &mut Vec<(NodeRef, Scope, Option<Rc<Include>>)>,
+    ) where
+        D: TemplateData + ?Sized,
+    {
+        let path = resolve(path, scope);
+        let len = data.len(&path).unwrap_or(0);
+        let mut copies = Vec::with_capacity(len);
+        for index in 0..len {
+            let copy = node.clone_with(true, &mut str::to_string);
+            node.insert_before(copy.clone());
+            let binding = Rc::new(Binding {
+                alias: alias.clone(),
+                path: format!("{}[{}]", path, index),
+                parent: scope.clone(),
+            });
+            copies.push((copy, Some(binding), include.clone()));
+        }
+        node.detach();
+        pending.extend(copies.into_iter().rev());
+    }
+}
+
+/// Replaces a leading name bound by an enclosing `for` with its item's
+/// full path.
+fn resolve(path: &str, scope: &Scope) -> String {
+    let split = path.find(['.', '[']).unwrap_or(path.len());
+    let (head, rest) = path.split_at(split);
+    let mut binding = scope.as_deref();
+    while let Some(current) = binding {
+        if current.alias == head {
+            return format!("{}{}", current.path, rest);
+        }
+        binding = current.parent.as_deref();
+    }
+    path.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns template data from key and value pairs.
+    fn data(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect()
+    }
+
+    /// Tests nested loops, conditions and includes.
+    ///
+    /// Verifies that inner loops see outer bindings, that conditions are
+    /// evaluated per copy, that includes and placeholders see the bindings
+    /// in effect, and that directive attributes are removed.
+    #[test]
+    fn render_nested_directives() {
+        let mut engine = TemplateEngine::new("t", "urn:t");
+        engine.add_partial(
+            "tag",
+            r#"<i t:for="tag in post.tags" title="{{tag}}">#</i>"#,
+        );
+        let doc = engine.parse(
+            r#"<div t:for="post in posts" class=post><b t:if="post.pinned">!</b><t:include name=tag></t:include>{{ post.title }}</div>"#,
+        );
+        let data = data(&[
+            ("posts[0].pinned", "true"),
+            ("posts[0].tags[0]", "a"),
+            ("posts[0].tags[1]", "b"),
+            ("posts[1].pinned", "false"),
+            ("posts[1].tags[0]", "c"),
+            ("posts[1].title", "<Two>"),
+        ]);
+
+        engine.render(&doc, &data).unwrap();
+
+        let body = doc.select_first("body").unwrap();
+        assert_eq!(
+            body.as_node().to_string(),
+            "<body><div class=\"post\"><b>!</b><i title=\"a\">#</i><i title=\"b\">#</i></div>\
+             <div class=\"post\"><i title=\"c\">#</i>&lt;Two&gt;</div></body>"
+        );
+    }
+
+    /// Tests the errors reported while rendering.
+    ///
+    /// Edge case: a partial including itself is reported as a cycle rather
+    /// than expanded forever.
+    #[test]
+    fn render_errors() {
+        let mut engine = TemplateEngine::new("t", "urn:t");
+        engine.add_partial("loop", "<p><t:include name=loop></t:include></p>");
+        let render = |html: &str| engine.render(&engine.parse(html), &data(&[]));
+
+        assert_eq!(
+            render("<t:include name=loop></t:include>"),
+            Err(TemplateError::IncludeCycle("loop".to_string()))
+        );
+        assert_eq!(
+            render("<t:include name=none></t:include>"),
+            Err(TemplateError::MissingPartial("none".to_string()))
+        );
+        assert_eq!(
+            render("<p t:for=items></p>"),
+            Err(TemplateError::InvalidDirective {
+                directive: "for".to_string(),
+                value: "items".to_string(),
+            })
+        );
+        assert_eq!(
+            render("<p t:unless=x></p>"),
+            Err(TemplateError::UnknownDirective("unless".to_string()))
+        );
+    }
+}
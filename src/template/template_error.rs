@@ -0,0 +1,40 @@
+/// Errors that can occur while rendering a template.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TemplateError {
+    /// An `include` directive names a partial that was not added.
+    MissingPartial(String),
+    /// An `include` directive includes a partial that is already being
+    /// included, which would never end.
+    IncludeCycle(String),
+    /// A directive's value cannot be understood, like a `for` without `in`.
+    InvalidDirective {
+        /// The directive's local name.
+        directive: String,
+        /// The directive's value.
+        value: String,
+    },
+    /// An element or attribute in the directive namespace is not a known
+    /// directive.
+    UnknownDirective(String),
+}
+
+/// Implements Display for TemplateError.
+///
+/// Provides human-readable error messages for template rendering errors.
+impl std::fmt::Display for TemplateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TemplateError::MissingPartial(name) => write!(f, "unknown partial '{name}'"),
+            TemplateError::IncludeCycle(name) => write!(f, "partial '{name}' includes itself"),
+            TemplateError::InvalidDirective { directive, value } => {
+                write!(f, "invalid '{directive}' directive: '{value}'")
+            }
+            TemplateError::UnknownDirective(name) => write!(f, "unknown directive '{name}'"),
+        }
+    }
+}
+
+/// Implements Error for TemplateError.
+///
+/// Allows TemplateError to be used with Rust's standard error handling mechanisms.
+impl std::error::Error for TemplateError {}
@@ -0,0 +1,101 @@
+use super::{FindTextOptions, TextMatch};
+use crate::iter::Descendants;
+use std::collections::VecDeque;
+use std::ops::Range;
+
+/// Elements whose text is not searched.
+pub(crate) const UNSEARCHED_ELEMENTS: [&str; 2] = ["script", "style"];
+
+/// An iterator over the matches of a text search, in document order.
+///
+/// Returned by [`NodeRef::find_text()`](crate::NodeRef::find_text). Text
+/// nodes are searched one at a time as the iterator advances.
+#[derive(Debug, Clone)]
+pub struct FindText {
+    /// The remaining nodes to search.
+    pub(super) descendants: Descendants,
+    /// The normalized query.
+    pub(super) query: Vec<char>,
+    /// The search options.
+    pub(super) options: FindTextOptions,
+    /// Matches found in the current text node but not yet returned.
+    pub(super) pending: VecDeque<TextMatch>,
+}
+
+/// Implements Iterator for FindText.
+///
+/// Provides the matches of each text node in turn, skipping the contents of
+/// `<script>` and `<style>` elements.
+impl Iterator for FindText {
+    type Item = TextMatch;
+
+    fn next(&mut self) -> Option<TextMatch> {
+        loop {
+            if let Some(found) = self.pending.pop_front() {
+                return Some(found);
+            }
+            if self.query.is_empty() {
+                return None;
+            }
+            let node = self.descendants.next()?;
+            if let Some(element) = node.as_element() {
+                if element.name.ns == ns!(html)
+                    && UNSEARCHED_ELEMENTS.contains(&&*element.name.local)
+                {
+                    self.descendants.skip_subtree();
+                }
+                continue;
+            }
+            if let Some(text) = node.as_text() {
+                let ranges = find_in(&text.borrow(), &self.query, self.options);
+                self.pending
+                    .extend(ranges.into_iter().map(|range| TextMatch {
+                        node: node.clone(),
+                        range,
+                    }));
+            }
+        }
+    }
+}
+
+/// Normalizes text for searching, pairing each resulting character with the
+/// byte range of the text it came from.
+pub(super) fn normalize(text: &str, options: FindTextOptions) -> Vec<(char, Range<usize>)> {
+    let mut normalized: Vec<(char, Range<usize>)> = Vec::new();
+    for (start, c) in text.char_indices() {
+        let range = start..start + c.len_utf8();
+        if options.normalize_whitespace && c.is_ascii_whitespace() {
+            match normalized.last_mut() {
+                Some((' ', last)) if last.end == start => {
+                    last.end = range.end;
+                }
+                _ => normalized.push((' ', range)),
+            }
+        } else if options.ignore_case {
+            normalized.extend(c.to_lowercase().map(|lower| (lower, range.clone())));
+        } else {
+            normalized.push((c, range));
+        }
+    }
+    normalized
+}
+
+/// Returns the byte ranges of the non-overlapping matches of a normalized
+/// query in text, from left to right.
+pub(super) fn find_in(text: &str, query: &[char], options: FindTextOptions) -> Vec<Range<usize>> {
+    let haystack = normalize(text, options);
+    let mut ranges: Vec<Range<usize>> = Vec::new();
+    let mut start = 0;
+    while start + query.len() <= haystack.len() {
+        let window = &haystack[start..start + query.len()];
+        let found = window.iter().map(|(c, _)| c).eq(query.iter());
+        let range = window[0].1.start..window[query.len() - 1].1.end;
+        if found && ranges.last().is_none_or(|last| last.end <= range.start) {
+            ranges.push(range);
+            start += query.len();
+        } else {
+            start += 1;
+        }
+    }
+    ranges
+}
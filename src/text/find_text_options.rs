@@ -0,0 +1,13 @@
+/// Options for [`NodeRef::find_text()`](crate::NodeRef::find_text).
+///
+/// The defaults match the query exactly.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FindTextOptions {
+    /// Whether to compare text ignoring case, using Unicode lowercase
+    /// mappings.
+    pub ignore_case: bool,
+    /// Whether runs of ASCII whitespace in the text and the query match
+    /// each other regardless of their length and characters, so `"a b"`
+    /// matches `"a\n   b"`.
+    pub normalize_whitespace: bool,
+}
@@ -0,0 +1,127 @@
+use super::TextMatch;
+use crate::tree::NodeRef;
+use std::collections::HashMap;
+use std::ops::Range;
+
+/// Wraps text matches in copies of an element, returning the wrappers in
+/// document order.
+///
+/// Each match's text node is split at the match boundaries and the matched
+/// text is moved into a shallow copy of `wrapper`, which keeps its name and
+/// attributes but not its children. Text nodes left empty are removed.
+///
+/// Matches are typically those returned by
+/// [`NodeRef::find_text()`](crate::NodeRef::find_text), collected before
+/// the tree is changed. A match is skipped if its node is not a text node,
+/// if its range is empty, out of bounds or not on character boundaries, or
+/// if it overlaps a later match in the same node.
+///
+/// # Examples
+///
+/// ```
+/// use brik::parse_html;
+/// use brik::text::{highlight, FindTextOptions};
+/// use brik::traits::*;
+///
+/// let doc = parse_html().one("<p>Rust and <i>rust</i> belts</p>");
+/// let options = FindTextOptions {
+///     ignore_case: true,
+///     ..FindTextOptions::default()
+/// };
+/// let matches: Vec<_> = doc.find_text("rust", &options).collect();
+/// let wrapper = parse_html().one("<mark class=hit></mark>").select_first("mark").unwrap();
+///
+/// let marks = highlight(matches, wrapper.as_node());
+/// assert_eq!(marks.len(), 2);
+/// assert_eq!(
+///     doc.select_first("p").unwrap().as_node().to_string(),
+///     r#"<p><mark class="hit">Rust</mark> and <i><mark class="hit">rust</mark></i> belts</p>"#
+/// );
+/// ```
+pub fn highlight<I>(matches: I, wrapper: &NodeRef) -> Vec<NodeRef>
+where
+    I: IntoIterator<Item = TextMatch>,
+{
+    let mut nodes: Vec<(NodeRef, Vec<Range<usize>>)> = Vec::new();
+    let mut indices: HashMap<NodeRef, usize> = HashMap::new();
+    for found in matches {
+        let index = *indices.entry(found.node.clone()).or_insert_with(|| {
+            nodes.push((found.node.clone(), Vec::new()));
+            nodes.len() - 1
+        });
+        nodes[index].1.push(found.range);
+    }
+
+    let mut wrappers = Vec::new();
+    for (node, mut ranges) in nodes {
+        let text = match node.as_text() {
+            Some(text) => text,
+            None => continue,
+        };
+        ranges.sort_by_key(|range| std::cmp::Reverse(range.start));
+        let mut node_wrappers = Vec::new();
+        let contents = text.borrow().to_string();
+        let mut limit = contents.len();
+        for range in ranges {
+            if range.start >= range.end
+                || range.end > limit
+                || contents.get(range.clone()).is_none()
+            {
+                continue;
+            }
+            if range.end < limit {
+                node.insert_after(NodeRef::new_text(&contents[range.end..limit]));
+            }
+            limit = range.start;
+            let copy = wrapper.clone_with(false, &mut str::to_string);
+            copy.append(NodeRef::new_text(&contents[range.clone()]));
+            node.insert_after(copy.clone());
+            *text.borrow_mut() = contents[..range.start].into();
+            node.mark_dirty();
+            node_wrappers.push(copy);
+        }
+        if text.borrow().is_empty() {
+            node.detach();
+        }
+        wrappers.extend(node_wrappers.into_iter().rev());
+    }
+    wrappers
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::html5ever::tendril::TendrilSink;
+    use crate::parse_html;
+
+    /// Tests splitting a text node at several matches.
+    ///
+    /// Edge case: matches given out of order and overlapping matches are
+    /// handled per node, keeping the later of two overlapping matches.
+    #[test]
+    fn several_matches_in_one_node() {
+        let doc = parse_html().one("<p>abcabc</p>");
+        let node = doc
+            .select_first("p")
+            .unwrap()
+            .as_node()
+            .first_child()
+            .unwrap();
+        let at = |range: Range<usize>| TextMatch {
+            node: node.clone(),
+            range,
+        };
+        let wrapper = NodeRef::new_element(
+            html5ever::QualName::new(None, ns!(html), local_name!("b")),
+            None,
+        );
+
+        let wrappers = highlight(vec![at(3..4), at(0..2), at(1..3)], &wrapper);
+        assert_eq!(wrappers.len(), 2);
+        assert_eq!(wrappers[0].text_contents(), "bc");
+        assert_eq!(
+            doc.select_first("p").unwrap().as_node().to_string(),
+            "<p>a<b>bc</b><b>a</b>bc</p>"
+        );
+    }
+}
@@ -0,0 +1,28 @@
+//! Search over the text of node trees.
+//!
+//! [`NodeRef::find_text()`](crate::NodeRef::find_text) finds a query in
+//! the text nodes of a subtree and reports each match as a text node and a
+//! byte range within it, so callers can act on matches in place, for
+//! example by wrapping them in elements with [`highlight()`].
+//!
+//! With the `regex` feature, `NodeRef::replace_text()` rewrites text with a
+//! regular expression, leaving markup untouched.
+
+/// Text search iterator.
+mod find_text;
+/// Text search options.
+mod find_text_options;
+/// Highlighting of text matches.
+mod highlight;
+/// Text search for NodeRef.
+mod node_ref_find_text;
+/// Regular expression text replacement for NodeRef.
+#[cfg(feature = "regex")]
+mod node_ref_replace_text;
+/// Text search match.
+mod text_match;
+
+pub use find_text::FindText;
+pub use find_text_options::FindTextOptions;
+pub use highlight::highlight;
+pub use text_match::TextMatch;
@@ -0,0 +1,96 @@
+//! Text search for node trees.
+
+use super::find_text::normalize;
+use super::{FindText, FindTextOptions};
+use crate::tree::NodeRef;
+use std::collections::VecDeque;
+
+/// Text search methods for NodeRef.
+///
+/// Provides lazy search of the text nodes in a subtree.
+impl NodeRef {
+    /// Finds `query` in the text nodes of this subtree, returning an
+    /// iterator over the matches in document order.
+    ///
+    /// Each text node is searched separately, so a match never spans
+    /// adjacent text nodes or element boundaries; matches within a text
+    /// node do not overlap. The contents of `<script>` and `<style>`
+    /// elements are not searched, and an empty query finds nothing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::parse_html;
+    /// use brik::text::FindTextOptions;
+    /// use brik::traits::*;
+    ///
+    /// let doc = parse_html().one("<p>Brik is a <b>brick</b>.\n  BRIK   IS fun.</p>");
+    /// let options = FindTextOptions {
+    ///     ignore_case: true,
+    ///     normalize_whitespace: true,
+    /// };
+    ///
+    /// let matches: Vec<_> = doc.find_text("brik is", &options).collect();
+    /// assert_eq!(matches.len(), 2);
+    /// assert_eq!(matches[0].range, 0..7);
+    /// assert_eq!(matches[1].text(), "BRIK   IS");
+    /// ```
+    pub fn find_text(&self, query: &str, options: &FindTextOptions) -> FindText {
+        FindText {
+            descendants: self.inclusive_descendants(),
+            query: normalize(query, *options)
+                .into_iter()
+                .map(|(c, _)| c)
+                .collect(),
+            options: *options,
+            pending: VecDeque::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::html5ever::tendril::TendrilSink;
+    use crate::parse_html;
+    use crate::text::FindTextOptions;
+
+    /// Tests matching within and across text nodes.
+    ///
+    /// Verifies that ranges are byte offsets into each node, that matches
+    /// do not span text nodes, and that script contents are skipped.
+    #[test]
+    fn byte_ranges_per_node() {
+        let doc = parse_html().one("<p>café café</p><p>ca<i>fé</i></p><script>var café;</script>");
+        let found: Vec<(String, std::ops::Range<usize>)> = doc
+            .find_text("café", &FindTextOptions::default())
+            .map(|found| (found.node.text_contents(), found.range))
+            .collect();
+
+        assert_eq!(
+            found,
+            [
+                ("café café".to_string(), 0..5),
+                ("café café".to_string(), 6..11),
+            ]
+        );
+    }
+
+    /// Tests case folding and whitespace normalization.
+    ///
+    /// Edge case: ranges cover the original text, including the whole
+    /// whitespace run and characters whose lowercase form is longer.
+    #[test]
+    fn normalized_ranges_cover_original_text() {
+        let doc = parse_html().one("<p>x İstanbul \t\n CITY x</p>");
+        let options = FindTextOptions {
+            ignore_case: true,
+            normalize_whitespace: true,
+        };
+        let found: Vec<String> = doc
+            .find_text("i\u{307}stanbul city", &options)
+            .map(|found| found.text())
+            .collect();
+
+        assert_eq!(found, ["İstanbul \t\n CITY"]);
+    }
+}
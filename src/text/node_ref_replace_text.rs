@@ -0,0 +1,125 @@
+//! Regular expression replacement over text nodes.
+
+use super::find_text::UNSEARCHED_ELEMENTS;
+use crate::tree::NodeRef;
+use regex::{Regex, Replacer};
+
+/// Text replacement methods for NodeRef.
+///
+/// Provides regular expression replacement that preserves markup.
+impl NodeRef {
+    /// Replaces every match of `regex` in the text of this subtree, returning
+    /// the number of matches replaced.
+    ///
+    /// Each run of adjacent text nodes is searched as one string, so matches
+    /// may span text nodes split by earlier edits; a run with matches is
+    /// merged into its first node, and a run left empty is removed. Matches
+    /// never span elements, and elements, attributes, comments and the
+    /// contents of `<script>` and `<style>` elements are not changed.
+    /// `replacement` accepts anything [`Regex::replace_all()`] does, including
+    /// `$1`-style group references and closures.
+    ///
+    /// **Note:** This method requires the `regex` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::parse_html;
+    /// use brik::traits::*;
+    /// use regex::Regex;
+    ///
+    /// let doc = parse_html().one(
+    ///     "<p class=colour>Colour and <b>colour</b></p><style>.colour {}</style>",
+    /// );
+    /// let regex = Regex::new(r"(?i)\b(c)olour\b").unwrap();
+    /// assert_eq!(doc.replace_text(&regex, "${1}olor"), 2);
+    /// assert_eq!(
+    ///     doc.select_first("body").unwrap().as_node().to_string(),
+    ///     r#"<body><p class="colour">Color and <b>color</b></p><style>.colour {}</style></body>"#
+    /// );
+    /// ```
+    pub fn replace_text<R: Replacer>(&self, regex: &Regex, mut replacement: R) -> usize {
+        let mut runs = Vec::new();
+        let mut descendants = self.inclusive_descendants();
+        while let Some(node) = descendants.next() {
+            if let Some(element) = node.as_element() {
+                if element.name.ns == ns!(html)
+                    && UNSEARCHED_ELEMENTS.contains(&&*element.name.local)
+                {
+                    descendants.skip_subtree();
+                }
+            } else if node.as_text().is_some()
+                && node
+                    .previous_sibling()
+                    .is_none_or(|previous| previous.as_text().is_none())
+            {
+                runs.push(node);
+            }
+        }
+
+        let mut count = 0;
+        for first in runs {
+            let run: Vec<NodeRef> = first
+                .inclusive_following_siblings()
+                .take_while(|node| node.as_text().is_some())
+                .collect();
+            let text: String = run
+                .iter()
+                .filter_map(|node| node.as_text())
+                .map(|text| text.borrow().to_string())
+                .collect();
+            let matches = regex.find_iter(&text).count();
+            if matches == 0 {
+                continue;
+            }
+            count += matches;
+            let replaced = regex.replace_all(&text, replacement.by_ref());
+            for node in &run[1..] {
+                node.detach();
+            }
+            if replaced.is_empty() {
+                first.detach();
+            } else if let Some(contents) = first.as_text() {
+                *contents.borrow_mut() = replaced.as_ref().into();
+                first.mark_dirty();
+            }
+        }
+        count
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::html5ever::tendril::TendrilSink;
+    use crate::parse_html;
+    use crate::NodeRef;
+    use regex::Regex;
+
+    /// Tests matches spanning adjacent text nodes.
+    ///
+    /// Verifies that split text is merged where it matches, that runs without
+    /// matches are left as they are, and that empty results are removed.
+    #[test]
+    fn matches_span_adjacent_text_nodes() {
+        let doc = parse_html().one("<p></p><div>keep</div>");
+        let p = doc.select_first("p").unwrap();
+        for text in &["re", "cieve", " TODO"] {
+            p.as_node().append(NodeRef::new_text(*text));
+        }
+        let div = doc.select_first("div").unwrap();
+        div.as_node().append(NodeRef::new_text(" me"));
+
+        let fixes = Regex::new("recieve").unwrap();
+        assert_eq!(doc.replace_text(&fixes, "receive"), 1);
+        assert_eq!(p.as_node().children().count(), 1);
+        assert_eq!(div.as_node().children().count(), 2);
+
+        let todo = Regex::new(r"\s*TODO").unwrap();
+        assert_eq!(p.as_node().replace_text(&todo, ""), 1);
+        assert_eq!(p.text_contents(), "receive");
+
+        let all = Regex::new(".+").unwrap();
+        assert_eq!(p.as_node().replace_text(&all, ""), 1);
+        assert_eq!(p.as_node().children().count(), 0);
+    }
+}
@@ -0,0 +1,27 @@
+use crate::tree::NodeRef;
+use std::ops::Range;
+
+/// A match found by [`NodeRef::find_text()`](crate::NodeRef::find_text).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextMatch {
+    /// The text node containing the match.
+    pub node: NodeRef,
+    /// The byte range of the match in the text node's contents.
+    pub range: Range<usize>,
+}
+
+/// Methods for TextMatch.
+///
+/// Provides access to the matched text.
+impl TextMatch {
+    /// Returns the matched text, as it currently appears in the text node.
+    ///
+    /// Returns an empty string if the node is no longer a text node long
+    /// enough to hold the range.
+    pub fn text(&self) -> String {
+        self.node
+            .as_text()
+            .and_then(|text| text.borrow().get(self.range.clone()).map(str::to_string))
+            .unwrap_or_default()
+    }
+}
@@ -1,20 +1,51 @@
+use crate::parser::ParseDiagnostic;
 use html5ever::tree_builder::QuirksMode;
-use std::cell::Cell;
+use std::cell::{Cell, Ref, RefCell};
 
 /// Data specific to document nodes.
 #[derive(Debug, PartialEq, Clone)]
 pub struct DocumentData {
     #[doc(hidden)]
     pub _quirks_mode: Cell<QuirksMode>,
+    #[doc(hidden)]
+    pub _parse_diagnostics: RefCell<Vec<ParseDiagnostic>>,
+    #[doc(hidden)]
+    pub _base_url: RefCell<Option<String>>,
 }
 
 /// Methods for DocumentData.
 ///
-/// Provides access to document-level properties such as quirks mode.
+/// Provides access to document-level properties such as quirks mode and
+/// the base URL.
 impl DocumentData {
     /// The quirks mode of the document, as determined by the HTML parser.
     #[inline]
     pub fn quirks_mode(&self) -> QuirksMode {
         self._quirks_mode.get()
     }
+
+    /// The parse errors recorded while parsing this document.
+    ///
+    /// Empty unless the document was parsed with
+    /// [`ParseOpts::collect_diagnostics`](crate::ParseOpts::collect_diagnostics) set.
+    #[inline]
+    pub fn parse_diagnostics(&self) -> Ref<'_, [ParseDiagnostic]> {
+        Ref::map(self._parse_diagnostics.borrow(), Vec::as_slice)
+    }
+
+    /// The absolute URL that relative URLs in the document resolve against.
+    ///
+    /// Set from [`ParseOpts::base_url`](crate::ParseOpts::base_url) and
+    /// from the first `<base href>` when parsing, or with
+    /// [`set_base_url()`](DocumentData::set_base_url).
+    #[inline]
+    pub fn base_url(&self) -> Option<String> {
+        self._base_url.borrow().clone()
+    }
+
+    /// Sets or clears the base URL of the document.
+    #[inline]
+    pub fn set_base_url(&self, base_url: Option<&str>) {
+        *self._base_url.borrow_mut() = base_url.map(str::to_owned);
+    }
 }
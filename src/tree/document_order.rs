@@ -0,0 +1,90 @@
+//! Sorting adapter ordering nodes by their position in the tree.
+
+use super::NodeRef;
+use std::cmp::Ordering;
+
+/// A node ordered by its position in document order.
+///
+/// `NodeRef` compares by identity, so it has no natural order. Wrapping
+/// nodes in `DocumentOrder` lets collections of matches be sorted, or kept
+/// in a `BTreeSet`, in the order they appear in the document, using
+/// [`NodeRef::cmp_document_order()`].
+///
+/// The order of nodes changes when the tree is modified, so sorted
+/// collections must not be kept across changes to the tree.
+///
+/// # Examples
+///
+/// ```
+/// use brik::parse_html;
+/// use brik::traits::*;
+/// use brik::DocumentOrder;
+///
+/// let doc = parse_html().one("<h1>A</h1><p>B</p><h2>C</h2>");
+/// let mut matches: Vec<_> = doc.select("h2, h1").unwrap().collect();
+/// matches.extend(doc.select("p").unwrap());
+/// matches.sort_by_key(|element| DocumentOrder(element.as_node().clone()));
+///
+/// let text: Vec<_> = matches.iter().map(|element| element.text_contents()).collect();
+/// assert_eq!(text, ["A", "B", "C"]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DocumentOrder(pub NodeRef);
+
+/// Implements Ord for DocumentOrder.
+///
+/// Compares the wrapped nodes with [`NodeRef::cmp_document_order()`].
+impl Ord for DocumentOrder {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp_document_order(&other.0)
+    }
+}
+
+/// Implements PartialOrd for DocumentOrder.
+///
+/// Delegates to the total order of `Ord`.
+impl PartialOrd for DocumentOrder {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::html5ever::tendril::TendrilSink;
+    use crate::parse_html;
+    use std::collections::{BTreeSet, HashSet};
+
+    /// Tests ordering and hashing nodes by identity.
+    ///
+    /// Verifies that a shuffled set of nodes sorts back into preorder,
+    /// that ancestors precede descendants, that nodes in other trees are
+    /// ordered consistently, and that hashing deduplicates matches.
+    #[test]
+    fn sorts_into_document_order() {
+        let doc = parse_html().one("<div><p>1<b>2</b></p><p>3</p></div><template>t</template>");
+        let nodes: Vec<NodeRef> = doc.inclusive_descendants().collect();
+        let sorted: BTreeSet<DocumentOrder> = nodes
+            .iter()
+            .rev()
+            .map(|node| DocumentOrder(node.clone()))
+            .collect();
+        let sorted: Vec<NodeRef> = sorted.into_iter().map(|node| node.0).collect();
+        assert_eq!(sorted, nodes);
+
+        let template = doc.select_first("template").unwrap();
+        let contents = template.template_contents.clone().unwrap();
+        let text = contents.first_child().unwrap();
+        let forward = doc.cmp_document_order(&text);
+        assert_ne!(forward, Ordering::Equal);
+        assert_eq!(text.cmp_document_order(&doc), forward.reverse());
+
+        let matches = doc
+            .select("p")
+            .unwrap()
+            .chain(doc.select("div > p").unwrap());
+        let unique: HashSet<_> = matches.collect();
+        assert_eq!(unique.len(), 2);
+    }
+}
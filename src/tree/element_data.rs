@@ -1,4 +1,5 @@
-use html5ever::QualName;
+use html5ever::tendril::StrTendril;
+use html5ever::{LocalName, QualName};
 use std::cell::RefCell;
 
 use crate::attributes::Attributes;
@@ -22,7 +23,7 @@ pub struct ElementData {
 /// Methods for ElementData.
 ///
 /// Provides accessors for element name components including
-/// namespace URI, local name, and prefix.
+/// namespace URI, local name, and prefix, and bulk attribute updates.
 impl ElementData {
     /// Returns the namespace URI of the element.
     ///
@@ -65,6 +66,51 @@ impl ElementData {
         &self.name.local
     }
 
+    /// Sets many attributes at once, overwriting existing values.
+    ///
+    /// This borrows the attributes mutably once for the whole update.
+    /// See [`Attributes::extend_from`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the attributes are already borrowed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::parse_html;
+    /// use brik::traits::*;
+    ///
+    /// let doc = parse_html().one("<a href='/'>Home</a>");
+    /// let a = doc.select_first("a").unwrap();
+    /// a.set_attributes([("href", "/index.html"), ("rel", "home")]);
+    /// assert_eq!(a.attributes.borrow().get("href"), Some("/index.html"));
+    /// ```
+    pub fn set_attributes<I, A, V>(&self, attributes: I)
+    where
+        I: IntoIterator<Item = (A, V)>,
+        A: Into<LocalName>,
+        V: Into<StrTendril>,
+    {
+        self.attributes.borrow_mut().extend_from(attributes);
+    }
+
+    /// Sets many attributes at once, keeping any that are already present.
+    ///
+    /// See [`Attributes::extend_missing_from`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the attributes are already borrowed.
+    pub fn set_missing_attributes<I, A, V>(&self, attributes: I)
+    where
+        I: IntoIterator<Item = (A, V)>,
+        A: Into<LocalName>,
+        V: Into<StrTendril>,
+    {
+        self.attributes.borrow_mut().extend_missing_from(attributes);
+    }
+
     /// Returns the namespace prefix of the element, if any.
     ///
     /// **Note:** This method requires the `namespaces` feature to be enabled.
@@ -90,6 +136,66 @@ impl ElementData {
     }
 }
 
+/// Template methods for ElementData.
+///
+/// Provides copying of `<template>` contents into fresh nodes.
+impl ElementData {
+    /// Returns a copy of the template contents, ready to be appended.
+    ///
+    /// The copy is a new document fragment holding fresh copies of the
+    /// contents, including nested template contents. Returns `None` if this
+    /// is not a `<template>` element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::parse_html;
+    /// use brik::traits::*;
+    ///
+    /// let doc = parse_html().one("<template><li>item</li></template><ul></ul>");
+    /// let template = doc.select_first("template").unwrap();
+    /// let ul = doc.select_first("ul").unwrap();
+    /// for _ in 0..2 {
+    ///     let fragment = template.instantiate_template().unwrap();
+    ///     for child in fragment.children() {
+    ///         ul.as_node().append(child);
+    ///     }
+    /// }
+    /// assert_eq!(ul.as_node().to_string(), "<ul><li>item</li><li>item</li></ul>");
+    /// ```
+    pub fn instantiate_template(&self) -> Option<NodeRef> {
+        self.instantiate_template_with(str::to_string)
+    }
+
+    /// Returns a copy of the template contents, substituting values.
+    ///
+    /// Works like [`ElementData::instantiate_template()`], but every text,
+    /// comment and attribute value in the copy is replaced by the result of
+    /// `substitute`, which receives the original value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::parse_html;
+    /// use brik::traits::*;
+    ///
+    /// let doc = parse_html().one(r#"<template><a href="/u/{id}">{name}</a></template>"#);
+    /// let template = doc.select_first("template").unwrap();
+    /// let fragment = template
+    ///     .instantiate_template_with(|value| value.replace("{id}", "7").replace("{name}", "Ada"))
+    ///     .unwrap();
+    /// assert_eq!(fragment.to_string(), r#"<a href="/u/7">Ada</a>"#);
+    /// ```
+    pub fn instantiate_template_with<F>(&self, mut substitute: F) -> Option<NodeRef>
+    where
+        F: FnMut(&str) -> String,
+    {
+        self.template_contents
+            .as_ref()
+            .map(|contents| contents.clone_with(true, &mut substitute))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::parser::parse_html;
@@ -143,6 +249,24 @@ mod tests {
         assert_eq!(body.local_name().as_ref(), "body");
     }
 
+    /// Tests bulk attribute updates through ElementData.
+    ///
+    /// Verifies that set_attributes() overwrites and set_missing_attributes()
+    /// only fills in absent attributes.
+    #[test]
+    fn element_set_attributes() {
+        let document = parse_html().one(r#"<img src="a.png" alt="A">"#);
+        let img = document.select_first("img").unwrap();
+
+        img.set_attributes([("src", "b.png")]);
+        img.set_missing_attributes([("alt", "B"), ("loading", "lazy")]);
+
+        let attrs = img.attributes.borrow();
+        assert_eq!(attrs.get("src"), Some("b.png"));
+        assert_eq!(attrs.get("alt"), Some("A"));
+        assert_eq!(attrs.get("loading"), Some("lazy"));
+    }
+
     /// Tests that `prefix()` returns None for elements without namespace prefixes.
     ///
     /// In HTML5, elements typically don't have namespace prefixes even when
@@ -169,4 +293,33 @@ mod tests {
         let rect = document.select_first("rect").unwrap();
         assert_eq!(rect.prefix(), None);
     }
+    /// Tests instantiating template contents.
+    ///
+    /// Verifies that instances are independent of the template and of each
+    /// other, that nested template contents are copied, and that non-template
+    /// elements return None.
+    #[test]
+    fn instantiate_template() {
+        let doc = parse_html()
+            .one("<template><p class=x>a</p><template><b>n</b></template></template><div></div>");
+        let template = doc.select_first("template").unwrap();
+
+        let first = template.instantiate_template().unwrap();
+        let second = template
+            .instantiate_template_with(|value| value.to_uppercase())
+            .unwrap();
+        first.first_child().unwrap().set_text_content("changed");
+        assert_eq!(
+            second.to_string(),
+            r#"<p class="X">A</p><template><b>N</b></template>"#
+        );
+        assert_eq!(
+            template.template_contents.as_ref().unwrap().to_string(),
+            r#"<p class="x">a</p><template><b>n</b></template>"#
+        );
+        assert!(second.first_child().unwrap().parent().unwrap() == second);
+
+        let div = doc.select_first("div").unwrap();
+        assert!(div.instantiate_template().is_none());
+    }
 }
@@ -0,0 +1,15 @@
+/// Options for [`NodeRef::import_node_with_options()`](crate::NodeRef::import_node_with_options).
+///
+/// The defaults make a shallow copy and keep ids as they are, like DOM
+/// `importNode()`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ImportOptions {
+    /// Copy descendants and template contents, not just the node itself.
+    pub deep: bool,
+    /// Rename imported ids that are already used in the target document.
+    ///
+    /// A clashing id gets the first free `-2`, `-3`, … suffix, and references
+    /// to it from within the imported nodes (`#fragment` links, `for`,
+    /// `headers` and ARIA id references) are updated to match.
+    pub unique_ids: bool,
+}
@@ -2,18 +2,65 @@
 pub mod doctype;
 /// Document node data.
 pub mod document_data;
+/// Document order sorting adapter.
+pub mod document_order;
 /// Element node data.
 pub mod element_data;
+/// Options for importing nodes.
+pub mod import_options;
 /// Node structure and operations.
 pub mod node;
 /// Node type-specific data enum.
 pub mod node_data;
 /// Strong reference to a node.
 pub mod node_ref;
+/// CDATA section text for NodeRef.
+mod node_ref_cdata;
+/// Deep copying for NodeRef.
+mod node_ref_clone_with;
+/// Whitespace collapsing for NodeRef.
+mod node_ref_collapse_whitespace;
+/// Comment removal for NodeRef.
+mod node_ref_comments;
+/// Change tracking for NodeRef.
+mod node_ref_dirty;
+/// Document order comparison for NodeRef.
+mod node_ref_document_order;
+/// Node import for NodeRef.
+mod node_ref_import_node;
+/// Placeholder interpolation for NodeRef.
+mod node_ref_interpolate;
+/// Language resolution for NodeRef.
+mod node_ref_language;
+/// Bulk child removal for NodeRef.
+mod node_ref_retain_children;
+/// Child reordering for NodeRef.
+mod node_ref_sort_children;
+/// Source markup access for NodeRef.
+mod node_ref_source_markup;
+/// Child range replacement for NodeRef.
+mod node_ref_splice_children;
+/// Text node splitting for NodeRef.
+mod node_ref_split_text;
+/// Configurable text extraction for NodeRef.
+mod node_ref_text_contents_with;
+/// Structural equality for NodeRef.
+mod node_ref_tree_eq;
+/// Text streaming for NodeRef.
+mod node_ref_write_text_contents;
+/// Source markup recorded for nodes.
+mod source_markup;
+/// Options for text extraction.
+pub mod text_options;
 
 pub use doctype::Doctype;
 pub use document_data::DocumentData;
+pub use document_order::DocumentOrder;
 pub use element_data::ElementData;
+pub use import_options::ImportOptions;
 pub use node::Node;
 pub use node_data::NodeData;
 pub use node_ref::NodeRef;
+pub use text_options::TextOptions;
+
+pub(crate) use source_markup::SourceMarkup;
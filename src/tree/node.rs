@@ -1,5 +1,7 @@
+use super::source_markup::SourceMarkup;
 use super::{Doctype, DocumentData, ElementData, NodeData, NodeRef};
 use crate::cell_extras::*;
+use html5ever::tendril::StrTendril;
 use std::cell::{Cell, RefCell};
 use std::fmt;
 use std::rc::{Rc, Weak};
@@ -16,6 +18,12 @@ pub struct Node {
     pub(super) first_child: Cell<Option<Rc<Node>>>,
     /// Weak reference to the last child.
     pub(super) last_child: Cell<Option<Weak<Node>>>,
+    /// Whether this node or its descendants changed since last serialized.
+    pub(super) dirty: Cell<bool>,
+    /// Whether this text node was a CDATA section in the source.
+    pub(super) cdata: Cell<bool>,
+    /// The markup this node was parsed from, if recorded.
+    pub(super) source: Cell<Option<Rc<SourceMarkup>>>,
     /// The data contained in this node.
     pub(super) data: NodeData,
 }
@@ -46,8 +54,28 @@ impl fmt::Debug for Node {
 ///
 /// The stack holds ancestors of the current node rather than preceding siblings,
 /// on the assumption that large document trees are typically wider than deep.
+///
+/// Template contents are owned by element data rather than the child chain,
+/// so dropping them in place would recurse once per nested template.
+/// Instead, they are queued and torn down one at a time by the outermost
+/// running `drop` call.
 impl Drop for Node {
     fn drop(&mut self) {
+        let template_contents = match self.data {
+            NodeData::Element(ref mut element) => element.template_contents.take(),
+            _ => None,
+        };
+        let outermost = DEFERRED_TEMPLATE_CONTENTS
+            .try_with(|deferred| {
+                let mut deferred = deferred.borrow_mut();
+                let outermost = deferred.is_none();
+                deferred
+                    .get_or_insert_with(Vec::new)
+                    .extend(template_contents.map(|contents| contents.0));
+                outermost
+            })
+            .unwrap_or(false);
+
         // `.take_if_unique_strong()` temporarily leaves the tree in an inconsistent state,
         // as the corresponding `Weak` reference in the other direction is not removed.
         // It is important that all `Some(_)` strong references it returns
@@ -91,9 +119,26 @@ impl Drop for Node {
                 return;
             }
         }
+
+        if outermost {
+            // Each dropped fragment may queue further template contents.
+            while let Some(contents) = DEFERRED_TEMPLATE_CONTENTS
+                .with(|deferred| deferred.borrow_mut().as_mut().and_then(Vec::pop))
+            {
+                drop(contents);
+            }
+            DEFERRED_TEMPLATE_CONTENTS.with(|deferred| *deferred.borrow_mut() = None);
+        }
     }
 }
 
+thread_local! {
+    /// Template contents awaiting teardown by the outermost running
+    /// `Node::drop`, or `None` when no teardown is in progress.
+    static DEFERRED_TEMPLATE_CONTENTS: RefCell<Option<Vec<Rc<Node>>>> =
+        const { RefCell::new(None) };
+}
+
 /// Core methods for Node.
 ///
 /// Provides accessors for node data, type-specific downcasting,
@@ -116,7 +161,7 @@ impl Node {
 
     /// If this node is a text node, return a reference to its contents.
     #[inline]
-    pub fn as_text(&self) -> Option<&RefCell<String>> {
+    pub fn as_text(&self) -> Option<&RefCell<StrTendril>> {
         match self.data {
             NodeData::Text(ref value) => Some(value),
             _ => None,
@@ -125,7 +170,7 @@ impl Node {
 
     /// If this node is a comment, return a reference to its contents.
     #[inline]
-    pub fn as_comment(&self) -> Option<&RefCell<String>> {
+    pub fn as_comment(&self) -> Option<&RefCell<StrTendril>> {
         match self.data {
             NodeData::Comment(ref value) => Some(value),
             _ => None,
@@ -229,6 +274,9 @@ impl Node {
                 parent_strong.first_child.replace(next_sibling_strong);
             }
         }
+        if let Some(parent_strong) = parent_weak.and_then(|weak| weak.upgrade()) {
+            parent_strong.mark_dirty();
+        }
     }
 }
 
@@ -236,6 +284,8 @@ impl Node {
 mod tests {
     use crate::html5ever::tendril::TendrilSink;
     use crate::parse_html;
+    use crate::NodeRef;
+    use html5ever::QualName;
 
     /// Tests that `as_text()` correctly extracts text content from a text node.
     ///
@@ -249,7 +299,7 @@ mod tests {
 
         let text_node = div.as_node().first_child().unwrap();
         assert!(text_node.as_text().is_some());
-        assert_eq!(&*text_node.as_text().unwrap().borrow(), "text content");
+        assert_eq!(&**text_node.as_text().unwrap().borrow(), "text content");
     }
 
     /// Tests that `as_comment()` correctly extracts comment content.
@@ -264,7 +314,7 @@ mod tests {
         let comment_node = doc.first_child().unwrap();
         assert!(comment_node.as_comment().is_some());
         assert_eq!(
-            &*comment_node.as_comment().unwrap().borrow(),
+            &**comment_node.as_comment().unwrap().borrow(),
             " comment text "
         );
     }
@@ -369,4 +419,47 @@ mod tests {
         let debug_str = format!("{:?}", div.as_node());
         assert!(debug_str.contains("Element"));
     }
+
+    /// Tests dropping a pathologically deep tree.
+    ///
+    /// Verifies that 100,000 nested elements are torn down without
+    /// overflowing the stack.
+    #[test]
+    fn drop_deep_tree() {
+        let root = NodeRef::new_document();
+        let mut parent = root.clone();
+        for _ in 0..100_000 {
+            let child =
+                NodeRef::new_element(QualName::new(None, ns!(html), local_name!("div")), None);
+            parent.append(child.clone());
+            parent = child;
+        }
+        drop(parent);
+        drop(root);
+    }
+
+    /// Tests dropping deeply nested template contents.
+    ///
+    /// Edge case: each template owns its contents outside the child chain,
+    /// so teardown must follow template contents iteratively as well.
+    #[test]
+    fn drop_deep_templates() {
+        let root = NodeRef::new_document();
+        let mut parent = root.clone();
+        for _ in 0..100_000 {
+            let template = NodeRef::new_element(
+                QualName::new(None, ns!(html), local_name!("template")),
+                None,
+            );
+            parent.append(template.clone());
+            parent = template
+                .as_element()
+                .unwrap()
+                .template_contents
+                .clone()
+                .unwrap();
+        }
+        drop(parent);
+        drop(root);
+    }
 }
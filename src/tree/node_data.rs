@@ -1,3 +1,4 @@
+use html5ever::tendril::StrTendril;
 use std::cell::RefCell;
 
 use super::{Doctype, DocumentData, ElementData};
@@ -9,10 +10,10 @@ pub enum NodeData {
     Element(ElementData),
 
     /// Text node
-    Text(RefCell<String>),
+    Text(RefCell<StrTendril>),
 
     /// Comment node
-    Comment(RefCell<String>),
+    Comment(RefCell<StrTendril>),
 
     /// Processing instruction node
     ProcessingInstruction(RefCell<(String, String)>),
@@ -2,9 +2,12 @@ use super::{Doctype, DocumentData, ElementData, Node, NodeData};
 use crate::attributes::{Attribute, Attributes, ExpandedName};
 use crate::cell_extras::*;
 use crate::iter::NodeIterator;
+use crate::node_data_ref::NodeDataRef;
+use html5ever::tendril::StrTendril;
 use html5ever::tree_builder::QuirksMode;
 use html5ever::QualName;
 use std::cell::{Cell, RefCell};
+use std::hash::{Hash, Hasher};
 use std::ops::Deref;
 use std::rc::Rc;
 
@@ -58,6 +61,17 @@ impl PartialEq for NodeRef {
     }
 }
 
+/// Implements Hash for NodeRef using pointer identity.
+///
+/// Hashes the memory address of the underlying Node, consistently with
+/// `PartialEq`, so nodes can be used as `HashMap` keys and `HashSet` members.
+impl Hash for NodeRef {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Rc::as_ptr(&self.0).hash(state);
+    }
+}
+
 /// Factory methods and tree manipulation for NodeRef.
 ///
 /// Provides constructors for all node types (elements, text, comments, etc.)
@@ -72,6 +86,9 @@ impl NodeRef {
             last_child: Cell::new(None),
             previous_sibling: Cell::new(None),
             next_sibling: Cell::new(None),
+            dirty: Cell::new(true),
+            cdata: Cell::new(false),
+            source: Cell::new(None),
             data,
         }))
     }
@@ -97,13 +114,13 @@ impl NodeRef {
 
     /// Create a new text node.
     #[inline]
-    pub fn new_text<T: Into<String>>(value: T) -> NodeRef {
+    pub fn new_text<T: Into<StrTendril>>(value: T) -> NodeRef {
         NodeRef::new(NodeData::Text(RefCell::new(value.into())))
     }
 
     /// Create a new comment node.
     #[inline]
-    pub fn new_comment<T: Into<String>>(value: T) -> NodeRef {
+    pub fn new_comment<T: Into<StrTendril>>(value: T) -> NodeRef {
         NodeRef::new(NodeData::Comment(RefCell::new(value.into())))
     }
 
@@ -140,6 +157,8 @@ impl NodeRef {
     pub fn new_document() -> NodeRef {
         NodeRef::new(NodeData::Document(DocumentData {
             _quirks_mode: Cell::new(QuirksMode::NoQuirks),
+            _parse_diagnostics: RefCell::default(),
+            _base_url: RefCell::default(),
         }))
     }
 
@@ -152,6 +171,47 @@ impl NodeRef {
         s
     }
 
+    /// Replace the text of this node, like assigning to DOM `textContent`.
+    ///
+    /// Elements, documents and document fragments lose all their children
+    /// and gain a single text node, unless `text` is empty. Text, comment and
+    /// processing instruction nodes have their value replaced. Doctype nodes
+    /// are left unchanged.
+    ///
+    /// For a `<template>` element only its children are replaced; its
+    /// template contents fragment is left untouched, as in the DOM.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::parse_html;
+    /// use brik::traits::*;
+    ///
+    /// let doc = parse_html().one("<p>Hello <b>World</b></p>");
+    /// let p = doc.select_first("p").unwrap();
+    /// p.as_node().set_text_content("Bye");
+    /// assert_eq!(p.as_node().to_string(), "<p>Bye</p>");
+    /// ```
+    pub fn set_text_content(&self, text: &str) {
+        match self.data() {
+            NodeData::Text(value) | NodeData::Comment(value) => {
+                *value.borrow_mut() = text.into();
+                self.mark_dirty();
+            }
+            NodeData::ProcessingInstruction(value) => {
+                value.borrow_mut().1 = text.to_string();
+                self.mark_dirty();
+            }
+            NodeData::Doctype(_) => {}
+            NodeData::Element(_) | NodeData::Document(_) | NodeData::DocumentFragment => {
+                self.clear_children();
+                if !text.is_empty() {
+                    self.append(NodeRef::new_text(text));
+                }
+            }
+        }
+    }
+
     /// Append a new child to this node, after existing children.
     ///
     /// The new child is detached from its previous position.
@@ -162,12 +222,14 @@ impl NodeRef {
             if let Some(last_child) = last_child_weak.upgrade() {
                 new_child.previous_sibling.replace(Some(last_child_weak));
                 debug_assert!(last_child.next_sibling.is_none());
-                last_child.next_sibling.replace(Some(new_child.0));
+                last_child.next_sibling.replace(Some(new_child.0.clone()));
+                new_child.mark_inserted();
                 return;
             }
         }
         debug_assert!(self.first_child.is_none());
-        self.first_child.replace(Some(new_child.0));
+        self.first_child.replace(Some(new_child.0.clone()));
+        new_child.mark_inserted();
     }
 
     /// Prepend a new child to this node, before existing children.
@@ -186,7 +248,8 @@ impl NodeRef {
             debug_assert!(self.first_child.is_none());
             self.last_child.replace(Some(Rc::downgrade(&new_child.0)));
         }
-        self.first_child.replace(Some(new_child.0));
+        self.first_child.replace(Some(new_child.0.clone()));
+        new_child.mark_inserted();
     }
 
     /// Insert a new sibling after this node.
@@ -214,7 +277,8 @@ impl NodeRef {
                 .last_child
                 .replace(Some(Rc::downgrade(&new_sibling.0)));
         }
-        self.next_sibling.replace(Some(new_sibling.0));
+        self.next_sibling.replace(Some(new_sibling.0.clone()));
+        new_sibling.mark_inserted();
     }
 
     /// Insert a new sibling before this node.
@@ -237,14 +301,18 @@ impl NodeRef {
                     .previous_sibling
                     .replace(Some(previous_sibling_weak));
                 debug_assert!(previous_sibling.next_sibling().unwrap() == *self);
-                previous_sibling.next_sibling.replace(Some(new_sibling.0));
+                previous_sibling
+                    .next_sibling
+                    .replace(Some(new_sibling.0.clone()));
+                new_sibling.mark_inserted();
                 return;
             }
         }
         if let Some(parent) = self.parent() {
             debug_assert!(parent.first_child().unwrap() == *self);
-            parent.first_child.replace(Some(new_sibling.0));
+            parent.first_child.replace(Some(new_sibling.0.clone()));
         }
+        new_sibling.mark_inserted();
     }
 
     /// Applies xmlns namespace declarations to elements and attributes (lenient).
@@ -336,6 +404,7 @@ impl NodeRef {
     /// let options = NsOptions {
     ///     namespaces,
     ///     strict: true,
+    ///     ..NsOptions::default()
     /// };
     ///
     /// match doc.apply_xmlns_opts(&options) {
@@ -400,13 +469,101 @@ impl NodeRef {
         crate::ns::apply_xmlns_opts(
             self,
             &crate::ns::NsOptions {
-                namespaces: std::collections::HashMap::new(),
                 strict: true,
+                ..crate::ns::NsOptions::default()
             },
         )
     }
 }
 
+/// Doctype helpers for NodeRef.
+///
+/// Provides finding and replacing the doctype child of a document node.
+impl NodeRef {
+    /// Returns the doctype child of this node, if any.
+    ///
+    /// This is meant to be called on a document node.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::parse_html;
+    /// use brik::traits::*;
+    ///
+    /// let doc = parse_html().one("<!DOCTYPE html><p>Hello</p>");
+    /// assert_eq!(doc.doctype().unwrap().name, "html");
+    ///
+    /// let legacy = parse_html().one("<p>Hello</p>");
+    /// assert!(legacy.doctype().is_none());
+    /// ```
+    pub fn doctype(&self) -> Option<NodeDataRef<Doctype>> {
+        self.children().find_map(NodeRef::into_doctype_ref)
+    }
+
+    /// Sets the doctype of this document node, replacing any existing doctype.
+    ///
+    /// A new doctype is inserted as the first child of the document.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::parse_html;
+    /// use brik::traits::*;
+    ///
+    /// let doc = parse_html().one("<p>Hello</p>");
+    /// doc.set_doctype(
+    ///     "html",
+    ///     "-//W3C//DTD XHTML 1.0 Strict//EN",
+    ///     "http://www.w3.org/TR/xhtml1/DTD/xhtml1-strict.dtd",
+    /// );
+    /// assert_eq!(doc.doctype().unwrap().public_id, "-//W3C//DTD XHTML 1.0 Strict//EN");
+    /// ```
+    pub fn set_doctype<T1, T2, T3>(&self, name: T1, public_id: T2, system_id: T3)
+    where
+        T1: Into<String>,
+        T2: Into<String>,
+        T3: Into<String>,
+    {
+        let doctype = NodeRef::new_doctype(name, public_id, system_id);
+        match self.doctype() {
+            Some(existing) => {
+                existing.as_node().insert_before(doctype);
+                existing.as_node().detach();
+            }
+            None => self.prepend(doctype),
+        }
+    }
+
+    /// Ensures this document node has the HTML5 doctype, `<!DOCTYPE html>`.
+    ///
+    /// Any other doctype is replaced. Returns true if the document was changed.
+    ///
+    /// This does not change the quirks mode recorded by the parser.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::parse_html;
+    /// use brik::traits::*;
+    ///
+    /// let doc = parse_html().one("<p>Hello</p>");
+    /// assert!(doc.ensure_html5_doctype());
+    /// assert!(!doc.ensure_html5_doctype());
+    /// assert!(doc.to_string().starts_with("<!DOCTYPE html><html>"));
+    /// ```
+    pub fn ensure_html5_doctype(&self) -> bool {
+        let is_html5 = self.doctype().is_some_and(|doctype| {
+            doctype.name.eq_ignore_ascii_case("html")
+                && doctype.public_id.is_empty()
+                && doctype.system_id.is_empty()
+        });
+        if !is_html5 {
+            self.set_doctype("html", "", "");
+        }
+        !is_html5
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -435,7 +592,7 @@ mod tests {
         let text = NodeRef::new_text("Hello World");
 
         assert!(text.as_text().is_some());
-        assert_eq!(&*text.as_text().unwrap().borrow(), "Hello World");
+        assert_eq!(&**text.as_text().unwrap().borrow(), "Hello World");
     }
 
     /// Tests that `new_comment()` creates a comment node with the specified content.
@@ -448,7 +605,7 @@ mod tests {
 
         assert!(comment.as_comment().is_some());
         assert_eq!(
-            &*comment.as_comment().unwrap().borrow(),
+            &**comment.as_comment().unwrap().borrow(),
             "This is a comment"
         );
     }
@@ -501,6 +658,30 @@ mod tests {
         assert_eq!(div.as_node().text_contents(), "Hello World!");
     }
 
+    /// Tests that `set_text_content()` replaces node text.
+    ///
+    /// Verifies that element children are replaced by one text node (or none
+    /// for empty text), that comment values are replaced, and that template
+    /// contents are left untouched.
+    #[test]
+    fn set_text_content() {
+        let doc = parse_html().one("<div>a<b>b</b><!--c--></div><template><i>t</i></template>");
+        let div = doc.select_first("div").unwrap();
+        let div = div.as_node();
+
+        div.last_child().unwrap().set_text_content("note");
+        assert_eq!(div.to_string(), "<div>a<b>b</b><!--note--></div>");
+        div.set_text_content("x < y");
+        assert_eq!(div.to_string(), "<div>x &lt; y</div>");
+        div.set_text_content("");
+        assert!(div.first_child().is_none());
+
+        let template = doc.select_first("template").unwrap();
+        template.as_node().set_text_content("ignored");
+        let contents = template.template_contents.as_ref().unwrap();
+        assert_eq!(contents.to_string(), "<i>t</i>");
+    }
+
     /// Tests that `append()` adds children in the correct order.
     ///
     /// Appends two text nodes and verifies that first_child, last_child,
@@ -662,4 +843,42 @@ mod tests {
         assert_eq!(parent.first_child().unwrap(), child2);
         assert!(child2.previous_sibling().is_none());
     }
+
+    /// Tests replacing an existing doctype with set_doctype().
+    ///
+    /// Verifies that the replacement keeps the doctype's position before the
+    /// root element and that only one doctype remains.
+    #[test]
+    fn set_doctype_replaces() {
+        let doc = parse_html()
+            .one(r#"<!DOCTYPE html PUBLIC "-//W3C//DTD HTML 4.01//EN"><!-- c --><p>Hi</p>"#);
+        assert_eq!(
+            doc.doctype().unwrap().public_id,
+            "-//W3C//DTD HTML 4.01//EN"
+        );
+
+        assert!(doc.ensure_html5_doctype());
+        let doctypes = doc
+            .children()
+            .filter(|child| child.as_doctype().is_some())
+            .count();
+        assert_eq!(doctypes, 1);
+        assert!(doc.first_child().unwrap().as_doctype().is_some());
+        assert_eq!(doc.doctype().unwrap().public_id, "");
+        assert!(doc
+            .to_string()
+            .starts_with("<!DOCTYPE html><!-- c --><html>"));
+    }
+
+    /// Tests doctype helpers on a document without children.
+    ///
+    /// Edge case: an empty document gets the doctype as its only child.
+    #[test]
+    fn set_doctype_empty_document() {
+        let doc = NodeRef::new_document();
+        assert!(doc.doctype().is_none());
+        doc.set_doctype("html", "", "");
+        assert_eq!(doc.children().count(), 1);
+        assert!(!doc.ensure_html5_doctype());
+    }
 }
@@ -0,0 +1,45 @@
+//! CDATA section text for node trees.
+
+use super::{Node, NodeData, NodeRef};
+use html5ever::tendril::StrTendril;
+use std::cell::RefCell;
+
+/// CDATA section methods for Node.
+///
+/// Provides the marker kept by
+/// [`ParseOpts::preserve_cdata`](crate::ParseOpts::preserve_cdata).
+impl Node {
+    /// Returns whether this is a text node holding a CDATA section.
+    ///
+    /// CDATA sections are text nodes, so text extraction and selectors
+    /// treat them like any other text. XML output, such as
+    /// `NodeRef::xml_events()`, writes them as CDATA sections again,
+    /// while HTML output escapes them as text. The marker belongs to the
+    /// node and is not carried over to copies.
+    #[inline]
+    pub fn is_cdata_section(&self) -> bool {
+        self.cdata.get()
+    }
+}
+
+/// CDATA section methods for NodeRef.
+///
+/// Provides construction of CDATA section text nodes.
+impl NodeRef {
+    /// Create a new text node marked as a CDATA section.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::NodeRef;
+    ///
+    /// let section = NodeRef::new_cdata_section("a < b");
+    /// assert!(section.is_cdata_section());
+    /// assert_eq!(section.text_contents(), "a < b");
+    /// ```
+    pub fn new_cdata_section<T: Into<StrTendril>>(value: T) -> NodeRef {
+        let node = NodeRef::new(NodeData::Text(RefCell::new(value.into())));
+        node.cdata.set(true);
+        node
+    }
+}
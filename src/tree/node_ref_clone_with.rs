@@ -0,0 +1,60 @@
+//! Deep copying of node trees.
+
+use super::{DocumentData, ElementData, NodeData, NodeRef};
+use std::cell::{Cell, RefCell};
+
+/// Deep copy methods for NodeRef.
+///
+/// Provides copying of a subtree into fresh, detached nodes.
+impl NodeRef {
+    /// Returns a detached copy of this node, and of its descendants if `deep`.
+    ///
+    /// Template contents are copied along with descendants; a shallow copy
+    /// of a template gets empty contents. Every text, comment and attribute
+    /// value passes through `substitute`, whose result is stored in the copy.
+    pub(crate) fn clone_with(
+        &self,
+        deep: bool,
+        substitute: &mut dyn FnMut(&str) -> String,
+    ) -> NodeRef {
+        let copy = NodeRef::new(match self.data() {
+            NodeData::Element(element) => {
+                let mut attributes = element.attributes.borrow().clone();
+                for attribute in attributes.map.values_mut() {
+                    attribute.value = substitute(&attribute.value).into();
+                }
+                NodeData::Element(ElementData {
+                    name: element.name.clone(),
+                    attributes: RefCell::new(attributes),
+                    template_contents: element.template_contents.as_ref().map(|contents| {
+                        if deep {
+                            contents.clone_with(true, substitute)
+                        } else {
+                            NodeRef::new(NodeData::DocumentFragment)
+                        }
+                    }),
+                })
+            }
+            NodeData::Text(text) => NodeData::Text(RefCell::new(substitute(&text.borrow()).into())),
+            NodeData::Comment(text) => {
+                NodeData::Comment(RefCell::new(substitute(&text.borrow()).into()))
+            }
+            NodeData::ProcessingInstruction(value) => {
+                NodeData::ProcessingInstruction(RefCell::new(value.borrow().clone()))
+            }
+            NodeData::Doctype(doctype) => NodeData::Doctype(doctype.clone()),
+            NodeData::Document(document) => NodeData::Document(DocumentData {
+                _quirks_mode: Cell::new(document.quirks_mode()),
+                _parse_diagnostics: RefCell::default(),
+                _base_url: RefCell::new(document.base_url()),
+            }),
+            NodeData::DocumentFragment => NodeData::DocumentFragment,
+        });
+        if deep {
+            for child in self.children() {
+                copy.append(child.clone_with(true, substitute));
+            }
+        }
+        copy
+    }
+}
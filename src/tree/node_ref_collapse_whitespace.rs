@@ -0,0 +1,247 @@
+//! Whitespace collapsing of node trees.
+
+use super::NodeRef;
+use crate::attributes::{Attributes, ExpandedName};
+
+/// Elements whose text keeps its whitespace.
+const PRESERVING_ELEMENTS: [&str; 7] = [
+    "listing",
+    "plaintext",
+    "pre",
+    "script",
+    "style",
+    "textarea",
+    "xmp",
+];
+
+/// Elements that start and end a line, so whitespace next to them is insignificant.
+pub(super) const BLOCK_ELEMENTS: [&str; 45] = [
+    "address",
+    "article",
+    "aside",
+    "blockquote",
+    "body",
+    "br",
+    "caption",
+    "dd",
+    "details",
+    "dialog",
+    "div",
+    "dl",
+    "dt",
+    "fieldset",
+    "figcaption",
+    "figure",
+    "footer",
+    "form",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "head",
+    "header",
+    "hgroup",
+    "hr",
+    "html",
+    "li",
+    "main",
+    "nav",
+    "ol",
+    "p",
+    "pre",
+    "section",
+    "summary",
+    "table",
+    "tbody",
+    "td",
+    "tfoot",
+    "th",
+    "thead",
+    "tr",
+    "ul",
+];
+
+/// Whitespace methods for NodeRef.
+///
+/// Provides removal of formatting whitespace from text nodes.
+impl NodeRef {
+    /// Collapses formatting whitespace in the text nodes of this subtree.
+    ///
+    /// Runs of ASCII whitespace become a single space. Whitespace at the start
+    /// or end of a text node is removed when it begins or ends its parent, or
+    /// is next to a block-level element such as `<div>`, `<p>`, `<li>` or
+    /// `<br>`. Text nodes left empty are removed.
+    ///
+    /// Text inside `<pre>`, `<textarea>`, `<script>`, `<style>`, `<listing>`,
+    /// `<plaintext>` and `<xmp>`, or inside an element whose `style` attribute
+    /// sets `white-space` to `pre`, `pre-wrap`, `pre-line` or `break-spaces`, is
+    /// left unchanged, as is text whose nearest `xml:space` declaration is
+    /// `preserve`. Template contents are not processed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::parse_html;
+    /// use brik::traits::*;
+    ///
+    /// let doc = parse_html().one("<ul>\n  <li> One  <b>two</b> </li>\n</ul><pre> a  b </pre>");
+    /// doc.collapse_whitespace();
+    /// let body = doc.select_first("body").unwrap();
+    /// assert_eq!(
+    ///     body.as_node().to_string(),
+    ///     "<body><ul><li>One <b>two</b></li></ul><pre> a  b </pre></body>"
+    /// );
+    /// ```
+    pub fn collapse_whitespace(&self) {
+        let text_nodes: Vec<NodeRef> = self
+            .inclusive_descendants()
+            .filter(|node| node.as_text().is_some() && !preserves_whitespace(node))
+            .collect();
+        for node in text_nodes {
+            let Some(text) = node.as_text() else {
+                continue;
+            };
+            let mut collapsed = String::new();
+            for (index, word) in text.borrow().split_ascii_whitespace().enumerate() {
+                if index > 0 {
+                    collapsed.push(' ');
+                }
+                collapsed.push_str(word);
+            }
+            let value = text.borrow();
+            let starts_with_space = value.starts_with(|c: char| c.is_ascii_whitespace());
+            let ends_with_space = value.ends_with(|c: char| c.is_ascii_whitespace());
+            drop(value);
+
+            let at_start = is_line_boundary(node.previous_sibling());
+            let at_end = is_line_boundary(node.next_sibling());
+            if collapsed.is_empty() {
+                if at_start || at_end {
+                    node.detach();
+                } else if starts_with_space {
+                    *text.borrow_mut() = " ".into();
+                    node.mark_dirty();
+                }
+                continue;
+            }
+            if starts_with_space && !at_start {
+                collapsed.insert(0, ' ');
+            }
+            if ends_with_space && !at_end {
+                collapsed.push(' ');
+            }
+            *text.borrow_mut() = collapsed.into();
+            node.mark_dirty();
+        }
+    }
+}
+
+/// Returns whether a sibling position marks the start or end of a line.
+///
+/// This is the case at the edge of the parent, and next to a block-level element.
+fn is_line_boundary(sibling: Option<NodeRef>) -> bool {
+    match sibling {
+        None => true,
+        Some(sibling) => sibling.as_element().is_some_and(|element| {
+            element.name.ns == ns!(html) && BLOCK_ELEMENTS.contains(&&*element.name.local)
+        }),
+    }
+}
+
+/// Returns whether a node is inside an element that preserves whitespace.
+fn preserves_whitespace(node: &NodeRef) -> bool {
+    let xml_space = node.ancestors().find_map(|ancestor| {
+        let element = ancestor.as_element()?;
+        let attributes = element.attributes.borrow();
+        xml_space(&attributes).map(|value| value == "preserve")
+    });
+    if xml_space == Some(true) {
+        return true;
+    }
+    node.ancestors().any(|ancestor| {
+        ancestor.as_element().is_some_and(|element| {
+            if element.name.ns == ns!(html) && PRESERVING_ELEMENTS.contains(&&*element.name.local) {
+                return true;
+            }
+            let attributes = element.attributes.borrow();
+            let Some(style) = attributes.get(local_name!("style")) else {
+                return false;
+            };
+            style.split(';').any(|declaration| {
+                let mut parts = declaration.splitn(2, ':');
+                let name = parts.next().unwrap_or_default().trim();
+                let value = parts.next().unwrap_or_default().trim();
+                name.eq_ignore_ascii_case("white-space")
+                    && ["pre", "pre-wrap", "pre-line", "break-spaces"]
+                        .iter()
+                        .any(|preserving| value.eq_ignore_ascii_case(preserving))
+            })
+        })
+    })
+}
+
+/// Returns the trimmed value of an `xml:space` attribute, in the XML
+/// namespace or, before namespaces are applied, under its literal name.
+fn xml_space(attributes: &Attributes) -> Option<&str> {
+    attributes
+        .map
+        .get(&ExpandedName::new(ns!(xml), local_name!("space")))
+        .map(|attribute| &*attribute.value)
+        .or_else(|| attributes.get("xml:space"))
+        .map(str::trim)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::html5ever::tendril::TendrilSink;
+    use crate::parse_html;
+
+    /// Tests collapsing around inline and block elements.
+    ///
+    /// Verifies that single spaces between inline elements are kept, while
+    /// whitespace next to block elements and at parent edges is removed.
+    #[test]
+    fn inline_and_block() {
+        let doc = parse_html()
+            .one("<div>\n  <span>a</span>   <span>b</span>\n  <p>\tc\n d </p>  e<br>\n f\n</div>");
+        doc.collapse_whitespace();
+        let div = doc.select_first("div").unwrap();
+        assert_eq!(
+            div.as_node().to_string(),
+            "<div><span>a</span> <span>b</span><p>c d</p>e<br>f</div>"
+        );
+    }
+
+    /// Tests whitespace-preserving elements and styles.
+    ///
+    /// Edge case: text inside `<textarea>`, `<script>` and elements styled
+    /// with `white-space: pre-wrap` is left unchanged.
+    #[test]
+    fn preserved() {
+        let doc = parse_html().one(
+            "<textarea> a  b </textarea><script> x\n  y </script>\
+             <div style='color: red; White-Space: Pre-Wrap'> c  d </div>",
+        );
+        doc.collapse_whitespace();
+        let body = doc.select_first("body").unwrap();
+        assert_eq!(body.as_node().text_contents(), " a  b  x\n  y  c  d ");
+    }
+
+    /// Tests `xml:space` declarations.
+    ///
+    /// Verifies that `preserve` keeps whitespace in descendants and that a
+    /// nearer `default` turns collapsing back on.
+    #[test]
+    fn xml_space() {
+        let doc = parse_html().one(
+            "<div xml:space=preserve> a  b <p xml:space=default> c  d </p></div>             <svg><text xml:space='preserve'> e  f </text></svg>",
+        );
+        doc.collapse_whitespace();
+        let div = doc.select_first("div").unwrap();
+        assert_eq!(div.as_node().text_contents(), " a  b c d");
+        let text = doc.select_first("text").unwrap();
+        assert_eq!(text.text_contents(), " e  f ");
+    }
+}
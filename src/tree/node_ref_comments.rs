@@ -0,0 +1,79 @@
+//! Comment removal for node trees.
+
+use super::NodeRef;
+
+/// Comment methods for NodeRef.
+///
+/// Provides removal of comment nodes from a subtree.
+impl NodeRef {
+    /// Removes every comment node in this subtree.
+    ///
+    /// Returns the number of comments removed. Template contents are not
+    /// processed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::parse_html;
+    /// use brik::traits::*;
+    ///
+    /// let doc = parse_html().one("<p>a<!-- one -->b<!-- two --></p>");
+    /// assert_eq!(doc.remove_comments(), 2);
+    /// assert_eq!(doc.select_first("p").unwrap().as_node().to_string(), "<p>ab</p>");
+    /// ```
+    pub fn remove_comments(&self) -> usize {
+        self.retain_comments(|_| false)
+    }
+
+    /// Removes the comment nodes in this subtree for which `keep` returns false.
+    ///
+    /// The predicate receives the comment text. Returns the number of comments
+    /// removed. Template contents are not processed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::parse_html;
+    /// use brik::traits::*;
+    ///
+    /// let doc = parse_html().one("<p><!-- note --><!--[if IE]>old<![endif]--></p>");
+    /// assert_eq!(doc.retain_comments(|text| text.starts_with("[if")), 1);
+    /// ```
+    pub fn retain_comments<F>(&self, mut keep: F) -> usize
+    where
+        F: FnMut(&str) -> bool,
+    {
+        let removed: Vec<NodeRef> = self
+            .inclusive_descendants()
+            .filter(|node| {
+                node.as_comment()
+                    .is_some_and(|comment| !keep(&comment.borrow()))
+            })
+            .collect();
+        for node in &removed {
+            node.detach();
+        }
+        removed.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::html5ever::tendril::TendrilSink;
+    use crate::parse_html;
+
+    /// Tests retaining comments by predicate.
+    ///
+    /// Verifies that only rejected comments are removed, including comments
+    /// outside the root element, and that the count is returned.
+    #[test]
+    fn retain() {
+        let doc =
+            parse_html().one("<!--a--><html><body><!--keep-->x<!--b--></body></html><!--c-->");
+        assert_eq!(doc.retain_comments(|text| text == "keep"), 3);
+        assert_eq!(
+            doc.to_string(),
+            "<html><head></head><body><!--keep-->x</body></html>"
+        );
+    }
+}
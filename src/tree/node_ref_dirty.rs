@@ -0,0 +1,140 @@
+//! Change tracking for nodes.
+
+use super::{ElementData, Node, NodeRef};
+use crate::attributes::Attributes;
+use crate::node_data_ref::NodeDataRef;
+use std::cell::RefMut;
+use std::rc::{Rc, Weak};
+
+/// Change tracking methods for Node.
+///
+/// Provides the dirty flag used by
+/// [`SerializeCache`](crate::SerializeCache) to find unchanged
+/// subtrees.
+impl Node {
+    /// Returns whether this node or one of its descendants may have changed
+    /// since it was last serialized by a
+    /// [`SerializeCache`](crate::SerializeCache).
+    ///
+    /// New nodes are dirty. Inserting or removing children marks a node and
+    /// its ancestors dirty. The descendants of an inserted node keep their
+    /// flags; a moved subtree is treated as changed through its root.
+    /// The crate's editing helpers mark the nodes they change. Changes made
+    /// in place through node data, such as `attributes.borrow_mut()` or
+    /// `as_text()`, are not seen; follow them
+    /// with [`Node::mark_dirty()`] or use
+    /// [`NodeDataRef::attributes_mut()`](crate::NodeDataRef::attributes_mut).
+    #[inline]
+    pub fn is_dirty(&self) -> bool {
+        self.dirty.get()
+    }
+
+    /// Marks this node and its ancestors as changed.
+    ///
+    /// A dirty node always has dirty ancestors, so the walk stops at the
+    /// first node that was already dirty.
+    pub fn mark_dirty(&self) {
+        if self.dirty.replace(true) {
+            return;
+        }
+        let mut next = self.parent();
+        while let Some(node) = next {
+            if node.dirty.replace(true) {
+                break;
+            }
+            next = node.parent();
+        }
+    }
+
+    /// Marks this node as unchanged since it was last serialized.
+    pub(crate) fn mark_clean(&self) {
+        self.dirty.set(false);
+    }
+}
+
+/// Change tracking methods for NodeRef.
+///
+/// Provides marking of inserted nodes.
+impl NodeRef {
+    /// Marks this node and its ancestors as changed.
+    ///
+    /// Used when a node is inserted. Only the inserted node is marked, so
+    /// building a tree stays linear; its descendants are rewritten because
+    /// their parent changed.
+    pub(crate) fn mark_inserted(&self) {
+        self.dirty.set(true);
+        if let Some(parent) = self.parent() {
+            parent.mark_dirty();
+        }
+    }
+
+    /// Returns a weak reference to this node, for identity keys that do
+    /// not keep the node alive.
+    pub(crate) fn downgrade(&self) -> Weak<Node> {
+        Rc::downgrade(&self.0)
+    }
+}
+
+/// Change tracking methods for element references.
+///
+/// Provides tracked access to attributes.
+impl NodeDataRef<ElementData> {
+    /// Mutably borrows the attributes of this element, marking it dirty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the attributes are currently borrowed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::parse_html;
+    /// use brik::traits::*;
+    ///
+    /// let doc = parse_html().one("<p>Text</p>");
+    /// let p = doc.select_first("p").unwrap();
+    /// p.attributes_mut().insert("class", "lead");
+    /// assert!(p.as_node().is_dirty());
+    /// assert_eq!(p.attributes.borrow().get("class"), Some("lead"));
+    /// ```
+    pub fn attributes_mut(&self) -> RefMut<'_, Attributes> {
+        self.as_node().mark_dirty();
+        self.attributes.borrow_mut()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::html5ever::tendril::TendrilSink;
+    use crate::parse_html;
+
+    /// Tests dirty flag propagation.
+    ///
+    /// Verifies that changes mark their ancestors dirty and leave unrelated
+    /// subtrees clean.
+    #[test]
+    fn mutations_mark_ancestors_dirty() {
+        let doc = parse_html().one("<div><p>One</p></div><section><b>Two</b></section>");
+        for node in doc.inclusive_descendants() {
+            node.mark_clean();
+        }
+        let p = doc.select_first("p").unwrap();
+        let section = doc.select_first("section").unwrap();
+
+        p.attributes_mut().insert("class", "x".to_string());
+        assert!(p.as_node().is_dirty());
+        assert!(doc.select_first("div").unwrap().as_node().is_dirty());
+        assert!(doc.is_dirty());
+        assert!(!section.as_node().is_dirty());
+
+        for node in doc.inclusive_descendants() {
+            node.mark_clean();
+        }
+        let b = doc.select_first("b").unwrap();
+        p.as_node().append(b.as_node().clone());
+        assert!(section.as_node().is_dirty());
+        assert!(b.as_node().is_dirty());
+        assert!(!b.as_node().first_child().unwrap().is_dirty());
+        assert!(!p.as_node().first_child().unwrap().is_dirty());
+    }
+}
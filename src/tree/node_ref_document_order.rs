@@ -0,0 +1,55 @@
+//! Document order comparison for node trees.
+
+use super::NodeRef;
+use std::cmp::Ordering;
+use std::rc::Rc;
+
+/// Document order methods for NodeRef.
+///
+/// Provides comparison of node positions in tree order.
+impl NodeRef {
+    /// Compares the positions of this node and `other` in document order.
+    ///
+    /// A node comes before its descendants and its following siblings, as
+    /// in a preorder traversal. Nodes in different trees, including
+    /// template contents, are ordered consistently by the identity of their
+    /// roots for as long as both trees exist.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::parse_html;
+    /// use brik::traits::*;
+    ///
+    /// let doc = parse_html().one("<p>One</p><p>Two</p>");
+    /// let mut nodes: Vec<_> = doc.descendants().collect();
+    /// nodes.reverse();
+    /// nodes.sort_by(|a, b| a.cmp_document_order(b));
+    /// assert_eq!(nodes, doc.descendants().collect::<Vec<_>>());
+    /// ```
+    pub fn cmp_document_order(&self, other: &NodeRef) -> Ordering {
+        if self == other {
+            return Ordering::Equal;
+        }
+        let chain = |node: &NodeRef| {
+            let mut chain: Vec<NodeRef> = node.inclusive_ancestors().collect();
+            chain.reverse();
+            chain
+        };
+        let (self_chain, other_chain) = (chain(self), chain(other));
+        if self_chain[0] != other_chain[0] {
+            return Rc::as_ptr(&self_chain[0].0).cmp(&Rc::as_ptr(&other_chain[0].0));
+        }
+        for (a, b) in self_chain.iter().zip(&other_chain) {
+            if a != b {
+                let b_follows = a.following_siblings().any(|sibling| sibling == *b);
+                return if b_follows {
+                    Ordering::Less
+                } else {
+                    Ordering::Greater
+                };
+            }
+        }
+        self_chain.len().cmp(&other_chain.len())
+    }
+}
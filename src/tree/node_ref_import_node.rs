@@ -0,0 +1,222 @@
+//! Copying nodes between documents.
+
+use super::{ImportOptions, NodeRef};
+use crate::iter::NodeIterator;
+use std::collections::{HashMap, HashSet};
+
+/// Attributes holding space-separated id references.
+const ID_REFERENCE_ATTRIBUTES: &[&str] = &[
+    "for",
+    "headers",
+    "list",
+    "form",
+    "aria-activedescendant",
+    "aria-controls",
+    "aria-describedby",
+    "aria-details",
+    "aria-errormessage",
+    "aria-flowto",
+    "aria-labelledby",
+    "aria-owns",
+];
+
+/// Node import methods for NodeRef.
+///
+/// Provides copying of nodes from other documents into this one.
+impl NodeRef {
+    /// Returns a copy of a node from another document, for use in this one.
+    ///
+    /// This is meant to be called on a document node. The copy is detached
+    /// and ready to be inserted; the original node is left untouched. With
+    /// `deep`, descendants and template contents are copied too.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::parse_html;
+    /// use brik::traits::*;
+    ///
+    /// let source = parse_html().one("<article><h1>News</h1></article>");
+    /// let report = parse_html().one("<main></main>");
+    ///
+    /// let article = source.select_first("article").unwrap();
+    /// let copy = report.import_node(article.as_node(), true);
+    /// report.select_first("main").unwrap().as_node().append(copy);
+    ///
+    /// assert_eq!(source.select("article").unwrap().count(), 1);
+    /// assert_eq!(report.select_first("h1").unwrap().text_contents(), "News");
+    /// ```
+    pub fn import_node(&self, node: &NodeRef, deep: bool) -> NodeRef {
+        self.import_node_with_options(
+            node,
+            &ImportOptions {
+                deep,
+                ..ImportOptions::default()
+            },
+        )
+    }
+
+    /// Returns a copy of a node from another document, with options.
+    ///
+    /// Works like [`NodeRef::import_node()`]. With
+    /// [`ImportOptions::unique_ids`], ids are checked against this document
+    /// as it is when called, so import and insert nodes one at a time.
+    /// Template contents are neither checked nor renamed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::parse_html;
+    /// use brik::traits::*;
+    /// use brik::ImportOptions;
+    ///
+    /// let source = parse_html().one(r##"<section id=intro><a href="#intro">top</a></section>"##);
+    /// let report = parse_html().one("<div id=intro></div>");
+    /// let options = ImportOptions { deep: true, unique_ids: true };
+    ///
+    /// let section = source.select_first("section").unwrap();
+    /// let copy = report.import_node_with_options(section.as_node(), &options);
+    /// assert_eq!(
+    ///     copy.to_string(),
+    ///     r##"<section id="intro-2"><a href="#intro-2">top</a></section>"##
+    /// );
+    /// ```
+    pub fn import_node_with_options(&self, node: &NodeRef, options: &ImportOptions) -> NodeRef {
+        let copy = node.clone_with(options.deep, &mut str::to_string);
+        if options.unique_ids {
+            self.rename_duplicate_ids(&copy);
+        }
+        copy
+    }
+
+    /// Renames ids in `copy` that clash with ids in this tree or earlier in `copy`.
+    ///
+    /// References within `copy` follow the first element carrying each
+    /// original id.
+    fn rename_duplicate_ids(&self, copy: &NodeRef) {
+        let mut taken: HashSet<String> = self
+            .descendants()
+            .elements()
+            .filter_map(|element| element.attributes.borrow().get("id").map(str::to_owned))
+            .collect();
+        let mut seen = HashSet::new();
+        let mut renamed = HashMap::new();
+        for element in copy.inclusive_descendants().elements() {
+            let mut attributes = element.attributes.borrow_mut();
+            let id = match attributes.get("id") {
+                Some(id) if !id.is_empty() => id.to_owned(),
+                _ => continue,
+            };
+            let first = seen.insert(id.clone());
+            if taken.insert(id.clone()) {
+                continue;
+            }
+            let unique = (2..)
+                .map(|n| format!("{}-{}", id, n))
+                .find(|candidate| !taken.contains(candidate))
+                .unwrap_or_default();
+            taken.insert(unique.clone());
+            attributes.insert("id", unique.clone());
+            if first {
+                renamed.insert(id, unique);
+            }
+        }
+        if renamed.is_empty() {
+            return;
+        }
+
+        for element in copy.inclusive_descendants().elements() {
+            let mut attributes = element.attributes.borrow_mut();
+            for name in ID_REFERENCE_ATTRIBUTES {
+                let updated = attributes.get(*name).and_then(|value| {
+                    let tokens: Vec<&str> = value
+                        .split_ascii_whitespace()
+                        .map(|token| renamed.get(token).map_or(token, String::as_str))
+                        .collect();
+                    let updated = tokens.join(" ");
+                    (updated != value).then_some(updated)
+                });
+                if let Some(updated) = updated {
+                    attributes.insert(*name, updated);
+                }
+            }
+            let href = attributes
+                .get("href")
+                .and_then(|href| href.strip_prefix('#'))
+                .and_then(|fragment| renamed.get(fragment))
+                .map(|unique| format!("#{}", unique));
+            if let Some(href) = href {
+                attributes.insert("href", href);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::html5ever::tendril::TendrilSink;
+    use crate::parse_html;
+
+    /// Tests shallow and deep imports.
+    ///
+    /// Verifies that a shallow import copies only the node and gives
+    /// templates empty contents, while a deep import copies template
+    /// contents independently of the source.
+    #[test]
+    fn import_node_depth() {
+        let source = parse_html().one("<template><b>t</b></template><p class=a>x<i>y</i></p>");
+        let target = parse_html().one("");
+        let p = source.select_first("p").unwrap();
+        let template = source.select_first("template").unwrap();
+
+        assert_eq!(
+            target.import_node(p.as_node(), false).to_string(),
+            r#"<p class="a"></p>"#
+        );
+        let shallow = target.import_node(template.as_node(), false);
+        let contents = shallow
+            .as_element()
+            .unwrap()
+            .template_contents
+            .clone()
+            .unwrap();
+        assert!(contents.first_child().is_none());
+
+        let deep = target.import_node(template.as_node(), true);
+        let contents = deep
+            .as_element()
+            .unwrap()
+            .template_contents
+            .clone()
+            .unwrap();
+        contents.clear_children();
+        assert_eq!(
+            template.template_contents.as_ref().unwrap().to_string(),
+            "<b>t</b>"
+        );
+    }
+
+    /// Tests renaming of clashing ids.
+    ///
+    /// Edge case: ids repeated inside the imported subtree are renamed too,
+    /// and references keep pointing at the first element with each id.
+    #[test]
+    fn import_node_unique_ids() {
+        let source = parse_html().one(
+            r#"<form><label for=name>Name</label><input id=name aria-describedby="hint name"><p id=hint></p><span id=name></span></form>"#,
+        );
+        let target = parse_html().one("<p id=name></p><p id=name-2></p>");
+        let options = ImportOptions {
+            deep: true,
+            unique_ids: true,
+        };
+
+        let form = source.select_first("form").unwrap();
+        let copy = target.import_node_with_options(form.as_node(), &options);
+        assert_eq!(
+            copy.to_string(),
+            r#"<form><label for="name-3">Name</label><input id="name-3" aria-describedby="hint name-3"><p id="hint"></p><span id="name-4"></span></form>"#
+        );
+    }
+}
@@ -0,0 +1,202 @@
+//! Placeholder interpolation in node trees.
+
+use super::NodeRef;
+use crate::parser::parse_in_context;
+
+/// Interpolation methods for NodeRef.
+///
+/// Provides replacement of `{{expr}}` placeholders with resolved values.
+impl NodeRef {
+    /// Replaces `{{expr}}` placeholders in the text and attribute values of
+    /// this subtree with values from `resolver`, returning the expressions
+    /// it could not resolve.
+    ///
+    /// The expression between the braces is trimmed and passed to
+    /// `resolver`; placeholders it returns `None` for are left as they are.
+    /// Values are inserted as text, so markup in them is escaped when the
+    /// tree is serialized. Triple braces, as in `{{{expr}}}`, insert the
+    /// value as markup instead, parsed in the context of the text's parent;
+    /// in attribute values they behave like double braces.
+    ///
+    /// Runs of adjacent text nodes containing a placeholder are merged
+    /// first, so placeholders split across text nodes are found.
+    /// Values are not themselves searched for placeholders. Template
+    /// contents are included.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::parse_html;
+    /// use brik::traits::*;
+    ///
+    /// let doc = parse_html().one(
+    ///     "<p title='{{ title }}'>Hi {{name}}! {{{badge}}} {{missing}}</p>",
+    /// );
+    /// let unresolved = doc.interpolate(|expr| match expr {
+    ///     "title" => Some("Greeting".to_string()),
+    ///     "name" => Some("<Ann>".to_string()),
+    ///     "badge" => Some("<b>new</b>".to_string()),
+    ///     _ => None,
+    /// });
+    ///
+    /// assert_eq!(unresolved, ["missing"]);
+    /// assert_eq!(
+    ///     doc.select_first("p").unwrap().as_node().to_string(),
+    ///     r#"<p title="Greeting">Hi &lt;Ann&gt;! <b>new</b> {{missing}}</p>"#
+    /// );
+    /// ```
+    pub fn interpolate<F>(&self, mut resolver: F) -> Vec<String>
+    where
+        F: FnMut(&str) -> Option<String>,
+    {
+        let mut unresolved = Vec::new();
+        let nodes: Vec<NodeRef> = self.inclusive_descendants_with_templates().collect();
+        for node in nodes {
+            // Text merged into a preceding node has been detached.
+            if node.as_text().is_some() && node.parent().is_none() && node != *self {
+                continue;
+            }
+            node.interpolate_node(&mut resolver, &mut unresolved);
+        }
+        unresolved
+    }
+
+    /// Interpolates the attribute values of an element, or the text of a
+    /// text node along with the text nodes following it.
+    pub(crate) fn interpolate_node(
+        &self,
+        resolver: &mut dyn FnMut(&str) -> Option<String>,
+        unresolved: &mut Vec<String>,
+    ) {
+        if let Some(element) = self.as_element() {
+            let mut attributes = element.attributes.borrow_mut();
+            for attribute in attributes.map.values_mut() {
+                if attribute.value.contains("{{") {
+                    let value: String = expand(&attribute.value, resolver, unresolved)
+                        .into_iter()
+                        .map(|(_, piece)| piece)
+                        .collect();
+                    attribute.value = value.into();
+                    self.mark_dirty();
+                }
+            }
+            return;
+        }
+        let text = match self.as_text() {
+            Some(text) => text,
+            None => return,
+        };
+
+        let mut combined = text.borrow().to_string();
+        let mut following = Vec::new();
+        let mut next = self.next_sibling();
+        while let Some(sibling) = next {
+            match sibling.as_text() {
+                Some(sibling_text) => combined.push_str(&sibling_text.borrow()),
+                None => break,
+            }
+            next = sibling.next_sibling();
+            following.push(sibling);
+        }
+        if !combined.contains("{{") {
+            return;
+        }
+        for sibling in &following {
+            sibling.detach();
+        }
+
+        let pieces = expand(&combined, resolver, unresolved);
+        let parent = self.parent();
+        let mut current = String::new();
+        let mut inserted = Vec::new();
+        for (raw, piece) in pieces {
+            match &parent {
+                Some(parent) if raw => {
+                    if !current.is_empty() {
+                        inserted.push(NodeRef::new_text(std::mem::take(&mut current)));
+                    }
+                    inserted.extend(parse_in_context(parent, &piece).children());
+                }
+                _ => current.push_str(&piece),
+            }
+        }
+        if inserted.is_empty() {
+            *text.borrow_mut() = current.into();
+            self.mark_dirty();
+            return;
+        }
+        if !current.is_empty() {
+            inserted.push(NodeRef::new_text(current));
+        }
+        for node in inserted {
+            self.insert_before(node);
+        }
+        self.detach();
+    }
+}
+
+/// Splits `text` into literal and resolved pieces, flagging the pieces
+/// from triple-brace placeholders as raw.
+fn expand(
+    text: &str,
+    resolver: &mut dyn FnMut(&str) -> Option<String>,
+    unresolved: &mut Vec<String>,
+) -> Vec<(bool, String)> {
+    let mut pieces = Vec::new();
+    let mut rest = text;
+    while let Some(start) = rest.find("{{") {
+        let (raw, close) = if rest[start..].starts_with("{{{") {
+            (true, "}}}")
+        } else {
+            (false, "}}")
+        };
+        let open = if raw { 3 } else { 2 };
+        let end = match rest[start + open..].find(close) {
+            Some(end) => start + open + end,
+            None => break,
+        };
+        let placeholder = &rest[start..end + close.len()];
+        let expr = rest[start + open..end].trim();
+        pieces.push((false, rest[..start].to_string()));
+        match resolver(expr) {
+            Some(value) => pieces.push((raw, value)),
+            None => {
+                unresolved.push(expr.to_string());
+                pieces.push((false, placeholder.to_string()));
+            }
+        }
+        rest = &rest[end + close.len()..];
+    }
+    pieces.push((false, rest.to_string()));
+    pieces
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::html5ever::tendril::TendrilSink;
+    use crate::parse_html;
+    use crate::NodeRef;
+
+    /// Tests placeholders split across text nodes.
+    ///
+    /// Verifies that adjacent text nodes are merged to complete a
+    /// placeholder, that resolved values are not searched again, and that
+    /// unterminated placeholders are left as text.
+    #[test]
+    fn interpolate_split_placeholder() {
+        let doc = parse_html().one("<p></p>");
+        let p = doc.select_first("p").unwrap();
+        p.as_node().append(NodeRef::new_text("a {{fi"));
+        p.as_node().append(NodeRef::new_text("rst}} b"));
+        p.as_node().append(NodeRef::new_text(" {{second"));
+
+        let unresolved = p.as_node().interpolate(|expr| match expr {
+            "first" => Some("{{second}}".to_string()),
+            _ => Some("2".to_string()),
+        });
+
+        assert!(unresolved.is_empty());
+        assert_eq!(p.as_node().children().count(), 1);
+        assert_eq!(p.text_contents(), "a {{second}} b {{second");
+    }
+}
@@ -0,0 +1,169 @@
+//! Language resolution for node trees.
+
+use indexmap::IndexMap;
+
+use super::{ElementData, NodeRef};
+use crate::attributes::ExpandedName;
+use crate::iter::{NodeEdge, NodeIterator};
+use crate::node_data_ref::NodeDataRef;
+
+/// Language methods for NodeRef.
+///
+/// Provides resolution of the `lang` cascade.
+impl NodeRef {
+    /// Returns the language of this node.
+    ///
+    /// The nearest inclusive ancestor element with a `lang` or `xml:lang`
+    /// attribute determines the language. As in HTML, `xml:lang` in the XML
+    /// namespace takes precedence over `lang`, which takes precedence over
+    /// an `xml:lang` that has not been namespaced. If no
+    /// element has one, the first language of a
+    /// `<meta http-equiv="content-language">` in the document is used.
+    ///
+    /// Returns `None` if the language is unknown, including when the nearest
+    /// declaration is an empty `lang=""`. Values are trimmed but otherwise
+    /// returned as written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::parse_html;
+    /// use brik::traits::*;
+    ///
+    /// let doc = parse_html().one("<html lang=en><p>Hi <q lang=fr-CA>Salut</q></p></html>");
+    /// let p = doc.select_first("p").unwrap();
+    /// let q = doc.select_first("q").unwrap();
+    /// assert_eq!(p.as_node().language().as_deref(), Some("en"));
+    /// assert_eq!(q.as_node().first_child().unwrap().language().as_deref(), Some("fr-CA"));
+    /// ```
+    pub fn language(&self) -> Option<String> {
+        for ancestor in self.inclusive_ancestors().elements() {
+            if let Some(lang) = declared_language(&ancestor) {
+                return lang;
+            }
+        }
+        let root = self.inclusive_ancestors().last()?;
+        meta_language(&root)
+    }
+
+    /// Returns the elements of this subtree grouped by their language.
+    ///
+    /// Groups are in order of first appearance, and elements within each
+    /// group are in document order. Languages are resolved as by
+    /// [`NodeRef::language()`], with `None` collecting elements of unknown
+    /// language. Template contents are not included.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::parse_html;
+    /// use brik::traits::*;
+    ///
+    /// let doc = parse_html().one("<div lang=de><p>Hallo</p></div><p lang=en>Hi</p>");
+    /// let body = doc.select_first("body").unwrap();
+    /// let groups = body.as_node().elements_by_language();
+    /// let sizes: Vec<_> = groups.iter().map(|(lang, els)| (lang.as_deref(), els.len())).collect();
+    /// assert_eq!(sizes, [(None, 1), (Some("de"), 2), (Some("en"), 1)]);
+    /// ```
+    pub fn elements_by_language(&self) -> IndexMap<Option<String>, Vec<NodeDataRef<ElementData>>> {
+        let mut groups: IndexMap<Option<String>, Vec<NodeDataRef<ElementData>>> = IndexMap::new();
+        let mut stack: Vec<Option<String>> = Vec::new();
+        for edge in self.traverse_inclusive() {
+            match edge {
+                NodeEdge::Start(node) => {
+                    let Some(element) = node.into_element_ref() else {
+                        continue;
+                    };
+                    let language = match (declared_language(&element), stack.last()) {
+                        (Some(lang), _) => lang,
+                        (None, Some(inherited)) => inherited.clone(),
+                        (None, None) => element.as_node().language(),
+                    };
+                    groups.entry(language.clone()).or_default().push(element);
+                    stack.push(language);
+                }
+                NodeEdge::End(node) => {
+                    if node.as_element().is_some() {
+                        stack.pop();
+                    }
+                }
+            }
+        }
+        groups
+    }
+}
+
+/// Returns the language declared on an element itself.
+///
+/// The outer `Option` is whether a declaration exists; the inner one is
+/// `None` for an empty declaration, which makes the language unknown.
+fn declared_language(element: &NodeDataRef<ElementData>) -> Option<Option<String>> {
+    let attributes = element.attributes.borrow();
+    let value = attributes
+        .map
+        .get(&ExpandedName::new(ns!(xml), local_name!("lang")))
+        .map(|attribute| &*attribute.value)
+        .or_else(|| attributes.get(local_name!("lang")))
+        .or_else(|| attributes.get("xml:lang"))?;
+    let value = value.trim();
+    Some((!value.is_empty()).then(|| value.to_string()))
+}
+
+/// Returns the first language of a `<meta http-equiv="content-language">`.
+fn meta_language(root: &NodeRef) -> Option<String> {
+    root.descendants().elements().find_map(|element| {
+        if element.name.local != local_name!("meta") {
+            return None;
+        }
+        let attributes = element.attributes.borrow();
+        if !attributes
+            .get(local_name!("http-equiv"))
+            .is_some_and(|value| value.trim().eq_ignore_ascii_case("content-language"))
+        {
+            return None;
+        }
+        let content = attributes.get(local_name!("content"))?;
+        let first = content.split(',').next()?.trim();
+        (!first.is_empty()).then(|| first.to_string())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::html5ever::tendril::TendrilSink;
+    use crate::parse_html;
+
+    /// Tests the language fallbacks.
+    ///
+    /// Verifies that xml:lang is used when lang is absent, that the meta
+    /// content-language applies when no element declares a language, and
+    /// that an empty lang makes the language unknown.
+    #[test]
+    fn fallbacks() {
+        let doc = parse_html().one(
+            "<meta http-equiv=Content-Language content='es, en'>\
+             <p id=a>x</p><p id=b xml:lang=it>y</p><div lang=pt><p id=c lang=''>z</p></div>",
+        );
+        let language = |selector| doc.select_first(selector).unwrap().as_node().language();
+
+        assert_eq!(language("#a").as_deref(), Some("es"));
+        assert_eq!(language("#b").as_deref(), Some("it"));
+        assert_eq!(language("div").as_deref(), Some("pt"));
+        assert_eq!(language("#c"), None);
+    }
+
+    /// Tests grouping with the meta fallback and unknown languages.
+    ///
+    /// Verifies that groups match language() for every element.
+    #[test]
+    fn groups_match_language() {
+        let doc = parse_html().one(
+            "<meta http-equiv=content-language content=nl><p>a<b lang=''>b</b></p><i lang=ja>c</i>",
+        );
+        for (language, elements) in doc.elements_by_language() {
+            for element in elements {
+                assert_eq!(element.as_node().language(), language);
+            }
+        }
+    }
+}
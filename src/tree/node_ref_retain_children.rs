@@ -0,0 +1,129 @@
+//! Bulk child removal for node trees.
+
+use super::NodeRef;
+
+/// Child removal methods for NodeRef.
+///
+/// Provides removal of some or all children in a single pass.
+impl NodeRef {
+    /// Removes the children for which `keep` returns false.
+    ///
+    /// Works like [`Vec::retain()`]: children are visited once, in order,
+    /// and removed children are detached. Descendants of kept children are
+    /// not visited.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::parse_html;
+    /// use brik::traits::*;
+    ///
+    /// let doc = parse_html().one("<ul><li>a</li> <li class=ad>b</li> <li>c</li></ul>");
+    /// let ul = doc.select_first("ul").unwrap();
+    /// ul.as_node().retain_children(|child| {
+    ///     child.as_element().is_some_and(|e| !e.attributes.borrow().contains("class"))
+    /// });
+    /// assert_eq!(ul.as_node().to_string(), "<ul><li>a</li><li>c</li></ul>");
+    /// ```
+    pub fn retain_children<F>(&self, mut keep: F)
+    where
+        F: FnMut(&NodeRef) -> bool,
+    {
+        let mut next = self.first_child();
+        while let Some(child) = next {
+            next = child.next_sibling();
+            if !keep(&child) {
+                child.detach();
+            }
+        }
+    }
+
+    /// Removes all children.
+    ///
+    /// The child list is unlinked in one walk, leaving each former child
+    /// without a parent or siblings.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::parse_html;
+    /// use brik::traits::*;
+    ///
+    /// let doc = parse_html().one("<p>a<b>b</b>c</p>");
+    /// let p = doc.select_first("p").unwrap();
+    /// p.as_node().clear_children();
+    /// assert_eq!(p.as_node().to_string(), "<p></p>");
+    /// ```
+    pub fn clear_children(&self) {
+        if let (Some(first), Some(last)) = (self.first_child(), self.last_child()) {
+            first.detach_through(&last);
+        }
+    }
+
+    /// Detaches this node and its following siblings up to and including
+    /// `last`, which must be this node or one of its following siblings.
+    ///
+    /// Takes linear time in the length of the range: the range is unlinked
+    /// from its surroundings once, then each detached node's own links are
+    /// cleared in one walk, without the neighbour updates that detaching
+    /// nodes one by one would need.
+    pub(crate) fn detach_through(&self, last: &NodeRef) {
+        let parent = self.parent.take().and_then(|weak| weak.upgrade());
+        let previous = self.previous_sibling.take();
+        let next = last.next_sibling.take();
+
+        if let Some(next) = next.as_ref() {
+            next.previous_sibling.replace(previous.clone());
+        } else if let Some(parent) = parent.as_ref() {
+            parent.last_child.replace(previous.clone());
+        }
+        // The strong reference to this node previously owned by the
+        // preceding sibling or the parent.
+        let _owner = match previous.and_then(|weak| weak.upgrade()) {
+            Some(previous) => previous.next_sibling.replace(next),
+            None => parent
+                .as_ref()
+                .and_then(|parent| parent.first_child.replace(next)),
+        };
+
+        let mut node = Some(self.clone());
+        while let Some(current) = node {
+            current.parent.take();
+            current.previous_sibling.take();
+            node = current.next_sibling.take().map(NodeRef);
+        }
+        if let Some(parent) = parent {
+            parent.mark_dirty();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::html5ever::tendril::TendrilSink;
+    use crate::parse_html;
+    use crate::NodeRef;
+
+    /// Tests retaining and clearing children.
+    ///
+    /// Verifies that retain_children keeps sibling links consistent when the
+    /// first and last children are removed, and that clear_children leaves
+    /// former children fully unlinked.
+    #[test]
+    fn retain_and_clear_children() {
+        let doc = parse_html().one("<p>a<b>b</b>c<i>d</i>e</p>");
+        let p = doc.select_first("p").unwrap();
+        let p = p.as_node();
+
+        p.retain_children(|child| child.as_element().is_some());
+        assert_eq!(p.to_string(), "<p><b>b</b><i>d</i></p>");
+        assert_eq!(p.last_child().unwrap().previous_sibling(), p.first_child());
+
+        let children: Vec<NodeRef> = p.children().collect();
+        p.clear_children();
+        assert!(p.first_child().is_none() && p.last_child().is_none());
+        assert!(children.iter().all(|child| child.parent().is_none()
+            && child.previous_sibling().is_none()
+            && child.next_sibling().is_none()));
+    }
+}
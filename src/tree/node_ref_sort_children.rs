@@ -0,0 +1,128 @@
+//! Child reordering for node trees.
+
+use super::NodeRef;
+use std::cmp::Ordering;
+
+/// Child reordering methods for NodeRef.
+///
+/// Provides sorting and moving of children in place.
+impl NodeRef {
+    /// Sorts the children with a comparator.
+    ///
+    /// The sort is stable, so children that compare equal keep their order.
+    /// The sibling chain is relinked in place; the children themselves are
+    /// not cloned or rebuilt.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::parse_html;
+    /// use brik::traits::*;
+    ///
+    /// let doc = parse_html().one("<ul><li>b</li><li>c</li><li>a</li></ul>");
+    /// let ul = doc.select_first("ul").unwrap();
+    /// ul.as_node().sort_children_by(|a, b| a.text_contents().cmp(&b.text_contents()));
+    /// assert_eq!(ul.text_contents(), "abc");
+    /// ```
+    pub fn sort_children_by<F>(&self, mut compare: F)
+    where
+        F: FnMut(&NodeRef, &NodeRef) -> Ordering,
+    {
+        let mut children: Vec<NodeRef> = self.children().collect();
+        children.sort_by(|a, b| compare(a, b));
+        self.splice_children(.., children);
+    }
+
+    /// Moves the child at index `from` so that it ends up at index `to`.
+    ///
+    /// Works like [`Vec::remove()`] followed by [`Vec::insert()`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if either index is not less than the number of children.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::parse_html;
+    /// use brik::traits::*;
+    ///
+    /// let doc = parse_html().one("<ol><li>a</li><li>b</li><li>c</li></ol>");
+    /// let ol = doc.select_first("ol").unwrap();
+    /// ol.as_node().move_child(0, 2);
+    /// assert_eq!(ol.text_contents(), "bca");
+    /// ```
+    pub fn move_child(&self, from: usize, to: usize) {
+        let children: Vec<NodeRef> = self.children().collect();
+        let len = children.len();
+        assert!(
+            from < len,
+            "move_child from index {} is out of bounds (len {})",
+            from,
+            len
+        );
+        assert!(
+            to < len,
+            "move_child to index {} is out of bounds (len {})",
+            to,
+            len
+        );
+
+        let child = &children[from];
+        match to.cmp(&from) {
+            Ordering::Equal => {}
+            Ordering::Less => children[to].insert_before(child.clone()),
+            Ordering::Greater => children[to].insert_after(child.clone()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::html5ever::tendril::TendrilSink;
+    use crate::parse_html;
+    use crate::NodeRef;
+
+    /// Tests stable sorting of children.
+    ///
+    /// Verifies that children with equal keys keep their relative order and
+    /// that backward sibling links match the new order.
+    #[test]
+    fn sort_children_by_stable() {
+        let doc = parse_html()
+            .one("<dl><dt>b</dt><dd>1</dd><dt>a</dt><dd>2</dd><dt>b</dt><dd>3</dd></dl>");
+        let dl = doc.select_first("dl").unwrap();
+        let dl = dl.as_node();
+        dl.sort_children_by(|a, b| {
+            let key = |node: &NodeRef| node.as_element().unwrap().name.local.clone();
+            key(a).cmp(&key(b))
+        });
+        assert_eq!(dl.text_contents(), "123bab");
+
+        let backward: String =
+            std::iter::successors(dl.last_child(), |node| node.previous_sibling())
+                .map(|node| node.text_contents())
+                .collect();
+        assert_eq!(backward, "bab321");
+    }
+
+    /// Tests moving children forward and backward.
+    ///
+    /// Edge case: moving to the same index leaves the order unchanged, and an
+    /// out of bounds index panics.
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn move_child() {
+        let doc = parse_html().one("<ol><li>a</li><li>b</li><li>c</li><li>d</li></ol>");
+        let ol = doc.select_first("ol").unwrap();
+        let ol = ol.as_node();
+
+        ol.move_child(3, 1);
+        assert_eq!(ol.text_contents(), "adbc");
+        ol.move_child(0, 3);
+        assert_eq!(ol.text_contents(), "dbca");
+        ol.move_child(2, 2);
+        assert_eq!(ol.text_contents(), "dbca");
+        ol.move_child(0, 4);
+    }
+}
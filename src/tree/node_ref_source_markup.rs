@@ -0,0 +1,52 @@
+//! Source markup access for node trees.
+
+use super::source_markup::SourceMarkup;
+use super::Node;
+use crate::cell_extras::*;
+use std::rc::Rc;
+
+/// Source markup methods for Node.
+///
+/// Provides the markup recorded by
+/// [`ParseOpts::preserve_source`](crate::ParseOpts::preserve_source).
+impl Node {
+    /// Returns the markup this node was parsed from: the start tag of an
+    /// element, or the undecoded text of a text node.
+    ///
+    /// Only recorded when parsing with
+    /// [`ParseOpts::preserve_source`](crate::ParseOpts::preserve_source),
+    /// and kept even if the node has since changed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::{parse_html_str_with_options, ParseOpts};
+    /// use brik::traits::*;
+    ///
+    /// let opts = ParseOpts {
+    ///     preserve_source: true,
+    ///     ..Default::default()
+    /// };
+    /// let doc = parse_html_str_with_options(opts, "<P Class=a>Fish &amp; chips</P>");
+    /// let p = doc.select_first("p").unwrap();
+    /// assert_eq!(p.as_node().source_markup().as_deref(), Some("<P Class=a>"));
+    ///
+    /// let text = p.as_node().first_child().unwrap();
+    /// assert_eq!(text.source_markup().as_deref(), Some("Fish &amp; chips"));
+    /// ```
+    pub fn source_markup(&self) -> Option<String> {
+        self.source
+            .clone_inner()
+            .map(|source| source.markup().to_string())
+    }
+
+    /// Returns the recorded source of this node.
+    pub(crate) fn source(&self) -> Option<Rc<SourceMarkup>> {
+        self.source.clone_inner()
+    }
+
+    /// Records the source of this node.
+    pub(crate) fn set_source(&self, source: SourceMarkup) {
+        self.source.set(Some(Rc::new(source)));
+    }
+}
@@ -0,0 +1,222 @@
+//! Child range replacement for node trees.
+
+use super::{Node, NodeRef};
+use std::collections::HashSet;
+use std::ops::{Bound, RangeBounds};
+use std::rc::Rc;
+
+/// Child splicing methods for NodeRef.
+///
+/// Provides replacement of a contiguous run of children in one operation.
+impl NodeRef {
+    /// Replaces a range of children with new nodes, returning the removed children.
+    ///
+    /// Works like [`Vec::splice()`]: the children at indices in `range` are
+    /// detached and the replacement nodes are inserted in their place, in
+    /// order. Replacement nodes are detached from their previous positions
+    /// first, so children of this node, including ones in the range, can be
+    /// reused. Repeated replacement nodes are inserted once.
+    ///
+    /// The sibling links around the range are updated directly, rather than
+    /// by detaching and inserting each node in turn.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the start of the range is greater than its end, or if its
+    /// end is greater than the number of children.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::parse_html;
+    /// use brik::traits::*;
+    /// use brik::NodeRef;
+    ///
+    /// let doc = parse_html().one("<p>a<b>b</b><i>c</i>d</p>");
+    /// let p = doc.select_first("p").unwrap();
+    /// let removed = p.as_node().splice_children(1..3, vec![NodeRef::new_text("-")]);
+    ///
+    /// assert_eq!(removed.len(), 2);
+    /// assert_eq!(p.as_node().to_string(), "<p>a-d</p>");
+    /// ```
+    pub fn splice_children<R, I>(&self, range: R, replacement: I) -> Vec<NodeRef>
+    where
+        R: RangeBounds<usize>,
+        I: IntoIterator<Item = NodeRef>,
+    {
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start + 1,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => Some(end + 1),
+            Bound::Excluded(&end) => Some(end),
+            Bound::Unbounded => None,
+        };
+        if let Some(end) = end {
+            assert!(
+                start <= end,
+                "splice range starts at {} but ends at {}",
+                start,
+                end
+            );
+        }
+
+        let mut previous = None;
+        let mut next = self.first_child();
+        for index in 0..start {
+            let child =
+                next.unwrap_or_else(|| panic!("splice range start {} is out of bounds", index + 1));
+            next = child.next_sibling();
+            previous = Some(child);
+        }
+        let mut removed = Vec::new();
+        let mut index = start;
+        while end.is_none_or(|end| index < end) {
+            match next {
+                Some(child) => {
+                    next = child.next_sibling();
+                    removed.push(child);
+                }
+                None if end.is_none() => break,
+                None => panic!("splice range end {} is out of bounds", index + 1),
+            }
+            index += 1;
+        }
+
+        let mut seen = HashSet::new();
+        let replacement: Vec<NodeRef> = replacement
+            .into_iter()
+            .filter(|node| seen.insert(Rc::as_ptr(&node.0)))
+            .collect();
+
+        // The nearest siblings that stay in place bound the gap to fill.
+        while let Some(node) = previous
+            .as_ref()
+            .filter(|node| seen.contains(&Rc::as_ptr(&node.0)))
+        {
+            previous = node.previous_sibling();
+        }
+        while let Some(node) = next
+            .as_ref()
+            .filter(|node| seen.contains(&Rc::as_ptr(&node.0)))
+        {
+            next = node.next_sibling();
+        }
+
+        for node in &replacement {
+            node.detach();
+        }
+        for node in &removed {
+            node.parent.take();
+            node.previous_sibling.take();
+            node.next_sibling.take();
+        }
+
+        let parent = Rc::downgrade(&self.0);
+        let mut last: Option<Rc<Node>> = previous.map(|node| node.0);
+        for node in &replacement {
+            node.parent.replace(Some(parent.clone()));
+            match &last {
+                Some(last) => {
+                    node.previous_sibling.replace(Some(Rc::downgrade(last)));
+                    last.next_sibling.replace(Some(node.0.clone()));
+                }
+                None => {
+                    self.first_child.replace(Some(node.0.clone()));
+                }
+            }
+            last = Some(node.0.clone());
+        }
+        match next {
+            Some(next) => {
+                next.previous_sibling
+                    .replace(last.as_ref().map(Rc::downgrade));
+                match &last {
+                    Some(last) => last.next_sibling.replace(Some(next.0)),
+                    None => self.first_child.replace(Some(next.0)),
+                };
+            }
+            None => {
+                self.last_child.replace(last.as_ref().map(Rc::downgrade));
+                match &last {
+                    Some(last) => last.next_sibling.replace(None),
+                    None => self.first_child.replace(None),
+                };
+            }
+        }
+        for node in &replacement {
+            node.mark_inserted();
+        }
+        self.mark_dirty();
+        removed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::html5ever::tendril::TendrilSink;
+    use crate::parse_html;
+    use crate::NodeRef;
+
+    /// Tests splicing at the ends of the child list.
+    ///
+    /// Verifies that prepending, appending and clearing through empty and
+    /// unbounded ranges keep the first and last child links consistent.
+    #[test]
+    fn splice_children_ends() {
+        let doc = parse_html().one("<p>b</p>");
+        let p = doc.select_first("p").unwrap();
+        let p = p.as_node();
+
+        assert!(p
+            .splice_children(0..0, vec![NodeRef::new_text("a")])
+            .is_empty());
+        assert!(p
+            .splice_children(2.., vec![NodeRef::new_text("c")])
+            .is_empty());
+        assert_eq!(p.to_string(), "<p>abc</p>");
+        assert_eq!(&**p.last_child().unwrap().as_text().unwrap().borrow(), "c");
+
+        let removed = p.splice_children(.., Vec::new());
+        assert_eq!(removed.len(), 3);
+        assert!(removed.iter().all(|node| node.parent().is_none()));
+        assert!(p.first_child().is_none() && p.last_child().is_none());
+    }
+
+    /// Tests reusing children as replacement nodes.
+    ///
+    /// Edge case: replacement nodes taken from inside and outside the range
+    /// are moved rather than duplicated, and backward links stay valid.
+    #[test]
+    fn splice_children_reuses_children() {
+        let doc = parse_html().one("<ul><li>1</li><li>2</li><li>3</li><li>4</li></ul>");
+        let ul = doc.select_first("ul").unwrap();
+        let ul = ul.as_node();
+        let items: Vec<NodeRef> = ul.children().collect();
+
+        ul.splice_children(
+            1..3,
+            vec![items[3].clone(), items[1].clone(), items[3].clone()],
+        );
+        assert_eq!(ul.to_string(), "<ul><li>1</li><li>4</li><li>2</li></ul>");
+
+        let backward: String =
+            std::iter::successors(ul.last_child(), |node| node.previous_sibling())
+                .map(|node| node.text_contents())
+                .collect();
+        assert_eq!(backward, "241");
+    }
+
+    /// Tests splicing with an out of bounds range.
+    ///
+    /// Edge case: a range ending past the last child panics.
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn splice_children_out_of_bounds() {
+        let doc = parse_html().one("<p>a</p>");
+        let p = doc.select_first("p").unwrap();
+        p.as_node().splice_children(0..2, Vec::new());
+    }
+}
@@ -0,0 +1,105 @@
+//! Text node splitting.
+
+use super::NodeRef;
+use crate::node_data_ref::NodeDataRef;
+use html5ever::tendril::StrTendril;
+use std::cell::RefCell;
+
+/// Text splitting methods for NodeRef.
+///
+/// Provides the equivalent of the DOM `Text.splitText()` method.
+impl NodeRef {
+    /// Splits this text node in two at a character offset, returning the new
+    /// node holding the text after the offset.
+    ///
+    /// This node keeps the text before the offset, and the new node is
+    /// inserted as its next sibling if it has a parent. Offsets count
+    /// Unicode scalar values, not UTF-16 code units as in the DOM. Returns
+    /// `None`, leaving the tree unchanged, if this is not a text node or
+    /// `offset` is past the end of its text.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::parse_html;
+    /// use brik::traits::*;
+    ///
+    /// let doc = parse_html().one("<p>naïve text</p>");
+    /// let p = doc.select_first("p").unwrap();
+    /// let text = p.as_node().first_child().unwrap();
+    ///
+    /// let rest = text.split_text(5).unwrap();
+    /// assert_eq!(&**text.as_text().unwrap().borrow(), "naïve");
+    /// assert_eq!(&**rest.as_text().unwrap().borrow(), " text");
+    /// assert_eq!(p.as_node().children().count(), 2);
+    /// ```
+    pub fn split_text(&self, offset: usize) -> Option<NodeRef> {
+        let text = self.as_text()?;
+        let contents = text.borrow().to_string();
+        let split = match contents.char_indices().nth(offset) {
+            Some((index, _)) => index,
+            None if contents.chars().count() == offset => contents.len(),
+            None => return None,
+        };
+        *text.borrow_mut() = contents[..split].into();
+        self.mark_dirty();
+        let rest = NodeRef::new_text(&contents[split..]);
+        if self.parent().is_some() {
+            self.insert_after(rest.clone());
+        }
+        Some(rest)
+    }
+}
+
+/// Text splitting methods for NodeDataRef<RefCell<StrTendril>>.
+///
+/// Provides [`NodeRef::split_text()`] for references to text nodes.
+impl NodeDataRef<RefCell<StrTendril>> {
+    /// Splits this text node in two at a character offset, returning the new
+    /// node holding the text after the offset.
+    ///
+    /// Returns `None`, leaving the tree unchanged, if `offset` is past the end
+    /// of the text. See [`NodeRef::split_text()`].
+    pub fn split_text(&self, offset: usize) -> Option<NodeDataRef<RefCell<StrTendril>>> {
+        self.as_node().split_text(offset)?.into_text_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::html5ever::tendril::TendrilSink;
+    use crate::parse_html;
+    use crate::NodeRef;
+
+    /// Tests offsets at the ends of the text and past them.
+    ///
+    /// Edge case: splitting at the end yields an empty node, splitting past
+    /// it fails, and detached nodes split without inserting the new node.
+    #[test]
+    fn split_text_bounds() {
+        let doc = parse_html().one("<p>ab</p>");
+        let text = doc
+            .select_first("p")
+            .unwrap()
+            .as_node()
+            .first_child()
+            .unwrap()
+            .into_text_ref()
+            .unwrap();
+
+        assert!(text.split_text(3).is_none());
+        let empty = text.split_text(2).unwrap();
+        assert_eq!(&**empty.borrow(), "");
+        let b = text.split_text(1).unwrap();
+        assert_eq!(&**b.borrow(), "b");
+        assert_eq!(
+            doc.select_first("p").unwrap().as_node().children().count(),
+            3
+        );
+
+        let detached = NodeRef::new_text("xy");
+        let y = detached.split_text(1).unwrap();
+        assert!(y.parent().is_none());
+        assert_eq!(detached.text_contents(), "x");
+    }
+}
@@ -0,0 +1,113 @@
+//! Configurable text extraction.
+
+use super::node_ref_collapse_whitespace::BLOCK_ELEMENTS;
+use super::{NodeRef, TextOptions};
+use crate::iter::NodeEdge;
+
+/// Configurable text methods for NodeRef.
+///
+/// Provides [`NodeRef::text_contents()`] with skipping, whitespace and
+/// separator options.
+impl NodeRef {
+    /// Returns the text of this subtree, with options.
+    ///
+    /// The text between each pair of element boundaries, or all the text if
+    /// there is no separator, is collapsed if requested; empty pieces are
+    /// dropped and the rest joined with the separator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::parse_html;
+    /// use brik::traits::*;
+    /// use brik::TextOptions;
+    ///
+    /// let doc = parse_html().one(
+    ///     "<h1>  Title </h1><script>track()</script>\
+    ///      <p>First\n   <b>bold</b> line</p><p>Second</p>",
+    /// );
+    /// assert_eq!(
+    ///     doc.text_contents_with(&TextOptions::default()),
+    ///     "Title First bold lineSecond"
+    /// );
+    ///
+    /// let options = TextOptions {
+    ///     separator: Some("\n".to_string()),
+    ///     blocks_only: true,
+    ///     ..TextOptions::default()
+    /// };
+    /// assert_eq!(doc.text_contents_with(&options), "Title\nFirst bold line\nSecond");
+    /// ```
+    pub fn text_contents_with(&self, options: &TextOptions) -> String {
+        let mut pieces = vec![String::new()];
+        let mut traverse = self.traverse_inclusive();
+        while let Some(edge) = traverse.next() {
+            let (node, start) = match edge {
+                NodeEdge::Start(node) => (node, true),
+                NodeEdge::End(node) => (node, false),
+            };
+            if let Some(text) = node.as_text() {
+                if start {
+                    if let Some(piece) = pieces.last_mut() {
+                        piece.push_str(&text.borrow());
+                    }
+                }
+                continue;
+            }
+            let element = match node.as_element() {
+                Some(element) => element,
+                None => continue,
+            };
+            let is_html = element.name.ns == ns!(html);
+            let name = &*element.name.local;
+            if start
+                && is_html
+                && options
+                    .skip_elements
+                    .iter()
+                    .any(|skipped| skipped.eq_ignore_ascii_case(name))
+            {
+                traverse.skip_subtree();
+            }
+            let boundary = !options.blocks_only || (is_html && BLOCK_ELEMENTS.contains(&name));
+            if options.separator.is_some() && boundary {
+                pieces.push(String::new());
+            }
+        }
+
+        let pieces = pieces.into_iter().filter_map(|piece| {
+            let piece = if options.collapse_whitespace {
+                piece.split_ascii_whitespace().collect::<Vec<_>>().join(" ")
+            } else {
+                piece
+            };
+            (!piece.is_empty()).then_some(piece)
+        });
+        pieces
+            .collect::<Vec<_>>()
+            .join(options.separator.as_deref().unwrap_or_default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::html5ever::tendril::TendrilSink;
+    use crate::parse_html;
+    use crate::TextOptions;
+
+    /// Tests separators at every element boundary without collapsing.
+    ///
+    /// Verifies that whitespace between boundaries is kept, that only empty
+    /// pieces are dropped, and that skipping can be turned off.
+    #[test]
+    fn separator_without_collapsing() {
+        let doc = parse_html().one("<p>a <b>b</b></p> <style>s</style>");
+        let options = TextOptions {
+            skip_elements: Vec::new(),
+            collapse_whitespace: false,
+            separator: Some("|".to_string()),
+            blocks_only: false,
+        };
+        assert_eq!(doc.text_contents_with(&options), "a |b| |s");
+    }
+}
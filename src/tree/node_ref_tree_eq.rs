@@ -0,0 +1,127 @@
+//! Structural equality of node trees.
+
+use super::{NodeData, NodeRef};
+
+/// Structural comparison methods for NodeRef.
+///
+/// Unlike `==`, which compares node identity, these compare tree contents.
+impl NodeRef {
+    /// Returns whether this subtree is structurally equal to another.
+    ///
+    /// Two nodes are equal if they are of the same kind and have equal data
+    /// and equal children, compared recursively in order. For elements, this
+    /// means the same namespace and local name, the same set of attributes
+    /// in any order, and equal template contents. Namespace prefixes are not
+    /// compared, and text is compared exactly without whitespace
+    /// normalization.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::parse_html;
+    /// use brik::traits::*;
+    ///
+    /// let a = parse_html().one("<p id=x class=y>Hi</p>");
+    /// let b = parse_html().one("<p class=y id=x>Hi</p>");
+    /// let c = parse_html().one("<p class=y id=x>Bye</p>");
+    ///
+    /// assert!(a != b);
+    /// assert!(a.tree_eq(&b));
+    /// assert!(!a.tree_eq(&c));
+    /// ```
+    pub fn tree_eq(&self, other: &NodeRef) -> bool {
+        if !data_eq(self, other) {
+            return false;
+        }
+        let mut children = self.children();
+        let mut other_children = other.children();
+        loop {
+            match (children.next(), other_children.next()) {
+                (None, None) => return true,
+                (Some(child), Some(other_child)) if child.tree_eq(&other_child) => {}
+                _ => return false,
+            }
+        }
+    }
+}
+
+/// Returns whether two nodes have equal data, ignoring their children.
+fn data_eq(node: &NodeRef, other: &NodeRef) -> bool {
+    match (node.data(), other.data()) {
+        (NodeData::Document(_), NodeData::Document(_)) => true,
+        (NodeData::DocumentFragment, NodeData::DocumentFragment) => true,
+        (NodeData::Doctype(a), NodeData::Doctype(b)) => {
+            a.name == b.name && a.public_id == b.public_id && a.system_id == b.system_id
+        }
+        (NodeData::Element(a), NodeData::Element(b)) => {
+            if a.name.ns != b.name.ns || a.name.local != b.name.local {
+                return false;
+            }
+            let attributes = a.attributes.borrow();
+            let other_attributes = b.attributes.borrow();
+            if attributes.map.len() != other_attributes.map.len()
+                || !attributes.map.iter().all(|(name, attribute)| {
+                    other_attributes
+                        .map
+                        .get(name)
+                        .is_some_and(|other| other.value == attribute.value)
+                })
+            {
+                return false;
+            }
+            match (&a.template_contents, &b.template_contents) {
+                (Some(contents), Some(other_contents)) => contents.tree_eq(other_contents),
+                (None, None) => true,
+                _ => false,
+            }
+        }
+        (NodeData::Text(a), NodeData::Text(b)) => *a.borrow() == *b.borrow(),
+        (NodeData::Comment(a), NodeData::Comment(b)) => *a.borrow() == *b.borrow(),
+        (NodeData::ProcessingInstruction(a), NodeData::ProcessingInstruction(b)) => {
+            *a.borrow() == *b.borrow()
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::html5ever::tendril::TendrilSink;
+    use crate::parse_html;
+
+    /// Tests tree_eq() on independently parsed documents.
+    ///
+    /// Verifies that attribute order is ignored while attribute values, text,
+    /// child counts and template contents are compared.
+    #[test]
+    fn documents() {
+        let parse = |html| parse_html().one(html);
+        let base = parse("<div a=1 b=2><template><i>t</i></template>x</div>");
+
+        assert!(base.tree_eq(&parse("<div b=2 a=1><template><i>t</i></template>x</div>")));
+        assert!(!base.tree_eq(&parse("<div a=1 b=3><template><i>t</i></template>x</div>")));
+        assert!(!base.tree_eq(&parse("<div a=1><template><i>t</i></template>x</div>")));
+        assert!(!base.tree_eq(&parse("<div a=1 b=2><template><b>t</b></template>x</div>")));
+        assert!(!base.tree_eq(&parse("<div a=1 b=2><template><i>t</i></template>y</div>")));
+        assert!(!base.tree_eq(&parse(
+            "<div a=1 b=2><template><i>t</i></template>x<p></div>"
+        )));
+    }
+
+    /// Tests tree_eq() across node kinds and namespaces.
+    ///
+    /// Edge case: elements with the same local name in different namespaces,
+    /// and nodes of different kinds, are not equal.
+    #[test]
+    fn kinds_and_namespaces() {
+        let doc = parse_html().one("<a></a><svg><a></a></svg><!--a-->");
+        let html_a = doc.select_first("a").unwrap();
+        let svg_a = doc.select_first("svg a").unwrap();
+        let body = doc.select_first("body").unwrap();
+        let comment = body.as_node().last_child().unwrap();
+
+        assert!(html_a.as_node().tree_eq(html_a.as_node()));
+        assert!(!html_a.as_node().tree_eq(svg_a.as_node()));
+        assert!(!comment.tree_eq(html_a.as_node()));
+    }
+}
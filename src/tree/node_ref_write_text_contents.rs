@@ -0,0 +1,134 @@
+//! Streaming of text contents into writers.
+
+use super::node_ref_collapse_whitespace::BLOCK_ELEMENTS;
+use super::NodeRef;
+use crate::iter::NodeEdge;
+use std::{fmt, io};
+
+/// Text streaming methods for NodeRef.
+///
+/// Provides [`NodeRef::text_contents()`] without building a `String`.
+impl NodeRef {
+    /// Writes the concatenation of all text nodes in this subtree to
+    /// `writer`.
+    ///
+    /// With a `block_separator`, the separator is written between the text
+    /// of different block-level HTML elements such as `<p>`, `<li>` and
+    /// `<br>`, but never before the first text or after the last.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from `writer`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use brik::parse_html;
+    /// use brik::traits::*;
+    ///
+    /// let doc = parse_html().one("<h1>Title</h1><p>One <b>two</b></p><p>Three</p>");
+    /// let mut text = String::new();
+    /// doc.write_text_contents(&mut text, Some("\n")).unwrap();
+    /// assert_eq!(text, "Title\nOne two\nThree");
+    /// ```
+    pub fn write_text_contents<W: fmt::Write>(
+        &self,
+        writer: &mut W,
+        block_separator: Option<&str>,
+    ) -> fmt::Result {
+        let mut pending = false;
+        let mut written = false;
+        for edge in self.traverse_inclusive() {
+            let (node, start) = match edge {
+                NodeEdge::Start(node) => (node, true),
+                NodeEdge::End(node) => (node, false),
+            };
+            if let Some(text) = node.as_text() {
+                if start {
+                    let text = text.borrow();
+                    if text.is_empty() {
+                        continue;
+                    }
+                    if let (true, Some(separator)) = (pending && written, block_separator) {
+                        writer.write_str(separator)?;
+                    }
+                    writer.write_str(&text)?;
+                    pending = false;
+                    written = true;
+                }
+            } else if let Some(element) = node.as_element() {
+                pending |=
+                    element.name.ns == ns!(html) && BLOCK_ELEMENTS.contains(&&*element.name.local);
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes the concatenation of all text nodes in this subtree to an I/O
+    /// writer, like [`NodeRef::write_text_contents()`].
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from `writer`.
+    pub fn write_text_contents_io<W: io::Write>(
+        &self,
+        writer: &mut W,
+        block_separator: Option<&str>,
+    ) -> io::Result<()> {
+        let mut adapter = IoAdapter {
+            inner: writer,
+            error: None,
+        };
+        match self.write_text_contents(&mut adapter, block_separator) {
+            Ok(()) => Ok(()),
+            Err(fmt::Error) => Err(adapter
+                .error
+                .unwrap_or_else(|| io::Error::other("formatter error"))),
+        }
+    }
+}
+
+/// Adapts an I/O writer to `fmt::Write`, keeping the first I/O error.
+struct IoAdapter<'a, W: io::Write> {
+    /// The wrapped writer.
+    inner: &'a mut W,
+    /// The error that stopped writing, if any.
+    error: Option<io::Error>,
+}
+
+/// Implements fmt::Write for IoAdapter.
+///
+/// Provides writing of UTF-8 text to the wrapped writer.
+impl<W: io::Write> fmt::Write for IoAdapter<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.inner.write_all(s.as_bytes()).map_err(|error| {
+            self.error = Some(error);
+            fmt::Error
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::html5ever::tendril::TendrilSink;
+    use crate::parse_html;
+
+    /// Tests streaming with and without block separators.
+    ///
+    /// Verifies that the output without a separator matches
+    /// `text_contents()`, and that separators are not repeated for nested
+    /// or empty blocks.
+    #[test]
+    fn matches_text_contents() {
+        let doc = parse_html()
+            .one("<ul><li><p>a</p></li><li></li><li>b<br>c</li></ul><div><div>d</div></div>");
+        let mut plain = Vec::new();
+        doc.write_text_contents_io(&mut plain, None).unwrap();
+        assert_eq!(String::from_utf8(plain).unwrap(), doc.text_contents());
+
+        let mut separated = Vec::new();
+        doc.write_text_contents_io(&mut separated, Some(" | "))
+            .unwrap();
+        assert_eq!(String::from_utf8(separated).unwrap(), "a | b | c | d");
+    }
+}
@@ -0,0 +1,67 @@
+//! Source markup recorded for nodes.
+
+use super::{NodeData, NodeRef};
+use crate::attributes::Attributes;
+use html5ever::tendril::StrTendril;
+use html5ever::QualName;
+
+/// The markup a node was parsed from, with the node's value at the time,
+/// recorded with [`ParseOpts::preserve_source`](crate::ParseOpts::preserve_source).
+#[derive(Debug)]
+pub(crate) enum SourceMarkup {
+    /// The start tag of an element.
+    StartTag {
+        /// The start tag as written in the source.
+        markup: String,
+        /// The end tag as written in the source, if it was not omitted.
+        end_markup: Option<String>,
+        /// The element's name when it was parsed.
+        name: QualName,
+        /// The element's attributes when it was parsed.
+        attributes: Attributes,
+    },
+    /// The text of a text node.
+    Text {
+        /// The text as written in the source, before decoding.
+        markup: String,
+        /// The node's text when it was parsed.
+        text: StrTendril,
+    },
+}
+
+/// Methods for SourceMarkup.
+///
+/// Provides access to the markup and change detection.
+impl SourceMarkup {
+    /// Returns the markup as written in the source.
+    pub(crate) fn markup(&self) -> &str {
+        match self {
+            SourceMarkup::StartTag { markup, .. } | SourceMarkup::Text { markup, .. } => markup,
+        }
+    }
+
+    /// Returns the end tag of an element as written in the source.
+    pub(crate) fn end_markup(&self) -> Option<&str> {
+        match self {
+            SourceMarkup::StartTag { end_markup, .. } => end_markup.as_deref(),
+            SourceMarkup::Text { .. } => None,
+        }
+    }
+
+    /// Returns whether `node` still has the value it was parsed with, so
+    /// the markup can be written in place of serializing it.
+    pub(crate) fn is_unchanged(&self, node: &NodeRef) -> bool {
+        match (self, node.data()) {
+            (
+                SourceMarkup::StartTag {
+                    name, attributes, ..
+                },
+                NodeData::Element(element),
+            ) => element.name == *name && *element.attributes.borrow() == *attributes,
+            (SourceMarkup::Text { text, .. }, NodeData::Text(current)) => {
+                *current.borrow() == *text
+            }
+            _ => false,
+        }
+    }
+}
@@ -0,0 +1,40 @@
+/// Options for [`NodeRef::text_contents_with()`](crate::NodeRef::text_contents_with).
+///
+/// The defaults give readable text: the contents of `<script>`, `<style>`
+/// and `<template>` elements are skipped and whitespace is collapsed, with
+/// no separator between elements.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextOptions {
+    /// Local names of HTML elements whose text is skipped.
+    pub skip_elements: Vec<String>,
+    /// Collapse runs of ASCII whitespace to a single space and trim the
+    /// text between separators.
+    pub collapse_whitespace: bool,
+    /// Text written between the text on either side of an element boundary:
+    /// the start or end of an element.
+    ///
+    /// Empty text between boundaries is dropped, so separators are never
+    /// doubled, leading or trailing.
+    pub separator: Option<String>,
+    /// Only separate text at the boundaries of block-level HTML elements,
+    /// such as `<p>`, `<li>` and `<br>`, not inline ones such as `<b>`.
+    pub blocks_only: bool,
+}
+
+/// Implements Default for TextOptions.
+///
+/// Provides the readable text defaults.
+impl Default for TextOptions {
+    fn default() -> Self {
+        TextOptions {
+            skip_elements: vec![
+                "script".to_string(),
+                "style".to_string(),
+                "template".to_string(),
+            ],
+            collapse_whitespace: true,
+            separator: None,
+            blocks_only: false,
+        }
+    }
+}
@@ -0,0 +1,13 @@
+use crate::tree::NodeRef;
+
+/// A reference to an `id` that no element has, found by
+/// [`dangling_references()`](super::dangling_references).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DanglingReference {
+    /// The element holding the reference.
+    pub node: NodeRef,
+    /// The name of the attribute holding the reference.
+    pub attribute: String,
+    /// The missing id.
+    pub id: String,
+}
@@ -0,0 +1,143 @@
+use super::DanglingReference;
+use crate::iter::NodeIterator;
+use crate::tree::NodeRef;
+use std::collections::HashSet;
+
+/// Attributes holding a single id reference.
+const SINGLE_REFERENCES: &[&str] = &[
+    "aria-activedescendant",
+    "aria-details",
+    "aria-errormessage",
+    "for",
+    "form",
+    "list",
+];
+
+/// Attributes holding a space-separated list of id references.
+const LIST_REFERENCES: &[&str] = &[
+    "aria-controls",
+    "aria-describedby",
+    "aria-flowto",
+    "aria-labelledby",
+    "aria-owns",
+    "headers",
+];
+
+/// Finds references to ids that no element in the tree has.
+///
+/// The references checked are internal links (`href="#x"`), `for`, `form`,
+/// `list` and `headers` attributes, and ARIA relationship attributes such
+/// as `aria-labelledby`. Internal links may also target an `<a name>`, and
+/// `href="#"` and `href="#top"` are always accepted, since browsers scroll
+/// to the top of the page for them. Fragments are compared without
+/// percent-decoding. References are returned in document order, one for
+/// each missing id.
+///
+/// # Examples
+///
+/// ```
+/// use brik::parse_html;
+/// use brik::traits::*;
+/// use brik::validate::dangling_references;
+///
+/// let doc = parse_html().one(
+///     "<a href='#intro'>Intro</a><a href='#usage'>Usage</a>\
+///      <label for=name>Name</label><input id=nmae>\
+///      <h2 id=intro>Intro</h2>",
+/// );
+/// let dangling = dangling_references(&doc);
+/// let ids: Vec<&str> = dangling.iter().map(|reference| reference.id.as_str()).collect();
+/// assert_eq!(ids, ["usage", "name"]);
+/// assert_eq!(dangling[1].attribute, "for");
+/// ```
+pub fn dangling_references(root: &NodeRef) -> Vec<DanglingReference> {
+    let mut ids = HashSet::new();
+    let mut names = HashSet::new();
+    for element in root.inclusive_descendants().elements() {
+        let attributes = element.attributes.borrow();
+        if let Some(id) = attributes.get("id") {
+            ids.insert(id.to_string());
+        }
+        if &*element.name.local == "a" {
+            if let Some(name) = attributes.get("name") {
+                names.insert(name.to_string());
+            }
+        }
+    }
+
+    let mut dangling = Vec::new();
+    for element in root.inclusive_descendants().elements() {
+        let attributes = element.attributes.borrow();
+        let mut report = |attribute: &str, id: &str| {
+            dangling.push(DanglingReference {
+                node: element.as_node().clone(),
+                attribute: attribute.to_string(),
+                id: id.to_string(),
+            });
+        };
+        if let Some(fragment) = attributes
+            .get("href")
+            .and_then(|href| href.trim().strip_prefix('#'))
+        {
+            if !fragment.is_empty()
+                && !fragment.eq_ignore_ascii_case("top")
+                && !ids.contains(fragment)
+                && !names.contains(fragment)
+            {
+                report("href", fragment);
+            }
+        }
+        for &attribute in SINGLE_REFERENCES {
+            if let Some(id) = attributes.get(attribute) {
+                if !id.is_empty() && !ids.contains(id) {
+                    report(attribute, id);
+                }
+            }
+        }
+        for &attribute in LIST_REFERENCES {
+            for id in attributes.get_tokens(attribute) {
+                if !ids.contains(id) {
+                    report(attribute, id);
+                }
+            }
+        }
+    }
+    dangling
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::html5ever::tendril::TendrilSink;
+    use crate::parse_html;
+
+    /// Tests the kinds of references checked.
+    ///
+    /// Verifies that legacy named anchors and `#top` satisfy links, that
+    /// each missing id in a reference list is reported, and that external
+    /// links are ignored.
+    #[test]
+    fn reference_kinds() {
+        let doc = parse_html().one(
+            "<a name=old></a><a href=#old>a</a><a href=#TOP>b</a>\
+             <a href=/page#gone>c</a><a href=#gone>d</a>\
+             <div id=x aria-labelledby='x y z'></div>\
+             <table><tr><td headers=x>e</td></tr></table>",
+        );
+        let dangling: Vec<(String, String)> = dangling_references(&doc)
+            .into_iter()
+            .map(|reference| (reference.attribute, reference.id))
+            .collect();
+
+        let expected = [
+            ("href", "gone"),
+            ("aria-labelledby", "y"),
+            ("aria-labelledby", "z"),
+        ];
+        let expected: Vec<(String, String)> = expected
+            .iter()
+            .map(|(attribute, id)| (attribute.to_string(), id.to_string()))
+            .collect();
+        assert_eq!(dangling, expected);
+    }
+}
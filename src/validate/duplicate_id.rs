@@ -0,0 +1,11 @@
+use crate::tree::NodeRef;
+
+/// An `id` shared by more than one element, found by
+/// [`duplicate_ids()`](super::duplicate_ids).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DuplicateId {
+    /// The duplicated id.
+    pub id: String,
+    /// The elements with the id, in document order.
+    pub nodes: Vec<NodeRef>,
+}
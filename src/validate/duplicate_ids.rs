@@ -0,0 +1,45 @@
+use super::DuplicateId;
+use crate::iter::NodeIterator;
+use crate::tree::NodeRef;
+use std::collections::HashMap;
+
+/// Finds `id` values used by more than one element.
+///
+/// Elements in any namespace are checked, comparing ids exactly. The
+/// duplicates are returned in the document order of their first use.
+///
+/// # Examples
+///
+/// ```
+/// use brik::parse_html;
+/// use brik::traits::*;
+/// use brik::validate::duplicate_ids;
+///
+/// let doc = parse_html().one("<p id=a>1</p><p id=b>2</p><p id=a>3</p>");
+/// let duplicates = duplicate_ids(&doc);
+/// assert_eq!(duplicates.len(), 1);
+/// assert_eq!(duplicates[0].id, "a");
+/// assert_eq!(duplicates[0].nodes[1].text_contents(), "3");
+/// ```
+pub fn duplicate_ids(root: &NodeRef) -> Vec<DuplicateId> {
+    let mut order = Vec::new();
+    let mut uses: HashMap<String, Vec<NodeRef>> = HashMap::new();
+    for element in root.inclusive_descendants().elements() {
+        let id = match element.attributes.borrow().get("id") {
+            Some(id) => id.to_string(),
+            None => continue,
+        };
+        let nodes = uses.entry(id.clone()).or_insert_with(|| {
+            order.push(id.clone());
+            Vec::new()
+        });
+        nodes.push(element.as_node().clone());
+    }
+    order
+        .into_iter()
+        .filter_map(|id| {
+            let nodes = uses.remove(&id)?;
+            (nodes.len() > 1).then_some(DuplicateId { id, nodes })
+        })
+        .collect()
+}
@@ -0,0 +1,41 @@
+//! Conformance checks for HTML content models.
+//!
+//! [`validate()`] walks a tree and reports elements placed where their
+//! content model does not allow them, such as `<li>` outside a list or
+//! `<div>` inside `<p>`. The parser repairs most of these in markup it
+//! reads, so the checks are mainly useful for trees that are built or
+//! edited in code. Each [`Violation`] carries the offending node and its
+//! path from the root of the tree.
+//!
+//! [`duplicate_ids()`] and [`dangling_references()`] check the ids in a
+//! tree: ids used more than once, and links, labels and ARIA attributes
+//! that refer to ids no element has.
+
+/// Dangling reference report.
+mod dangling_reference;
+/// Dangling reference check.
+mod dangling_references;
+/// Duplicate id report.
+mod duplicate_id;
+/// Duplicate id check.
+mod duplicate_ids;
+/// Node paths.
+mod node_path;
+/// Validation options.
+mod validate_options;
+/// Tree validation.
+mod validate_tree;
+/// Violation report.
+mod violation;
+/// Violation kinds.
+mod violation_kind;
+
+pub use dangling_reference::DanglingReference;
+pub use dangling_references::dangling_references;
+pub use duplicate_id::DuplicateId;
+pub use duplicate_ids::duplicate_ids;
+pub use node_path::node_path;
+pub use validate_options::ValidateOptions;
+pub use validate_tree::{validate, validate_with_options};
+pub use violation::Violation;
+pub use violation_kind::ViolationKind;
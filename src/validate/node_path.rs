@@ -0,0 +1,53 @@
+use crate::tree::{NodeData, NodeRef};
+
+/// Returns the path to a node from the root of its tree.
+///
+/// Each step is an element name, `text()`, `comment()` or `node()` with a
+/// 1-based index among siblings of that kind, as in XPath, for example
+/// `/html[1]/body[1]/p[2]/text()[1]`. Document and document fragment roots
+/// contribute no step, so the path of a root is `/`. Template contents
+/// are a separate tree, so paths inside them start at the template's
+/// contents.
+///
+/// # Examples
+///
+/// ```
+/// use brik::parse_html;
+/// use brik::traits::*;
+/// use brik::validate::node_path;
+///
+/// let doc = parse_html().one("<p>a</p><p>b <em>c</em></p>");
+/// let em = doc.select_first("em").unwrap();
+/// assert_eq!(node_path(em.as_node()), "/html[1]/body[1]/p[2]/em[1]");
+/// assert_eq!(node_path(&doc), "/");
+/// ```
+pub fn node_path(node: &NodeRef) -> String {
+    let mut steps = Vec::new();
+    for node in node.inclusive_ancestors() {
+        let step = match step_name(&node) {
+            Some(step) => step,
+            None => continue,
+        };
+        let index = 1 + node
+            .preceding_siblings()
+            .filter(|sibling| step_name(sibling).as_deref() == Some(step.as_str()))
+            .count();
+        steps.push(format!("/{}[{}]", step, index));
+    }
+    if steps.is_empty() {
+        return "/".to_string();
+    }
+    steps.reverse();
+    steps.concat()
+}
+
+/// Returns the name of a node's path step, or `None` for tree roots.
+fn step_name(node: &NodeRef) -> Option<String> {
+    match node.data() {
+        NodeData::Element(element) => Some(element.name.local.to_string()),
+        NodeData::Text(_) => Some("text()".to_string()),
+        NodeData::Comment(_) => Some("comment()".to_string()),
+        NodeData::Document(_) | NodeData::DocumentFragment => None,
+        _ => Some("node()".to_string()),
+    }
+}
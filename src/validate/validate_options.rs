@@ -0,0 +1,12 @@
+/// Options for [`validate_with_options()`](super::validate_with_options).
+///
+/// The defaults check content models only.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ValidateOptions {
+    /// Attributes that elements must have, as `(tag, attribute)` pairs, for
+    /// example `("img", "alt")`.
+    ///
+    /// Tag and attribute names are compared ignoring ASCII case, and only
+    /// HTML elements are checked.
+    pub required_attributes: Vec<(String, String)>,
+}
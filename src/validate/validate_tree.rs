@@ -0,0 +1,241 @@
+use super::{node_path, ValidateOptions, Violation, ViolationKind};
+use crate::iter::NodeIterator;
+use crate::node_data_ref::NodeDataRef;
+use crate::tree::{ElementData, NodeRef};
+
+/// Checks a tree against HTML content models.
+///
+/// This is equivalent to [`validate_with_options()`] with default options.
+///
+/// # Examples
+///
+/// ```
+/// use brik::builder::Element;
+/// use brik::validate::{validate, ViolationKind};
+///
+/// let list = Element::new("div")
+///     .child(Element::new("li").text("Orphan"))
+///     .child(Element::new("p").child(Element::new("div")))
+///     .build();
+///
+/// let violations = validate(&list);
+/// assert_eq!(violations.len(), 2);
+/// assert_eq!(violations[0].kind, ViolationKind::MisplacedElement);
+/// assert_eq!(violations[0].path, "/div[1]/li[1]");
+/// assert_eq!(violations[1].kind, ViolationKind::NonPhrasingContent);
+/// ```
+pub fn validate(root: &NodeRef) -> Vec<Violation> {
+    validate_with_options(root, &ValidateOptions::default())
+}
+
+/// Checks a tree against HTML content models, with options.
+///
+/// The root and its descendants are checked in document order, and the
+/// violations are returned in that order. Only HTML elements are checked;
+/// an element's parent is only checked when it is an element, so the top
+/// level of a fragment may hold any content. An element can break more than
+/// one rule.
+///
+/// The checks are:
+///
+/// - Elements that belong in a specific container: `<li>` in a list, `<dt>`
+///   and `<dd>` in `<dl>` or `<div>`, table parts in tables and rows,
+///   `<option>` and `<optgroup>` in selects, `<figcaption>` in `<figure>`,
+///   `<legend>` in `<fieldset>`, `<summary>` in `<details>`, and `<source>`
+///   and `<track>` in media elements.
+/// - Flow content such as `<div>` or `<ul>` inside elements that only allow
+///   phrasing content, such as `<p>`, headings and `<span>`.
+/// - Interactive content such as links, buttons and form controls inside
+///   `<a>` or `<button>`.
+/// - The attributes in [`ValidateOptions::required_attributes`].
+pub fn validate_with_options(root: &NodeRef, options: &ValidateOptions) -> Vec<Violation> {
+    let mut violations = Vec::new();
+    for element in root.inclusive_descendants().elements() {
+        if element.name.ns != ns!(html) {
+            continue;
+        }
+        let name = &*element.name.local;
+        let mut report = |kind: ViolationKind, message: String| {
+            violations.push(Violation {
+                kind,
+                node: element.as_node().clone(),
+                path: node_path(element.as_node()),
+                message,
+            });
+        };
+
+        if let (Some(parents), Some(parent)) = (allowed_parents(name), parent_name(&element)) {
+            if !parents.contains(&parent.as_str()) {
+                report(
+                    ViolationKind::MisplacedElement,
+                    format!("<{}> is not allowed in <{}>", name, parent),
+                );
+            }
+        }
+        if !is_phrasing(name) {
+            if let Some(parent) = parent_name(&element)
+                .filter(|parent| PHRASING_CONTAINERS.contains(&parent.as_str()))
+            {
+                report(
+                    ViolationKind::NonPhrasingContent,
+                    format!(
+                        "<{}> is not phrasing content, as <{}> requires",
+                        name, parent
+                    ),
+                );
+            }
+        }
+        if is_interactive(&element) {
+            let container = element.as_node().ancestors().elements().find(|ancestor| {
+                ancestor.name.ns == ns!(html) && matches!(&*ancestor.name.local, "a" | "button")
+            });
+            if let Some(container) = container {
+                report(
+                    ViolationKind::NestedInteractive,
+                    format!(
+                        "<{}> is interactive content inside <{}>",
+                        name, container.name.local
+                    ),
+                );
+            }
+        }
+        for (tag, attribute) in &options.required_attributes {
+            if tag.eq_ignore_ascii_case(name) {
+                let attributes = element.attributes.borrow();
+                let present = attributes
+                    .map
+                    .keys()
+                    .any(|key| key.ns == ns!() && attribute.eq_ignore_ascii_case(&key.local));
+                if !present {
+                    report(
+                        ViolationKind::MissingAttribute(attribute.clone()),
+                        format!("<{}> is missing the {} attribute", name, attribute),
+                    );
+                }
+            }
+        }
+    }
+    violations
+}
+
+/// Elements whose content model only allows phrasing content.
+const PHRASING_CONTAINERS: &[&str] = &[
+    "abbr", "b", "bdi", "bdo", "cite", "code", "data", "dfn", "em", "h1", "h2", "h3", "h4", "h5",
+    "h6", "i", "kbd", "label", "mark", "output", "p", "pre", "q", "rp", "s", "samp", "small",
+    "span", "strong", "sub", "sup", "time", "u", "var",
+];
+
+/// HTML elements that are phrasing content.
+const PHRASING: &[&str] = &[
+    "a", "abbr", "area", "audio", "b", "bdi", "bdo", "br", "button", "canvas", "cite", "code",
+    "data", "datalist", "del", "dfn", "em", "embed", "i", "iframe", "img", "input", "ins", "kbd",
+    "label", "link", "map", "mark", "meta", "meter", "noscript", "object", "output", "picture",
+    "progress", "q", "rp", "rt", "ruby", "s", "samp", "script", "select", "slot", "small", "span",
+    "strong", "sub", "sup", "template", "textarea", "time", "u", "var", "video", "wbr",
+];
+
+/// Returns the parents an element is restricted to, if any.
+fn allowed_parents(name: &str) -> Option<&'static [&'static str]> {
+    Some(match name {
+        "li" => &["ul", "ol", "menu"],
+        "dt" | "dd" => &["dl", "div"],
+        "tr" => &["table", "thead", "tbody", "tfoot"],
+        "td" | "th" => &["tr"],
+        "thead" | "tbody" | "tfoot" | "caption" | "colgroup" => &["table"],
+        "col" => &["colgroup"],
+        "option" => &["select", "datalist", "optgroup"],
+        "optgroup" => &["select"],
+        "figcaption" => &["figure"],
+        "legend" => &["fieldset"],
+        "summary" => &["details"],
+        "source" => &["audio", "video", "picture"],
+        "track" => &["audio", "video"],
+        _ => return None,
+    })
+}
+
+/// Returns the local name of an element's parent, if the parent is an HTML
+/// element.
+fn parent_name(element: &NodeDataRef<ElementData>) -> Option<String> {
+    let parent = element.as_node().parent()?;
+    let parent = parent.as_element()?;
+    if parent.name.ns != ns!(html) {
+        return None;
+    }
+    Some(parent.name.local.to_string())
+}
+
+/// Returns whether an HTML element is phrasing content.
+///
+/// Autonomous custom elements, whose names contain a hyphen, are treated as
+/// phrasing content.
+fn is_phrasing(name: &str) -> bool {
+    name.contains('-') || PHRASING.contains(&name)
+}
+
+/// Returns whether an HTML element is interactive content.
+fn is_interactive(element: &NodeDataRef<ElementData>) -> bool {
+    let attributes = element.attributes.borrow();
+    match &*element.name.local {
+        "button" | "details" | "embed" | "iframe" | "label" | "select" | "textarea" => true,
+        "a" => attributes.contains("href"),
+        "input" => !attributes
+            .get("type")
+            .is_some_and(|kind| kind.eq_ignore_ascii_case("hidden")),
+        "audio" | "video" => attributes.contains("controls"),
+        "img" => attributes.contains("usemap"),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::html5ever::tendril::TendrilSink;
+    use crate::parse_html;
+
+    /// Tests that conforming documents have no violations.
+    ///
+    /// Verifies that lists, tables, definition lists, selects and phrasing
+    /// content in paragraphs produced by the parser all pass.
+    #[test]
+    fn conforming_document() {
+        let doc = parse_html().one(
+            "<ul><li>a</li></ul><dl><div><dt>t</dt><dd>d</dd></div></dl>\
+             <table><tr><td>c</td></tr></table>\
+             <select><optgroup><option>o</option></optgroup></select>\
+             <p><a href=/x><img src=a.png></a> <my-widget></my-widget></p>\
+             <svg><a><div></div></a></svg>",
+        );
+        assert_eq!(validate(&doc), Vec::new());
+    }
+
+    /// Tests nested interactive content and required attributes.
+    ///
+    /// Verifies that a hidden input inside a button is allowed, that a
+    /// link inside a link is reported once per rule, and that required
+    /// attributes are matched ignoring ASCII case.
+    #[test]
+    fn interactive_and_required_attributes() {
+        let doc = parse_html().one(
+            "<button><input type=HIDDEN><span><a href=/x>x</a></span></button>\
+             <img src=a.png><img ALT='' src=b.png>",
+        );
+        let options = ValidateOptions {
+            required_attributes: vec![("IMG".to_string(), "alt".to_string())],
+        };
+        let violations = validate_with_options(&doc, &options);
+
+        assert_eq!(violations.len(), 2);
+        assert_eq!(violations[0].kind, ViolationKind::NestedInteractive);
+        assert_eq!(
+            violations[0].to_string(),
+            "/html[1]/body[1]/button[1]/span[1]/a[1]: <a> is interactive content inside <button>"
+        );
+        assert_eq!(
+            violations[1].kind,
+            ViolationKind::MissingAttribute("alt".to_string())
+        );
+        assert_eq!(violations[1].path, "/html[1]/body[1]/img[1]");
+    }
+}
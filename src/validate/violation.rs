@@ -0,0 +1,26 @@
+use super::ViolationKind;
+use crate::tree::NodeRef;
+use std::fmt;
+
+/// A content model violation found by [`validate()`](super::validate).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Violation {
+    /// The rule that was broken.
+    pub kind: ViolationKind,
+    /// The offending element.
+    pub node: NodeRef,
+    /// The path to the offending element, as returned by
+    /// [`node_path()`](super::node_path).
+    pub path: String,
+    /// A description of the violation.
+    pub message: String,
+}
+
+/// Implements Display for Violation.
+///
+/// Formats the violation as `path: message`.
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.path, self.message)
+    }
+}
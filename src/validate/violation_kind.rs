@@ -0,0 +1,14 @@
+/// The rule broken by a [`Violation`](super::Violation).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ViolationKind {
+    /// The element's parent is not one its content model allows, such as
+    /// `<li>` outside `<ul>`, `<ol>` or `<menu>`.
+    MisplacedElement,
+    /// The element is flow content inside an element that only allows
+    /// phrasing content, such as `<div>` inside `<p>`.
+    NonPhrasingContent,
+    /// The element is interactive content inside `<a>` or `<button>`.
+    NestedInteractive,
+    /// The element is missing a required attribute, given here.
+    MissingAttribute(String),
+}
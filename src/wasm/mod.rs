@@ -0,0 +1,11 @@
+//! JavaScript bindings for WebAssembly builds.
+//!
+//! Exposes parsing, selection and serialization through `wasm-bindgen`, so
+//! the same extraction logic can run in browser tooling and on the server.
+//! Build with `--target wasm32-unknown-unknown --features wasm` and run
+//! `wasm-bindgen` or `wasm-pack` on the output.
+
+/// Parsed document handle for JavaScript.
+mod wasm_document;
+
+pub use wasm_document::WasmDocument;
@@ -0,0 +1,168 @@
+use crate::iter::{Descendants, Elements, Select};
+use crate::tree::NodeRef;
+use html5ever::tendril::TendrilSink;
+use wasm_bindgen::prelude::*;
+
+/// A parsed HTML document, exported to JavaScript as `WasmDocument`.
+///
+/// The document is owned by the WebAssembly module; call `free()` from
+/// JavaScript when it is no longer needed.
+#[wasm_bindgen]
+#[derive(Debug, Clone)]
+pub struct WasmDocument {
+    /// The document node.
+    root: NodeRef,
+}
+
+/// Methods for WasmDocument.
+///
+/// Provides the functions exported to JavaScript.
+#[wasm_bindgen]
+impl WasmDocument {
+    /// Parses an HTML document.
+    ///
+    /// Exported to JavaScript as the `WasmDocument` constructor.
+    #[wasm_bindgen(constructor)]
+    pub fn parse(html: &str) -> WasmDocument {
+        WasmDocument {
+            root: crate::parse_html().one(html),
+        }
+    }
+
+    /// Returns the outer HTML of every element matching `selectors`, in
+    /// document order.
+    ///
+    /// # Errors
+    ///
+    /// Returns a JavaScript error if `selectors` is not a valid selector
+    /// list.
+    pub fn select(&self, selectors: &str) -> Result<Vec<String>, JsError> {
+        self.map_matches(selectors, |element| element.to_string())
+    }
+
+    /// Returns the text content of every element matching `selectors`, in
+    /// document order.
+    ///
+    /// # Errors
+    ///
+    /// Returns a JavaScript error if `selectors` is not a valid selector
+    /// list.
+    #[wasm_bindgen(js_name = selectText)]
+    pub fn select_text(&self, selectors: &str) -> Result<Vec<String>, JsError> {
+        self.map_matches(selectors, NodeRef::text_contents)
+    }
+
+    /// Returns the value of attribute `name` on every element matching
+    /// `selectors` that has it, in document order.
+    ///
+    /// # Errors
+    ///
+    /// Returns a JavaScript error if `selectors` is not a valid selector
+    /// list.
+    #[wasm_bindgen(js_name = selectAttribute)]
+    pub fn select_attribute(&self, selectors: &str, name: &str) -> Result<Vec<String>, JsError> {
+        let matches = self
+            .matches(selectors)
+            .map_err(|message| JsError::new(&message))?;
+        let values = matches
+            .filter_map(|element| {
+                let value = element.attributes.borrow().get(name).map(str::to_string);
+                value
+            })
+            .collect();
+        Ok(values)
+    }
+
+    /// Serializes the document as HTML.
+    #[wasm_bindgen(js_name = toHtml)]
+    pub fn to_html(&self) -> String {
+        self.root.to_string()
+    }
+
+    /// Returns the text content of the whole document.
+    #[wasm_bindgen(js_name = textContent)]
+    pub fn text_content(&self) -> String {
+        self.root.text_contents()
+    }
+}
+
+/// Private methods for WasmDocument.
+///
+/// Provides selection shared by the exported functions.
+impl WasmDocument {
+    /// Applies `f` to every element matching `selectors`.
+    fn map_matches<F>(&self, selectors: &str, f: F) -> Result<Vec<String>, JsError>
+    where
+        F: Fn(&NodeRef) -> String,
+    {
+        let matches = self
+            .matches(selectors)
+            .map_err(|message| JsError::new(&message))?;
+        Ok(matches.map(|element| f(element.as_node())).collect())
+    }
+
+    /// Returns the elements matching `selectors`.
+    ///
+    /// Errors are reported as messages rather than `JsError`s, which can
+    /// only be created when running under a JavaScript host.
+    fn matches(&self, selectors: &str) -> Result<Select<Elements<Descendants>>, String> {
+        self.root
+            .select(selectors)
+            .map_err(|_| format!("invalid selector: {selectors}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WasmDocument;
+
+    /// Tests the exported functions on a native build.
+    ///
+    /// Verifies selection of outer HTML, text and attributes, and
+    /// serialization, which do not need a JavaScript host.
+    #[test]
+    fn wasm_document_select() {
+        let doc = WasmDocument::parse("<a href=/a>A</a><a>B</a>");
+
+        assert_eq!(
+            doc.select("a").ok().unwrap(),
+            ["<a href=\"/a\">A</a>", "<a>B</a>"]
+        );
+        assert_eq!(doc.select_text("a").ok().unwrap(), ["A", "B"]);
+        assert_eq!(doc.select_attribute("a", "href").ok().unwrap(), ["/a"]);
+        assert_eq!(
+            doc.to_html(),
+            "<html><head></head><body><a href=\"/a\">A</a><a>B</a></body></html>"
+        );
+    }
+
+    /// Tests invalid selectors on a native build.
+    ///
+    /// Verifies the error message without converting it to a `JsError`,
+    /// which panics outside a JavaScript host.
+    #[test]
+    fn wasm_document_invalid_selector() {
+        let doc = WasmDocument::parse("<a>A</a>");
+        assert_eq!(doc.matches("a[").err().unwrap(), "invalid selector: a[");
+    }
+}
+
+/// Tests run under a JavaScript host with `wasm-bindgen-test`.
+#[cfg(all(test, target_arch = "wasm32"))]
+mod wasm_tests {
+    use super::WasmDocument;
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    /// Tests the exported functions through their JavaScript errors.
+    ///
+    /// Verifies that selection still works in WebAssembly and that invalid
+    /// selectors become `JsError`s from every selecting function.
+    #[wasm_bindgen_test]
+    fn wasm_document_errors() {
+        let doc = WasmDocument::parse("<a href=/a>A</a>");
+        assert_eq!(doc.select_text("a").ok().unwrap(), ["A"]);
+        assert!(doc.select("a[").is_err());
+        assert!(doc.select_text("a[").is_err());
+        assert!(doc.select_attribute("a[", "href").is_err());
+    }
+}